@@ -12,11 +12,13 @@ use quaint::connector::SqlFamily;
 use sql_schema_describer::*;
 use tracing::debug;
 
-/// Calculate a data model from a database schema.
+/// Calculate a data model from a database schema. The migrations table is left out, it is `None` when
+/// it is not in the introspected schema.
 pub fn calculate_datamodel(
     schema: &SqlSchema,
     family: &SqlFamily,
     previous_data_model: &Datamodel,
+    migrations_table_name: Option<&str>,
 ) -> SqlIntrospectionResult<IntrospectionResult> {
     debug!("Calculating data model.");
 
@@ -24,7 +26,13 @@ pub fn calculate_datamodel(
     let mut data_model = Datamodel::new();
 
     // 1to1 translation of the sql schema
-    introspect(schema, &mut version_check, &mut data_model, *family)?;
+    introspect(
+        schema,
+        &mut version_check,
+        &mut data_model,
+        *family,
+        migrations_table_name,
+    )?;
 
     // our opinionation about valid names
     sanitize_datamodel_names(&mut data_model, family);
@@ -75,6 +83,7 @@ mod tests {
                 is_embedded: false,
                 is_commented_out: false,
                 is_ignored: false,
+                soft_delete_field: None,
                 fields: vec![
                     Field::ScalarField(ScalarField::new(
                         "optional",
@@ -94,6 +103,7 @@ mod tests {
                         is_updated_at: false,
                         is_commented_out: false,
                         is_ignored: false,
+                        generation_expression: None,
                     }),
                     Field::ScalarField(ScalarField::new(
                         "list",
@@ -117,18 +127,21 @@ mod tests {
                         tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Nullable),
                         default: None,
                         auto_increment: false,
+                        generation_expression: None,
                     },
                     Column {
                         name: "required".to_string(),
                         tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
                         default: None,
                         auto_increment: true,
+                        generation_expression: None,
                     },
                     Column {
                         name: "list".to_string(),
                         tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::List),
                         default: None,
                         auto_increment: false,
+                        generation_expression: None,
                     },
                 ],
                 indices: vec![],
@@ -143,7 +156,7 @@ mod tests {
             sequences: vec![],
         };
         let introspection_result =
-            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new()).expect("calculate data model");
+            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), None).expect("calculate data model");
 
         assert_eq!(introspection_result.data_model, ref_data_model);
     }
@@ -160,6 +173,7 @@ mod tests {
                     is_embedded: false,
                     is_commented_out: false,
                     is_ignored: false,
+                    soft_delete_field: None,
                     fields: vec![Field::ScalarField(ScalarField {
                         name: "primary".to_string(),
                         arity: FieldArity::Required,
@@ -180,6 +194,7 @@ mod tests {
                         is_updated_at: false,
                         is_commented_out: false,
                         is_ignored: false,
+                        generation_expression: None,
                     })],
                     is_generated: false,
                     indices: vec![],
@@ -193,6 +208,7 @@ mod tests {
                     is_embedded: false,
                     is_commented_out: false,
                     is_ignored: false,
+                    soft_delete_field: None,
                     fields: vec![Field::ScalarField(ScalarField {
                         name: "primary".to_string(),
                         arity: FieldArity::Required,
@@ -213,6 +229,7 @@ mod tests {
                         is_updated_at: false,
                         is_commented_out: false,
                         is_ignored: false,
+                        generation_expression: None,
                     })],
                     is_generated: false,
                     indices: vec![],
@@ -226,6 +243,7 @@ mod tests {
                     is_embedded: false,
                     is_commented_out: false,
                     is_ignored: false,
+                    soft_delete_field: None,
                     fields: vec![Field::ScalarField(ScalarField {
                         name: "primary".to_string(),
                         arity: FieldArity::Required,
@@ -246,6 +264,7 @@ mod tests {
                         is_updated_at: false,
                         is_commented_out: false,
                         is_ignored: false,
+                        generation_expression: None,
                     })],
                     is_generated: false,
                     indices: vec![],
@@ -269,6 +288,7 @@ mod tests {
                         },
                         default: None,
                         auto_increment: true,
+                        generation_expression: None,
                     }],
                     indices: vec![],
                     primary_key: Some(PrimaryKey {
@@ -290,6 +310,7 @@ mod tests {
                         },
                         default: None,
                         auto_increment: false,
+                        generation_expression: None,
                     }],
                     indices: vec![],
                     primary_key: Some(PrimaryKey {
@@ -311,6 +332,7 @@ mod tests {
                         },
                         default: None,
                         auto_increment: true,
+                        generation_expression: None,
                     }],
                     indices: vec![],
                     primary_key: Some(PrimaryKey {
@@ -327,7 +349,7 @@ mod tests {
             sequences: vec![],
         };
         let introspection_result =
-            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new()).expect("calculate data model");
+            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), None).expect("calculate data model");
 
         assert_eq!(introspection_result.data_model, ref_data_model);
     }
@@ -342,6 +364,7 @@ mod tests {
                 is_embedded: false,
                 is_commented_out: false,
                 is_ignored: false,
+                soft_delete_field: None,
                 fields: vec![
                     Field::ScalarField(ScalarField::new(
                         "non_unique",
@@ -361,6 +384,7 @@ mod tests {
                         is_updated_at: false,
                         is_commented_out: false,
                         is_ignored: false,
+                        generation_expression: None,
                     }),
                 ],
                 is_generated: false,
@@ -379,12 +403,14 @@ mod tests {
                         tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Nullable),
                         default: None,
                         auto_increment: false,
+                        generation_expression: None,
                     },
                     Column {
                         name: "unique".to_string(),
                         tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
                         default: None,
                         auto_increment: false,
+                        generation_expression: None,
                     },
                 ],
                 indices: vec![Index {
@@ -399,7 +425,7 @@ mod tests {
             sequences: vec![],
         };
         let introspection_result =
-            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new()).expect("calculate data model");
+            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), None).expect("calculate data model");
 
         assert_eq!(introspection_result.data_model, ref_data_model);
     }
@@ -415,6 +441,7 @@ mod tests {
                     is_embedded: false,
                     is_commented_out: false,
                     is_ignored: false,
+                    soft_delete_field: None,
                     fields: vec![
                         Field::ScalarField(ScalarField {
                             name: "id".to_string(),
@@ -436,6 +463,7 @@ mod tests {
                             is_updated_at: false,
                             is_commented_out: false,
                             is_ignored: false,
+                            generation_expression: None,
                         }),
                         Field::ScalarField(ScalarField::new(
                             "name",
@@ -472,6 +500,7 @@ mod tests {
                     is_embedded: false,
                     is_commented_out: false,
                     is_ignored: false,
+                    soft_delete_field: None,
                     fields: vec![
                         Field::ScalarField(ScalarField {
                             name: "id".to_string(),
@@ -493,6 +522,7 @@ mod tests {
                             is_updated_at: false,
                             is_commented_out: false,
                             is_ignored: false,
+                            generation_expression: None,
                         }),
                         Field::ScalarField(ScalarField {
                             name: "city_id".to_string(),
@@ -514,6 +544,7 @@ mod tests {
                             is_updated_at: false,
                             is_commented_out: false,
                             is_ignored: false,
+                            generation_expression: None,
                         }),
                         Field::ScalarField(ScalarField {
                             name: "city_name".to_string(),
@@ -535,6 +566,7 @@ mod tests {
                             is_updated_at: false,
                             is_commented_out: false,
                             is_ignored: false,
+                            generation_expression: None,
                         }),
                         Field::RelationField(RelationField::new(
                             "City",
@@ -571,6 +603,7 @@ mod tests {
                             },
                             default: None,
                             auto_increment: true,
+                            generation_expression: None,
                         },
                         Column {
                             name: "name".to_string(),
@@ -582,6 +615,7 @@ mod tests {
                             },
                             default: None,
                             auto_increment: false,
+                            generation_expression: None,
                         },
                     ],
                     indices: vec![],
@@ -605,6 +639,7 @@ mod tests {
                             },
                             default: None,
                             auto_increment: true,
+                            generation_expression: None,
                         },
                         Column {
                             name: "city-id".to_string(),
@@ -616,6 +651,7 @@ mod tests {
                             },
                             default: None,
                             auto_increment: false,
+                            generation_expression: None,
                         },
                         Column {
                             name: "city-name".to_string(),
@@ -627,6 +663,7 @@ mod tests {
                             },
                             default: None,
                             auto_increment: false,
+                            generation_expression: None,
                         },
                     ],
                     indices: vec![],
@@ -650,7 +687,7 @@ mod tests {
             sequences: vec![],
         };
         let introspection_result =
-            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new()).expect("calculate data model");
+            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), None).expect("calculate data model");
 
         assert_eq!(introspection_result.data_model, expected_data_model);
     }
@@ -665,6 +702,7 @@ mod tests {
                 is_embedded: false,
                 is_commented_out: false,
                 is_ignored: false,
+                soft_delete_field: None,
                 fields: vec![
                     Field::ScalarField(ScalarField {
                         name: "id".to_string(),
@@ -686,6 +724,7 @@ mod tests {
                         is_updated_at: false,
                         is_commented_out: false,
                         is_ignored: false,
+                        generation_expression: None,
                     }),
                     Field::ScalarField(ScalarField::new(
                         "name",
@@ -737,6 +776,7 @@ mod tests {
                         },
                         default: None,
                         auto_increment: true,
+                        generation_expression: None,
                     },
                     Column {
                         name: "name".to_string(),
@@ -748,6 +788,7 @@ mod tests {
                         },
                         default: None,
                         auto_increment: false,
+                        generation_expression: None,
                     },
                     Column {
                         name: "lastname".to_string(),
@@ -759,6 +800,7 @@ mod tests {
                         },
                         default: None,
                         auto_increment: false,
+                        generation_expression: None,
                     },
                 ],
                 indices: vec![Index {
@@ -777,7 +819,7 @@ mod tests {
             sequences: vec![],
         };
         let introspection_result =
-            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new()).expect("calculate data model");
+            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), None).expect("calculate data model");
 
         assert_eq!(introspection_result.data_model, ref_data_model);
     }
@@ -793,6 +835,7 @@ mod tests {
                     is_embedded: false,
                     is_commented_out: false,
                     is_ignored: false,
+                    soft_delete_field: None,
                     fields: vec![
                         Field::ScalarField(ScalarField {
                             name: "id".to_string(),
@@ -814,6 +857,7 @@ mod tests {
                             is_updated_at: false,
                             is_commented_out: false,
                             is_ignored: false,
+                            generation_expression: None,
                         }),
                         Field::ScalarField(ScalarField::new(
                             "name",
@@ -850,6 +894,7 @@ mod tests {
                     is_embedded: false,
                     is_commented_out: false,
                     is_ignored: false,
+                    soft_delete_field: None,
                     fields: vec![
                         Field::ScalarField(ScalarField {
                             name: "id".to_string(),
@@ -871,6 +916,7 @@ mod tests {
                             is_updated_at: false,
                             is_commented_out: false,
                             is_ignored: false,
+                            generation_expression: None,
                         }),
                         Field::ScalarField(ScalarField::new(
                             "city_id",
@@ -919,6 +965,7 @@ mod tests {
                             },
                             default: None,
                             auto_increment: true,
+                            generation_expression: None,
                         },
                         Column {
                             name: "name".to_string(),
@@ -930,6 +977,7 @@ mod tests {
                             },
                             default: None,
                             auto_increment: false,
+                            generation_expression: None,
                         },
                     ],
                     indices: vec![],
@@ -953,6 +1001,7 @@ mod tests {
                             },
                             default: None,
                             auto_increment: true,
+                            generation_expression: None,
                         },
                         Column {
                             name: "city_id".to_string(),
@@ -964,6 +1013,7 @@ mod tests {
                             },
                             default: None,
                             auto_increment: false,
+                            generation_expression: None,
                         },
                     ],
                     indices: vec![],
@@ -986,7 +1036,7 @@ mod tests {
             sequences: vec![],
         };
         let introspection_result =
-            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new()).expect("calculate data model");
+            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), None).expect("calculate data model");
 
         assert_eq!(introspection_result.data_model, ref_data_model);
     }
@@ -1027,8 +1077,67 @@ mod tests {
             sequences: vec![],
         };
         let introspection_result =
-            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new()).expect("calculate data model");
+            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), None).expect("calculate data model");
 
         assert_eq!(introspection_result.data_model, ref_data_model);
     }
+    #[test]
+    fn only_the_configured_migrations_table_is_left_out() {
+        let migrations_table = |name: &str| Table {
+            name: name.to_string(),
+            columns: [
+                "id",
+                "checksum",
+                "finished_at",
+                "migration_name",
+                "logs",
+                "rolled_back_at",
+                "started_at",
+                "applied_steps_count",
+            ]
+            .iter()
+            .map(|column_name| Column {
+                name: column_name.to_string(),
+                tpe: ColumnType::pure(ColumnTypeFamily::String, ColumnArity::Nullable),
+                default: None,
+                auto_increment: false,
+                generation_expression: None,
+            })
+            .collect(),
+            indices: vec![],
+            primary_key: Some(PrimaryKey {
+                columns: vec!["id".to_string()],
+                sequence: None,
+                constraint_name: None,
+            }),
+            foreign_keys: vec![],
+        };
+
+        let schema = SqlSchema {
+            tables: vec![
+                migrations_table("_prisma_migrations"),
+                migrations_table("schema_history"),
+            ],
+            enums: vec![],
+            sequences: vec![],
+        };
+
+        let introspection_result =
+            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), Some("schema_history"))
+                .expect("calculate data model");
+
+        let table_names: Vec<&str> = introspection_result
+            .data_model
+            .models
+            .iter()
+            .map(|model| model.database_name.as_deref().unwrap_or(&model.name))
+            .collect();
+
+        assert_eq!(table_names, &["_prisma_migrations"]);
+
+        let introspection_result =
+            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), None).expect("calculate data model");
+
+        assert_eq!(introspection_result.data_model.models.len(), 2);
+    }
 }
@@ -16,12 +16,13 @@ pub fn introspect(
     version_check: &mut VersionChecker,
     data_model: &mut Datamodel,
     sql_family: SqlFamily,
+    migrations_table_name: Option<&str>,
 ) -> Result<(), SqlError> {
     for table in schema
         .tables
         .iter()
         .filter(|table| !is_old_migration_table(&table))
-        .filter(|table| !is_new_migration_table(&table))
+        .filter(|table| !is_new_migration_table(&table, migrations_table_name))
         .filter(|table| !is_prisma_1_point_1_or_2_join_table(&table))
         .filter(|table| !is_prisma_1_point_0_join_table(&table))
         .filter(|table| !is_relay_table(&table))
@@ -27,8 +27,10 @@ pub fn is_old_migration_table(table: &Table) -> bool {
         && table.columns.iter().any(|c| c.name == "finished_at")
 }
 
-pub fn is_new_migration_table(table: &Table) -> bool {
-    table.name == "_prisma_migrations"
+/// The migrations table of the migration engine, under its configured name. `None` when it does not
+/// live in the introspected schema.
+pub fn is_new_migration_table(table: &Table, migrations_table_name: Option<&str>) -> bool {
+    migrations_table_name == Some(table.name.as_str())
         && table.columns.iter().any(|c| c.name == "id")
         && table.columns.iter().any(|c| c.name == "checksum")
         && table.columns.iter().any(|c| c.name == "finished_at")
@@ -164,6 +166,7 @@ pub(crate) fn calculate_scalar_field(table: &Table, column: &Column, family: &Sq
         is_updated_at: false,
         is_commented_out: false,
         is_ignored: false,
+        generation_expression: column.generation_expression.clone(),
     }
 }
 
@@ -325,6 +328,8 @@ pub(crate) fn calculate_scalar_field_type_for_native_type(column: &Column) -> Fi
         ColumnTypeFamily::Boolean => FieldType::Base(ScalarType::Boolean, None),
         ColumnTypeFamily::String => FieldType::Base(ScalarType::String, None),
         ColumnTypeFamily::DateTime => FieldType::Base(ScalarType::DateTime, None),
+        ColumnTypeFamily::Date => FieldType::Base(ScalarType::Date, None),
+        ColumnTypeFamily::Time => FieldType::Base(ScalarType::Time, None),
         ColumnTypeFamily::Json => FieldType::Base(ScalarType::Json, None),
         ColumnTypeFamily::Uuid => FieldType::Base(ScalarType::String, None),
         ColumnTypeFamily::Binary => FieldType::Base(ScalarType::Bytes, None),
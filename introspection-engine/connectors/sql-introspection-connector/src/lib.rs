@@ -22,9 +22,14 @@ use tracing_futures::Instrument;
 
 pub type SqlIntrospectionResult<T> = core::result::Result<T, SqlError>;
 
+/// The default name of the migration engine's migrations table.
+const DEFAULT_MIGRATIONS_TABLE_NAME: &str = "_prisma_migrations";
+
 pub struct SqlIntrospectionConnector {
     connection_info: ConnectionInfo,
     describer: Box<dyn SqlSchemaDescriberBackend>,
+    /// The migrations table to leave out of the data model, `None` when it is in another schema.
+    migrations_table_name: Option<String>,
 }
 
 impl fmt::Debug for SqlIntrospectionConnector {
@@ -32,6 +37,7 @@ impl fmt::Debug for SqlIntrospectionConnector {
         f.debug_struct("SqlIntrospectionConnector")
             .field("connection_info", &self.connection_info)
             .field("describer", &"Box<dyn SqlSchemaDescriberBackend>")
+            .field("migrations_table_name", &self.migrations_table_name)
             .finish()
     }
 }
@@ -52,9 +58,26 @@ impl SqlIntrospectionConnector {
         Ok(SqlIntrospectionConnector {
             describer,
             connection_info,
+            migrations_table_name: Some(DEFAULT_MIGRATIONS_TABLE_NAME.to_owned()),
         })
     }
 
+    /// Use the migrations table configured with the `migrationsTable` and
+    /// `migrationsSchema` properties of the datasource.
+    pub fn with_migrations_table(mut self, name: Option<&str>, schema: Option<&str>) -> Self {
+        let in_introspected_schema = schema
+            .map(|schema| schema == self.connection_info.schema_name())
+            .unwrap_or(true);
+
+        self.migrations_table_name = if in_introspected_schema {
+            Some(name.unwrap_or(DEFAULT_MIGRATIONS_TABLE_NAME).to_owned())
+        } else {
+            None
+        };
+
+        self
+    }
+
     async fn catch<O>(&self, fut: impl Future<Output = Result<O, SqlError>>) -> ConnectorResult<O> {
         fut.await
             .map_err(|sql_introspection_error| sql_introspection_error.into_connector_error(&self.connection_info))
@@ -115,8 +138,13 @@ impl IntrospectionConnector for SqlIntrospectionConnector {
 
         let family = self.connection_info.sql_family();
 
-        let introspection_result = calculate_datamodel::calculate_datamodel(&sql_schema, &family, &previous_data_model)
-            .map_err(|sql_introspection_error| sql_introspection_error.into_connector_error(&self.connection_info))?;
+        let introspection_result = calculate_datamodel::calculate_datamodel(
+            &sql_schema,
+            &family,
+            &previous_data_model,
+            self.migrations_table_name.as_deref(),
+        )
+        .map_err(|sql_introspection_error| sql_introspection_error.into_connector_error(&self.connection_info))?;
 
         tracing::debug!("Calculating datamodel is done: {:?}", introspection_result.data_model);
 
@@ -97,8 +97,8 @@ async fn native_type_columns_feature_on(api: &TestApi) -> crate::TestResult {
           binary      Bytes    @sqlserver.Binary(230)
           varbinary   Bytes    @sqlserver.VarBinary(150)
           varbinary_2 Bytes
-          date        DateTime @sqlserver.Date
-          time        DateTime @sqlserver.Time
+          date        Date
+          time        Time
           datetime    DateTime @sqlserver.DateTime
           datetime2   DateTime
           xml         String   @sqlserver.Xml
@@ -117,8 +117,8 @@ async fn native_type_columns_feature_on(api: &TestApi) -> crate::TestResult {
             text                           String   @mysql.Text
             mediumText                     String   @mysql.MediumText
             longText                       String   @mysql.LongText
-            date                           DateTime @mysql.Date
-            timeWithPrecision              DateTime @mysql.Time(3)
+            date                           Date
+            timeWithPrecision              Time
             timeWithPrecision_no_precision DateTime @mysql.DateTime(0)
             dateTimeWithPrecision          DateTime
             timestampWithPrecision         DateTime {default} @mysql.Timestamp(3)
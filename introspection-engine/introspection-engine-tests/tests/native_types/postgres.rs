@@ -86,9 +86,9 @@ async fn native_type_columns_feature_on(api: &TestApi) -> crate::TestResult {
             bytea           Bytes
             ts              DateTime @postgres.Timestamp(0)
             tstz            DateTime @postgres.Timestamptz(2)
-            date            DateTime @postgres.Date
-            time            DateTime @postgres.Time(2)
-            time_2          DateTime @postgres.Time(6)
+            date            Date
+            time            Time     @postgres.Time(2)
+            time_2          Time     @postgres.Time(6)
             timetz          DateTime @postgres.Timetz(2)
             bool            Boolean
             bit             String   @postgres.Bit(1)
@@ -165,7 +165,7 @@ async fn native_type_array_columns_feature_on(api: &TestApi) -> crate::TestResul
           varbit_array      String[]   @postgres.VarBit(2)
           varbit_array_2    String[]   @postgres.VarBit
           timestamp_array   DateTime[] @postgres.Timestamp(4)
-          time_array        DateTime[] @postgres.Time(4)
+          time_array        Time[]     @postgres.Time(4)
         }
     "#}
     .to_string();
@@ -39,6 +39,7 @@ async fn database_description_for_mysql_should_work(api: &TestApi) -> crate::Tes
                     },
                     default: None,
                     auto_increment: true,
+                    generation_expression: None,
                 },
                 Column {
                     name: "string",
@@ -54,6 +55,7 @@ async fn database_description_for_mysql_should_work(api: &TestApi) -> crate::Tes
                     },
                     default: None,
                     auto_increment: false,
+                    generation_expression: None,
                 },
             ],
             indices: [],
@@ -102,6 +104,7 @@ async fn database_description_for_mysql_8_should_work(api: &TestApi) -> crate::T
                     },
                     default: None,
                     auto_increment: true,
+                    generation_expression: None,
                 },
                 Column {
                     name: "string",
@@ -117,6 +120,7 @@ async fn database_description_for_mysql_8_should_work(api: &TestApi) -> crate::T
                     },
                     default: None,
                     auto_increment: false,
+                    generation_expression: None,
                 },
             ],
             indices: [],
@@ -172,6 +176,7 @@ async fn database_description_for_postgres_should_work(api: &TestApi) -> crate::
                         },
                     ),
                     auto_increment: true,
+                    generation_expression: None,
                 },
                 Column {
                     name: "string",
@@ -187,6 +192,7 @@ async fn database_description_for_postgres_should_work(api: &TestApi) -> crate::
                     },
                     default: None,
                     auto_increment: false,
+                    generation_expression: None,
                 },
             ],
             indices: [],
@@ -241,6 +247,7 @@ async fn database_description_for_sqlite_should_work(api: &TestApi) -> crate::Te
                     },
                     default: None,
                     auto_increment: true,
+                    generation_expression: None,
                 },
                 Column {
                     name: "string",
@@ -252,6 +259,7 @@ async fn database_description_for_sqlite_should_work(api: &TestApi) -> crate::Te
                     },
                     default: None,
                     auto_increment: false,
+                    generation_expression: None,
                 },
             ],
             indices: [],
@@ -133,6 +133,11 @@ pub enum ConnectorCapability {
     CreateMany,
     WritableAutoincField,
     CreateSkipDuplicates,
+    JoinRelationLoading,
+    CountDistinctAggregation,
+    StringAggregation,
+    MedianAggregation,
+    StdDevAggregation,
 }
 
 /// Contains all capabilities that the connector is able to serve.
@@ -327,6 +327,9 @@ pub struct ScalarField {
 
     /// Indicates if this field is ignored by the Client.
     pub is_ignored: bool,
+
+    /// The expression the database computes the field from, set by `@generated`.
+    pub generation_expression: Option<String>,
 }
 
 impl ScalarField {
@@ -345,6 +348,7 @@ impl ScalarField {
             is_updated_at: false,
             is_commented_out: false,
             is_ignored: false,
+            generation_expression: None,
         }
     }
     /// Creates a new field with the given name and type, marked as generated and optional.
@@ -24,6 +24,8 @@ pub struct Model {
     pub is_commented_out: bool,
     /// Indicates if this model has to be ignored by the Client.
     pub is_ignored: bool,
+    /// The field holding the time a record was soft deleted at, set by `@@softDelete`.
+    pub soft_delete_field: Option<String>,
 }
 
 /// Represents an index defined via `@@index` or `@@unique`.
@@ -72,6 +74,7 @@ impl Model {
             is_generated: false,
             is_commented_out: false,
             is_ignored: false,
+            soft_delete_field: None,
         }
     }
 
@@ -11,6 +11,8 @@ pub enum ScalarType {
     Boolean,
     String,
     DateTime,
+    Date,
+    Time,
     Json,
     Bytes,
     Decimal,
@@ -44,6 +46,8 @@ impl FromStr for ScalarType {
             "Boolean" => Ok(ScalarType::Boolean),
             "String" => Ok(ScalarType::String),
             "DateTime" => Ok(ScalarType::DateTime),
+            "Date" => Ok(ScalarType::Date),
+            "Time" => Ok(ScalarType::Time),
             "Json" => Ok(ScalarType::Json),
             "Bytes" => Ok(ScalarType::Bytes),
             "Decimal" => Ok(ScalarType::Decimal),
@@ -61,6 +65,8 @@ impl ToString for ScalarType {
             ScalarType::Boolean => String::from("Boolean"),
             ScalarType::String => String::from("String"),
             ScalarType::DateTime => String::from("DateTime"),
+            ScalarType::Date => String::from("Date"),
+            ScalarType::Time => String::from("Time"),
             ScalarType::Json => String::from("Json"),
             ScalarType::Bytes => String::from("Bytes"),
             ScalarType::Decimal => String::from("Decimal"),
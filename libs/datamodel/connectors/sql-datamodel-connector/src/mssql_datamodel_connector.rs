@@ -52,6 +52,9 @@ impl MsSqlDatamodelConnector {
             ConnectorCapability::AutoIncrementMultipleAllowed,
             ConnectorCapability::AutoIncrementNonIndexedAllowed,
             ConnectorCapability::CreateMany,
+            ConnectorCapability::CountDistinctAggregation,
+            ConnectorCapability::StringAggregation,
+            ConnectorCapability::StdDevAggregation,
         ];
 
         let constructors: Vec<NativeTypeConstructor> = vec![
@@ -66,8 +69,8 @@ impl MsSqlDatamodelConnector {
             NativeTypeConstructor::without_args(BIT_TYPE_NAME, vec![ScalarType::Boolean, ScalarType::Int]),
             NativeTypeConstructor::with_optional_args(FLOAT_TYPE_NAME, 1, vec![ScalarType::Float]),
             NativeTypeConstructor::without_args(REAL_TYPE_NAME, vec![ScalarType::Float]),
-            NativeTypeConstructor::without_args(DATE_TYPE_NAME, vec![ScalarType::DateTime]),
-            NativeTypeConstructor::without_args(TIME_TYPE_NAME, vec![ScalarType::DateTime]),
+            NativeTypeConstructor::without_args(DATE_TYPE_NAME, vec![ScalarType::Date, ScalarType::DateTime]),
+            NativeTypeConstructor::without_args(TIME_TYPE_NAME, vec![ScalarType::Time, ScalarType::DateTime]),
             NativeTypeConstructor::without_args(DATETIME_TYPE_NAME, vec![ScalarType::DateTime]),
             NativeTypeConstructor::without_args(DATETIME2_TYPE_NAME, vec![ScalarType::DateTime]),
             NativeTypeConstructor::without_args(DATETIME_OFFSET_TYPE_NAME, vec![ScalarType::DateTime]),
@@ -103,6 +106,8 @@ const SCALAR_TYPE_DEFAULTS: &[(ScalarType, MsSqlType)] = &[
         MsSqlType::NVarChar(Some(MsSqlTypeParameter::Number(1000))),
     ),
     (ScalarType::DateTime, MsSqlType::DateTime2),
+    (ScalarType::Date, MsSqlType::Date),
+    (ScalarType::Time, MsSqlType::Time),
     (ScalarType::Bytes, MsSqlType::VarBinary(Some(MsSqlTypeParameter::Max))),
     (
         ScalarType::Json,
@@ -70,6 +70,10 @@ impl MySqlDatamodelConnector {
             ConnectorCapability::CreateMany,
             ConnectorCapability::WritableAutoincField,
             ConnectorCapability::CreateSkipDuplicates,
+            ConnectorCapability::JoinRelationLoading,
+            ConnectorCapability::CountDistinctAggregation,
+            ConnectorCapability::StringAggregation,
+            ConnectorCapability::StdDevAggregation,
         ];
 
         let int = NativeTypeConstructor::without_args(INT_TYPE_NAME, vec![ScalarType::Int]);
@@ -102,8 +106,9 @@ impl MySqlDatamodelConnector {
         let text = NativeTypeConstructor::without_args(TEXT_TYPE_NAME, vec![ScalarType::String]);
         let medium_text = NativeTypeConstructor::without_args(MEDIUM_TEXT_TYPE_NAME, vec![ScalarType::String]);
         let long_text = NativeTypeConstructor::without_args(LONG_TEXT_TYPE_NAME, vec![ScalarType::String]);
-        let date = NativeTypeConstructor::without_args(DATE_TYPE_NAME, vec![ScalarType::DateTime]);
-        let time = NativeTypeConstructor::with_optional_args(TIME_TYPE_NAME, 1, vec![ScalarType::DateTime]);
+        let date = NativeTypeConstructor::without_args(DATE_TYPE_NAME, vec![ScalarType::Date, ScalarType::DateTime]);
+        let time =
+            NativeTypeConstructor::with_optional_args(TIME_TYPE_NAME, 1, vec![ScalarType::Time, ScalarType::DateTime]);
         let datetime = NativeTypeConstructor::with_optional_args(DATETIME_TYPE_NAME, 1, vec![ScalarType::DateTime]);
         let timestamp = NativeTypeConstructor::with_optional_args(TIMESTAMP_TYPE_NAME, 1, vec![ScalarType::DateTime]);
         let year = NativeTypeConstructor::without_args(YEAR_TYPE_NAME, vec![ScalarType::Int]);
@@ -159,6 +164,8 @@ const SCALAR_TYPE_DEFAULTS: &[(ScalarType, MySqlType)] = &[
     (ScalarType::Boolean, MySqlType::TinyInt),
     (ScalarType::String, MySqlType::VarChar(191)),
     (ScalarType::DateTime, MySqlType::DateTime(Some(3))),
+    (ScalarType::Date, MySqlType::Date),
+    (ScalarType::Time, MySqlType::Time(Some(3))),
     (ScalarType::Bytes, MySqlType::LongBlob),
     (ScalarType::Json, MySqlType::Json),
 ];
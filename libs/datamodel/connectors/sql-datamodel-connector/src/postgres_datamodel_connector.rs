@@ -56,6 +56,11 @@ impl PostgresDatamodelConnector {
             ConnectorCapability::CreateMany,
             ConnectorCapability::WritableAutoincField,
             ConnectorCapability::CreateSkipDuplicates,
+            ConnectorCapability::JoinRelationLoading,
+            ConnectorCapability::CountDistinctAggregation,
+            ConnectorCapability::StringAggregation,
+            ConnectorCapability::MedianAggregation,
+            ConnectorCapability::StdDevAggregation,
         ];
 
         let small_int = NativeTypeConstructor::without_args(SMALL_INT_TYPE_NAME, vec![ScalarType::Int]);
@@ -75,8 +80,10 @@ impl PostgresDatamodelConnector {
         let timestamp = NativeTypeConstructor::with_optional_args(TIMESTAMP_TYPE_NAME, 1, vec![ScalarType::DateTime]);
         let timestamptz =
             NativeTypeConstructor::with_optional_args(TIMESTAMP_TZ_TYPE_NAME, 1, vec![ScalarType::DateTime]);
-        let date = NativeTypeConstructor::without_args(DATE_TYPE_NAME, vec![ScalarType::DateTime]);
-        let time = NativeTypeConstructor::with_optional_args(TIME_TYPE_NAME, 1, vec![ScalarType::DateTime]);
+        let date = NativeTypeConstructor::without_args(DATE_TYPE_NAME, vec![ScalarType::Date, ScalarType::DateTime]);
+        let time =
+            NativeTypeConstructor::with_optional_args(TIME_TYPE_NAME, 1, vec![ScalarType::Time, ScalarType::DateTime]);
+        // `Time` can't hold the offset of a `timetz`.
         let timetz = NativeTypeConstructor::with_optional_args(TIME_TZ_TYPE_NAME, 1, vec![ScalarType::DateTime]);
         let boolean = NativeTypeConstructor::without_args(BOOLEAN_TYPE_NAME, vec![ScalarType::Boolean]);
         let bit = NativeTypeConstructor::with_optional_args(BIT_TYPE_NAME, 1, vec![ScalarType::String]);
@@ -130,6 +137,8 @@ const SCALAR_TYPE_DEFAULTS: &[(ScalarType, PostgresType)] = &[
     (ScalarType::Boolean, PostgresType::Boolean),
     (ScalarType::String, PostgresType::Text),
     (ScalarType::DateTime, PostgresType::Timestamp(Some(3))),
+    (ScalarType::Date, PostgresType::Date),
+    (ScalarType::Time, PostgresType::Time(Some(3))),
     (ScalarType::Bytes, PostgresType::ByteA),
     (ScalarType::Json, PostgresType::JSONB),
 ];
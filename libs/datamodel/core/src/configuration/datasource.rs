@@ -9,6 +9,14 @@ pub struct Datasource {
     /// the provider that was selected as active from all specified providers
     pub active_provider: String,
     pub url: StringFromEnvVar,
+    /// urls of read replicas that read-only queries can be routed to
+    pub replica_urls: Vec<StringFromEnvVar>,
+    /// how nested relations are loaded by default, either `query` or `join`
+    pub relation_load_strategy: Option<String>,
+    /// the table where the migration engine records applied migrations, if not `_prisma_migrations`
+    pub migrations_table: Option<String>,
+    /// the schema of the migrations table, if not the schema of the connection
+    pub migrations_schema: Option<String>,
     pub documentation: Option<String>,
     /// a connector representing the intersection of all providers specified
     pub combined_connector: Box<dyn Connector>,
@@ -24,6 +32,10 @@ impl std::fmt::Debug for Datasource {
             .field("provider", &self.provider)
             .field("active_provider", &self.active_provider)
             .field("url", &self.url)
+            .field("replica_urls", &self.replica_urls)
+            .field("relation_load_strategy", &self.relation_load_strategy)
+            .field("migrations_table", &self.migrations_table)
+            .field("migrations_schema", &self.migrations_schema)
             .field("documentation", &self.documentation)
             .field("active_connector", &&"...")
             .finish()
@@ -35,6 +47,22 @@ impl Datasource {
         &self.url
     }
 
+    pub fn replica_urls(&self) -> &[StringFromEnvVar] {
+        &self.replica_urls
+    }
+
+    pub fn relation_load_strategy(&self) -> Option<&str> {
+        self.relation_load_strategy.as_deref()
+    }
+
+    pub fn migrations_table(&self) -> Option<&str> {
+        self.migrations_table.as_deref()
+    }
+
+    pub fn migrations_schema(&self) -> Option<&str> {
+        self.migrations_schema.as_deref()
+    }
+
     pub fn capabilities(&self) -> ConnectorCapabilities {
         let capabilities = self.active_connector.capabilities().clone();
         ConnectorCapabilities::new(capabilities)
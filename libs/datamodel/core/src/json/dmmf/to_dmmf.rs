@@ -144,9 +144,13 @@ fn prisma_value_to_serde(value: &PrismaValue) -> serde_json::Value {
         PrismaValue::Float(val) => {
             serde_json::Value::Number(serde_json::Number::from_f64(val.to_f64().unwrap()).unwrap())
         }
+        PrismaValue::Decimal(val) => serde_json::Value::String(val.to_string()),
         PrismaValue::Int(val) => serde_json::Value::Number(serde_json::Number::from_f64(*val as f64).unwrap()),
         PrismaValue::BigInt(val) => serde_json::Value::String(val.to_string()),
+        PrismaValue::UnsignedBigInt(val) => serde_json::Value::String(val.to_string()),
         PrismaValue::DateTime(val) => serde_json::Value::String(val.to_rfc3339()),
+        PrismaValue::Date(val) => serde_json::Value::String(prisma_value::stringify_date_only(val)),
+        PrismaValue::Time(val) => serde_json::Value::String(prisma_value::stringify_time_only(val)),
         PrismaValue::Null => serde_json::Value::Null,
         PrismaValue::Uuid(val) => serde_json::Value::String(val.to_string()),
         PrismaValue::Json(val) => serde_json::Value::String(val.to_string()),
@@ -7,6 +7,14 @@ pub struct SourceConfig {
     pub provider: Vec<String>,
     pub active_provider: String,
     pub url: StringFromEnvVar,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub replica_urls: Vec<StringFromEnvVar>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relation_load_strategy: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub migrations_table: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub migrations_schema: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub documentation: Option<String>,
 }
@@ -37,6 +45,10 @@ fn source_to_json_struct(source: &configuration::Datasource) -> SourceConfig {
         provider: source.provider.clone(),
         active_provider: source.active_provider.to_string(),
         url: source.url().clone(),
+        replica_urls: source.replica_urls().to_vec(),
+        relation_load_strategy: source.relation_load_strategy.clone(),
+        migrations_table: source.migrations_table.clone(),
+        migrations_schema: source.migrations_schema.clone(),
         documentation: source.documentation.clone(),
     }
 }
@@ -5,12 +5,18 @@ use super::{
     datasource_provider::DatasourceProvider,
 };
 use crate::ast::Span;
+use crate::common::provider_names::SQLITE_SOURCE_NAME;
 use crate::configuration::StringFromEnvVar;
 use crate::diagnostics::{DatamodelError, DatamodelWarning, Diagnostics, ValidatedDatasource, ValidatedDatasources};
 use crate::{ast, Datasource};
 use datamodel_connector::{CombinedConnector, Connector};
 
 const PREVIEW_FEATURES_KEY: &str = "previewFeatures";
+const REPLICA_URLS_KEY: &str = "replicaUrls";
+const RELATION_LOAD_STRATEGY_KEY: &str = "relationLoadStrategy";
+const RELATION_LOAD_STRATEGIES: &[&str] = &["query", "join"];
+const MIGRATIONS_TABLE_KEY: &str = "migrationsTable";
+const MIGRATIONS_SCHEMA_KEY: &str = "migrationsSchema";
 
 /// Is responsible for loading and validating Datasources defined in an AST.
 pub struct DatasourceLoader {
@@ -161,6 +167,69 @@ impl DatasourceLoader {
             )));
         }
 
+        let mut replica_urls = Vec::new();
+
+        if !ignore_datasource_urls {
+            if let Ok(replica_urls_arg) = args.arg(REPLICA_URLS_KEY) {
+                for replica_url_arg in replica_urls_arg.as_array() {
+                    let (from_env_var, value) = match replica_url_arg.as_str_from_env() {
+                        Ok(res) => res,
+                        Err(err) => return Err(diagnostics.merge_error(err)),
+                    };
+
+                    if value.trim().is_empty() {
+                        let msg = format!(
+                            "You must provide nonempty replica URLs for the datasource `{}`.",
+                            source_name
+                        );
+
+                        return Err(diagnostics.merge_error(DatamodelError::new_source_validation_error(
+                            &msg,
+                            source_name,
+                            replica_url_arg.span(),
+                        )));
+                    }
+
+                    let replica_url = StringFromEnvVar {
+                        from_env_var,
+                        value: value.trim().to_owned(),
+                    };
+
+                    replica_urls.push((replica_url, replica_url_arg.span()));
+                }
+            }
+        }
+
+        let relation_load_strategy = match args.arg(RELATION_LOAD_STRATEGY_KEY) {
+            Ok(arg) => {
+                let strategy = arg.as_str()?;
+
+                if !RELATION_LOAD_STRATEGIES.contains(&strategy.as_str()) {
+                    let msg = format!(
+                        "Invalid relation load strategy `{}` for the datasource `{}`. Supported values are: {}.",
+                        strategy,
+                        source_name,
+                        RELATION_LOAD_STRATEGIES.join(", ")
+                    );
+
+                    return Err(diagnostics.merge_error(DatamodelError::new_source_validation_error(
+                        &msg,
+                        source_name,
+                        arg.span(),
+                    )));
+                }
+
+                Some(strategy)
+            }
+            Err(_) => None,
+        };
+
+        let migrations_table = lift_non_empty_string(&mut args, MIGRATIONS_TABLE_KEY, source_name)
+            .map_err(|err| diagnostics.merge_error(err))?
+            .map(|(table, _)| table);
+        let migrations_schema = lift_non_empty_string(&mut args, MIGRATIONS_SCHEMA_KEY, source_name)
+            .map_err(|err| diagnostics.merge_error(err))?;
+
         let preview_features_arg = args.arg(PREVIEW_FEATURES_KEY);
         let (preview_features, span) = match preview_features_arg.ok() {
             Some(x) => (x.as_array().to_str_vec()?, x.span()),
@@ -211,12 +280,43 @@ impl DatasourceLoader {
         let (successes, errors): (Vec<_>, Vec<_>) = validated_providers.into_iter().partition(|result| result.is_ok());
         if !successes.is_empty() {
             let first_successful_provider = successes.into_iter().next().unwrap()?;
+
+            if let (Some((_, span)), SQLITE_SOURCE_NAME) =
+                (&migrations_schema, first_successful_provider.canonical_name())
+            {
+                let msg = format!(
+                    "The `{}` property of the datasource `{}` is not supported on SQLite.",
+                    MIGRATIONS_SCHEMA_KEY, source_name
+                );
+
+                return Err(diagnostics.merge_error(DatamodelError::new_source_validation_error(
+                    &msg,
+                    source_name,
+                    *span,
+                )));
+            }
+
+            // Replicas must be reachable with the same provider as the primary.
+            for (replica_url, span) in replica_urls.iter() {
+                if let Err(err_msg) = first_successful_provider.can_handle_url(source_name, replica_url) {
+                    return Err(diagnostics.merge_error(DatamodelError::new_source_validation_error(
+                        &err_msg,
+                        source_name,
+                        *span,
+                    )));
+                }
+            }
+
             Ok(ValidatedDatasource {
                 subject: Datasource {
                     name: source_name.to_string(),
                     provider: providers,
                     active_provider: first_successful_provider.canonical_name().to_string(),
                     url,
+                    replica_urls: replica_urls.into_iter().map(|(url, _)| url).collect(),
+                    relation_load_strategy,
+                    migrations_table,
+                    migrations_schema: migrations_schema.map(|(schema, _)| schema),
                     documentation,
                     combined_connector,
                     active_connector: first_successful_provider.connector(),
@@ -234,6 +334,32 @@ impl DatasourceLoader {
     }
 }
 
+/// Lift an optional string argument of the datasource. Empty strings are rejected.
+fn lift_non_empty_string(
+    args: &mut Arguments<'_>,
+    key: &str,
+    source_name: &str,
+) -> Result<Option<(String, Span)>, DatamodelError> {
+    let arg = match args.arg(key) {
+        Ok(arg) => arg,
+        Err(_) => return Ok(None),
+    };
+
+    let value = arg.as_str()?;
+
+    if value.trim().is_empty() {
+        let msg = format!("The `{}` of the datasource `{}` must not be empty.", key, source_name);
+
+        return Err(DatamodelError::new_source_validation_error(
+            &msg,
+            source_name,
+            arg.span(),
+        ));
+    }
+
+    Ok(Some((value, arg.span())))
+}
+
 fn get_builtin_datasource_providers() -> Vec<Box<dyn DatasourceProvider>> {
     vec![
         Box::new(MySqlDatasourceProvider::new()),
@@ -92,6 +92,12 @@ impl<'a> Validator<'a> {
                 errors_for_model.append(the_errors);
             }
 
+            if let Err(ref mut the_errors) =
+                self.validate_generated_fields(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors_for_model.append(the_errors);
+            }
+
             if let Err(ref mut the_errors) = self.validate_base_fields_for_relation(
                 schema,
                 ast_schema.find_model(&model.name).expect(STATE_ERROR),
@@ -361,6 +367,40 @@ impl<'a> Validator<'a> {
         }
     }
 
+    /// Generated fields are computed by the database, they can't have values of their own.
+    fn validate_generated_fields(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), Diagnostics> {
+        let mut errors = Diagnostics::new();
+
+        for field in model
+            .scalar_fields()
+            .filter(|field| field.generation_expression.is_some())
+        {
+            let conflicting_attribute = if field.default_value.is_some() {
+                Some("@default")
+            } else if field.is_id {
+                Some("@id")
+            } else if field.is_updated_at {
+                Some("@updatedAt")
+            } else {
+                None
+            };
+
+            if let Some(attribute) = conflicting_attribute {
+                errors.push_error(DatamodelError::new_attribute_validation_error(
+                    &format!("Fields that are marked with @generated can not have {}.", attribute),
+                    "generated",
+                    ast_model.find_field(&field.name).span,
+                ));
+            }
+        }
+
+        if errors.has_errors() {
+            Err(errors)
+        } else {
+            Ok(())
+        }
+    }
+
     fn validate_model_has_strict_unique_criteria(
         &self,
         ast_model: &ast::Model,
@@ -95,9 +95,17 @@ pub fn lower_prisma_value(pv: &PrismaValue) -> ast::Expression {
         PrismaValue::String(value) => ast::Expression::StringValue(value.clone(), ast::Span::empty()),
         PrismaValue::Enum(value) => ast::Expression::ConstantValue(value.clone(), ast::Span::empty()),
         PrismaValue::DateTime(value) => ast::Expression::StringValue(value.to_rfc3339(), ast::Span::empty()),
+        PrismaValue::Date(value) => {
+            ast::Expression::StringValue(prisma_value::stringify_date_only(value), ast::Span::empty())
+        }
+        PrismaValue::Time(value) => {
+            ast::Expression::StringValue(prisma_value::stringify_time_only(value), ast::Span::empty())
+        }
         PrismaValue::Float(value) => ast::Expression::NumericValue(value.to_string(), ast::Span::empty()),
+        PrismaValue::Decimal(value) => ast::Expression::NumericValue(value.to_string(), ast::Span::empty()),
         PrismaValue::Int(value) => ast::Expression::NumericValue(value.to_string(), ast::Span::empty()),
         PrismaValue::BigInt(value) => ast::Expression::NumericValue(value.to_string(), ast::Span::empty()),
+        PrismaValue::UnsignedBigInt(value) => ast::Expression::NumericValue(value.to_string(), ast::Span::empty()),
         PrismaValue::Null => ast::Expression::ConstantValue("null".to_string(), ast::Span::empty()),
         PrismaValue::Uuid(val) => ast::Expression::StringValue(val.to_string(), ast::Span::empty()),
         PrismaValue::Json(val) => ast::Expression::StringValue(val.to_string(), ast::Span::empty()),
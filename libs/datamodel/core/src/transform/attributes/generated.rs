@@ -0,0 +1,54 @@
+use super::{super::helpers::*, AttributeValidator};
+use crate::diagnostics::DatamodelError;
+use crate::{ast, dml, Datamodel};
+
+/// Prismas builtin `@generated` attribute, for columns computed by the
+/// database from an expression, e.g. `GENERATED ALWAYS AS (expr) STORED`.
+pub struct GeneratedAttributeValidator {}
+
+const ATTRIBUTE_NAME: &str = "generated";
+
+impl AttributeValidator<dml::Field> for GeneratedAttributeValidator {
+    fn attribute_name(&self) -> &str {
+        ATTRIBUTE_NAME
+    }
+
+    fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Field) -> Result<(), DatamodelError> {
+        let expression = args
+            .default_arg("expression")?
+            .as_str()
+            .map_err(|err| self.wrap_in_attribute_validation_error(&err))?;
+
+        match obj {
+            dml::Field::ScalarField(sf) if !sf.is_list() => {
+                sf.generation_expression = Some(expression);
+
+                Ok(())
+            }
+            dml::Field::ScalarField(_) => self.new_attribute_validation_error(
+                "Fields that are marked with @generated can not be lists.",
+                args.span(),
+            ),
+            dml::Field::RelationField(_) => self.new_attribute_validation_error(
+                "The attribute `@generated` can not be used on relation fields.",
+                args.span(),
+            ),
+        }
+    }
+
+    fn serialize(&self, obj: &dml::Field, _datamodel: &Datamodel) -> Vec<ast::Attribute> {
+        match obj {
+            dml::Field::ScalarField(sf) => match &sf.generation_expression {
+                Some(expression) => vec![ast::Attribute::new(
+                    ATTRIBUTE_NAME,
+                    vec![ast::Argument::new_unnamed(ast::Expression::StringValue(
+                        expression.clone(),
+                        ast::Span::empty(),
+                    ))],
+                )],
+                None => vec![],
+            },
+            dml::Field::RelationField(_) => vec![],
+        }
+    }
+}
@@ -1,10 +1,12 @@
 mod attribute_list_validator;
 mod attribute_validator;
 mod default;
+mod generated;
 mod id;
 mod ignore;
 mod map;
 mod relation;
+mod soft_delete;
 mod unique_and_index;
 mod updated_at;
 
@@ -39,6 +41,7 @@ fn new_builtin_field_attributes() -> AttributeListValidator<dml::Field> {
     validator.add(Box::new(unique_and_index::FieldLevelUniqueAttributeValidator {}));
     validator.add(Box::new(default::DefaultAttributeValidator {}));
     validator.add(Box::new(updated_at::UpdatedAtAttributeValidator {}));
+    validator.add(Box::new(generated::GeneratedAttributeValidator {}));
     validator.add(Box::new(map::MapAttributeValidatorForField {}));
     validator.add(Box::new(relation::RelationAttributeValidator {}));
     validator.add(Box::new(ignore::IgnoreAttributeValidatorForField {}));
@@ -55,6 +58,7 @@ fn new_builtin_model_attributes() -> AttributeListValidator<dml::Model> {
     validator.add(Box::new(unique_and_index::ModelLevelIndexAttributeValidator {}));
     validator.add(Box::new(map::MapAttributeValidator {}));
     validator.add(Box::new(ignore::IgnoreAttributeValidator {}));
+    validator.add(Box::new(soft_delete::SoftDeleteAttributeValidator {}));
 
     validator
 }
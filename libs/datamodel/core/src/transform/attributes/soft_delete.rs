@@ -0,0 +1,50 @@
+use super::{super::helpers::*, AttributeValidator};
+use crate::diagnostics::DatamodelError;
+use crate::{ast, dml, Datamodel};
+
+/// Prismas builtin `@@softDelete` attribute.
+pub struct SoftDeleteAttributeValidator {}
+
+const ATTRIBUTE_NAME: &str = "softDelete";
+
+impl AttributeValidator<dml::Model> for SoftDeleteAttributeValidator {
+    fn attribute_name(&self) -> &str {
+        ATTRIBUTE_NAME
+    }
+
+    fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Model) -> Result<(), DatamodelError> {
+        let field_name = args
+            .default_arg("field")?
+            .as_constant_literal()
+            .map_err(|err| self.wrap_in_attribute_validation_error(&err))?;
+
+        let is_valid = match obj.find_field(&field_name) {
+            Some(dml::Field::ScalarField(sf)) => sf.field_type.is_datetime() && sf.is_optional() && !sf.is_ignored,
+            _ => false,
+        };
+
+        if !is_valid {
+            return self.new_attribute_validation_error(
+                &format!(
+                    "The soft delete field `{}` must be an optional `DateTime` field of the model.",
+                    field_name
+                ),
+                args.span(),
+            );
+        }
+
+        obj.soft_delete_field = Some(field_name);
+
+        Ok(())
+    }
+
+    fn serialize(&self, obj: &dml::Model, _datamodel: &Datamodel) -> Vec<ast::Attribute> {
+        match &obj.soft_delete_field {
+            Some(field_name) => vec![ast::Attribute::new(
+                ATTRIBUTE_NAME,
+                vec![ast::Argument::new_constant("field", field_name)],
+            )],
+            None => vec![],
+        }
+    }
+}
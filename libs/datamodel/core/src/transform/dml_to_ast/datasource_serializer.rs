@@ -31,6 +31,35 @@ impl DatasourceSerializer {
             }
         }
 
+        if !source.replica_urls.is_empty() {
+            let replica_urls: Vec<ast::Expression> = source
+                .replica_urls
+                .iter()
+                .map(|url| match url.from_env_var {
+                    Some(ref env_var) => ast::Expression::Function(
+                        "env".to_owned(),
+                        vec![ast::Expression::StringValue(env_var.to_string(), ast::Span::empty())],
+                        ast::Span::empty(),
+                    ),
+                    None => ast::Expression::StringValue(url.value.to_owned(), ast::Span::empty()),
+                })
+                .collect();
+
+            arguments.push(ast::Argument::new_array("replicaUrls", replica_urls));
+        }
+
+        if let Some(ref strategy) = source.relation_load_strategy {
+            arguments.push(ast::Argument::new_string("relationLoadStrategy", strategy));
+        }
+
+        if let Some(ref table) = source.migrations_table {
+            arguments.push(ast::Argument::new_string("migrationsTable", table));
+        }
+
+        if let Some(ref schema) = source.migrations_schema {
+            arguments.push(ast::Argument::new_string("migrationsSchema", schema));
+        }
+
         if !&source.preview_features.is_empty() {
             let features: Vec<ast::Expression> = source
                 .preview_features
@@ -4,7 +4,7 @@ use crate::diagnostics::DatamodelError;
 use crate::ValueGenerator;
 use crate::{ast, DefaultValue};
 use bigdecimal::BigDecimal;
-use chrono::{DateTime, FixedOffset};
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveTime};
 use dml::scalars::ScalarType;
 use prisma_value::PrismaValue;
 use std::error;
@@ -58,6 +58,8 @@ impl ValueValidator {
             ScalarType::Float => self.as_float().map(PrismaValue::Float),
             ScalarType::Boolean => self.as_bool().map(PrismaValue::Boolean),
             ScalarType::DateTime => self.as_date_time().map(PrismaValue::DateTime),
+            ScalarType::Date => self.as_date().map(PrismaValue::Date),
+            ScalarType::Time => self.as_time().map(PrismaValue::Time),
             ScalarType::String => self.as_str().map(PrismaValue::String),
             ScalarType::Json => self.as_str().map(PrismaValue::String),
             ScalarType::Bytes => self.as_str().and_then(|s| {
@@ -157,6 +159,26 @@ impl ValueValidator {
         }
     }
 
+    /// Tries to convert the wrapped value to a date without a time, e.g. `2020-12-31`.
+    pub fn as_date(&self) -> Result<NaiveDate, DatamodelError> {
+        match &self.value {
+            ast::Expression::StringValue(value, _) | ast::Expression::Any(value, _) => {
+                self.wrap_error_from_result(NaiveDate::parse_from_str(value, "%Y-%m-%d"), "date")
+            }
+            _ => Err(self.construct_type_mismatch_error("date")),
+        }
+    }
+
+    /// Tries to convert the wrapped value to a time of day, e.g. `13:30:00`.
+    pub fn as_time(&self) -> Result<NaiveTime, DatamodelError> {
+        match &self.value {
+            ast::Expression::StringValue(value, _) | ast::Expression::Any(value, _) => {
+                self.wrap_error_from_result(NaiveTime::parse_from_str(value, "%H:%M:%S%.f"), "time")
+            }
+            _ => Err(self.construct_type_mismatch_error("time")),
+        }
+    }
+
     /// Unwraps the wrapped value as a constant literal..
     pub fn as_constant_literal(&self) -> Result<String, DatamodelError> {
         match &self.value {
@@ -174,6 +174,10 @@ impl<'a> ScalarFieldWalker<'a> {
         }
     }
 
+    pub fn generation_expression(&self) -> Option<&'a str> {
+        self.field.generation_expression.as_deref()
+    }
+
     pub fn is_id(&self) -> bool {
         self.field.is_id
     }
@@ -0,0 +1,89 @@
+use crate::common::*;
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn generated_must_work() {
+    let dml = r#"
+    model Order {
+        id       Int @id
+        price    Int
+        quantity Int
+        total    Int @generated("price * quantity")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let order = schema.assert_has_model("Order");
+
+    assert_eq!(
+        order.find_scalar_field("total").unwrap().generation_expression,
+        Some("price * quantity".to_owned())
+    );
+    assert_eq!(order.find_scalar_field("price").unwrap().generation_expression, None);
+}
+
+#[test]
+fn generated_must_roundtrip() {
+    let input = indoc!(
+        r#"
+        model Order {
+          id       Int @id
+          price    Int
+          quantity Int
+          total    Int @generated("price * quantity")
+        }
+        "#
+    );
+
+    let dml = datamodel::parse_datamodel(input).unwrap().subject;
+    let rendered = datamodel::render_datamodel_to_string(&dml);
+
+    assert_eq!(input, rendered);
+}
+
+#[test]
+fn generated_must_error_on_lists() {
+    let dml = r#"
+    datasource db {
+        provider = "postgres"
+        url      = "postgresql://localhost:5432"
+    }
+
+    model Order {
+        id     Int   @id
+        totals Int[] @generated("1")
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@generated\": Fields that are marked with @generated can not be lists.",
+    );
+}
+
+#[test]
+fn generated_must_error_with_a_default() {
+    let dml = r#"
+    model Order {
+        id    Int @id
+        total Int @default(0) @generated("1")
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@generated\": Fields that are marked with @generated can not have @default.",
+    );
+}
+
+#[test]
+fn generated_must_error_on_ids() {
+    let dml = r#"
+    model Order {
+        id Int @id @generated("1")
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@generated\": Fields that are marked with @generated can not have @id.",
+    );
+}
@@ -1,6 +1,7 @@
 pub mod builtin_attributes;
 pub mod default_negative;
 pub mod default_positive;
+pub mod generated;
 pub mod id_negative;
 pub mod id_positive;
 pub mod index;
@@ -11,6 +12,7 @@ pub mod relations_legacy;
 pub mod relations_negative;
 pub mod relations_new;
 pub mod relations_positive;
+pub mod soft_delete;
 pub mod unique;
 pub mod unique_criteria;
 pub mod updated_at_negative;
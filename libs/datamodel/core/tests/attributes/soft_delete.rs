@@ -0,0 +1,86 @@
+use crate::common::*;
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn soft_delete_must_work() {
+    let dml = r#"
+    model Post {
+        id        Int       @id
+        deletedAt DateTime?
+
+        @@softDelete(field: deletedAt)
+    }
+    "#;
+
+    let schema = parse(dml);
+    let post = schema.assert_has_model("Post");
+
+    assert_eq!(post.soft_delete_field, Some("deletedAt".to_owned()));
+}
+
+#[test]
+fn soft_delete_must_roundtrip() {
+    let input = indoc!(
+        r#"
+        model Post {
+          id        Int       @id
+          deletedAt DateTime?
+
+          @@softDelete(field: deletedAt)
+        }
+        "#
+    );
+
+    let dml = datamodel::parse_datamodel(input).unwrap().subject;
+    let rendered = datamodel::render_datamodel_to_string(&dml);
+
+    assert_eq!(input, rendered);
+}
+
+#[test]
+fn soft_delete_must_error_on_unknown_fields() {
+    let dml = r#"
+    model Post {
+        id Int @id
+
+        @@softDelete(field: deletedAt)
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@softDelete\": The soft delete field `deletedAt` must be an optional `DateTime` field of the model.",
+    );
+}
+
+#[test]
+fn soft_delete_must_error_on_required_fields() {
+    let dml = r#"
+    model Post {
+        id        Int      @id
+        deletedAt DateTime
+
+        @@softDelete(field: deletedAt)
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@softDelete\": The soft delete field `deletedAt` must be an optional `DateTime` field of the model.",
+    );
+}
+
+#[test]
+fn soft_delete_must_error_on_fields_of_other_types() {
+    let dml = r#"
+    model Post {
+        id        Int     @id
+        isDeleted Boolean?
+
+        @@softDelete(field: isDeleted)
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@softDelete\": The soft delete field `isDeleted` must be an optional `DateTime` field of the model.",
+    );
+}
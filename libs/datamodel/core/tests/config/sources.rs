@@ -373,6 +373,185 @@ fn microsoft_sql_server_preview_feature_must_work() {
     assert!(generator.preview_features.contains(&String::from("microsoftSqlServer")));
 }
 
+#[test]
+#[serial]
+fn replica_urls_must_work() {
+    let schema = r#"
+        datasource ds {
+          provider = "postgresql"
+          url = "postgresql://primary"
+          replicaUrls = ["postgresql://replica1", env("REPLICA_URL")]
+        }
+    "#;
+
+    std::env::set_var("REPLICA_URL", "postgresql://replica2");
+
+    let config = parse_configuration(schema);
+    let rendered = datamodel::json::mcf::render_sources_to_json(&config.datasources);
+
+    let expected = r#"[
+        {
+          "name": "ds",
+          "provider": ["postgresql"],
+          "activeProvider": "postgresql",
+          "url": {
+              "fromEnvVar": null,
+              "value": "postgresql://primary"
+          },
+          "replicaUrls": [
+            {
+              "fromEnvVar": null,
+              "value": "postgresql://replica1"
+            },
+            {
+              "fromEnvVar": "REPLICA_URL",
+              "value": "postgresql://replica2"
+            }
+          ]
+        }
+    ]"#;
+
+    assert_eq_json(&rendered, expected);
+
+    std::env::remove_var("REPLICA_URL");
+}
+
+#[test]
+fn must_error_if_wrong_protocol_is_used_for_a_replica() {
+    let schema = r#"
+        datasource myds {
+            provider = "postgresql"
+            url = "postgresql://primary"
+            replicaUrls = ["mysql://replica"]
+        }
+    "#;
+
+    let diagnostics = datamodel::parse_configuration(schema).err().expect("This must error");
+    let error = diagnostics.to_error_iter().next().unwrap();
+
+    assert_eq!(
+        error.description(),
+        "The URL for datasource `myds` must start with the protocol `postgresql://`."
+    );
+}
+
+#[test]
+fn relation_load_strategy_must_work() {
+    let schema = r#"
+        datasource ds {
+          provider = "postgresql"
+          url = "postgresql://localhost"
+          relationLoadStrategy = "join"
+        }
+    "#;
+
+    let config = parse_configuration(schema);
+    let rendered = datamodel::json::mcf::render_sources_to_json(&config.datasources);
+
+    let expected = r#"[
+        {
+          "name": "ds",
+          "provider": ["postgresql"],
+          "activeProvider": "postgresql",
+          "url": {
+              "fromEnvVar": null,
+              "value": "postgresql://localhost"
+          },
+          "relationLoadStrategy": "join"
+        }
+    ]"#;
+
+    assert_eq_json(&rendered, expected);
+}
+
+#[test]
+fn must_error_for_an_unknown_relation_load_strategy() {
+    let schema = r#"
+        datasource myds {
+            provider = "postgresql"
+            url = "postgresql://localhost"
+            relationLoadStrategy = "lateral"
+        }
+    "#;
+
+    let diagnostics = datamodel::parse_configuration(schema).err().expect("This must error");
+    let error = diagnostics.to_error_iter().next().unwrap();
+
+    assert_eq!(
+        error.description(),
+        "Invalid relation load strategy `lateral` for the datasource `myds`. Supported values are: query, join."
+    );
+}
+
+#[test]
+fn migrations_table_and_schema_must_work() {
+    let schema = r#"
+        datasource ds {
+          provider = "postgresql"
+          url = "postgresql://localhost"
+          migrationsTable = "migrations"
+          migrationsSchema = "admin"
+        }
+    "#;
+
+    let config = parse_configuration(schema);
+    let rendered = datamodel::json::mcf::render_sources_to_json(&config.datasources);
+
+    let expected = r#"[
+        {
+          "name": "ds",
+          "provider": ["postgresql"],
+          "activeProvider": "postgresql",
+          "url": {
+              "fromEnvVar": null,
+              "value": "postgresql://localhost"
+          },
+          "migrationsTable": "migrations",
+          "migrationsSchema": "admin"
+        }
+    ]"#;
+
+    assert_eq_json(&rendered, expected);
+}
+
+#[test]
+fn must_error_for_an_empty_migrations_table() {
+    let schema = r#"
+        datasource myds {
+            provider = "postgresql"
+            url = "postgresql://localhost"
+            migrationsTable = " "
+        }
+    "#;
+
+    let diagnostics = datamodel::parse_configuration(schema).err().expect("This must error");
+    let error = diagnostics.to_error_iter().next().unwrap();
+
+    assert_eq!(
+        error.description(),
+        "The `migrationsTable` of the datasource `myds` must not be empty."
+    );
+}
+
+#[test]
+fn must_error_for_a_migrations_schema_on_sqlite() {
+    let schema = r#"
+        datasource myds {
+            provider = "sqlite"
+            url = "file:dev.db"
+            migrationsSchema = "admin"
+        }
+    "#;
+
+    let diagnostics = datamodel::parse_configuration(schema).err().expect("This must error");
+    let error = diagnostics.to_error_iter().next().unwrap();
+
+    assert_eq!(
+        error.description(),
+        "The `migrationsSchema` property of the datasource `myds` is not supported on SQLite."
+    );
+}
+
 fn assert_eq_json(a: &str, b: &str) {
     let json_a: serde_json::Value = serde_json::from_str(a).expect("The String a was not valid JSON.");
     let json_b: serde_json::Value = serde_json::from_str(b).expect("The String b was not valid JSON.");
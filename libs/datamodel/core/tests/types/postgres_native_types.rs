@@ -1,5 +1,5 @@
 use crate::common::*;
-use crate::types::helper::test_native_types_without_attributes;
+use crate::types::helper::{test_native_types_compatibility, test_native_types_without_attributes};
 use datamodel::{ast, diagnostics::DatamodelError};
 use native_types::PostgresType;
 
@@ -101,3 +101,19 @@ fn xml_should_work_with_string_scalar_type() {
     let postgres_tpe: PostgresType = sft.deserialize_native_type();
     assert_eq!(postgres_tpe, PostgresType::Xml);
 }
+
+#[test]
+fn timetz_should_not_work_with_time_scalar_type() {
+    let dml = r#"
+        model Blog {
+            id Int  @id
+            at Time @db.Timetz(3)
+        }
+    "#;
+
+    test_native_types_compatibility(
+        dml,
+        "Native type Timetz is not compatible with declared field type Time, expected field type DateTime.",
+        POSTGRES_SOURCE,
+    );
+}
@@ -119,6 +119,8 @@ impl<'a> DatamodelConverter<'a> {
                         db_name: sf.database_name.clone(),
                         arity: sf.arity,
                         default_value: sf.default_value.clone(),
+                        generation_expression: sf.generation_expression.clone(),
+                        native_type: sf.field_type.native_type().map(|nt| nt.name.clone()),
                     }))
                 }
             })
@@ -433,6 +435,8 @@ impl DatamodelFieldExtensions for dml::ScalarField {
             dml::FieldType::Base(scalar, _) => match scalar {
                 dml::ScalarType::Boolean => TypeIdentifier::Boolean,
                 dml::ScalarType::DateTime => TypeIdentifier::DateTime,
+                dml::ScalarType::Date => TypeIdentifier::Date,
+                dml::ScalarType::Time => TypeIdentifier::Time,
                 dml::ScalarType::Float => TypeIdentifier::Float,
                 dml::ScalarType::Decimal => TypeIdentifier::Decimal,
                 dml::ScalarType::Int => TypeIdentifier::Int,
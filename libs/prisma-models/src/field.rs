@@ -69,6 +69,8 @@ pub enum TypeIdentifier {
     Json,
     Xml,
     DateTime,
+    Date,
+    Time,
     Bytes,
     Unsupported,
 }
@@ -191,6 +193,8 @@ impl From<ScalarType> for TypeIdentifier {
             ScalarType::Float => Self::Float,
             ScalarType::Boolean => Self::Boolean,
             ScalarType::DateTime => Self::DateTime,
+            ScalarType::Date => Self::Date,
+            ScalarType::Time => Self::Time,
             ScalarType::Json => Self::Json,
             ScalarType::Decimal => Self::Decimal,
             ScalarType::Bytes => Self::Bytes,
@@ -30,6 +30,8 @@ pub struct ScalarFieldTemplate {
     pub arity: FieldArity,
     pub db_name: Option<String>,
     pub default_value: Option<DefaultValue>,
+    pub generation_expression: Option<String>,
+    pub native_type: Option<String>,
 }
 
 pub struct ScalarField {
@@ -46,6 +48,14 @@ pub struct ScalarField {
     pub db_name: Option<String>,
     pub default_value: Option<DefaultValue>,
 
+    /// The expression the database computes the field from, if it is
+    /// generated. Generated fields can't be written.
+    pub generation_expression: Option<String>,
+
+    /// The name of the native type of the field in the schema, like
+    /// `Timestamptz`, if it has one.
+    pub native_type: Option<String>,
+
     pub model: ModelWeakRef,
     pub(crate) is_unique: bool,
     pub(crate) read_only: OnceCell<bool>,
@@ -66,6 +76,8 @@ impl Debug for ScalarField {
             .field("arity", &self.arity)
             .field("db_name", &self.db_name)
             .field("default_value", &self.default_value)
+            .field("generation_expression", &self.generation_expression)
+            .field("native_type", &self.native_type)
             .field("model", &"#ModelWeakRef#")
             .field("is_unique", &self.is_unique)
             .field("read_only", &self.read_only)
@@ -103,6 +115,8 @@ impl PartialEq for ScalarField {
             && self.internal_enum == other.internal_enum
             && self.behaviour == other.behaviour
             && self.default_value == other.default_value
+            && self.generation_expression == other.generation_expression
+            && self.native_type == other.native_type
             && self.is_unique == other.is_unique
             && self.model() == other.model()
             && self.arity == other.arity
@@ -140,6 +154,8 @@ impl ScalarFieldTemplate {
             arity: self.arity,
             db_name: self.db_name,
             default_value: self.default_value,
+            generation_expression: self.generation_expression,
+            native_type: self.native_type,
             model,
         };
 
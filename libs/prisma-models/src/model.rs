@@ -160,6 +160,15 @@ impl Model {
             .expect("InternalDataModel does not exist anymore. Parent internal_data_model is deleted without deleting the child internal_data_model.")
     }
 
+    /// The field holding the time records were soft deleted at, if the model
+    /// has a `@@softDelete` attribute. Records are soft deleted if it is set.
+    pub fn soft_delete_field(&self) -> Option<ScalarFieldRef> {
+        self.dml_model
+            .soft_delete_field
+            .as_ref()
+            .map(|name| self.fields().find_from_scalar(name).unwrap())
+    }
+
     pub fn map_scalar_db_field_name(&self, name: &str) -> Option<ScalarFieldRef> {
         self.fields()
             .scalar()
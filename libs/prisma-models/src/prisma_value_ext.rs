@@ -16,15 +16,21 @@ impl PrismaValueExtensions for PrismaValue {
             (PrismaValue::Null, _) => PrismaValue::Null,
             (val @ PrismaValue::String(_), TypeIdentifier::String) => val,
             (val @ PrismaValue::Int(_), TypeIdentifier::Int) => val,
+            (val @ PrismaValue::BigInt(_), TypeIdentifier::BigInt) => val,
+            (val @ PrismaValue::UnsignedBigInt(_), TypeIdentifier::BigInt) => val,
             (val @ PrismaValue::Float(_), TypeIdentifier::Float) => val,
+            (val @ PrismaValue::Decimal(_), TypeIdentifier::Decimal) => val,
             (val @ PrismaValue::Boolean(_), TypeIdentifier::Boolean) => val,
             (val @ PrismaValue::DateTime(_), TypeIdentifier::DateTime) => val,
+            (val @ PrismaValue::Date(_), TypeIdentifier::Date) => val,
+            (val @ PrismaValue::Time(_), TypeIdentifier::Time) => val,
             (val @ PrismaValue::Enum(_), TypeIdentifier::Enum(_)) => val,
             (val @ PrismaValue::Uuid(_), TypeIdentifier::UUID) => val,
 
             // Valid String coercions
             (PrismaValue::Int(i), TypeIdentifier::String) => PrismaValue::String(format!("{}", i)),
             (PrismaValue::Float(f), TypeIdentifier::String) => PrismaValue::String(f.to_string()),
+            (PrismaValue::Decimal(d), TypeIdentifier::String) => PrismaValue::String(d.to_string()),
             (PrismaValue::Boolean(b), TypeIdentifier::String) => PrismaValue::String(format!("{}", b)),
             (PrismaValue::Enum(e), TypeIdentifier::String) => PrismaValue::String(e),
             (PrismaValue::Uuid(u), TypeIdentifier::String) => PrismaValue::String(u.to_string()),
@@ -41,6 +47,10 @@ impl PrismaValueExtensions for PrismaValue {
             },
             (PrismaValue::Float(f), TypeIdentifier::Int) => PrismaValue::Int(f.to_i64().unwrap()),
 
+            // Valid Decimal coercions
+            (PrismaValue::Int(i), TypeIdentifier::Decimal) => PrismaValue::Decimal(i.into()),
+            (PrismaValue::Float(f), TypeIdentifier::Decimal) => PrismaValue::Decimal(f),
+
             // Todo other coercions here
 
             // Lists
@@ -166,7 +166,7 @@ where
             TypeIdentifier::UUID => TypeFamily::Uuid,
             TypeIdentifier::Json => TypeFamily::Text,
             TypeIdentifier::Xml => TypeFamily::Text,
-            TypeIdentifier::DateTime => TypeFamily::DateTime,
+            TypeIdentifier::DateTime | TypeIdentifier::Date | TypeIdentifier::Time => TypeFamily::DateTime,
             TypeIdentifier::Bytes => TypeFamily::Bytes,
             TypeIdentifier::Unsupported => unreachable!("No unsupported field should reach that path"),
         };
@@ -1,3 +1,4 @@
+use bigdecimal::BigDecimal;
 use chrono::Utc;
 use prisma_value::PrismaValue;
 use quaint::ast::Value;
@@ -13,11 +14,15 @@ impl ScalarFieldExt for ScalarField {
         match (pv, &self.type_identifier) {
             (PrismaValue::String(s), _) => s.into(),
             (PrismaValue::Float(f), _) => f.into(),
+            (PrismaValue::Decimal(d), _) => d.into(),
             (PrismaValue::Boolean(b), _) => b.into(),
             (PrismaValue::DateTime(d), _) => d.with_timezone(&Utc).into(),
+            (PrismaValue::Date(d), _) => Value::Date(Some(d)),
+            (PrismaValue::Time(t), _) => Value::Time(Some(t)),
             (PrismaValue::Enum(e), _) => e.into(),
             (PrismaValue::Int(i), _) => (i as i64).into(),
             (PrismaValue::BigInt(i), _) => (i as i64).into(),
+            (PrismaValue::UnsignedBigInt(u), _) => BigDecimal::from(u).into(),
             (PrismaValue::Uuid(u), _) => u.to_string().into(),
             (PrismaValue::List(l), _) => Value::Array(Some(l.into_iter().map(|x| self.value(x)).collect())),
             (PrismaValue::Json(s), _) => Value::Json(serde_json::from_str(&s).unwrap()),
@@ -31,6 +36,8 @@ impl ScalarFieldExt for ScalarField {
                 TypeIdentifier::Enum(_) => Value::Enum(None),
                 TypeIdentifier::Json => Value::Json(None),
                 TypeIdentifier::DateTime => Value::DateTime(None),
+                TypeIdentifier::Date => Value::Date(None),
+                TypeIdentifier::Time => Value::Time(None),
                 TypeIdentifier::UUID => Value::Uuid(None),
                 TypeIdentifier::Int => Value::Integer(None),
                 TypeIdentifier::BigInt => Value::Integer(None),
@@ -48,11 +55,15 @@ pub fn convert_lossy<'a>(pv: PrismaValue) -> Value<'a> {
     match pv {
         PrismaValue::String(s) => s.into(),
         PrismaValue::Float(f) => f.into(),
+        PrismaValue::Decimal(d) => d.into(),
         PrismaValue::Boolean(b) => b.into(),
         PrismaValue::DateTime(d) => d.with_timezone(&Utc).into(),
+        PrismaValue::Date(d) => Value::Date(Some(d)),
+        PrismaValue::Time(t) => Value::Time(Some(t)),
         PrismaValue::Enum(e) => e.into(),
         PrismaValue::Int(i) => (i as i64).into(),
         PrismaValue::BigInt(i) => (i as i64).into(),
+        PrismaValue::UnsignedBigInt(u) => BigDecimal::from(u).into(),
         PrismaValue::Uuid(u) => u.to_string().into(),
         PrismaValue::List(l) => Value::Array(Some(l.into_iter().map(convert_lossy).collect())),
         PrismaValue::Json(s) => Value::Json(serde_json::from_str(&s).unwrap()),
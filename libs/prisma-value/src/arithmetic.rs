@@ -23,6 +23,13 @@ macro_rules! number_operation {
 
           (PrismaValue::Float(l), PrismaValue::Float(r)) => PrismaValue::Float(l $op r),
 
+          // Decimals never go through an integer or a float, to keep their precision.
+          (PrismaValue::Decimal(l), PrismaValue::Decimal(r)) => PrismaValue::Decimal(l $op r),
+          (PrismaValue::Decimal(l), PrismaValue::Float(r)) => PrismaValue::Decimal(l $op r),
+          (PrismaValue::Float(l), PrismaValue::Decimal(r)) => PrismaValue::Decimal(l $op r),
+          (PrismaValue::Decimal(l), PrismaValue::Int(r)) => PrismaValue::Decimal(l $op BigDecimal::from(r)),
+          (PrismaValue::Int(l), PrismaValue::Decimal(r)) => PrismaValue::Decimal(BigDecimal::from(l) $op r),
+
           _ => unimplemented!(),
         }
       }
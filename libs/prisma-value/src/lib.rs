@@ -32,12 +32,32 @@ pub enum PrismaValue {
     #[serde(serialize_with = "serialize_date")]
     DateTime(DateTime<FixedOffset>),
 
+    /// A calendar date without a time or a time zone.
+    #[serde(serialize_with = "serialize_date_only")]
+    Date(NaiveDate),
+
+    /// A time of day without a date or a time zone.
+    #[serde(serialize_with = "serialize_time_only")]
+    Time(NaiveTime),
+
     #[serde(serialize_with = "serialize_decimal", deserialize_with = "deserialize_decimal")]
     Float(BigDecimal),
 
+    /// A fixed-point number. Serialized as a string, so that clients don't
+    /// read it into a floating-point number and lose precision.
+    #[serde(
+        serialize_with = "serialize_decimal_string",
+        deserialize_with = "deserialize_decimal"
+    )]
+    Decimal(BigDecimal),
+
     #[serde(serialize_with = "serialize_bigint")]
     BigInt(i64),
 
+    /// A 64-bit integer above `i64::MAX`, as read from unsigned columns.
+    #[serde(serialize_with = "serialize_unsigned_bigint")]
+    UnsignedBigInt(u64),
+
     #[serde(serialize_with = "serialize_bytes")]
     Bytes(Vec<u8>),
 }
@@ -46,6 +66,14 @@ pub fn stringify_date(date: &DateTime<FixedOffset>) -> String {
     date.to_rfc3339()
 }
 
+pub fn stringify_date_only(date: &NaiveDate) -> String {
+    date.format("%Y-%m-%d").to_string()
+}
+
+pub fn stringify_time_only(time: &NaiveTime) -> String {
+    time.format("%H:%M:%S%.f").to_string()
+}
+
 pub fn encode_bytes(bytes: &[u8]) -> String {
     base64::encode(bytes)
 }
@@ -69,6 +97,8 @@ impl TryFrom<serde_json::Value> for PrismaValue {
             serde_json::Value::Number(num) => {
                 if num.is_i64() {
                     Ok(PrismaValue::Int(num.as_i64().unwrap()))
+                } else if num.is_u64() {
+                    Ok(PrismaValue::UnsignedBigInt(num.as_u64().unwrap()))
                 } else {
                     let fl = num.as_f64().unwrap();
                     let dec = BigDecimal::from_f64(fl).unwrap().normalized();
@@ -101,6 +131,20 @@ where
     stringify_date(date).serialize(serializer)
 }
 
+fn serialize_date_only<S>(date: &NaiveDate, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    stringify_date_only(date).serialize(serializer)
+}
+
+fn serialize_time_only<S>(time: &NaiveTime, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    stringify_time_only(time).serialize(serializer)
+}
+
 fn serialize_bytes<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
@@ -122,6 +166,13 @@ where
     int.to_string().serialize(serializer)
 }
 
+fn serialize_unsigned_bigint<S>(int: &u64, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    int.to_string().serialize(serializer)
+}
+
 fn serialize_decimal<S>(decimal: &BigDecimal, serializer: S) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
@@ -129,6 +180,13 @@ where
     decimal.to_string().parse::<f64>().unwrap().serialize(serializer)
 }
 
+fn serialize_decimal_string<S>(decimal: &BigDecimal, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    decimal.to_string().serialize(serializer)
+}
+
 fn deserialize_decimal<'de, D>(deserializer: D) -> Result<BigDecimal, D::Error>
 where
     D: Deserializer<'de>,
@@ -211,6 +269,10 @@ impl PrismaValue {
         PrismaValue::Float(BigDecimal::from_f64(float).unwrap())
     }
 
+    pub fn new_decimal(decimal: &str) -> PrismaValue {
+        PrismaValue::Decimal(BigDecimal::from_str(decimal).unwrap())
+    }
+
     pub fn new_datetime(datetime: &str) -> PrismaValue {
         PrismaValue::DateTime(DateTime::parse_from_rfc3339(datetime).unwrap())
     }
@@ -221,8 +283,11 @@ impl fmt::Display for PrismaValue {
         match self {
             PrismaValue::String(x) => x.fmt(f),
             PrismaValue::Float(x) => x.fmt(f),
+            PrismaValue::Decimal(x) => x.fmt(f),
             PrismaValue::Boolean(x) => x.fmt(f),
             PrismaValue::DateTime(x) => x.fmt(f),
+            PrismaValue::Date(x) => stringify_date_only(x).fmt(f),
+            PrismaValue::Time(x) => stringify_time_only(x).fmt(f),
             PrismaValue::Enum(x) => x.fmt(f),
             PrismaValue::Int(x) => x.fmt(f),
             PrismaValue::Null => "null".fmt(f),
@@ -230,6 +295,7 @@ impl fmt::Display for PrismaValue {
             PrismaValue::Json(x) => x.fmt(f),
             PrismaValue::Xml(x) => x.fmt(f),
             PrismaValue::BigInt(x) => x.fmt(f),
+            PrismaValue::UnsignedBigInt(x) => x.fmt(f),
             PrismaValue::List(x) => {
                 let as_string = format!("{:?}", x);
                 as_string.fmt(f)
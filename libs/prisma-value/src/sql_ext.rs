@@ -1,6 +1,5 @@
 use crate::PrismaValue;
 use bigdecimal::{BigDecimal, FromPrimitive};
-use chrono::{DateTime, NaiveDate, Utc};
 use quaint::ast::Value;
 use std::convert::TryFrom;
 
@@ -48,8 +47,8 @@ impl<'a> TryFrom<Value<'a>> for PrismaValue {
             Value::Double(None) => PrismaValue::Null,
 
             Value::Numeric(d) => d
-                // chop the trailing zeroes off so javascript doesn't start rounding things wrong
-                .map(|d| PrismaValue::Float(d.normalized()))
+                // chop the trailing zeroes off, they're not part of the value
+                .map(|d| PrismaValue::Decimal(d.normalized()))
                 .unwrap_or(PrismaValue::Null),
 
             Value::Text(s) => s
@@ -80,20 +79,9 @@ impl<'a> TryFrom<Value<'a>> for PrismaValue {
 
             Value::Uuid(uuid) => uuid.map(PrismaValue::Uuid).unwrap_or(PrismaValue::Null),
 
-            Value::Date(d) => d
-                .map(|d| {
-                    let dt = DateTime::<Utc>::from_utc(d.and_hms(0, 0, 0), Utc);
-                    PrismaValue::DateTime(dt.into())
-                })
-                .unwrap_or(PrismaValue::Null),
+            Value::Date(d) => d.map(PrismaValue::Date).unwrap_or(PrismaValue::Null),
 
-            Value::Time(t) => t
-                .map(|t| {
-                    let d = NaiveDate::from_ymd(1970, 1, 1);
-                    let dt = DateTime::<Utc>::from_utc(d.and_time(t), Utc);
-                    PrismaValue::DateTime(dt.into())
-                })
-                .unwrap_or(PrismaValue::Null),
+            Value::Time(t) => t.map(PrismaValue::Time).unwrap_or(PrismaValue::Null),
 
             Value::DateTime(dt) => dt
                 .map(|dt| PrismaValue::DateTime(dt.into()))
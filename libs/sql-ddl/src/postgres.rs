@@ -173,6 +173,8 @@ impl<'a> Display for CreateEnum<'a> {
 pub struct CreateIndex<'a> {
     pub index_name: PostgresIdentifier<'a>,
     pub is_unique: bool,
+    /// Build the index without blocking writes to the table. This can't run inside a transaction.
+    pub concurrently: bool,
     pub table_reference: PostgresIdentifier<'a>,
     pub columns: Vec<Cow<'a, str>>,
 }
@@ -181,8 +183,9 @@ impl<'a> Display for CreateIndex<'a> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "CREATE {uniqueness}INDEX {index_name} ON {table_reference}(",
+            "CREATE {uniqueness}INDEX {concurrently}{index_name} ON {table_reference}(",
             uniqueness = if self.is_unique { "UNIQUE " } else { "" },
+            concurrently = if self.concurrently { "CONCURRENTLY " } else { "" },
             index_name = self.index_name,
             table_reference = self.table_reference,
         )?;
@@ -227,6 +230,7 @@ mod tests {
 
         let create_index = CreateIndex {
             is_unique: true,
+            concurrently: false,
             index_name: "meow_idx".into(),
             table_reference: "Cat".into(),
             columns,
@@ -238,6 +242,22 @@ mod tests {
         )
     }
 
+    #[test]
+    fn create_index_concurrently() {
+        let create_index = CreateIndex {
+            is_unique: false,
+            concurrently: true,
+            index_name: "meow_idx".into(),
+            table_reference: "Cat".into(),
+            columns: vec!["name".into()],
+        };
+
+        assert_eq!(
+            create_index.to_string(),
+            "CREATE INDEX CONCURRENTLY \"meow_idx\" ON \"Cat\"(\"name\")"
+        )
+    }
+
     #[test]
     fn full_alter_table_add_foreign_key() {
         let alter_table = AlterTable {
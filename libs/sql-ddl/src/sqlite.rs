@@ -131,6 +131,8 @@ pub struct Column<'a> {
     pub default: Option<Cow<'a, str>>,
     /// Whether to render AUTOINCREMENT on the primary key.
     pub autoincrement: bool,
+    /// The expression of a `GENERATED ALWAYS AS (...) STORED` column.
+    pub generated: Option<Cow<'a, str>>,
 }
 
 impl Display for Column<'_> {
@@ -150,6 +152,10 @@ impl Display for Column<'_> {
             f.write_str(default)?;
         }
 
+        if let Some(expression) = &self.generated {
+            write!(f, " GENERATED ALWAYS AS ({}) STORED", expression)?;
+        }
+
         Ok(())
     }
 }
@@ -227,6 +233,39 @@ mod tests {
         assert_eq!(create_table.to_string(), expected.trim_matches('\n'))
     }
 
+    #[test]
+    fn create_table_with_generated_column() {
+        let create_table = CreateTable {
+            table_name: "Cat".into(),
+            columns: vec![
+                Column {
+                    name: "lives".into(),
+                    r#type: "integer".into(),
+                    not_null: true,
+                    ..Default::default()
+                },
+                Column {
+                    name: "halfLives".into(),
+                    r#type: "integer".into(),
+                    generated: Some(r#""lives" / 2"#.into()),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+
+        let expected = indoc!(
+            r#"
+            CREATE TABLE "Cat" (
+                "lives" integer NOT NULL,
+                "halfLives" integer GENERATED ALWAYS AS ("lives" / 2) STORED
+            )
+            "#
+        );
+
+        assert_eq!(create_table.to_string(), expected.trim_matches('\n'))
+    }
+
     #[test]
     fn create_table_with_primary_key_and_foreign_keys() {
         let create_table = CreateTable {
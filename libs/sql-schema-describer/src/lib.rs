@@ -240,6 +240,8 @@ pub struct Column {
     pub default: Option<DefaultValue>,
     /// Is the column auto-incrementing?
     pub auto_increment: bool,
+    /// The expression a generated (computed) column is computed from.
+    pub generation_expression: Option<String>,
 }
 
 impl Column {
@@ -299,6 +301,10 @@ pub enum ColumnTypeFamily {
     String,
     /// DateTime types.
     DateTime,
+    /// Date types, without a time of day.
+    Date,
+    /// Time of day types, without a date.
+    Time,
     /// Binary types.
     Binary,
     /// JSON types.
@@ -346,6 +352,8 @@ impl fmt::Display for ColumnTypeFamily {
             Self::Boolean => "boolean".to_string(),
             Self::String => "string".to_string(),
             Self::DateTime => "dateTime".to_string(),
+            Self::Date => "date".to_string(),
+            Self::Time => "time".to_string(),
             Self::Binary => "binary".to_string(),
             Self::Json => "json".to_string(),
             Self::Uuid => "uuid".to_string(),
@@ -201,6 +201,7 @@ impl SqlSchemaDescriber {
                 COLUMNPROPERTY(c.object_id, c.name, 'IsIdentity')  AS is_identity,
                 OBJECT_NAME(c.object_id)                           AS table_name,
                 OBJECT_NAME(c.default_object_id)                   AS constraint_name,
+                cc.definition                                      AS generation_expression,
                 convert(tinyint, CASE
                     WHEN c.system_type_id IN (48, 52, 56, 59, 60, 62, 106, 108, 122, 127) THEN c.precision
                     END)                                           AS numeric_precision,
@@ -209,6 +210,7 @@ impl SqlSchemaDescriber {
                     ELSE ODBCSCALE(c.system_type_id, c.scale) END) AS numeric_scale
             FROM sys.columns c
                     INNER JOIN sys.tables t ON c.object_id = t.object_id
+                    LEFT JOIN sys.computed_columns cc ON cc.object_id = c.object_id AND cc.column_id = c.column_id
             WHERE OBJECT_SCHEMA_NAME(c.object_id) = @P1
             AND t.is_ms_shipped = 0
 
@@ -291,6 +293,9 @@ impl SqlSchemaDescriber {
                                 "getdate()" => DefaultValue::now(),
                                 _ => DefaultValue::db_generated(default_string),
                             },
+                            ColumnTypeFamily::Date | ColumnTypeFamily::Time => {
+                                DefaultValue::db_generated(default_string)
+                            }
                             ColumnTypeFamily::Binary => DefaultValue::db_generated(default_string),
                             ColumnTypeFamily::Json => DefaultValue::db_generated(default_string),
                             ColumnTypeFamily::Uuid => DefaultValue::db_generated(default_string),
@@ -312,6 +317,7 @@ impl SqlSchemaDescriber {
                 tpe,
                 default,
                 auto_increment,
+                generation_expression: col.get_string("generation_expression"),
             });
         }
 
@@ -610,8 +616,8 @@ impl SqlSchemaDescriber {
             "bit" => (Boolean, Some(MsSqlType::Bit)),
             "float" => (Float, Some(MsSqlType::Float(numeric_precision))),
             "real" => (Float, Some(MsSqlType::Real)),
-            "date" => (DateTime, Some(MsSqlType::Date)),
-            "time" => (DateTime, Some(MsSqlType::Time)),
+            "date" => (Date, Some(MsSqlType::Date)),
+            "time" => (Time, Some(MsSqlType::Time)),
             "datetime" => (DateTime, Some(MsSqlType::DateTime)),
             "datetime2" => (DateTime, Some(MsSqlType::DateTime2)),
             "datetimeoffset" => (DateTime, Some(MsSqlType::DateTimeOffset)),
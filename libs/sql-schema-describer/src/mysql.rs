@@ -15,6 +15,24 @@ fn is_mariadb(version: &str) -> bool {
     version.contains("MariaDB")
 }
 
+/// The generation expressions of generated columns are in the information
+/// schema from MySQL 5.7 and MariaDB 10.2 on.
+fn has_generation_expressions(version: &str) -> bool {
+    // MariaDB versions can be prefixed with a fake MySQL version.
+    let mut numbers = version
+        .trim_start_matches("5.5.5-")
+        .split(|c: char| !c.is_ascii_digit())
+        .map(|number| number.parse::<u32>().unwrap_or(0));
+
+    let major_minor = (numbers.next().unwrap_or(0), numbers.next().unwrap_or(0));
+
+    if is_mariadb(version) {
+        major_minor >= (10, 2)
+    } else {
+        major_minor >= (5, 7)
+    }
+}
+
 enum Flavour {
     Mysql,
     MariaDb,
@@ -60,7 +78,8 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
 
         let table_names = self.get_table_names(schema).await?;
         let mut tables = Vec::with_capacity(table_names.len());
-        let mut columns = Self::get_all_columns(&self.conn, schema, &flavour).await?;
+        let generation_expressions = version.as_deref().map(has_generation_expressions).unwrap_or(false);
+        let mut columns = Self::get_all_columns(&self.conn, schema, &flavour, generation_expressions).await?;
         let mut indexes = Self::get_all_indexes(&self.conn, schema).await?;
         let mut fks = Self::get_foreign_keys(&self.conn, schema).await?;
 
@@ -177,11 +196,19 @@ impl SqlSchemaDescriber {
         conn: &dyn Queryable,
         schema_name: &str,
         flavour: &Flavour,
+        generation_expressions: bool,
     ) -> DescriberResult<HashMap<String, (Vec<Column>, Vec<Enum>)>> {
+        let generation_expression = if generation_expressions {
+            "generation_expression"
+        } else {
+            "NULL"
+        };
+
         // We alias all the columns because MySQL column names are case-insensitive in queries, but the
         // information schema column names became upper-case in MySQL 8, causing the code fetching
         // the result values by column name below to fail.
-        let sql = "
+        let sql = format!(
+            "
             SELECT
                 column_name column_name,
                 data_type data_type,
@@ -193,15 +220,18 @@ impl SqlSchemaDescriber {
                 column_default column_default,
                 is_nullable is_nullable,
                 extra extra,
+                {} generation_expression,
                 table_name table_name
             FROM information_schema.columns
             WHERE table_schema = ?
             ORDER BY ordinal_position
-        ";
+        ",
+            generation_expression
+        );
 
         let mut map = HashMap::new();
 
-        let rows = conn.query_raw(sql, &[schema_name.into()]).await?;
+        let rows = conn.query_raw(&sql, &[schema_name.into()]).await?;
 
         for col in rows {
             trace!("Got column: {:?}", col);
@@ -249,6 +279,13 @@ impl SqlSchemaDescriber {
             let extra = col.get_expect_string("extra").to_lowercase();
             let auto_increment = matches!(extra.as_str(), "auto_increment");
 
+            // Not to be confused with `DEFAULT_GENERATED`, for expression defaults.
+            let generation_expression = if extra.contains("stored generated") || extra.contains("virtual generated") {
+                col.get_string("generation_expression")
+            } else {
+                None
+            };
+
             let entry = map.entry(table_name).or_insert((Vec::new(), Vec::new()));
 
             if let Some(enm) = enum_option {
@@ -291,6 +328,9 @@ impl SqlSchemaDescriber {
                                 true => DefaultValue::now(),
                                 _ => DefaultValue::db_generated(default_string),
                             },
+                            ColumnTypeFamily::Date | ColumnTypeFamily::Time => {
+                                DefaultValue::db_generated(default_string)
+                            }
                             ColumnTypeFamily::Binary => DefaultValue::db_generated(default_string),
                             ColumnTypeFamily::Json => DefaultValue::db_generated(default_string),
                             ColumnTypeFamily::Uuid => DefaultValue::db_generated(default_string),
@@ -308,6 +348,7 @@ impl SqlSchemaDescriber {
                 tpe,
                 default,
                 auto_increment,
+                generation_expression,
             };
 
             entry.0.push(col);
@@ -593,10 +634,10 @@ impl SqlSchemaDescriber {
             "json" => (ColumnTypeFamily::Json, Some(MySqlType::Json)),
             "set" => (ColumnTypeFamily::String, None),
             //temporal
-            "date" => (ColumnTypeFamily::DateTime, Some(MySqlType::Date)),
+            "date" => (ColumnTypeFamily::Date, Some(MySqlType::Date)),
             "time" => (
                 //Fixme this can either be a time or a duration -.-
-                ColumnTypeFamily::DateTime,
+                ColumnTypeFamily::Time,
                 Some(MySqlType::Time(precision.time_precision)),
             ),
             "datetime" => (
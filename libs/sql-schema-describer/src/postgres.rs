@@ -168,6 +168,8 @@ impl SqlSchemaDescriber {
                 info.column_default,
                 info.is_nullable,
                 info.is_identity,
+                info.is_generated,
+                info.generation_expression,
                 info.data_type,
                 info.character_maximum_length
             FROM information_schema.columns info
@@ -206,11 +208,18 @@ impl SqlSchemaDescriber {
             let auto_increment =
                 is_identity || matches!(default.as_ref().map(|d| d.kind()), Some(DefaultKind::SEQUENCE(_)));
 
+            // `GENERATED ALWAYS AS (...) STORED`, available from Postgres 12 on.
+            let generation_expression = match col.get_string("is_generated").as_deref() {
+                Some("ALWAYS") => col.get_string("generation_expression"),
+                _ => None,
+            };
+
             let col = Column {
                 name,
                 tpe,
                 default,
                 auto_increment,
+                generation_expression,
             };
 
             columns.entry(table_name).or_default().push(col);
@@ -625,6 +634,7 @@ impl SqlSchemaDescriber {
                                 _ => DefaultValue::db_generated(default_string), //todo parse values
                             }
                         }
+                        ColumnTypeFamily::Date | ColumnTypeFamily::Time => DefaultValue::db_generated(default_string),
                         ColumnTypeFamily::Binary => DefaultValue::db_generated(default_string),
                         // JSON/JSONB defaults come in the '{}'::jsonb form.
                         ColumnTypeFamily::Json => unsuffix_default_literal(&default_string, "jsonb", "jsonb")
@@ -691,7 +701,7 @@ fn get_column_type(row: &ResultRow, enums: &[Enum]) -> ColumnType {
         "citext" | "_citext" => (String, Some(PostgresType::Citext)),
         "varchar" | "_varchar" => (String, Some(PostgresType::VarChar(precision.character_maximum_length))),
         "bpchar" | "_bpchar" => (String, Some(PostgresType::Char(precision.character_maximum_length))),
-        "date" | "_date" => (DateTime, Some(PostgresType::Date)),
+        "date" | "_date" => (Date, Some(PostgresType::Date)),
         "bytea" | "_bytea" => (Binary, Some(PostgresType::ByteA)),
         "json" | "_json" => (Json, Some(PostgresType::JSON)),
         "jsonb" | "_jsonb" => (Json, Some(PostgresType::JSONB)),
@@ -712,7 +722,7 @@ fn get_column_type(row: &ResultRow, enums: &[Enum]) -> ColumnType {
         ),
         "money" | "_money" => (Decimal, Some(PostgresType::Money)),
         "pg_lsn" | "_pg_lsn" => unsupported_type(),
-        "time" | "_time" => (DateTime, Some(PostgresType::Time(precision.time_precision))),
+        "time" | "_time" => (Time, Some(PostgresType::Time(precision.time_precision))),
         "timetz" | "_timetz" => (DateTime, Some(PostgresType::Timetz(precision.time_precision))),
         "timestamp" | "_timestamp" => (DateTime, Some(PostgresType::Timestamp(precision.time_precision))),
         "timestamptz" | "_timestamptz" => (DateTime, Some(PostgresType::Timestamptz(precision.time_precision))),
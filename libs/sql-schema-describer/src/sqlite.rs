@@ -140,13 +140,18 @@ impl SqlSchemaDescriber {
 
     #[tracing::instrument]
     async fn get_columns(&self, table: &str) -> DescriberResult<(Vec<Column>, Option<PrimaryKey>)> {
-        let sql = format!(r#"PRAGMA table_info ("{}")"#, table);
+        // `table_xinfo` also lists generated columns, `table_info` leaves them out.
+        let sql = format!(r#"PRAGMA table_xinfo ("{}")"#, table);
         let result_set = self.conn.query_raw(&sql, &[]).await?;
+        let create_table = self.get_create_table(table).await?;
         let mut pk_cols: HashMap<i64, String> = HashMap::new();
         let mut cols: Vec<Column> = result_set
             .into_iter()
+            // Hidden columns of virtual tables.
+            .filter(|row| row.get("hidden").and_then(|x| x.as_i64()) != Some(1))
             .map(|row| {
                 trace!("Got column row {:?}", row);
+                let name = row.get("name").and_then(|x| x.to_string()).expect("name");
                 let is_required = row.get("notnull").and_then(|x| x.as_bool()).expect("notnull");
 
                 let arity = if is_required {
@@ -154,7 +159,16 @@ impl SqlSchemaDescriber {
                 } else {
                     ColumnArity::Nullable
                 };
-                let tpe = get_column_type(&row.get("type").and_then(|x| x.to_string()).expect("type"), arity);
+                let full_data_type = row.get("type").and_then(|x| x.to_string()).expect("type");
+                let tpe = get_column_type(strip_generated_always(&full_data_type), arity);
+
+                // Virtual (2) and stored (3) generated columns.
+                let generation_expression = match row.get("hidden").and_then(|x| x.as_i64()) {
+                    Some(2) | Some(3) => create_table
+                        .as_deref()
+                        .and_then(|create_table| generation_expression(create_table, &name)),
+                    _ => None,
+                };
 
                 let default = match row.get("dflt_value") {
                     None => None,
@@ -199,6 +213,9 @@ impl SqlSchemaDescriber {
                                     }
                                     _ => DefaultValue::db_generated(default_string),
                                 },
+                                ColumnTypeFamily::Date | ColumnTypeFamily::Time => {
+                                    DefaultValue::db_generated(default_string)
+                                }
                                 ColumnTypeFamily::Binary => DefaultValue::db_generated(default_string),
                                 ColumnTypeFamily::Json => DefaultValue::db_generated(default_string),
                                 ColumnTypeFamily::Uuid => DefaultValue::db_generated(default_string),
@@ -212,10 +229,11 @@ impl SqlSchemaDescriber {
 
                 let pk_col = row.get("pk").and_then(|x| x.as_i64()).expect("primary key");
                 let col = Column {
-                    name: row.get("name").and_then(|x| x.to_string()).expect("name"),
+                    name,
                     tpe,
                     default,
                     auto_increment: false,
+                    generation_expression,
                 };
                 if pk_col > 0 {
                     pk_cols.insert(pk_col, col.name.clone());
@@ -269,6 +287,19 @@ impl SqlSchemaDescriber {
         Ok((cols, primary_key))
     }
 
+    /// The `CREATE TABLE` statement of the table, which is the only place SQLite
+    /// keeps the expressions of generated columns in.
+    #[tracing::instrument]
+    async fn get_create_table(&self, table: &str) -> DescriberResult<Option<String>> {
+        let sql = r#"SELECT sql FROM sqlite_master WHERE type = 'table' AND name = ?"#;
+        let result_set = self.conn.query_raw(sql, &[table.into()]).await?;
+
+        Ok(result_set
+            .into_iter()
+            .next()
+            .and_then(|row| row.get("sql").and_then(|x| x.to_string())))
+    }
+
     async fn get_foreign_keys(&self, table: &str) -> DescriberResult<Vec<ForeignKey>> {
         struct IntermediateForeignKey {
             pub columns: HashMap<i64, String>,
@@ -452,14 +483,16 @@ fn get_column_type(tpe: &str, arity: ColumnArity) -> ColumnType {
         s if s.contains("char") => ColumnTypeFamily::String,
         s if s.contains("numeric") => ColumnTypeFamily::Decimal,
         s if s.contains("decimal") => ColumnTypeFamily::Decimal,
-        "date" => ColumnTypeFamily::DateTime,
+        "date" => ColumnTypeFamily::Date,
+        "time" => ColumnTypeFamily::Time,
         "datetime" => ColumnTypeFamily::DateTime,
         "timestamp" => ColumnTypeFamily::DateTime,
         "binary" | "blob" => ColumnTypeFamily::Binary,
         "double" => ColumnTypeFamily::Float,
         "binary[]" => ColumnTypeFamily::Binary,
         "boolean[]" => ColumnTypeFamily::Boolean,
-        "date[]" => ColumnTypeFamily::DateTime,
+        "date[]" => ColumnTypeFamily::Date,
+        "time[]" => ColumnTypeFamily::Time,
         "datetime[]" => ColumnTypeFamily::DateTime,
         "timestamp[]" => ColumnTypeFamily::DateTime,
         "double[]" => ColumnTypeFamily::Float,
@@ -494,6 +527,101 @@ fn unquote_sqlite_string_default(s: &str) -> Cow<'_, str> {
     }
 }
 
+/// Older SQLite versions keep the `GENERATED ALWAYS` keywords of generated
+/// columns in their type.
+fn strip_generated_always(tpe: &str) -> &str {
+    static GENERATED_ALWAYS_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"(?i)\s*GENERATED\s+ALWAYS$"#).unwrap());
+
+    match GENERATED_ALWAYS_RE.find(tpe) {
+        Some(suffix) => &tpe[..suffix.start()],
+        None => tpe,
+    }
+}
+
+/// Extracts the expression of a generated column from the `CREATE TABLE`
+/// statement of its table, e.g. `price * quantity` from
+/// `"total" REAL GENERATED ALWAYS AS (price * quantity) STORED`.
+fn generation_expression(create_table: &str, column: &str) -> Option<String> {
+    static AS_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"(?i)\bAS\s*\("#).unwrap());
+
+    let body = &create_table[create_table.find('(')? + 1..];
+
+    split_definitions(body).into_iter().find_map(|definition| {
+        let definition = definition.trim_start();
+
+        if !unquote_identifier(definition).eq_ignore_ascii_case(column) {
+            return None;
+        }
+
+        let start = AS_RE.find(definition)?.end();
+        let length = balanced_length(&definition[start..])?;
+
+        Some(definition[start..start + length].trim().to_owned())
+    })
+}
+
+/// Splits the body of a `CREATE TABLE` statement into its column and
+/// constraint definitions.
+fn split_definitions(body: &str) -> Vec<&str> {
+    let mut definitions = Vec::new();
+    let mut depth = 0;
+    let mut quote: Option<char> = None;
+    let mut start = 0;
+
+    for (idx, c) in body.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => (),
+            (None, '\'') | (None, '"') | (None, '`') => quote = Some(c),
+            (None, '[') => quote = Some(']'),
+            (None, '(') => depth += 1,
+            (None, ')') if depth == 0 => {
+                definitions.push(&body[start..idx]);
+                return definitions;
+            }
+            (None, ')') => depth -= 1,
+            (None, ',') if depth == 0 => {
+                definitions.push(&body[start..idx]);
+                start = idx + 1;
+            }
+            _ => (),
+        }
+    }
+
+    definitions.push(&body[start..]);
+    definitions
+}
+
+/// The length of the text up to the closing parenthesis matching an already
+/// opened one.
+fn balanced_length(s: &str) -> Option<usize> {
+    let mut depth = 0;
+
+    for (idx, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' if depth == 0 => return Some(idx),
+            ')' => depth -= 1,
+            _ => (),
+        }
+    }
+
+    None
+}
+
+/// The leading, possibly quoted identifier of a column definition.
+fn unquote_identifier(definition: &str) -> &str {
+    let closing = match definition.chars().next() {
+        Some('"') => '"',
+        Some('`') => '`',
+        Some('[') => ']',
+        _ => return definition.split_whitespace().next().unwrap_or(""),
+    };
+
+    let rest = &definition[1..];
+    rest.find(closing).map(|end| &rest[..end]).unwrap_or(rest)
+}
+
 /// Returns whether a table is one of the SQLite system tables.
 fn is_system_table(table_name: &str) -> bool {
     SQLITE_SYSTEM_TABLES
@@ -509,3 +637,31 @@ const SQLITE_SYSTEM_TABLES: &[&str] = &[
     "sqlite_stat3",
     "sqlite_stat4",
 ];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sqlite_generation_expression_works() {
+        let create_table = r#"CREATE TABLE "Order" (
+            "id" INTEGER NOT NULL PRIMARY KEY,
+            "price" REAL NOT NULL DEFAULT 1.0,
+            "quantity" INTEGER NOT NULL,
+            "total" REAL GENERATED ALWAYS AS (round("price" * (1 + "quantity"), 2)) STORED,
+            [label] TEXT AS (upper('a, b')) VIRTUAL,
+            CHECK ("quantity" > 0)
+        )"#;
+
+        assert_eq!(
+            Some(r#"round("price" * (1 + "quantity"), 2)"#.to_owned()),
+            generation_expression(create_table, "total")
+        );
+        assert_eq!(
+            Some("upper('a, b')".to_owned()),
+            generation_expression(create_table, "label")
+        );
+        assert_eq!(None, generation_expression(create_table, "price"));
+        assert_eq!("REAL", strip_generated_always("REAL GENERATED ALWAYS"));
+    }
+}
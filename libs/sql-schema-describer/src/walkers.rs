@@ -81,6 +81,11 @@ impl<'a> ColumnWalker<'a> {
         self.column().default.as_ref()
     }
 
+    /// The expression the column is generated from, if it is a generated column.
+    pub fn generation_expression(&self) -> Option<&'a str> {
+        self.column().generation_expression.as_deref()
+    }
+
     /// The full column type.
     pub fn column_type(&self) -> &'a ColumnType {
         &self.column().tpe
@@ -79,6 +79,7 @@ async fn is_required_must_work(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "column2".to_string(),
@@ -90,6 +91,7 @@ async fn is_required_must_work(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
     ];
 
@@ -130,6 +132,7 @@ async fn foreign_keys_must_work(api: &TestApi) {
         },
         default: None,
         auto_increment: false,
+        generation_expression: None,
     }];
 
     let on_delete_action = match api.sql_family() {
@@ -222,6 +225,7 @@ async fn multi_column_foreign_keys_must_work(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "city_name".to_string(),
@@ -233,6 +237,7 @@ async fn multi_column_foreign_keys_must_work(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
     ];
 
@@ -297,6 +302,7 @@ async fn names_with_hyphens_must_work(api: &TestApi) {
         },
         default: None,
         auto_increment: false,
+        generation_expression: None,
     }];
     assert_eq!(user_table.columns, expected_columns);
 }
@@ -345,6 +351,7 @@ async fn composite_primary_keys_must_work(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "name".to_string(),
@@ -356,6 +363,7 @@ async fn composite_primary_keys_must_work(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -409,6 +417,7 @@ async fn indices_must_work(api: &TestApi) {
 
             default,
             auto_increment: true,
+            generation_expression: None,
         },
         Column {
             name: "count".to_string(),
@@ -420,6 +429,7 @@ async fn indices_must_work(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
     ];
     let pk_sequence = match api.sql_family() {
@@ -485,6 +495,7 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "uniq2".to_string(),
@@ -497,6 +508,7 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
     ];
     let mut expected_indices = vec![Index {
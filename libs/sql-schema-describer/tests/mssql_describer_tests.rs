@@ -64,6 +64,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: true,
+            generation_expression: None,
         },
         Column {
             name: "bit_col".to_string(),
@@ -76,6 +77,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "decimal_col".to_string(),
@@ -88,6 +90,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "int_col".to_string(),
@@ -99,6 +102,7 @@ async fn all_mssql_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "money_col".to_string(),
@@ -111,6 +115,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "numeric_col".to_string(),
@@ -123,6 +128,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "smallint_col".to_string(),
@@ -135,6 +141,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "smallmoney_col".to_string(),
@@ -147,6 +154,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "tinyint_col".to_string(),
@@ -159,6 +167,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "float_col".to_string(),
@@ -171,6 +180,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "double_col".to_string(),
@@ -183,18 +193,20 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "date_col".to_string(),
             tpe: ColumnType {
                 full_data_type: "date".to_string(),
-                family: ColumnTypeFamily::DateTime,
+                family: ColumnTypeFamily::Date,
                 arity: ColumnArity::Required,
                 native_type: Some(MsSqlType::Date.to_json()),
             },
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "datetime_col".to_string(),
@@ -206,6 +218,7 @@ async fn all_mssql_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "datetime2_col".to_string(),
@@ -218,6 +231,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "datetimeoffset_col".to_string(),
@@ -230,6 +244,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "smalldatetime_col".to_string(),
@@ -242,18 +257,20 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "time_col".to_string(),
             tpe: ColumnType {
                 full_data_type: "time".to_string(),
-                family: ColumnTypeFamily::DateTime,
+                family: ColumnTypeFamily::Time,
                 arity: ColumnArity::Required,
                 native_type: Some(MsSqlType::Time.to_json()),
             },
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "char_col".to_string(),
@@ -266,6 +283,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "varchar_col".to_string(),
@@ -278,6 +296,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "varchar_max_col".to_string(),
@@ -290,6 +309,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "text_col".to_string(),
@@ -302,6 +322,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "nvarchar_col".to_string(),
@@ -314,6 +335,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "nvarchar_max_col".to_string(),
@@ -326,6 +348,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "ntext_col".to_string(),
@@ -338,6 +361,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "binary_col".to_string(),
@@ -350,6 +374,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "varbinary_col".to_string(),
@@ -362,6 +387,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "varbinary_max_col".to_string(),
@@ -374,6 +400,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "image_col".to_string(),
@@ -386,6 +413,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "xml_col".to_string(),
@@ -398,6 +426,7 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -495,6 +524,7 @@ async fn mssql_foreign_key_on_delete_must_be_handled() {
 
                     default: None,
                     auto_increment: true,
+                    generation_expression: None,
                 },
                 Column {
                     name: "city".to_string(),
@@ -506,6 +536,7 @@ async fn mssql_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generation_expression: None,
                 },
                 Column {
                     name: "city_cascade".to_string(),
@@ -517,6 +548,7 @@ async fn mssql_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generation_expression: None,
                 },
             ],
             indices: vec![],
@@ -75,6 +75,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: true,
+            generation_expression: None,
         },
         Column {
             name: "int_col".to_string(),
@@ -87,6 +88,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "smallint_col".to_string(),
@@ -99,6 +101,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "tinyint4_col".to_string(),
@@ -110,6 +113,7 @@ async fn all_mysql_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "tinyint1_col".to_string(),
@@ -122,6 +126,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "mediumint_col".to_string(),
@@ -134,6 +139,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "bigint_col".to_string(),
@@ -146,6 +152,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "decimal_col".to_string(),
@@ -158,6 +165,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "numeric_col".to_string(),
@@ -170,6 +178,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "float_col".to_string(),
@@ -182,6 +191,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "double_col".to_string(),
@@ -194,30 +204,33 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "date_col".to_string(),
             tpe: ColumnType {
                 full_data_type: "date".to_string(),
-                family: ColumnTypeFamily::DateTime,
+                family: ColumnTypeFamily::Date,
                 arity: ColumnArity::Required,
                 native_type: Some(MySqlType::Date.to_json()),
             },
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "time_col".to_string(),
             tpe: ColumnType {
                 full_data_type: "time".to_string(),
-                family: ColumnTypeFamily::DateTime,
+                family: ColumnTypeFamily::Time,
                 arity: ColumnArity::Required,
                 native_type: Some(MySqlType::Time(Some(0)).to_json()),
             },
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "datetime_col".to_string(),
@@ -230,6 +243,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "timestamp_col".to_string(),
@@ -242,6 +256,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: Some(DefaultValue::now()),
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "year_col".to_string(),
@@ -254,6 +269,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "char_col".to_string(),
@@ -266,6 +282,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "varchar_col".to_string(),
@@ -278,6 +295,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "text_col".to_string(),
@@ -290,6 +308,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "tinytext_col".to_string(),
@@ -302,6 +321,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "mediumtext_col".to_string(),
@@ -314,6 +334,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "longtext_col".to_string(),
@@ -326,6 +347,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "enum_col".to_string(),
@@ -338,6 +360,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "set_col".to_string(),
@@ -350,6 +373,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "binary_col".to_string(),
@@ -362,6 +386,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "varbinary_col".to_string(),
@@ -374,6 +399,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "blob_col".to_string(),
@@ -386,6 +412,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "tinyblob_col".to_string(),
@@ -398,6 +425,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "mediumblob_col".to_string(),
@@ -410,6 +438,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "longblob_col".to_string(),
@@ -422,6 +451,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "geometry_col".to_string(),
@@ -434,6 +464,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "point_col".to_string(),
@@ -445,6 +476,7 @@ async fn all_mysql_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "linestring_col".to_string(),
@@ -456,6 +488,7 @@ async fn all_mysql_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "polygon_col".to_string(),
@@ -467,6 +500,7 @@ async fn all_mysql_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "multipoint_col".to_string(),
@@ -478,6 +512,7 @@ async fn all_mysql_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "multilinestring_col".to_string(),
@@ -489,6 +524,7 @@ async fn all_mysql_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "multipolygon_col".to_string(),
@@ -500,6 +536,7 @@ async fn all_mysql_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "geometrycollection_col".to_string(),
@@ -511,6 +548,7 @@ async fn all_mysql_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "json_col".to_string(),
@@ -522,6 +560,7 @@ async fn all_mysql_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -580,6 +619,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: true,
+                    generation_expression: None,
                 },
                 Column {
                     name: "city".to_string(),
@@ -591,6 +631,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generation_expression: None,
                 },
                 Column {
                     name: "city_cascade".to_string(),
@@ -602,6 +643,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generation_expression: None,
                 },
                 Column {
                     name: "city_restrict".to_string(),
@@ -613,6 +655,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generation_expression: None,
                 },
                 Column {
                     name: "city_set_null".to_string(),
@@ -624,6 +667,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generation_expression: None,
                 },
             ],
             indices: vec![
@@ -78,6 +78,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "array_bool_col".into(),
@@ -89,17 +90,19 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "array_date_col".into(),
             tpe: ColumnType {
                 full_data_type: "_date".into(),
-                family: ColumnTypeFamily::DateTime,
+                family: ColumnTypeFamily::Date,
                 arity: ColumnArity::List,
                 native_type: Some(PostgresType::Date.to_json()),
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "array_double_col".into(),
@@ -111,6 +114,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "array_float_col".into(),
@@ -122,6 +126,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "array_int_col".into(),
@@ -133,6 +138,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "array_text_col".into(),
@@ -144,6 +150,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "array_varchar_col".into(),
@@ -155,6 +162,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "binary_col".into(),
@@ -166,6 +174,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "boolean_col".into(),
@@ -177,17 +186,19 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "date_time_col".into(),
             tpe: ColumnType {
                 full_data_type: "date".into(),
-                family: ColumnTypeFamily::DateTime,
+                family: ColumnTypeFamily::Date,
                 arity: ColumnArity::Required,
                 native_type: Some(PostgresType::Date.to_json()),
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "double_col".into(),
@@ -199,6 +210,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "float_col".into(),
@@ -210,6 +222,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "int_col".into(),
@@ -221,6 +234,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "primary_col".into(),
@@ -232,6 +246,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: Some(DefaultValue::sequence("User_primary_col_seq".to_string())),
             auto_increment: true,
+            generation_expression: None,
         },
         Column {
             name: "string1_col".into(),
@@ -243,6 +258,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "string2_col".into(),
@@ -254,6 +270,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "bigint_col".into(),
@@ -265,6 +282,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "bigserial_col".into(),
@@ -276,6 +294,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: Some(DefaultValue::sequence("User_bigserial_col_seq".to_string())),
             auto_increment: true,
+            generation_expression: None,
         },
         Column {
             name: "bit_col".into(),
@@ -287,6 +306,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "bit_varying_col".into(),
@@ -298,6 +318,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "box_col".into(),
@@ -309,6 +330,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "char_col".into(),
@@ -320,6 +342,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "circle_col".into(),
@@ -331,6 +354,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "line_col".into(),
@@ -342,17 +366,19 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "time_col".into(),
             tpe: ColumnType {
                 full_data_type: "time".into(),
-                family: ColumnTypeFamily::DateTime,
+                family: ColumnTypeFamily::Time,
                 arity: ColumnArity::Required,
                 native_type: Some(PostgresType::Time(Some(6)).to_json()),
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "timetz_col".into(),
@@ -364,6 +390,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "timestamp_col".into(),
@@ -375,6 +402,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "timestamptz_col".into(),
@@ -386,6 +414,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "lseg_col".into(),
@@ -397,6 +426,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "numeric_col".into(),
@@ -408,6 +438,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "path_col".into(),
@@ -419,6 +450,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "pg_lsn_col".into(),
@@ -430,6 +462,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "polygon_col".into(),
@@ -441,6 +474,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "smallint_col".into(),
@@ -452,6 +486,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "smallserial_col".into(),
@@ -463,6 +498,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: Some(DefaultValue::sequence("User_smallserial_col_seq".to_string())),
             auto_increment: true,
+            generation_expression: None,
         },
         Column {
             name: "serial_col".into(),
@@ -474,6 +510,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: Some(DefaultValue::sequence("User_serial_col_seq".to_string())),
             auto_increment: true,
+            generation_expression: None,
         },
         Column {
             name: "tsquery_col".into(),
@@ -485,6 +522,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "tsvector_col".into(),
@@ -496,6 +534,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "txid_col".into(),
@@ -507,6 +546,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "json_col".into(),
@@ -518,6 +558,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "jsonb_col".into(),
@@ -529,6 +570,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "uuid_col".into(),
@@ -540,6 +582,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -629,6 +672,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
 
                     default: None,
                     auto_increment: false,
+                    generation_expression: None,
                 },
                 Column {
                     name: "city".into(),
@@ -640,6 +684,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generation_expression: None,
                 },
                 Column {
                     name: "city_cascade".into(),
@@ -651,6 +696,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generation_expression: None,
                 },
                 Column {
                     name: "city_restrict".into(),
@@ -662,6 +708,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generation_expression: None,
                 },
                 Column {
                     name: "city_set_null".into(),
@@ -673,6 +720,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generation_expression: None,
                 },
                 Column {
                     name: "city_set_default".into(),
@@ -684,6 +732,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generation_expression: None,
                 },
             ],
             indices: vec![],
@@ -38,6 +38,7 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "int4_col".to_string(),
@@ -49,6 +50,7 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "text_col".to_string(),
@@ -60,6 +62,7 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "real_col".to_string(),
@@ -71,6 +74,7 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
         Column {
             name: "primary_col".to_string(),
@@ -82,6 +86,7 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: true,
+            generation_expression: None,
         },
         Column {
             name: "decimal_col".to_string(),
@@ -93,6 +98,7 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generation_expression: None,
         },
     ];
 
@@ -145,6 +151,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: true,
+                    generation_expression: None,
                 },
                 Column {
                     name: "city".to_string(),
@@ -156,6 +163,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generation_expression: None,
                 },
                 Column {
                     name: "city_cascade".to_string(),
@@ -167,6 +175,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generation_expression: None,
                 },
                 Column {
                     name: "city_restrict".to_string(),
@@ -178,6 +187,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generation_expression: None,
                 },
                 Column {
                     name: "city_set_default".to_string(),
@@ -189,6 +199,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generation_expression: None,
                 },
                 Column {
                     name: "city_set_null".to_string(),
@@ -200,6 +211,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generation_expression: None,
                 },
             ],
             indices: vec![],
@@ -168,6 +168,7 @@ async fn schema_push(cmd: &SchemaPush) -> anyhow::Result<()> {
             schema,
             force: cmd.force,
             assume_empty: false,
+            enum_value_renames: Vec::new(),
         })
         .await?;
 
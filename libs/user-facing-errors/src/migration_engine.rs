@@ -162,6 +162,80 @@ pub struct ProviderSwitchedError {
     ///The provider specified in the schema.
     pub provider: String,
 }
+#[derive(Debug, Serialize, UserFacingError)]
+#[user_facing(
+    code = "P3015",
+    message = "The migration violates lint rules that are configured as errors. Change the migration, or lower the severity of the rules:\n{details}"
+)]
+pub struct MigrationLintFailed {
+    /// The lint errors, one per line.
+    pub details: String,
+}
+
+#[derive(Debug, Serialize, UserFacingError)]
+#[user_facing(
+    code = "P3016",
+    message = "The migration `{migration_name}` squashes migrations that were only partially applied to the database. Apply the following migrations from a version of the migrations directory before the squash, then apply `{migration_name}` again: {unapplied_migration_names}"
+)]
+pub struct SquashedMigrationPartiallyApplied {
+    /// The name of the squashed migration.
+    pub migration_name: String,
+    /// The comma-separated names of the squashed migrations that were not applied.
+    pub unapplied_migration_names: String,
+}
+
+#[derive(Debug, Serialize, UserFacingError)]
+#[user_facing(
+    code = "P3017",
+    message = "The migration `{migration_name}` to squash could not be found in the migrations directory."
+)]
+pub struct MigrationToSquashNotFound {
+    /// The name of the migration.
+    pub migration_name: String,
+}
+
+#[derive(Debug, Serialize, UserFacingError)]
+#[user_facing(
+    code = "P3018",
+    message = "A baseline migration can only be created for a database without migration history, with an empty migrations directory. {reason}"
+)]
+pub struct CannotBaselineMigrationHistory {
+    /// Why the history can't be baselined.
+    pub reason: String,
+}
+
+#[derive(Debug, Serialize, UserFacingError)]
+#[user_facing(
+    code = "P3019",
+    message = "The seed file at `{seed_file_path}` is invalid.\n{reason}"
+)]
+pub struct InvalidSeedFile {
+    /// The path of the seed file.
+    pub seed_file_path: String,
+    /// What is wrong with the seed file, one problem per line.
+    pub reason: String,
+}
+
+#[derive(Debug, Serialize, UserFacingError)]
+#[user_facing(
+    code = "P3020",
+    message = "The migrations to squash contain statements that the squashed migration would not carry over, like data changes, views, triggers or functions. Squash with `force` to drop them anyway.\n{statements}"
+)]
+pub struct SquashedMigrationsHaveNonSchemaStatements {
+    /// The dropped statements, with the migration they come from.
+    pub statements: String,
+}
+
+#[derive(Debug, Serialize, UserFacingError)]
+#[user_facing(
+    code = "P3021",
+    message = "The squashed migration would be written to `{migration_name}`, which already exists in the migrations directory. Choose another name for the squashed migration."
+)]
+pub struct SquashedMigrationDirectoryExists {
+    /// The name of the squashed migration directory.
+    pub migration_name: String,
+}
+
 impl crate::UserFacingError for ShadowDbCreationError {
     const ERROR_CODE: &'static str = "P3014";
 
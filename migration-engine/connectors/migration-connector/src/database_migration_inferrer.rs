@@ -1,5 +1,6 @@
-use crate::{migrations_directory::MigrationDirectory, ConnectorResult};
+use crate::{migrations_directory::MigrationDirectory, ConnectorResult, Drift};
 use datamodel::Datamodel;
+use serde::Deserialize;
 
 /// The component responsible for generating a
 /// [DatabaseMigration](trait.MigrationConnector.html#associatedtype.DatabaseMigration)
@@ -11,25 +12,56 @@ use datamodel::Datamodel;
 #[async_trait::async_trait]
 pub trait DatabaseMigrationInferrer<T>: Send + Sync {
     /// Infer the database migration to the passed in Prisma schema.
-    async fn infer(&self, next: &Datamodel) -> ConnectorResult<T>;
+    async fn infer(&self, next: &Datamodel, enum_value_renames: &[EnumValueRename]) -> ConnectorResult<T>;
 
     /// Infer the database migration steps assuming an empty schema on a new
     /// database as a starting point.
     fn infer_from_empty(&self, next: &Datamodel) -> ConnectorResult<T>;
 
+    /// Infer the database migration creating the current schema of the
+    /// database from an empty database, to baseline an existing database.
+    async fn infer_baseline_migration(&self) -> ConnectorResult<T>;
+
     /// Look at the previous migrations and the target Prisma schema, and infer
     /// a database migration taking the database to the target Prisma schema.
     async fn infer_next_migration(
         &self,
         previous_migrations: &[MigrationDirectory],
         target_schema: &Datamodel,
+        enum_value_renames: &[EnumValueRename],
+    ) -> ConnectorResult<T>;
+
+    /// Infer a database migration equivalent to a range of migrations, taking
+    /// the database from its schema at the end of `previous_migrations` to its
+    /// schema at the end of `migrations_through_range`. The latter starts with
+    /// `previous_migrations`, followed by the migrations in the range.
+    async fn infer_squashed_migration(
+        &self,
+        previous_migrations: &[MigrationDirectory],
+        migrations_through_range: &[MigrationDirectory],
     ) -> ConnectorResult<T>;
 
     /// Check that the current local database's schema matches its expected
     /// state at the end of the passed in migrations history. If there is drift,
-    /// it should return a script to attempt to correct it.
-    async fn calculate_drift(&self, applied_migrations: &[MigrationDirectory]) -> ConnectorResult<Option<String>>;
+    /// it should return a script to attempt to correct it, and a report of what
+    /// drifted.
+    async fn calculate_drift(&self, applied_migrations: &[MigrationDirectory]) -> ConnectorResult<Option<Drift>>;
 
     /// If possible, check that the passed in migrations apply cleanly.
     async fn validate_migrations(&self, migrations: &[MigrationDirectory]) -> ConnectorResult<()>;
 }
+
+/// An enum value the user renamed. We can't tell a renamed value apart from a
+/// removed value and an added value, so renames are only migrated when they
+/// are passed in explicitly. Other values are dropped and created.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EnumValueRename {
+    /// The name of the enum in the database. On MySQL, where enums are column
+    /// types, this is `{table}_{column}`.
+    pub enum_name: String,
+    /// The value in the previous version of the enum.
+    pub previous_value: String,
+    /// The value in the next version of the enum.
+    pub next_value: String,
+}
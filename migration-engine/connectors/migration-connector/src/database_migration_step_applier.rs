@@ -1,4 +1,5 @@
 use crate::{destructive_change_checker::DestructiveChangeDiagnostics, ConnectorResult};
+use serde::Deserialize;
 
 /// Apply a single migration step to the connector's database. At this level, we are working with database migrations,
 /// i.e. the [associated type on MigrationConnector](trait.MigrationConnector.html#associatedtype.DatabaseMigration).
@@ -9,14 +10,75 @@ pub trait DatabaseMigrationStepApplier<T>: Send + Sync {
     async fn apply_step(&self, database_migration: &T, step: usize) -> ConnectorResult<bool>;
 
     /// Render steps for the CLI. Each step will contain the raw field.
-    fn render_steps_pretty(&self, database_migration: &T) -> ConnectorResult<Vec<PrettyDatabaseMigrationStep>>;
+    fn render_steps_pretty(
+        &self,
+        database_migration: &T,
+        mode: ScriptMode,
+    ) -> ConnectorResult<Vec<PrettyDatabaseMigrationStep>>;
 
     /// Render the migration to a runnable script.
-    fn render_script(&self, database_migration: &T, diagnostics: &DestructiveChangeDiagnostics) -> String;
+    fn render_script(
+        &self,
+        database_migration: &T,
+        diagnostics: &DestructiveChangeDiagnostics,
+        mode: ScriptMode,
+    ) -> String;
 
     /// Apply a migration script to the database. The migration persistence is
     /// managed by the core.
     async fn apply_script(&self, script: &str) -> ConnectorResult<()>;
+
+    /// Split a migration script into its statements, so they can be applied
+    /// one by one with `apply_script`. This is how non-transactional migration
+    /// scripts are applied.
+    fn split_script(&self, script: &str) -> Vec<String>;
+
+    /// Undo what a statement of a non-transactional script left behind when it
+    /// failed, so the statement can be run again when the migration is resumed.
+    async fn clean_up_failed_statement(&self, statement: &str) -> ConnectorResult<()>;
+
+    /// The statements of a migration script with effects that are not
+    /// described by the Prisma schema, like data changes, views, triggers or
+    /// functions. They are lost when the migration is squashed.
+    fn non_schema_statements(&self, script: &str) -> Vec<String>;
+}
+
+/// How the statements of a migration script are rendered.
+#[derive(Debug, Deserialize, PartialEq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum ScriptMode {
+    /// Plain statements, for scripts applied by the migration engine.
+    Default,
+    /// Statements guarded by checks on the existing schema, so the script can
+    /// be run again after a partial run. This is for scripts applied manually.
+    /// Steps without a possible guard are rendered as in the default mode. On
+    /// SQLite, which has no control flow statements, a table redefinition that
+    /// failed halfway through leaves its temporary table behind, and the table
+    /// has to be dropped by hand before running the script again.
+    Idempotent,
+}
+
+impl Default for ScriptMode {
+    fn default() -> Self {
+        ScriptMode::Default
+    }
+}
+
+/// The header comment that opts a migration script out of running in a
+/// transaction. Non-transactional scripts are applied statement by statement,
+/// and the progress is recorded after each statement, so a failed migration
+/// can be resumed from the statement that failed.
+pub const NON_TRANSACTIONAL_DIRECTIVE: &str = "-- prisma:no-transaction";
+
+/// Whether the migration script contains the
+/// [NON_TRANSACTIONAL_DIRECTIVE](constant.NON_TRANSACTIONAL_DIRECTIVE.html) in
+/// the comments at the top of the script.
+pub fn is_non_transactional_script(script: &str) -> bool {
+    script
+        .lines()
+        .map(str::trim)
+        .take_while(|line| line.is_empty() || line.starts_with("--"))
+        .any(|line| line == NON_TRANSACTIONAL_DIRECTIVE)
 }
 
 /// A helper struct to serialize a database migration with an additional `raw` field containing the
@@ -0,0 +1,253 @@
+use serde::Serialize;
+use std::fmt::{self, Display};
+
+/// Drift between the schema the migration history should produce and the
+/// actual schema of the database, as returned by
+/// [calculate_drift](trait.DatabaseMigrationInferrer.html#tymethod.calculate_drift).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Drift {
+    /// A database script to correct the drift by reverting to the expected schema.
+    pub rollback: String,
+    /// What drifted, from the expected schema to the actual database schema.
+    pub report: DriftReport,
+}
+
+/// A structured description of schema drift. "Added" things are in the
+/// database but not in the migration history, "removed" things are in the
+/// migration history but not in the database.
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DriftReport {
+    /// The names of the tables that were added.
+    pub added_tables: Vec<String>,
+    /// The names of the tables that were removed.
+    pub removed_tables: Vec<String>,
+    /// The tables present in both schemas, with differences.
+    pub changed_tables: Vec<TableDrift>,
+    /// The names of the enums that were added.
+    pub added_enums: Vec<String>,
+    /// The names of the enums that were removed.
+    pub removed_enums: Vec<String>,
+    /// The enums present in both schemas, with different values.
+    pub changed_enums: Vec<EnumDrift>,
+}
+
+impl DriftReport {
+    /// Is there no drift at all?
+    pub fn is_empty(&self) -> bool {
+        self.added_tables.is_empty()
+            && self.removed_tables.is_empty()
+            && self.changed_tables.is_empty()
+            && self.added_enums.is_empty()
+            && self.removed_enums.is_empty()
+            && self.changed_enums.is_empty()
+    }
+}
+
+/// The differences on a table present in both schemas.
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TableDrift {
+    /// The name of the table.
+    pub table: String,
+    /// The columns that were added.
+    pub added_columns: Vec<DriftColumn>,
+    /// The columns that were removed.
+    pub removed_columns: Vec<DriftColumn>,
+    /// The columns present in both schemas, with differences.
+    pub changed_columns: Vec<ChangedColumn>,
+    /// The names of the indexes that were added.
+    pub added_indexes: Vec<String>,
+    /// The names of the indexes that were removed.
+    pub removed_indexes: Vec<String>,
+    /// The names of the indexes that were changed, in the database.
+    pub changed_indexes: Vec<String>,
+    /// The foreign keys that were added.
+    pub added_foreign_keys: Vec<DriftForeignKey>,
+    /// The foreign keys that were removed.
+    pub removed_foreign_keys: Vec<DriftForeignKey>,
+    /// Whether the primary key was added, removed or changed.
+    pub primary_key_changed: bool,
+}
+
+/// A column, as described in a drift report.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DriftColumn {
+    /// The name of the column.
+    pub name: String,
+    /// The type of the column, as it would be rendered in its definition.
+    pub column_type: String,
+    /// Whether the column is `NOT NULL`.
+    pub required: bool,
+}
+
+/// A column present in both schemas, with differences.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangedColumn {
+    /// The column as expected from the migration history.
+    pub before: DriftColumn,
+    /// The column in the database.
+    pub after: DriftColumn,
+    /// Whether the default value of the column changed.
+    pub default_changed: bool,
+}
+
+/// A foreign key, as described in a drift report.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DriftForeignKey {
+    /// The name of the constraint, on the databases that have one.
+    pub constraint_name: Option<String>,
+    /// The constrained columns on the table.
+    pub columns: Vec<String>,
+    /// The name of the referenced table.
+    pub referenced_table: String,
+    /// The referenced columns.
+    pub referenced_columns: Vec<String>,
+}
+
+/// The differences on an enum present in both schemas.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnumDrift {
+    /// The name of the enum.
+    pub name: String,
+    /// The values that were added.
+    pub added_values: Vec<String>,
+    /// The values that were removed.
+    pub removed_values: Vec<String>,
+}
+
+/// One line per difference, for example "Column `users.email` changed from
+/// VARCHAR(191) to TEXT".
+impl Display for DriftReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for table in &self.added_tables {
+            writeln!(f, "Added table `{}`", table)?;
+        }
+
+        for table in &self.removed_tables {
+            writeln!(f, "Removed table `{}`", table)?;
+        }
+
+        for table in &self.changed_tables {
+            let name = &table.table;
+
+            for column in &table.added_columns {
+                writeln!(f, "Added column `{}.{}` ({})", name, column.name, render_type(column))?;
+            }
+
+            for column in &table.removed_columns {
+                writeln!(f, "Removed column `{}.{}` ({})", name, column.name, render_type(column))?;
+            }
+
+            for column in &table.changed_columns {
+                let (before, after) = (&column.before, &column.after);
+
+                if before.name != after.name {
+                    writeln!(f, "Column `{}.{}` was renamed to `{}`", name, before.name, after.name)?;
+                }
+
+                if before.column_type != after.column_type {
+                    writeln!(
+                        f,
+                        "Column `{}.{}` changed from {} to {}",
+                        name, after.name, before.column_type, after.column_type
+                    )?;
+                }
+
+                if before.required != after.required {
+                    writeln!(
+                        f,
+                        "Column `{}.{}` changed from {} to {}",
+                        name,
+                        after.name,
+                        render_arity(before),
+                        render_arity(after)
+                    )?;
+                }
+
+                if column.default_changed {
+                    writeln!(f, "The default value of column `{}.{}` changed", name, after.name)?;
+                }
+            }
+
+            if table.primary_key_changed {
+                writeln!(f, "The primary key of table `{}` changed", name)?;
+            }
+
+            for index in &table.added_indexes {
+                writeln!(f, "Added index `{}` on table `{}`", index, name)?;
+            }
+
+            for index in &table.removed_indexes {
+                writeln!(f, "Removed index `{}` on table `{}`", index, name)?;
+            }
+
+            for index in &table.changed_indexes {
+                writeln!(f, "Changed index `{}` on table `{}`", index, name)?;
+            }
+
+            for foreign_key in &table.added_foreign_keys {
+                writeln!(f, "Added foreign key {}", render_foreign_key(name, foreign_key))?;
+            }
+
+            for foreign_key in &table.removed_foreign_keys {
+                writeln!(f, "Removed foreign key {}", render_foreign_key(name, foreign_key))?;
+            }
+        }
+
+        for r#enum in &self.added_enums {
+            writeln!(f, "Added enum `{}`", r#enum)?;
+        }
+
+        for r#enum in &self.removed_enums {
+            writeln!(f, "Removed enum `{}`", r#enum)?;
+        }
+
+        for r#enum in &self.changed_enums {
+            for value in &r#enum.added_values {
+                writeln!(f, "Added value `{}` to enum `{}`", value, r#enum.name)?;
+            }
+
+            for value in &r#enum.removed_values {
+                writeln!(f, "Removed value `{}` from enum `{}`", value, r#enum.name)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn render_type(column: &DriftColumn) -> String {
+    if column.required {
+        format!("{} NOT NULL", column.column_type)
+    } else {
+        column.column_type.clone()
+    }
+}
+
+fn render_arity(column: &DriftColumn) -> &'static str {
+    if column.required {
+        "required"
+    } else {
+        "optional"
+    }
+}
+
+fn render_foreign_key(table: &str, foreign_key: &DriftForeignKey) -> String {
+    format!(
+        "{}on `{}`({}) referencing `{}`({})",
+        foreign_key
+            .constraint_name
+            .as_ref()
+            .map(|name| format!("`{}` ", name))
+            .unwrap_or_default(),
+        table,
+        foreign_key.columns.join(", "),
+        foreign_key.referenced_table,
+        foreign_key.referenced_columns.join(", "),
+    )
+}
@@ -4,14 +4,20 @@ use datamodel::Configuration;
 use enumflags2::BitFlags;
 
 /// Parse features from data model configuration.
-pub fn from_config(_config: &Configuration) -> BitFlags<MigrationFeature> {
-    BitFlags::empty()
+pub fn from_config(config: &Configuration) -> BitFlags<MigrationFeature> {
+    config
+        .preview_features()
+        .fold(BitFlags::empty(), |acc, feature| match feature {
+            "onlineIndexes" => acc | MigrationFeature::OnlineIndexes,
+            _ => acc,
+        })
 }
 
 #[derive(BitFlags, Copy, Clone, Debug, PartialEq)]
 /// Feature flags to enable in the migration engine
 #[repr(u8)]
 pub enum MigrationFeature {
-    /// Placeholder.
-    Other = 0b1,
+    /// Build and drop indexes on existing tables without blocking writes.
+    /// The migrations containing these steps run outside of a transaction.
+    OnlineIndexes = 0b1,
 }
@@ -7,22 +7,32 @@ pub mod features;
 mod database_migration_inferrer;
 mod database_migration_step_applier;
 mod destructive_change_checker;
+mod drift_report;
 mod error;
+mod migration_linter;
 mod migration_persistence;
 mod migrations_directory;
+mod seed;
 
-pub use database_migration_inferrer::DatabaseMigrationInferrer;
-pub use database_migration_step_applier::{DatabaseMigrationStepApplier, PrettyDatabaseMigrationStep};
+pub use database_migration_inferrer::{DatabaseMigrationInferrer, EnumValueRename};
+pub use database_migration_step_applier::{
+    is_non_transactional_script, DatabaseMigrationStepApplier, PrettyDatabaseMigrationStep, ScriptMode,
+    NON_TRANSACTIONAL_DIRECTIVE,
+};
 pub use destructive_change_checker::{
     DestructiveChangeChecker, DestructiveChangeDiagnostics, MigrationWarning, UnexecutableMigration,
 };
+pub use drift_report::{ChangedColumn, Drift, DriftColumn, DriftForeignKey, DriftReport, EnumDrift, TableDrift};
 pub use error::ConnectorError;
 pub use features::MigrationFeature;
+pub use migration_linter::{LintDiagnostic, LintRules, LintSeverity, MigrationLinter};
 pub use migration_persistence::{MigrationPersistence, MigrationRecord, PersistenceNotInitializedError, Timestamp};
 pub use migrations_directory::{
-    create_migration_directory, error_on_changed_provider, list_migrations, match_provider_in_lock_file,
-    write_migration_lock_file, ListMigrationsError, MigrationDirectory,
+    create_migration_directory, create_squashed_migration_directory, error_on_changed_provider, list_migrations,
+    match_provider_in_lock_file, squashed_migration_directory_name, squashed_migration_names,
+    write_migration_lock_file, ListMigrationsError, MigrationDirectory, SQUASHED_MIGRATION_DIRECTIVE,
 };
+pub use seed::{validate_seed, ModelSeed, ModelSeedResult, SeedApplier};
 
 use sha2::{Digest, Sha256};
 use std::fmt::Debug;
@@ -70,6 +80,12 @@ pub trait MigrationConnector: Send + Sync + 'static {
 
     /// See [DestructiveChangeChecker](trait.DestructiveChangeChecker.html).
     fn destructive_change_checker(&self) -> &dyn DestructiveChangeChecker<Self::DatabaseMigration>;
+
+    /// See [MigrationLinter](trait.MigrationLinter.html).
+    fn migration_linter(&self) -> &dyn MigrationLinter<Self::DatabaseMigration>;
+
+    /// See [SeedApplier](trait.SeedApplier.html).
+    fn seed_applier(&self) -> &dyn SeedApplier;
 }
 
 /// Marker for the associated migration type for a connector.
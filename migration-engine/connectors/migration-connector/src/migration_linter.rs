@@ -0,0 +1,100 @@
+use crate::{ConnectorError, ConnectorResult};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Implementors of this trait are responsible for flagging operations in a
+/// migration that are risky to run against a live database, for example
+/// because they hold heavy locks for the duration of a table rewrite.
+///
+/// The type parameter is the connector's
+/// [DatabaseMigration](trait.MigrationConnector.html#associatedtype.DatabaseMigration)
+/// type.
+pub trait MigrationLinter<T>: Send + Sync
+where
+    T: Send + Sync + 'static,
+{
+    /// Lint the steps of a migration generated by the engine.
+    fn lint(&self, database_migration: &T, rules: &LintRules) -> Vec<LintDiagnostic>;
+
+    /// Lint a rendered migration script, for example from an existing
+    /// migrations directory. The step indexes of the diagnostics are the
+    /// indexes of the statements in the script.
+    fn lint_script(&self, script: &str, rules: &LintRules) -> Vec<LintDiagnostic>;
+
+    /// The names of the rules the linter checks, as used in the configuration.
+    fn rule_names(&self) -> &'static [&'static str];
+}
+
+/// How a lint rule is reported.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum LintSeverity {
+    /// The migration should not be created or applied.
+    Error,
+    /// The migration can go ahead, but the user should be told.
+    Warn,
+    /// The rule is disabled.
+    Off,
+}
+
+/// The per-project lint configuration, mapping rule names to severities.
+/// Rules that are not mentioned run with their default severity.
+#[derive(Debug, Deserialize, Default, Clone)]
+#[serde(transparent)]
+pub struct LintRules(HashMap<String, LintSeverity>);
+
+impl LintRules {
+    /// The configured severity for the rule, or the default if the rule is not configured.
+    pub fn severity(&self, rule: &str, default: LintSeverity) -> LintSeverity {
+        self.0.get(rule).copied().unwrap_or(default)
+    }
+
+    /// Set the severity for a rule.
+    pub fn set(&mut self, rule: impl Into<String>, severity: LintSeverity) {
+        self.0.insert(rule.into(), severity);
+    }
+
+    /// Error on configured rules that are not in `known_rules`, instead of
+    /// silently ignoring a misspelled rule.
+    pub fn check_rule_names(&self, known_rules: &[&str]) -> ConnectorResult<()> {
+        let mut unknown_rules: Vec<&str> = self
+            .0
+            .keys()
+            .map(String::as_str)
+            .filter(|rule| !known_rules.contains(rule))
+            .collect();
+
+        if unknown_rules.is_empty() {
+            return Ok(());
+        }
+
+        unknown_rules.sort_unstable();
+
+        Err(ConnectorError::generic(anyhow::anyhow!(
+            "Unknown migration lint rules: {}. The known rules are: {}.",
+            unknown_rules.join(", "),
+            known_rules.join(", ")
+        )))
+    }
+}
+
+/// A lint rule violation reported by the [MigrationLinter](trait.MigrationLinter.html).
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LintDiagnostic {
+    /// The name of the rule, as used in the configuration.
+    pub rule: String,
+    /// The configured severity of the rule. Never `Off`.
+    pub severity: LintSeverity,
+    /// The user-facing description of the problem.
+    pub description: String,
+    /// The index of the step in the migration that this diagnostic applies to.
+    pub step_index: usize,
+}
+
+impl LintDiagnostic {
+    /// Is this diagnostic an error?
+    pub fn is_error(&self) -> bool {
+        self.severity == LintSeverity::Error
+    }
+}
@@ -23,12 +23,70 @@ pub const MIGRATION_SCRIPT_FILENAME: &str = "migration";
 /// The file name for the migration lock file, not including the file extension.
 pub const MIGRATION_LOCK_FILENAME: &str = "migration_lock";
 
+/// The header comment recording, in a squashed migration, the name of one of
+/// the migrations it replaces. There is one such line per replaced migration.
+pub const SQUASHED_MIGRATION_DIRECTIVE: &str = "-- prisma:squashed";
+
 /// Create a directory for a new migration.
 pub fn create_migration_directory(
     migrations_directory_path: &Path,
     migration_name: &str,
 ) -> io::Result<MigrationDirectory> {
     let timestamp = chrono::Utc::now().format("%Y%m%d%H%M%S");
+
+    create_migration_directory_with_timestamp(migrations_directory_path, &timestamp, migration_name)
+}
+
+/// Create a directory for a migration squashing other migrations. It reuses
+/// the timestamp of the last squashed migration, so it takes the place of the
+/// squashed migrations in the migrations history.
+pub fn create_squashed_migration_directory(
+    migrations_directory_path: &Path,
+    last_squashed_migration: &MigrationDirectory,
+    migration_name: &str,
+) -> io::Result<MigrationDirectory> {
+    let timestamp = squashed_migration_timestamp(last_squashed_migration);
+
+    create_migration_directory_with_timestamp(migrations_directory_path, &timestamp, migration_name)
+}
+
+/// The name of the directory `create_squashed_migration_directory` creates.
+/// It is the name of the last squashed migration if `migration_name` is the
+/// same.
+pub fn squashed_migration_directory_name(last_squashed_migration: &MigrationDirectory, migration_name: &str) -> String {
+    format!(
+        "{}_{}",
+        squashed_migration_timestamp(last_squashed_migration),
+        migration_name
+    )
+}
+
+fn squashed_migration_timestamp(last_squashed_migration: &MigrationDirectory) -> &str {
+    last_squashed_migration
+        .migration_name()
+        .split('_')
+        .next()
+        .unwrap_or_default()
+}
+
+/// The names of the migrations replaced by a squashed migration, from the
+/// header of its script. Empty for migrations that were not squashed.
+pub fn squashed_migration_names(script: &str) -> Vec<&str> {
+    script
+        .lines()
+        .map(str::trim)
+        .take_while(|line| line.is_empty() || line.starts_with("--"))
+        .filter_map(|line| line.strip_prefix(SQUASHED_MIGRATION_DIRECTIVE))
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .collect()
+}
+
+fn create_migration_directory_with_timestamp(
+    migrations_directory_path: &Path,
+    timestamp: &dyn Display,
+    migration_name: &str,
+) -> io::Result<MigrationDirectory> {
     let directory_name = format!(
         "{timestamp}_{migration_name}",
         timestamp = timestamp,
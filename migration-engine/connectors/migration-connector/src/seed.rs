@@ -0,0 +1,319 @@
+use crate::ConnectorResult;
+use datamodel::{
+    dml::{FieldType, Model, ScalarField, ScalarType, WithDatabaseName},
+    Datamodel,
+};
+use prisma_value::PrismaValue;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Implementors of this trait are responsible for writing seed data to the
+/// database.
+#[async_trait::async_trait]
+pub trait SeedApplier: Send + Sync {
+    /// Upsert the records of each model, in order: records that already exist
+    /// for their unique criteria are updated, the others are inserted.
+    async fn apply_seed(&self, seeds: &[ModelSeed]) -> ConnectorResult<Vec<ModelSeedResult>>;
+}
+
+/// The seed records of a model, validated against the datamodel. See
+/// [validate_seed](fn.validate_seed.html).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModelSeed {
+    /// The name of the model.
+    pub model: String,
+    /// The name of the table of the model in the database.
+    pub table: String,
+    /// The columns of the unique criteria identifying the records.
+    pub unique_columns: Vec<String>,
+    /// The records, mapping column names to values.
+    pub records: Vec<BTreeMap<String, PrismaValue>>,
+}
+
+/// The outcome of seeding a model.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModelSeedResult {
+    /// The name of the model.
+    pub model: String,
+    /// The number of records that did not exist and were inserted.
+    pub inserted: u64,
+    /// The number of records that already existed and were updated.
+    pub updated: u64,
+}
+
+/// Validate the contents of a seed file against the datamodel. The seed is an
+/// object with model names as keys and arrays of records as values. Records
+/// are objects with field names as keys.
+///
+/// The seeds are returned in an order where models come after the models
+/// they reference. The error is the list of problems found in the seed.
+pub fn validate_seed(datamodel: &Datamodel, seed: &serde_json::Value) -> Result<Vec<ModelSeed>, Vec<String>> {
+    let mut errors = Vec::new();
+    let mut seeds = Vec::new();
+
+    let seed = match seed.as_object() {
+        Some(seed) => seed,
+        None => return Err(vec!["The seed must be an object with model names as keys.".to_owned()]),
+    };
+
+    for (model_name, records) in seed {
+        let model = match datamodel.find_model(model_name) {
+            Some(model) if !model.is_ignored && !model.is_commented_out => model,
+            _ => {
+                errors.push(format!("The model `{}` does not exist in the schema.", model_name));
+                continue;
+            }
+        };
+
+        let records = match records.as_array() {
+            Some(records) => records,
+            None => {
+                errors.push(format!("The records of model `{}` must be an array.", model_name));
+                continue;
+            }
+        };
+
+        let errors_count = errors.len();
+        let records: Vec<_> = records
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, record)| validate_record(datamodel, model, idx, record, &mut errors))
+            .collect();
+
+        if errors.len() > errors_count {
+            continue;
+        }
+
+        match unique_columns(model, &records) {
+            Some(unique_columns) => seeds.push(ModelSeed {
+                model: model.name.clone(),
+                table: model.final_database_name().to_owned(),
+                unique_columns,
+                records,
+            }),
+            None => errors.push(format!(
+                "Every record of model `{}` must have values for the fields of its id or of a unique constraint, to be matched with the existing records.",
+                model_name
+            )),
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    Ok(sort_by_dependencies(datamodel, seeds))
+}
+
+fn validate_record(
+    datamodel: &Datamodel,
+    model: &Model,
+    record_index: usize,
+    record: &serde_json::Value,
+    errors: &mut Vec<String>,
+) -> Option<BTreeMap<String, PrismaValue>> {
+    let location = format!("record {} of model `{}`", record_index, model.name);
+
+    let record = match record.as_object() {
+        Some(record) => record,
+        None => {
+            errors.push(format!("The {} must be an object.", location));
+            return None;
+        }
+    };
+
+    let errors_count = errors.len();
+    let mut values = BTreeMap::new();
+
+    for (field_name, value) in record {
+        let field = match model.find_scalar_field(field_name) {
+            Some(field) if !field.is_commented_out => field,
+            _ if model.find_relation_field(field_name).is_some() => {
+                errors.push(format!(
+                    "The field `{}` in the {} is a relation field. Set the fields of the relation instead.",
+                    field_name, location
+                ));
+                continue;
+            }
+            _ => {
+                errors.push(format!(
+                    "The field `{}` in the {} does not exist in the schema.",
+                    field_name, location
+                ));
+                continue;
+            }
+        };
+
+        match field_value(datamodel, field, value) {
+            Ok(value) => {
+                values.insert(field.db_name().to_owned(), value);
+            }
+            Err(expected) => errors.push(format!(
+                "The value of field `{}` in the {} is invalid: expected {}.",
+                field_name, location, expected
+            )),
+        }
+    }
+
+    for field in model.scalar_fields() {
+        let is_missing = !record.contains_key(&field.name) && field.is_required();
+
+        if is_missing && field.default_value.is_none() && !field.is_updated_at && !field.is_commented_out {
+            errors.push(format!(
+                "The {} is missing the required field `{}`.",
+                location, field.name
+            ));
+        }
+    }
+
+    if errors.len() > errors_count {
+        return None;
+    }
+
+    Some(values)
+}
+
+/// Convert a JSON value to the value of the field, or return a description of
+/// the expected value.
+fn field_value(datamodel: &Datamodel, field: &ScalarField, value: &serde_json::Value) -> Result<PrismaValue, String> {
+    if value.is_null() {
+        return if field.is_optional() {
+            Ok(PrismaValue::Null)
+        } else {
+            Err("a value for a required field, not null".to_owned())
+        };
+    }
+
+    if field.is_list() {
+        return match value.as_array() {
+            Some(values) => values
+                .iter()
+                .map(|value| scalar_value(datamodel, &field.field_type, value))
+                .collect::<Result<_, _>>()
+                .map(PrismaValue::List),
+            None => Err("an array".to_owned()),
+        };
+    }
+
+    scalar_value(datamodel, &field.field_type, value)
+}
+
+fn scalar_value(
+    datamodel: &Datamodel,
+    field_type: &FieldType,
+    value: &serde_json::Value,
+) -> Result<PrismaValue, String> {
+    let scalar_type = match field_type {
+        FieldType::Base(scalar_type, _) | FieldType::NativeType(scalar_type, _) => scalar_type,
+        FieldType::Enum(enum_name) => {
+            let r#enum = datamodel.find_enum(enum_name).unwrap();
+            let enum_value = value
+                .as_str()
+                .and_then(|value| r#enum.values().find(|enum_value| enum_value.name == value));
+
+            return match enum_value {
+                Some(enum_value) => Ok(PrismaValue::Enum(enum_value.final_database_name().to_owned())),
+                None => Err(format!("one of the values of enum `{}`", enum_name)),
+            };
+        }
+        FieldType::Relation(_) | FieldType::Unsupported(_) => {
+            return Err("a field with a supported type".to_owned());
+        }
+    };
+
+    let converted = match scalar_type {
+        ScalarType::Int => value.as_i64().map(PrismaValue::Int),
+        ScalarType::BigInt => value.as_i64().map(PrismaValue::BigInt),
+        ScalarType::Float => value.as_f64().map(PrismaValue::new_float),
+        ScalarType::Boolean => value.as_bool().map(PrismaValue::Boolean),
+        ScalarType::String => value.as_str().map(|value| PrismaValue::String(value.to_owned())),
+        ScalarType::DateTime => value
+            .as_str()
+            .and_then(|value| chrono::DateTime::parse_from_rfc3339(value).ok())
+            .map(PrismaValue::DateTime),
+        ScalarType::Date => value
+            .as_str()
+            .and_then(|value| chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d").ok())
+            .map(PrismaValue::Date),
+        ScalarType::Time => value
+            .as_str()
+            .and_then(|value| chrono::NaiveTime::parse_from_str(value, "%H:%M:%S%.f").ok())
+            .map(PrismaValue::Time),
+        ScalarType::Json => Some(PrismaValue::Json(value.to_string())),
+        ScalarType::Bytes => value
+            .as_str()
+            .and_then(|value| prisma_value::decode_bytes(value).ok())
+            .map(PrismaValue::Bytes),
+        ScalarType::Decimal => match value {
+            serde_json::Value::Number(number) => Some(PrismaValue::new_decimal(&number.to_string())),
+            serde_json::Value::String(decimal) if is_decimal(decimal) => Some(PrismaValue::new_decimal(decimal)),
+            _ => None,
+        },
+    };
+
+    converted.ok_or_else(|| match scalar_type {
+        ScalarType::Int | ScalarType::BigInt => "an integer".to_owned(),
+        ScalarType::Float => "a number".to_owned(),
+        ScalarType::Boolean => "a boolean".to_owned(),
+        ScalarType::String => "a string".to_owned(),
+        ScalarType::DateTime => "an RFC 3339 date and time, like `2021-01-31T12:00:00Z`".to_owned(),
+        ScalarType::Date => "a date, like `2021-01-31`".to_owned(),
+        ScalarType::Time => "a time, like `12:00:00`".to_owned(),
+        ScalarType::Json => "a JSON value".to_owned(),
+        ScalarType::Bytes => "a base64 encoded string".to_owned(),
+        ScalarType::Decimal => "a number, or a string containing a decimal number".to_owned(),
+    })
+}
+
+fn is_decimal(s: &str) -> bool {
+    s.parse::<f64>().is_ok()
+        && s.chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, '+' | '-' | '.' | 'e' | 'E'))
+}
+
+/// The columns of the first unique criteria of the model with non-null values
+/// in all the records.
+fn unique_columns(model: &Model, records: &[BTreeMap<String, PrismaValue>]) -> Option<Vec<String>> {
+    model
+        .strict_unique_criterias()
+        .into_iter()
+        .map(|criteria| {
+            criteria
+                .fields
+                .iter()
+                .map(|field| field.db_name().to_owned())
+                .collect::<Vec<_>>()
+        })
+        .find(|columns| {
+            records.iter().all(|record| {
+                columns
+                    .iter()
+                    .all(|column| matches!(record.get(column), Some(value) if !value.is_null()))
+            })
+        })
+}
+
+/// Order the seeds so that the models holding foreign keys come after the
+/// models they reference. Cycles are left in their original order.
+fn sort_by_dependencies(datamodel: &Datamodel, mut seeds: Vec<ModelSeed>) -> Vec<ModelSeed> {
+    let mut sorted: Vec<ModelSeed> = Vec::with_capacity(seeds.len());
+
+    while !seeds.is_empty() {
+        let next_seed_index = seeds
+            .iter()
+            .position(|seed| {
+                let model = datamodel.find_model(&seed.model).unwrap();
+
+                model
+                    .relation_fields()
+                    .filter(|field| !field.relation_info.fields.is_empty() && field.relation_info.to != model.name)
+                    .all(|field| !seeds.iter().any(|other| other.model == field.relation_info.to))
+            })
+            .unwrap_or(0);
+
+        sorted.push(seeds.remove(next_seed_index));
+    }
+
+    sorted
+}
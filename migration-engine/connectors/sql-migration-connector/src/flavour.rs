@@ -15,7 +15,7 @@ pub(crate) use sqlite::SqliteFlavour;
 use crate::{
     connection_wrapper::Connection, sql_destructive_change_checker::DestructiveChangeCheckerFlavour,
     sql_renderer::SqlRenderer, sql_schema_calculator::SqlSchemaCalculatorFlavour,
-    sql_schema_differ::SqlSchemaDifferFlavour,
+    sql_schema_differ::SqlSchemaDifferFlavour, MigrationsTable,
 };
 use datamodel::Datamodel;
 use enumflags2::BitFlags;
@@ -35,20 +35,23 @@ pub(crate) const MYSQL_IDENTIFIER_SIZE_LIMIT: usize = 64;
 pub(crate) fn from_connection_info(
     connection_info: &ConnectionInfo,
     features: BitFlags<MigrationFeature>,
+    migrations_table: MigrationsTable,
 ) -> Box<dyn SqlFlavour + Send + Sync + 'static> {
     match connection_info {
         ConnectionInfo::Mysql(url) => Box::new(MysqlFlavour {
             url: url.clone(),
             circumstances: Default::default(),
             features,
+            migrations_table,
         }),
-        ConnectionInfo::Postgres(url) => Box::new(PostgresFlavour::new(url.clone(), features)),
+        ConnectionInfo::Postgres(url) => Box::new(PostgresFlavour::new(url.clone(), features, migrations_table)),
         ConnectionInfo::Sqlite { file_path, db_name } => Box::new(SqliteFlavour {
             file_path: file_path.clone(),
             attached_name: db_name.clone(),
             features,
+            migrations_table,
         }),
-        ConnectionInfo::Mssql(url) => Box::new(MssqlFlavour::new(url.clone(), features)),
+        ConnectionInfo::Mssql(url) => Box::new(MssqlFlavour::new(url.clone(), features, migrations_table)),
         ConnectionInfo::InMemorySqlite { .. } => unreachable!("SqlFlavour for in-memory SQLite"),
     }
 }
@@ -67,7 +70,7 @@ pub(crate) trait SqlFlavour:
     /// Create a database for the given URL on the server, if applicable.
     async fn create_database(&self, database_url: &str) -> ConnectorResult<String>;
 
-    /// Initialize the `_prisma_migrations` table.
+    /// Initialize the migrations table.
     async fn create_imperative_migrations_table(&self, connection: &Connection) -> ConnectorResult<()>;
 
     /// Describe the SQL schema.
@@ -98,14 +101,46 @@ pub(crate) trait SqlFlavour:
         connection: &Connection,
     ) -> ConnectorResult<SqlSchema>;
 
+    /// The configured table to store applied migrations.
+    fn migrations_table(&self) -> &MigrationsTable;
+
+    /// The schema described and migrated through the connection: the
+    /// database on MySQL, the attached database on SQLite.
+    fn connection_schema_name(&self) -> &str;
+
+    /// Whether the migrations table is in the schema of the connection, and
+    /// shows up in its described schema.
+    fn migrations_table_is_in_connection_schema(&self) -> bool {
+        match &self.migrations_table().schema {
+            Some(schema) => schema == self.connection_schema_name(),
+            None => true,
+        }
+    }
+
     /// Table to store applied migrations, the name part.
-    fn imperative_migrations_table_name(&self) -> &'static str {
-        "_prisma_migrations"
+    fn imperative_migrations_table_name(&self) -> &str {
+        &self.migrations_table().name
     }
 
     /// Table to store applied migrations.
     fn imperative_migrations_table(&self) -> Table<'_> {
-        self.imperative_migrations_table_name().into()
+        let migrations_table = self.migrations_table();
+
+        match &migrations_table.schema {
+            Some(schema) => (schema.as_str(), migrations_table.name.as_str()).into(),
+            None => migrations_table.name.as_str().into(),
+        }
+    }
+
+    /// Table to store applied migrations, quoted and qualified with its schema
+    /// for use in raw SQL.
+    fn render_imperative_migrations_table(&self) -> String {
+        let migrations_table = self.migrations_table();
+
+        match &migrations_table.schema {
+            Some(schema) => format!("{}.{}", self.quote(schema), self.quote(&migrations_table.name)),
+            None => self.quote(&migrations_table.name).to_string(),
+        }
     }
 
     /// Feature flags for the flavor
@@ -1,4 +1,10 @@
-use crate::{connect, connection_wrapper::Connection, error::quaint_error_to_connector_error, SqlFlavour};
+use crate::{
+    connect,
+    connection_wrapper::Connection,
+    error::quaint_error_to_connector_error,
+    sql_renderer::{escape_mssql_string_literal, SqlRenderer},
+    MigrationsTable, SqlFlavour,
+};
 use connection_string::JdbcString;
 use enumflags2::BitFlags;
 use indoc::formatdoc;
@@ -15,17 +21,30 @@ use user_facing_errors::{introspection_engine::DatabaseSchemaInconsistent, Known
 pub(crate) struct MssqlFlavour {
     pub(crate) url: MssqlUrl,
     features: BitFlags<MigrationFeature>,
+    migrations_table: MigrationsTable,
 }
 
 impl MssqlFlavour {
-    pub fn new(url: MssqlUrl, features: BitFlags<MigrationFeature>) -> Self {
-        Self { url, features }
+    pub fn new(url: MssqlUrl, features: BitFlags<MigrationFeature>, migrations_table: MigrationsTable) -> Self {
+        Self {
+            url,
+            features,
+            migrations_table,
+        }
     }
 
     pub(crate) fn schema_name(&self) -> &str {
         self.url.schema()
     }
 
+    /// The migrations table is always qualified with a schema on SQL Server.
+    fn migrations_schema_name(&self) -> &str {
+        self.migrations_table
+            .schema
+            .as_deref()
+            .unwrap_or_else(|| self.schema_name())
+    }
+
     /// Get the url as a JDBC string, extract the database name, and re-encode the string.
     fn master_url(input: &str) -> ConnectorResult<(String, String)> {
         let mut conn = JdbcString::from_str(&format!("jdbc:{}", input))
@@ -40,7 +59,15 @@ impl MssqlFlavour {
 #[async_trait::async_trait]
 impl SqlFlavour for MssqlFlavour {
     fn imperative_migrations_table(&self) -> Table<'_> {
-        (self.schema_name(), self.imperative_migrations_table_name()).into()
+        (self.migrations_schema_name(), self.imperative_migrations_table_name()).into()
+    }
+
+    fn render_imperative_migrations_table(&self) -> String {
+        format!(
+            "{}.{}",
+            self.quote(self.migrations_schema_name()),
+            self.quote(self.imperative_migrations_table_name())
+        )
     }
 
     async fn create_database(&self, jdbc_string: &str) -> ConnectorResult<String> {
@@ -60,8 +87,19 @@ impl SqlFlavour for MssqlFlavour {
     }
 
     async fn create_imperative_migrations_table(&self, connection: &Connection) -> ConnectorResult<()> {
+        if let Some(schema) = &self.migrations_table.schema {
+            // `CREATE SCHEMA` has to be the only statement in its batch.
+            let create_schema = format!(
+                "IF NOT EXISTS (SELECT * FROM sys.schemas WHERE name = '{}') EXEC('{}')",
+                escape_mssql_string_literal(schema),
+                escape_mssql_string_literal(&format!("CREATE SCHEMA {}", self.quote(schema))),
+            );
+
+            connection.raw_cmd(&create_schema).await?;
+        }
+
         let sql = formatdoc! { r#"
-            CREATE TABLE [{}].[{}] (
+            CREATE TABLE {} (
                 id                      VARCHAR(36) PRIMARY KEY NOT NULL,
                 checksum                VARCHAR(64) NOT NULL,
                 finished_at             DATETIMEOFFSET,
@@ -71,7 +109,7 @@ impl SqlFlavour for MssqlFlavour {
                 started_at              DATETIMEOFFSET NOT NULL DEFAULT CURRENT_TIMESTAMP,
                 applied_steps_count     INT NOT NULL DEFAULT 0
             );
-        "#, self.schema_name(), self.imperative_migrations_table_name()};
+        "#, self.render_imperative_migrations_table()};
 
         Ok(connection.raw_cmd(&sql).await?)
     }
@@ -140,6 +178,16 @@ impl SqlFlavour for MssqlFlavour {
         connection.raw_cmd(&drop_fks).await?;
         connection.raw_cmd(&drop_tables).await?;
 
+        // The migrations table is not dropped with the tables of the schema when it lives in another schema.
+        if self.migrations_table.schema.is_some() {
+            connection
+                .raw_cmd(&format!(
+                    "DROP TABLE IF EXISTS {}",
+                    self.render_imperative_migrations_table()
+                ))
+                .await?;
+        }
+
         Ok(())
     }
 
@@ -251,4 +299,12 @@ impl SqlFlavour for MssqlFlavour {
     fn features(&self) -> BitFlags<MigrationFeature> {
         self.features
     }
+
+    fn migrations_table(&self) -> &MigrationsTable {
+        &self.migrations_table
+    }
+
+    fn connection_schema_name(&self) -> &str {
+        self.url.schema()
+    }
 }
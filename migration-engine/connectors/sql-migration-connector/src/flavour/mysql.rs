@@ -3,10 +3,11 @@ use crate::{
     connect,
     connection_wrapper::Connection,
     error::{quaint_error_to_connector_error, SystemDatabase},
+    MigrationsTable,
 };
 use datamodel::{walkers::walk_scalar_fields, Datamodel};
 use enumflags2::BitFlags;
-use indoc::indoc;
+use indoc::formatdoc;
 use migration_connector::{ConnectorError, ConnectorResult, MigrationDirectory, MigrationFeature};
 use once_cell::sync::Lazy;
 use quaint::{connector::MysqlUrl, prelude::SqlFamily};
@@ -22,6 +23,8 @@ pub(crate) struct MysqlFlavour {
     pub(super) circumstances: AtomicU8,
     /// Relevant features enabled in the schema,
     pub(super) features: BitFlags<MigrationFeature>,
+    /// Where the applied migrations are recorded. The schema is a database on MySQL.
+    pub(super) migrations_table: MigrationsTable,
 }
 
 impl MysqlFlavour {
@@ -94,8 +97,8 @@ impl SqlFlavour for MysqlFlavour {
     }
 
     async fn create_imperative_migrations_table(&self, connection: &Connection) -> ConnectorResult<()> {
-        let sql = indoc! {r#"
-            CREATE TABLE _prisma_migrations (
+        let sql = formatdoc! {r#"
+            CREATE TABLE {} (
                 id                      VARCHAR(36) PRIMARY KEY NOT NULL,
                 checksum                VARCHAR(64) NOT NULL,
                 finished_at             DATETIME(3),
@@ -105,9 +108,9 @@ impl SqlFlavour for MysqlFlavour {
                 started_at              DATETIME(3) NOT NULL DEFAULT CURRENT_TIMESTAMP(3),
                 applied_steps_count     INTEGER UNSIGNED NOT NULL DEFAULT 0
             ) DEFAULT CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci;
-        "#};
+        "#, self.render_imperative_migrations_table()};
 
-        Ok(connection.raw_cmd(sql).await?)
+        Ok(connection.raw_cmd(&sql).await?)
     }
 
     async fn describe_schema<'a>(&'a self, connection: &Connection) -> ConnectorResult<SqlSchema> {
@@ -209,6 +212,16 @@ impl SqlFlavour for MysqlFlavour {
         connection.raw_cmd(&format!("CREATE DATABASE `{}`", db_name)).await?;
         connection.raw_cmd(&format!("USE `{}`", db_name)).await?;
 
+        // The migrations table is not dropped with the database when it lives in another database.
+        if self.migrations_table.schema.is_some() {
+            connection
+                .raw_cmd(&format!(
+                    "DROP TABLE IF EXISTS {}",
+                    self.render_imperative_migrations_table()
+                ))
+                .await?;
+        }
+
         Ok(())
     }
 
@@ -274,6 +287,14 @@ impl SqlFlavour for MysqlFlavour {
     fn features(&self) -> BitFlags<MigrationFeature> {
         self.features
     }
+
+    fn migrations_table(&self) -> &MigrationsTable {
+        &self.migrations_table
+    }
+
+    fn connection_schema_name(&self) -> &str {
+        self.url.dbname()
+    }
 }
 
 #[derive(BitFlags, Debug, Clone, Copy, PartialEq)]
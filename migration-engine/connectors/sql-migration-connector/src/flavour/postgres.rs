@@ -1,7 +1,12 @@
-use crate::{connect, connection_wrapper::Connection, error::quaint_error_to_connector_error, SqlFlavour};
+use crate::{
+    connect, connection_wrapper::Connection, error::quaint_error_to_connector_error, sql_renderer::SqlRenderer,
+    sql_script::split_statements, MigrationsTable, SqlFlavour,
+};
 use enumflags2::BitFlags;
-use indoc::indoc;
-use migration_connector::{ConnectorError, ConnectorResult, MigrationDirectory, MigrationFeature};
+use indoc::formatdoc;
+use migration_connector::{
+    is_non_transactional_script, ConnectorError, ConnectorResult, MigrationDirectory, MigrationFeature,
+};
 use quaint::{connector::PostgresUrl, error::ErrorKind as QuaintKind, prelude::SqlFamily};
 use sql_schema_describer::{DescriberErrorKind, SqlSchema, SqlSchemaDescriberBackend};
 use std::collections::HashMap;
@@ -15,11 +20,16 @@ use user_facing_errors::{
 pub(crate) struct PostgresFlavour {
     pub(crate) url: PostgresUrl,
     features: BitFlags<MigrationFeature>,
+    migrations_table: MigrationsTable,
 }
 
 impl PostgresFlavour {
-    pub fn new(url: PostgresUrl, features: BitFlags<MigrationFeature>) -> Self {
-        Self { url, features }
+    pub fn new(url: PostgresUrl, features: BitFlags<MigrationFeature>, migrations_table: MigrationsTable) -> Self {
+        Self {
+            url,
+            features,
+            migrations_table,
+        }
     }
 
     pub(crate) fn schema_name(&self) -> &str {
@@ -70,8 +80,14 @@ impl SqlFlavour for PostgresFlavour {
     }
 
     async fn create_imperative_migrations_table(&self, connection: &Connection) -> ConnectorResult<()> {
-        let sql = indoc! {r#"
-            CREATE TABLE _prisma_migrations (
+        if let Some(schema) = &self.migrations_table.schema {
+            connection
+                .raw_cmd(&format!("CREATE SCHEMA IF NOT EXISTS {}", self.quote(schema)))
+                .await?;
+        }
+
+        let sql = formatdoc! {r#"
+            CREATE TABLE {} (
                 id                      VARCHAR(36) PRIMARY KEY NOT NULL,
                 checksum                VARCHAR(64) NOT NULL,
                 finished_at             TIMESTAMPTZ,
@@ -81,9 +97,9 @@ impl SqlFlavour for PostgresFlavour {
                 started_at              TIMESTAMPTZ NOT NULL DEFAULT now(),
                 applied_steps_count     INTEGER NOT NULL DEFAULT 0
             );
-        "#};
+        "#, self.render_imperative_migrations_table()};
 
-        Ok(connection.raw_cmd(sql).await?)
+        Ok(connection.raw_cmd(&sql).await?)
     }
 
     async fn describe_schema<'a>(&'a self, connection: &Connection) -> ConnectorResult<SqlSchema> {
@@ -182,6 +198,16 @@ impl SqlFlavour for PostgresFlavour {
             .raw_cmd(&format!("CREATE SCHEMA \"{}\"", schema_name))
             .await?;
 
+        // The migrations table is not dropped with the schema when it lives in another schema.
+        if self.migrations_table.schema.is_some() {
+            connection
+                .raw_cmd(&format!(
+                    "DROP TABLE IF EXISTS {}",
+                    self.render_imperative_migrations_table()
+                ))
+                .await?;
+        }
+
         Ok(())
     }
 
@@ -229,13 +255,24 @@ impl SqlFlavour for PostgresFlavour {
                         migration.migration_name()
                     );
 
-                    temporary_database
-                        .raw_cmd(&script)
-                        .await
-                        .map_err(ConnectorError::from)
-                        .map_err(|connector_error| {
-                            connector_error.into_migration_does_not_apply_cleanly(migration.migration_name().to_owned())
-                        })?;
+                    // Statements like `CREATE INDEX CONCURRENTLY` fail in the
+                    // implicit transaction of a multi-statement command.
+                    let statements = if is_non_transactional_script(&script) {
+                        split_statements(&script)
+                    } else {
+                        vec![script]
+                    };
+
+                    for statement in statements {
+                        temporary_database
+                            .raw_cmd(&statement)
+                            .await
+                            .map_err(ConnectorError::from)
+                            .map_err(|connector_error| {
+                                connector_error
+                                    .into_migration_does_not_apply_cleanly(migration.migration_name().to_owned())
+                            })?;
+                    }
                 }
 
                 // the connection to the temporary database is dropped at the end of
@@ -254,6 +291,14 @@ impl SqlFlavour for PostgresFlavour {
     fn features(&self) -> BitFlags<MigrationFeature> {
         self.features
     }
+
+    fn migrations_table(&self) -> &MigrationsTable {
+        &self.migrations_table
+    }
+
+    fn connection_schema_name(&self) -> &str {
+        self.url.schema()
+    }
 }
 
 fn strip_schema_param_from_url(url: &mut Url) {
@@ -1,6 +1,9 @@
-use crate::{connect, connection_wrapper::Connection, error::quaint_error_to_connector_error, flavour::SqlFlavour};
+use crate::{
+    connect, connection_wrapper::Connection, error::quaint_error_to_connector_error, flavour::SqlFlavour,
+    MigrationsTable,
+};
 use enumflags2::BitFlags;
-use indoc::indoc;
+use indoc::formatdoc;
 use migration_connector::{ConnectorError, ConnectorResult, MigrationDirectory, MigrationFeature};
 use quaint::prelude::{ConnectionInfo, SqlFamily};
 use sql_schema_describer::{DescriberErrorKind, SqlSchema, SqlSchemaDescriberBackend};
@@ -11,6 +14,7 @@ pub(crate) struct SqliteFlavour {
     pub(super) file_path: String,
     pub(super) attached_name: String,
     pub(super) features: BitFlags<MigrationFeature>,
+    pub(super) migrations_table: MigrationsTable,
 }
 
 #[async_trait::async_trait]
@@ -37,8 +41,8 @@ impl SqlFlavour for SqliteFlavour {
     }
 
     async fn create_imperative_migrations_table(&self, connection: &Connection) -> ConnectorResult<()> {
-        let sql = indoc! {r#"
-            CREATE TABLE "_prisma_migrations" (
+        let sql = formatdoc! {r#"
+            CREATE TABLE {} (
                 "id"                    TEXT PRIMARY KEY NOT NULL,
                 "checksum"              TEXT NOT NULL,
                 "finished_at"           DATETIME,
@@ -48,7 +52,7 @@ impl SqlFlavour for SqliteFlavour {
                 "started_at"            DATETIME NOT NULL DEFAULT current_timestamp,
                 "applied_steps_count"   INTEGER UNSIGNED NOT NULL DEFAULT 0
             );
-        "#};
+        "#, self.render_imperative_migrations_table()};
 
         Ok(connection.raw_cmd(&sql).await?)
     }
@@ -146,4 +150,12 @@ impl SqlFlavour for SqliteFlavour {
     fn features(&self) -> BitFlags<MigrationFeature> {
         self.features
     }
+
+    fn migrations_table(&self) -> &MigrationsTable {
+        &self.migrations_table
+    }
+
+    fn connection_schema_name(&self) -> &str {
+        &self.attached_name
+    }
 }
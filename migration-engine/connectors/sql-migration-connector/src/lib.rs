@@ -6,15 +6,20 @@
 mod connection_wrapper;
 mod error;
 mod flavour;
+mod migrations_table;
 mod pair;
 mod sql_database_migration_inferrer;
 mod sql_database_step_applier;
 mod sql_destructive_change_checker;
+mod sql_drift_report;
 mod sql_migration;
+mod sql_migration_linter;
 mod sql_migration_persistence;
 mod sql_renderer;
 mod sql_schema_calculator;
 mod sql_schema_differ;
+mod sql_script;
+mod sql_seed_applier;
 
 use connection_wrapper::Connection;
 use datamodel::Datamodel;
@@ -22,6 +27,7 @@ use enumflags2::BitFlags;
 use error::quaint_error_to_connector_error;
 use flavour::SqlFlavour;
 use migration_connector::*;
+pub use migrations_table::MigrationsTable;
 use quaint::{prelude::ConnectionInfo, single::Quaint};
 use sql_migration::SqlMigration;
 use sql_schema_describer::SqlSchema;
@@ -35,9 +41,13 @@ pub struct SqlMigrationConnector {
 
 impl SqlMigrationConnector {
     /// Construct and initialize the SQL migration connector.
-    pub async fn new(database_str: &str, features: BitFlags<MigrationFeature>) -> ConnectorResult<Self> {
+    pub async fn new(
+        database_str: &str,
+        features: BitFlags<MigrationFeature>,
+        migrations_table: MigrationsTable,
+    ) -> ConnectorResult<Self> {
         let connection = connect(database_str).await?;
-        let flavour = flavour::from_connection_info(connection.connection_info(), features);
+        let flavour = flavour::from_connection_info(connection.connection_info(), features, migrations_table);
 
         flavour.ensure_connection_validity(&connection).await?;
 
@@ -48,7 +58,7 @@ impl SqlMigrationConnector {
     pub async fn create_database(database_str: &str) -> ConnectorResult<String> {
         let connection_info =
             ConnectionInfo::from_url(database_str).map_err(|err| ConnectorError::url_parse_error(err, database_str))?;
-        let flavour = flavour::from_connection_info(&connection_info, BitFlags::empty());
+        let flavour = flavour::from_connection_info(&connection_info, BitFlags::empty(), MigrationsTable::default());
         flavour.create_database(database_str).await
     }
 
@@ -56,7 +66,7 @@ impl SqlMigrationConnector {
     pub async fn drop_database(database_str: &str) -> ConnectorResult<()> {
         let connection_info =
             ConnectionInfo::from_url(database_str).map_err(|err| ConnectorError::url_parse_error(err, database_str))?;
-        let flavour = flavour::from_connection_info(&connection_info, BitFlags::empty());
+        let flavour = flavour::from_connection_info(&connection_info, BitFlags::empty(), MigrationsTable::default());
 
         flavour.drop_database(database_str).await
     }
@@ -66,7 +76,7 @@ impl SqlMigrationConnector {
         let connection_info =
             ConnectionInfo::from_url(database_str).map_err(|err| ConnectorError::url_parse_error(err, database_str))?;
 
-        let flavour = flavour::from_connection_info(&connection_info, BitFlags::empty());
+        let flavour = flavour::from_connection_info(&connection_info, BitFlags::empty(), MigrationsTable::default());
 
         flavour.qe_setup(database_str).await
     }
@@ -135,9 +145,17 @@ impl MigrationConnector for SqlMigrationConnector {
         self
     }
 
+    fn migration_linter(&self) -> &dyn MigrationLinter<SqlMigration> {
+        self
+    }
+
     fn migration_persistence(&self) -> &dyn MigrationPersistence {
         self
     }
+
+    fn seed_applier(&self) -> &dyn SeedApplier {
+        self
+    }
 }
 
 async fn connect(database_str: &str) -> ConnectorResult<Connection> {
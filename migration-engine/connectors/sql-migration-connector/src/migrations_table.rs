@@ -0,0 +1,33 @@
+//! Configuration of the table where the applied migrations are recorded.
+
+/// The default name of the migrations table.
+const DEFAULT_MIGRATIONS_TABLE_NAME: &str = "_prisma_migrations";
+
+/// The table where the migration engine records the applied migrations. It
+/// is configured with the `migrationsTable` and `migrationsSchema` properties
+/// of the datasource.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MigrationsTable {
+    /// The name of the table.
+    pub name: String,
+    /// The schema of the table, when it is not the schema of the connection.
+    /// SQLite does not support it.
+    pub schema: Option<String>,
+}
+
+impl MigrationsTable {
+    /// Build the configuration from the datasource properties, falling back
+    /// to `_prisma_migrations` in the schema of the connection.
+    pub fn new(name: Option<&str>, schema: Option<&str>) -> Self {
+        MigrationsTable {
+            name: name.unwrap_or(DEFAULT_MIGRATIONS_TABLE_NAME).to_owned(),
+            schema: schema.map(String::from),
+        }
+    }
+}
+
+impl Default for MigrationsTable {
+    fn default() -> Self {
+        MigrationsTable::new(None, None)
+    }
+}
@@ -1,17 +1,25 @@
 use crate::{
-    flavour::SqlFlavour, pair::Pair, sql_migration::SqlMigration, sql_schema_calculator, sql_schema_differ,
-    SqlMigrationConnector,
+    flavour::SqlFlavour, pair::Pair, sql_drift_report, sql_migration::SqlMigration, sql_schema_calculator,
+    sql_schema_differ, SqlMigrationConnector,
 };
 use datamodel::*;
-use migration_connector::{ConnectorResult, DatabaseMigrationInferrer, MigrationConnector, MigrationDirectory};
+use migration_connector::{
+    ConnectorResult, DatabaseMigrationInferrer, Drift, EnumValueRename, MigrationConnector, MigrationDirectory,
+    ScriptMode,
+};
 use sql_schema_describer::*;
 
 #[async_trait::async_trait]
 impl DatabaseMigrationInferrer<SqlMigration> for SqlMigrationConnector {
-    async fn infer(&self, next: &Datamodel) -> ConnectorResult<SqlMigration> {
+    async fn infer(&self, next: &Datamodel, enum_value_renames: &[EnumValueRename]) -> ConnectorResult<SqlMigration> {
         let current_database_schema: SqlSchema = self.describe_schema().await?;
         let expected_database_schema = sql_schema_calculator::calculate_sql_schema(next, self.flavour());
-        Ok(infer(current_database_schema, expected_database_schema, self.flavour()))
+        Ok(infer(
+            current_database_schema,
+            expected_database_schema,
+            self.flavour(),
+            enum_value_renames,
+        ))
     }
 
     /// Infer the database migration steps, skipping the schema describer and assuming an empty database.
@@ -19,7 +27,19 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlMigrationConnector {
         let current_database_schema = SqlSchema::empty();
         let expected_database_schema = sql_schema_calculator::calculate_sql_schema(next, self.flavour());
 
-        Ok(infer(current_database_schema, expected_database_schema, self.flavour()))
+        Ok(infer(
+            current_database_schema,
+            expected_database_schema,
+            self.flavour(),
+            &[],
+        ))
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn infer_baseline_migration(&self) -> ConnectorResult<SqlMigration> {
+        let current_database_schema = self.describe_schema().await?;
+
+        Ok(infer(SqlSchema::empty(), current_database_schema, self.flavour(), &[]))
     }
 
     #[tracing::instrument(skip(self, previous_migrations, target_schema))]
@@ -27,6 +47,7 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlMigrationConnector {
         &self,
         previous_migrations: &[MigrationDirectory],
         target_schema: &Datamodel,
+        enum_value_renames: &[EnumValueRename],
     ) -> ConnectorResult<SqlMigration> {
         let current_database_schema = self
             .flavour()
@@ -34,11 +55,34 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlMigrationConnector {
             .await?;
         let expected_database_schema = sql_schema_calculator::calculate_sql_schema(target_schema, self.flavour());
 
-        Ok(infer(current_database_schema, expected_database_schema, self.flavour()))
+        Ok(infer(
+            current_database_schema,
+            expected_database_schema,
+            self.flavour(),
+            enum_value_renames,
+        ))
+    }
+
+    #[tracing::instrument(skip(self, previous_migrations, migrations_through_range))]
+    async fn infer_squashed_migration(
+        &self,
+        previous_migrations: &[MigrationDirectory],
+        migrations_through_range: &[MigrationDirectory],
+    ) -> ConnectorResult<SqlMigration> {
+        let schema_before_range = self
+            .flavour()
+            .sql_schema_from_migration_history(previous_migrations, self.conn())
+            .await?;
+        let schema_after_range = self
+            .flavour()
+            .sql_schema_from_migration_history(migrations_through_range, self.conn())
+            .await?;
+
+        Ok(infer(schema_before_range, schema_after_range, self.flavour(), &[]))
     }
 
     #[tracing::instrument(skip(self, applied_migrations))]
-    async fn calculate_drift(&self, applied_migrations: &[MigrationDirectory]) -> ConnectorResult<Option<String>> {
+    async fn calculate_drift(&self, applied_migrations: &[MigrationDirectory]) -> ConnectorResult<Option<Drift>> {
         let expected_schema = self
             .flavour()
             .sql_schema_from_migration_history(applied_migrations, self.conn())
@@ -46,7 +90,8 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlMigrationConnector {
 
         let actual_schema = self.describe_schema().await?;
 
-        let steps = sql_schema_differ::calculate_steps(Pair::new(&actual_schema, &expected_schema), self.flavour());
+        let steps =
+            sql_schema_differ::calculate_steps(Pair::new(&actual_schema, &expected_schema), self.flavour(), &[]);
 
         if steps.is_empty() {
             return Ok(None);
@@ -60,11 +105,21 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlMigrationConnector {
 
         let diagnostics = self.destructive_change_checker().pure_check(&migration);
 
-        let rollback = self
-            .database_migration_step_applier()
-            .render_script(&migration, &diagnostics);
-
-        Ok(Some(rollback))
+        let rollback =
+            self.database_migration_step_applier()
+                .render_script(&migration, &diagnostics, ScriptMode::Default);
+
+        // The rollback goes from the actual schema to the expected schema, the
+        // report describes the drift the other way around.
+        let drift_steps =
+            sql_schema_differ::calculate_steps(Pair::new(&migration.after, &migration.before), self.flavour(), &[]);
+        let report = sql_drift_report::drift_report(
+            &drift_steps,
+            Pair::new(&migration.after, &migration.before),
+            self.flavour(),
+        );
+
+        Ok(Some(Drift { rollback, report }))
     }
 
     #[tracing::instrument(skip(self, migrations))]
@@ -81,9 +136,13 @@ fn infer(
     current_database_schema: SqlSchema,
     expected_database_schema: SqlSchema,
     flavour: &dyn SqlFlavour,
+    enum_value_renames: &[EnumValueRename],
 ) -> SqlMigration {
-    let steps =
-        sql_schema_differ::calculate_steps(Pair::new(&current_database_schema, &expected_database_schema), flavour);
+    let steps = sql_schema_differ::calculate_steps(
+        Pair::new(&current_database_schema, &expected_database_schema),
+        flavour,
+        enum_value_renames,
+    );
 
     SqlMigration {
         before: current_database_schema,
@@ -1,11 +1,15 @@
 use crate::{
     pair::Pair,
-    sql_migration::{CreateTable, DropTable, SqlMigration, SqlMigrationStep},
+    sql_migration::{
+        AddColumn, AlterTable, CreateTable, DropColumn, DropTable, SqlMigration, SqlMigrationStep, TableChange,
+    },
+    sql_renderer::Guard,
+    sql_script::{concurrently_created_index, non_schema_statements, split_statements},
     SqlFlavour, SqlMigrationConnector,
 };
 use migration_connector::{
     ConnectorResult, DatabaseMigrationMarker, DatabaseMigrationStepApplier, DestructiveChangeDiagnostics,
-    PrettyDatabaseMigrationStep,
+    PrettyDatabaseMigrationStep, ScriptMode, NON_TRANSACTIONAL_DIRECTIVE,
 };
 use sql_schema_describer::{walkers::SqlSchemaExt, SqlSchema};
 
@@ -25,11 +29,12 @@ impl DatabaseMigrationStepApplier<SqlMigration> for SqlMigrationConnector {
     fn render_steps_pretty(
         &self,
         database_migration: &SqlMigration,
+        mode: ScriptMode,
     ) -> ConnectorResult<Vec<PrettyDatabaseMigrationStep>> {
         let mut steps = Vec::with_capacity(database_migration.steps.len());
 
         for step in &database_migration.steps {
-            let sql = render_raw_sql(&step, self.flavour(), database_migration.schemas()).join(";\n");
+            let sql = render_step(&step, self.flavour(), database_migration.schemas(), mode).join(";\n");
 
             if !sql.is_empty() {
                 steps.push(PrettyDatabaseMigrationStep { raw: sql });
@@ -39,13 +44,27 @@ impl DatabaseMigrationStepApplier<SqlMigration> for SqlMigrationConnector {
         Ok(steps)
     }
 
-    fn render_script(&self, database_migration: &SqlMigration, diagnostics: &DestructiveChangeDiagnostics) -> String {
+    fn render_script(
+        &self,
+        database_migration: &SqlMigration,
+        diagnostics: &DestructiveChangeDiagnostics,
+        mode: ScriptMode,
+    ) -> String {
         if database_migration.is_empty() {
             return "-- This is an empty migration.".to_string();
         }
 
         let mut script = String::with_capacity(40 * database_migration.steps.len());
 
+        if database_migration
+            .steps
+            .iter()
+            .any(|step| self.flavour().is_non_transactional_step(step))
+        {
+            script.push_str(NON_TRANSACTIONAL_DIRECTIVE);
+            script.push('\n');
+        }
+
         // Note: it would be much nicer if we could place the warnings next to
         // the SQL for the steps that triggered them.
         if diagnostics.has_warnings() || !diagnostics.unexecutable_migrations.is_empty() {
@@ -72,10 +91,11 @@ impl DatabaseMigrationStepApplier<SqlMigration> for SqlMigrationConnector {
         let mut is_first_step = true;
 
         for step in &database_migration.steps {
-            let statements: Vec<String> = render_raw_sql(
+            let statements: Vec<String> = render_step(
                 step,
                 self.flavour(),
                 Pair::new(&database_migration.before, &database_migration.after),
+                mode,
             );
 
             if !statements.is_empty() {
@@ -106,6 +126,53 @@ impl DatabaseMigrationStepApplier<SqlMigration> for SqlMigrationConnector {
     async fn apply_script(&self, script: &str) -> ConnectorResult<()> {
         Ok(self.conn().raw_cmd(script).await?)
     }
+
+    fn split_script(&self, script: &str) -> Vec<String> {
+        split_statements(script)
+    }
+
+    async fn clean_up_failed_statement(&self, statement: &str) -> ConnectorResult<()> {
+        // A failed `CREATE INDEX CONCURRENTLY` leaves an invalid index behind,
+        // and running the statement again would fail on the existing index.
+        // The statement can also fail because a valid index with the same
+        // name already exists: that one must be left alone.
+        let index_name = match concurrently_created_index(statement) {
+            Some(index_name) => index_name,
+            None => return Ok(()),
+        };
+
+        let schema_name = self.flavour().connection_schema_name();
+        let sql = "SELECT EXISTS(
+            SELECT 1 FROM pg_index
+            JOIN pg_class ON pg_class.oid = pg_index.indexrelid
+            JOIN pg_namespace ON pg_namespace.oid = pg_class.relnamespace
+            WHERE pg_namespace.nspname = $1 AND pg_class.relname = $2 AND NOT pg_index.indisvalid
+        )";
+
+        let index_is_invalid = self
+            .conn()
+            .query_raw(sql, &[schema_name.into(), index_name.as_str().into()])
+            .await?
+            .get(0)
+            .and_then(|row| row.at(0).and_then(|value| value.as_bool()))
+            .unwrap_or(false);
+
+        if index_is_invalid {
+            let drop_index = format!(
+                "DROP INDEX CONCURRENTLY IF EXISTS {}.{}",
+                self.flavour().quote(schema_name),
+                self.flavour().quote(&index_name),
+            );
+
+            self.conn().raw_cmd(&drop_index).await?;
+        }
+
+        Ok(())
+    }
+
+    fn non_schema_statements(&self, script: &str) -> Vec<String> {
+        non_schema_statements(script)
+    }
 }
 
 impl SqlMigrationConnector {
@@ -134,6 +201,165 @@ impl SqlMigrationConnector {
     }
 }
 
+fn render_step(
+    step: &SqlMigrationStep,
+    renderer: &(dyn SqlFlavour + Send + Sync),
+    schemas: Pair<&SqlSchema>,
+    mode: ScriptMode,
+) -> Vec<String> {
+    match mode {
+        ScriptMode::Default => render_raw_sql(step, renderer, schemas),
+        ScriptMode::Idempotent => render_idempotent_sql(step, renderer, schemas),
+    }
+}
+
+/// Render the step so that running the statements again after they succeeded
+/// does nothing. Steps that can't be guarded are rendered as usual.
+fn render_idempotent_sql(
+    step: &SqlMigrationStep,
+    renderer: &(dyn SqlFlavour + Send + Sync),
+    schemas: Pair<&SqlSchema>,
+) -> Vec<String> {
+    let guarded = |guard: Guard<'_>| guard_statements(render_raw_sql(step, renderer, schemas), renderer, guard);
+
+    match step {
+        SqlMigrationStep::CreateTable(CreateTable { table_index }) => guarded(Guard::TableMissing {
+            table: schemas.next().table_walker_at(*table_index).name(),
+        }),
+        SqlMigrationStep::DropTable(DropTable { table_index }) => guarded(Guard::TableExists {
+            table: schemas.previous().table_walker_at(*table_index).name(),
+        }),
+        SqlMigrationStep::CreateEnum(create_enum) => guarded(Guard::EnumMissing {
+            name: schemas.next().enum_walker_at(create_enum.enum_index).name(),
+        }),
+        SqlMigrationStep::DropEnum(drop_enum) => guarded(Guard::EnumExists {
+            name: schemas.previous().enum_walker_at(drop_enum.enum_index).name(),
+        }),
+        SqlMigrationStep::CreateIndex(create_index) => {
+            let index = schemas
+                .next()
+                .table_walker_at(create_index.table_index)
+                .index_at(create_index.index_index);
+
+            guarded(Guard::IndexMissing {
+                table: index.table().name(),
+                index: index.name(),
+            })
+        }
+        SqlMigrationStep::DropIndex(drop_index) => {
+            let index = schemas
+                .previous()
+                .table_walker_at(drop_index.table_index)
+                .index_at(drop_index.index_index);
+
+            guarded(Guard::IndexExists {
+                table: index.table().name(),
+                index: index.name(),
+            })
+        }
+        SqlMigrationStep::AddForeignKey(add_foreign_key) => {
+            let foreign_key = schemas
+                .next()
+                .table_walker_at(add_foreign_key.table_index)
+                .foreign_key_at(add_foreign_key.foreign_key_index);
+
+            match foreign_key.constraint_name() {
+                Some(constraint_name) => guarded(Guard::ForeignKeyMissing {
+                    table: foreign_key.table().name(),
+                    constraint_name,
+                }),
+                None => render_raw_sql(step, renderer, schemas),
+            }
+        }
+        SqlMigrationStep::DropForeignKey(drop_foreign_key) => guarded(Guard::ForeignKeyExists {
+            table: &drop_foreign_key.table,
+            constraint_name: &drop_foreign_key.constraint_name,
+        }),
+        // Each change is rendered on its own, so it can be guarded on its own.
+        SqlMigrationStep::AlterTable(alter_table) => {
+            let tables = schemas.tables(&alter_table.table_index);
+
+            alter_table
+                .changes
+                .iter()
+                .flat_map(|change| {
+                    let statements = renderer.render_alter_table(
+                        &AlterTable {
+                            table_index: alter_table.table_index,
+                            changes: vec![change.clone()],
+                        },
+                        &schemas,
+                    );
+
+                    let guard = match change {
+                        TableChange::AddColumn(AddColumn { column_index }) => Guard::ColumnMissing {
+                            table: tables.next().name(),
+                            column: tables.next().column_at(*column_index).name(),
+                        },
+                        TableChange::DropColumn(DropColumn { index }) => Guard::ColumnExists {
+                            table: tables.previous().name(),
+                            column: tables.previous().column_at(*index).name(),
+                        },
+                        _ => return statements,
+                    };
+
+                    guard_statements(statements, renderer, guard)
+                })
+                .collect()
+        }
+        SqlMigrationStep::AlterEnum(alter_enum) => {
+            let enums = schemas.enums(&alter_enum.index);
+
+            if alter_enum.is_empty() {
+                return guarded(Guard::EnumMissing {
+                    name: enums.next().name(),
+                });
+            }
+
+            // When the enum is also renamed, the values are added under its
+            // previous name, and running the script again fails on the
+            // missing enum.
+            if alter_enum.only_adds_variants() {
+                return guarded(Guard::EnumValuesMissing {
+                    name: enums.previous().name(),
+                });
+            }
+
+            let removed_values: Vec<String> = alter_enum
+                .dropped_variants
+                .iter()
+                .cloned()
+                .chain(alter_enum.renamed_variants.iter().map(|(previous, _)| previous.clone()))
+                .collect();
+
+            guarded(Guard::EnumValuesExist {
+                name: enums.previous().name(),
+                values: &removed_values,
+            })
+        }
+        // SQLite has no control flow statements to guard a table redefinition
+        // with. Running a completed redefinition again copies the rows again,
+        // but a redefinition that failed halfway through leaves its temporary
+        // table behind, see `ScriptMode::Idempotent`.
+        SqlMigrationStep::RedefineTables(_)
+        | SqlMigrationStep::RedefineIndex { .. }
+        | SqlMigrationStep::AlterIndex { .. } => render_raw_sql(step, renderer, schemas),
+    }
+}
+
+fn guard_statements(
+    statements: Vec<String>,
+    renderer: &(dyn SqlFlavour + Send + Sync),
+    guard: Guard<'_>,
+) -> Vec<String> {
+    // Steps can render to nothing, e.g. enums on MySQL.
+    if statements.is_empty() {
+        return statements;
+    }
+
+    renderer.render_guarded(statements, guard)
+}
+
 fn render_raw_sql(
     step: &SqlMigrationStep,
     renderer: &(dyn SqlFlavour + Send + Sync),
@@ -176,18 +402,30 @@ fn render_raw_sql(
             vec![renderer.render_drop_foreign_key(&foreign_key)]
         }
         SqlMigrationStep::AlterTable(alter_table) => renderer.render_alter_table(alter_table, &schemas),
-        SqlMigrationStep::CreateIndex(create_index) => vec![renderer.render_create_index(
-            &schemas
+        SqlMigrationStep::CreateIndex(create_index) => {
+            let index = schemas
                 .next()
                 .table_walker_at(create_index.table_index)
-                .index_at(create_index.index_index),
-        )],
-        SqlMigrationStep::DropIndex(drop_index) => vec![renderer.render_drop_index(
-            &schemas
+                .index_at(create_index.index_index);
+
+            if renderer.is_non_transactional_step(step) {
+                vec![renderer.render_create_index_concurrently(&index)]
+            } else {
+                vec![renderer.render_create_index(&index)]
+            }
+        }
+        SqlMigrationStep::DropIndex(drop_index) => {
+            let index = schemas
                 .previous()
                 .table_walker_at(drop_index.table_index)
-                .index_at(drop_index.index_index),
-        )],
+                .index_at(drop_index.index_index);
+
+            if renderer.is_non_transactional_step(step) {
+                vec![renderer.render_drop_index_concurrently(&index)]
+            } else {
+                vec![renderer.render_drop_index(&index)]
+            }
+        }
         SqlMigrationStep::AlterIndex { table, index } => {
             renderer.render_alter_index(schemas.tables(table).indexes(index).as_ref())
         }
@@ -18,6 +18,8 @@ mod check;
 mod database_inspection_results;
 mod destructive_change_checker_flavour;
 mod destructive_check_plan;
+mod enum_value_usages;
+mod narrowing;
 mod unexecutable_step_check;
 mod warning_check;
 
@@ -30,9 +32,10 @@ use crate::{
     SqlMigration, SqlMigrationConnector,
 };
 use destructive_check_plan::DestructiveCheckPlan;
+use enum_value_usages::EnumColumn;
 use migration_connector::{ConnectorResult, DestructiveChangeChecker, DestructiveChangeDiagnostics};
 use sql_schema_describer::{
-    walkers::{ColumnWalker, SqlSchemaExt},
+    walkers::{walk_columns, ColumnWalker, SqlSchemaExt},
     ColumnArity, SqlSchema,
 };
 use unexecutable_step_check::UnexecutableStepCheck;
@@ -234,15 +237,27 @@ impl SqlMigrationConnector {
                 }
                 SqlMigrationStep::AlterEnum(AlterEnum {
                     index,
-                    created_variants: _,
                     dropped_variants,
-                }) if !dropped_variants.is_empty() => plan.push_warning(
-                    SqlMigrationWarningCheck::EnumValueRemoval {
-                        enm: schemas.next().enum_walker_at(*index.next()).name().to_owned(),
-                        values: dropped_variants.clone(),
-                    },
-                    step_index,
-                ),
+                    ..
+                }) if !dropped_variants.is_empty() => {
+                    let previous_enum_name = schemas.previous().enum_walker_at(*index.previous()).name();
+
+                    plan.push_warning(
+                        SqlMigrationWarningCheck::EnumValueRemoval {
+                            enm: schemas.next().enum_walker_at(*index.next()).name().to_owned(),
+                            values: dropped_variants.clone(),
+                            columns: walk_columns(schemas.previous())
+                                .filter(|column| column.column_type_family().as_enum() == Some(previous_enum_name))
+                                .map(|column| EnumColumn {
+                                    table: column.table().name().to_owned(),
+                                    column: column.name().to_owned(),
+                                    is_list: column.arity().is_list(),
+                                })
+                                .collect(),
+                        },
+                        step_index,
+                    )
+                }
                 _ => (),
             }
         }
@@ -258,7 +273,7 @@ impl SqlMigrationConnector {
     ) -> ConnectorResult<DestructiveChangeDiagnostics> {
         let plan = self.plan(steps, schemas);
 
-        plan.execute(self.conn()).await
+        plan.execute(self.conn(), self.flavour()).await
     }
 }
 
@@ -267,7 +282,7 @@ impl DestructiveChangeChecker<SqlMigration> for SqlMigrationConnector {
     async fn check(&self, database_migration: &SqlMigration) -> ConnectorResult<DestructiveChangeDiagnostics> {
         let plan = self.plan(&database_migration.steps, &database_migration.schemas());
 
-        plan.execute(self.conn()).await
+        plan.execute(self.conn(), self.flavour()).await
     }
 
     fn pure_check(&self, database_migration: &SqlMigration) -> DestructiveChangeDiagnostics {
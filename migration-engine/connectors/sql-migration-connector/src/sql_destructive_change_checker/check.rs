@@ -1,4 +1,6 @@
-use super::database_inspection_results::DatabaseInspectionResults;
+use super::{
+    database_inspection_results::DatabaseInspectionResults, enum_value_usages::EnumColumn, narrowing::Narrowing,
+};
 
 /// This trait should be implemented by warning and unexecutable migration types. It lets them
 /// describe what data they need from the current state of the database to be as accurate and
@@ -14,6 +16,18 @@ pub(super) trait Check {
         None
     }
 
+    /// Indicates that the values that do not fit the returned narrowed type should be counted
+    /// for the returned table and column.
+    fn needed_narrowing_violation_count(&self) -> Option<(&str, &str, &Narrowing)> {
+        None
+    }
+
+    /// Indicates that the rows holding one of the returned enum values should be counted in each
+    /// of the returned columns.
+    fn needed_enum_value_usage_count(&self) -> Option<(&[EnumColumn], &[String])> {
+        None
+    }
+
     /// This function will always be called for every check in a migration. Each change must check
     /// for the data it needs in the database inspection results. If there is no data, it should
     /// assume the current state of the database could not be inspected and warn with a best effort
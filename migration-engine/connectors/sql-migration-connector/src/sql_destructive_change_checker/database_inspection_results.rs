@@ -1,3 +1,4 @@
+use super::narrowing::NarrowingViolations;
 use std::{borrow::Cow, collections::HashMap};
 
 /// The information about the current state of the database gathered by the destructive change checker.
@@ -7,6 +8,10 @@ pub(super) struct DatabaseInspectionResults {
     row_counts: HashMap<String, i64>,
     /// HashMap from (table name, column name) to non-null values count.
     value_counts: HashMap<(Cow<'static, str>, Cow<'static, str>), i64>,
+    /// HashMap from (table name, column name) to the values that do not fit a narrowed column type.
+    narrowing_violations: HashMap<(Cow<'static, str>, Cow<'static, str>), NarrowingViolations>,
+    /// HashMap from (table name, column name) to the number of rows holding removed enum values.
+    enum_value_usages: HashMap<(Cow<'static, str>, Cow<'static, str>), i64>,
 }
 
 impl DatabaseInspectionResults {
@@ -30,4 +35,34 @@ impl DatabaseInspectionResults {
     pub(super) fn set_value_count(&mut self, table: Cow<'static, str>, column: Cow<'static, str>, count: i64) {
         self.value_counts.insert((table, column), count);
     }
+
+    pub(super) fn get_narrowing_violations(&self, table: &str, column: &str) -> Option<NarrowingViolations> {
+        self.narrowing_violations
+            .get(&(Cow::Borrowed(table), Cow::Borrowed(column)))
+            .copied()
+    }
+
+    pub(super) fn set_narrowing_violations(
+        &mut self,
+        table: Cow<'static, str>,
+        column: Cow<'static, str>,
+        violations: NarrowingViolations,
+    ) {
+        self.narrowing_violations.insert((table, column), violations);
+    }
+
+    pub(super) fn get_enum_value_usage_count(&self, table: &str, column: &str) -> Option<i64> {
+        self.enum_value_usages
+            .get(&(Cow::Borrowed(table), Cow::Borrowed(column)))
+            .copied()
+    }
+
+    pub(super) fn set_enum_value_usage_count(
+        &mut self,
+        table: Cow<'static, str>,
+        column: Cow<'static, str>,
+        count: i64,
+    ) {
+        self.enum_value_usages.insert((table, column), count);
+    }
 }
@@ -25,6 +25,7 @@ impl DestructiveChangeCheckerFlavour for MssqlFlavour {
             column_index: _,
             changes,
             type_change,
+            renamed_enum_values: _,
         } = alter_column;
 
         if changes.only_default_changed() {
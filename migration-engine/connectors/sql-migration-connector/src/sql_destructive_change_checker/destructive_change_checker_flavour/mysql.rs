@@ -3,13 +3,14 @@ use crate::{
     flavour::MysqlFlavour,
     pair::Pair,
     sql_destructive_change_checker::{
-        destructive_check_plan::DestructiveCheckPlan, unexecutable_step_check::UnexecutableStepCheck,
-        warning_check::SqlMigrationWarningCheck,
+        destructive_check_plan::DestructiveCheckPlan, enum_value_usages::EnumColumn, narrowing::Narrowing,
+        unexecutable_step_check::UnexecutableStepCheck, warning_check::SqlMigrationWarningCheck,
     },
     sql_migration::{AlterColumn, ColumnTypeChange},
     sql_schema_differ::ColumnChanges,
 };
 use datamodel_connector::Connector;
+use native_types::MySqlType;
 use sql_datamodel_connector::SqlDatamodelConnectors;
 use sql_schema_describer::{walkers::ColumnWalker, DefaultKind, DefaultValue};
 
@@ -25,6 +26,7 @@ impl DestructiveChangeCheckerFlavour for MysqlFlavour {
             column_index: _,
             changes,
             type_change,
+            renamed_enum_values,
         } = alter_column;
 
         // If only the default changed, the migration is safe.
@@ -47,7 +49,7 @@ impl DestructiveChangeCheckerFlavour for MysqlFlavour {
             return;
         }
 
-        if changes.only_type_changed() && is_safe_enum_change(columns, plan, step_index) {
+        if changes.only_type_changed() && is_safe_enum_change(columns, renamed_enum_values, plan, step_index) {
             return;
         }
 
@@ -64,8 +66,18 @@ impl DestructiveChangeCheckerFlavour for MysqlFlavour {
 
         match type_change {
             None | Some(ColumnTypeChange::SafeCast) => (),
-            Some(ColumnTypeChange::RiskyCast) => {
-                plan.push_warning(
+            Some(ColumnTypeChange::RiskyCast) => match narrowing(columns) {
+                Some(narrowing) => plan.push_warning(
+                    SqlMigrationWarningCheck::NarrowingCast {
+                        table: columns.previous().table().name().to_owned(),
+                        column: columns.previous().name().to_owned(),
+                        previous_type,
+                        next_type,
+                        narrowing,
+                    },
+                    step_index,
+                ),
+                None => plan.push_warning(
                     SqlMigrationWarningCheck::RiskyCast {
                         table: columns.previous().table().name().to_owned(),
                         column: columns.previous().name().to_owned(),
@@ -73,8 +85,8 @@ impl DestructiveChangeCheckerFlavour for MysqlFlavour {
                         next_type,
                     },
                     step_index,
-                );
-            }
+                ),
+            },
             Some(ColumnTypeChange::NotCastable) => {
                 plan.push_warning(
                     SqlMigrationWarningCheck::NotCastable {
@@ -131,11 +143,17 @@ impl DestructiveChangeCheckerFlavour for MysqlFlavour {
 }
 
 /// If the type change is an enum change, diagnose it, and return whether it _was_ an enum change.
-fn is_safe_enum_change(columns: &Pair<ColumnWalker<'_>>, plan: &mut DestructiveCheckPlan, step_index: usize) -> bool {
+fn is_safe_enum_change(
+    columns: &Pair<ColumnWalker<'_>>,
+    renamed_values: &[(String, String)],
+    plan: &mut DestructiveCheckPlan,
+    step_index: usize,
+) -> bool {
     if let (Some(previous_enum), Some(next_enum)) = (
         columns.previous().column_type_family_as_enum(),
         columns.next().column_type_family_as_enum(),
     ) {
+        // Renamed values are migrated by the rendered script.
         let removed_values: Vec<String> = previous_enum
             .values
             .iter()
@@ -144,6 +162,7 @@ fn is_safe_enum_change(columns: &Pair<ColumnWalker<'_>>, plan: &mut DestructiveC
                     .values
                     .iter()
                     .any(|next_value| previous_value.as_str() == next_value.as_str())
+                    && !renamed_values.iter().any(|(renamed, _)| renamed == *previous_value)
             })
             .cloned()
             .collect();
@@ -153,6 +172,11 @@ fn is_safe_enum_change(columns: &Pair<ColumnWalker<'_>>, plan: &mut DestructiveC
                 SqlMigrationWarningCheck::EnumValueRemoval {
                     enm: next_enum.name.clone(),
                     values: removed_values,
+                    columns: vec![EnumColumn {
+                        table: columns.previous().table().name().to_owned(),
+                        column: columns.previous().name().to_owned(),
+                        is_list: columns.previous().arity().is_list(),
+                    }],
                 },
                 step_index,
             );
@@ -164,6 +188,55 @@ fn is_safe_enum_change(columns: &Pair<ColumnWalker<'_>>, plan: &mut DestructiveC
     false
 }
 
+/// The range of values the column is narrowed to, when it is cast from a text,
+/// integer or decimal type to a smaller type of the same kind.
+fn narrowing(columns: &Pair<ColumnWalker<'_>>) -> Option<Narrowing> {
+    let previous = columns.previous().column_native_type::<MySqlType>()?;
+    let next = columns.next().column_native_type::<MySqlType>()?;
+
+    if let (Some(previous_length), Some(Some(next_length))) = (max_text_length(&previous), max_text_length(&next)) {
+        return Narrowing::text(previous_length, next_length);
+    }
+
+    if let (Some(previous_range), Some(next_range)) = (integer_range(&previous), integer_range(&next)) {
+        return Narrowing::integer(previous_range, next_range);
+    }
+
+    match (previous, next) {
+        // A decimal without parameters is a decimal(10, 0).
+        (MySqlType::Decimal(previous_params), MySqlType::Decimal(next_params)) => {
+            Narrowing::decimal(Some(previous_params.unwrap_or((10, 0))), next_params.unwrap_or((10, 0)))
+        }
+        _ => None,
+    }
+}
+
+/// The maximum length in characters of text types, `Some(None)` for the
+/// `*TEXT` types, limited in bytes, and `None` for other types.
+fn max_text_length(tpe: &MySqlType) -> Option<Option<u32>> {
+    match tpe {
+        MySqlType::VarChar(length) | MySqlType::Char(length) => Some(Some(*length)),
+        MySqlType::TinyText | MySqlType::Text | MySqlType::MediumText | MySqlType::LongText => Some(None),
+        _ => None,
+    }
+}
+
+fn integer_range(tpe: &MySqlType) -> Option<(i128, i128)> {
+    match tpe {
+        MySqlType::TinyInt => Some((i8::MIN.into(), i8::MAX.into())),
+        MySqlType::UnsignedTinyInt => Some((0, u8::MAX.into())),
+        MySqlType::SmallInt => Some((i16::MIN.into(), i16::MAX.into())),
+        MySqlType::UnsignedSmallInt => Some((0, u16::MAX.into())),
+        MySqlType::MediumInt => Some((-(1 << 23), (1 << 23) - 1)),
+        MySqlType::UnsignedMediumInt => Some((0, (1 << 24) - 1)),
+        MySqlType::Int => Some((i32::MIN.into(), i32::MAX.into())),
+        MySqlType::UnsignedInt => Some((0, u32::MAX.into())),
+        MySqlType::BigInt => Some((i64::MIN.into(), i64::MAX.into())),
+        MySqlType::UnsignedBigInt => Some((0, u64::MAX.into())),
+        _ => None,
+    }
+}
+
 fn default_can_be_rendered(default: Option<&DefaultValue>) -> bool {
     match default.as_ref().map(|d| d.kind()) {
         None => false,
@@ -3,13 +3,14 @@ use crate::{
     flavour::PostgresFlavour,
     pair::Pair,
     sql_destructive_change_checker::{
-        destructive_check_plan::DestructiveCheckPlan, unexecutable_step_check::UnexecutableStepCheck,
-        warning_check::SqlMigrationWarningCheck,
+        destructive_check_plan::DestructiveCheckPlan, narrowing::Narrowing,
+        unexecutable_step_check::UnexecutableStepCheck, warning_check::SqlMigrationWarningCheck,
     },
     sql_migration::{AlterColumn, ColumnTypeChange},
     sql_schema_differ::ColumnChanges,
 };
 use datamodel_connector::Connector;
+use native_types::PostgresType;
 use sql_datamodel_connector::SqlDatamodelConnectors;
 use sql_schema_describer::{walkers::ColumnWalker, DefaultKind, DefaultValue};
 
@@ -25,6 +26,7 @@ impl DestructiveChangeCheckerFlavour for PostgresFlavour {
             column_index: _,
             changes,
             type_change,
+            renamed_enum_values: _,
         } = alter_column;
 
         if changes.arity_changed() && columns.previous().arity().is_nullable() && columns.next().arity().is_required() {
@@ -60,8 +62,18 @@ impl DestructiveChangeCheckerFlavour for PostgresFlavour {
 
         match type_change {
             None | Some(ColumnTypeChange::SafeCast) => (),
-            Some(ColumnTypeChange::RiskyCast) => {
-                plan.push_warning(
+            Some(ColumnTypeChange::RiskyCast) => match narrowing(columns) {
+                Some(narrowing) => plan.push_warning(
+                    SqlMigrationWarningCheck::NarrowingCast {
+                        table: columns.previous().table().name().to_owned(),
+                        column: columns.previous().name().to_owned(),
+                        previous_type,
+                        next_type,
+                        narrowing,
+                    },
+                    step_index,
+                ),
+                None => plan.push_warning(
                     SqlMigrationWarningCheck::RiskyCast {
                         table: columns.previous().table().name().to_owned(),
                         column: columns.previous().name().to_owned(),
@@ -69,8 +81,8 @@ impl DestructiveChangeCheckerFlavour for PostgresFlavour {
                         next_type,
                     },
                     step_index,
-                );
-            }
+                ),
+            },
             Some(ColumnTypeChange::NotCastable) => {
                 plan.push_warning(
                     SqlMigrationWarningCheck::NotCastable {
@@ -126,6 +138,48 @@ impl DestructiveChangeCheckerFlavour for PostgresFlavour {
     }
 }
 
+/// The range of values the column is narrowed to, when it is cast from a text,
+/// integer or decimal type to a smaller type of the same kind.
+fn narrowing(columns: &Pair<ColumnWalker<'_>>) -> Option<Narrowing> {
+    let previous = columns.previous().column_native_type::<PostgresType>()?;
+    let next = columns.next().column_native_type::<PostgresType>()?;
+
+    if let (Some(previous_length), Some(Some(next_length))) = (max_text_length(&previous), max_text_length(&next)) {
+        return Narrowing::text(previous_length, next_length);
+    }
+
+    if let (Some(previous_range), Some(next_range)) = (integer_range(&previous), integer_range(&next)) {
+        return Narrowing::integer(previous_range, next_range);
+    }
+
+    match (previous, next) {
+        (PostgresType::Decimal(previous_params), PostgresType::Decimal(Some(next_params))) => {
+            Narrowing::decimal(previous_params, next_params)
+        }
+        _ => None,
+    }
+}
+
+/// `Some(None)` for unbounded text types, `None` for other types.
+fn max_text_length(tpe: &PostgresType) -> Option<Option<u32>> {
+    match tpe {
+        PostgresType::Text | PostgresType::Citext | PostgresType::VarChar(None) => Some(None),
+        PostgresType::VarChar(Some(length)) => Some(Some(*length)),
+        // A char without length is a char(1).
+        PostgresType::Char(length) => Some(Some(length.unwrap_or(1))),
+        _ => None,
+    }
+}
+
+fn integer_range(tpe: &PostgresType) -> Option<(i128, i128)> {
+    match tpe {
+        PostgresType::SmallInt => Some((i16::MIN.into(), i16::MAX.into())),
+        PostgresType::Integer => Some((i32::MIN.into(), i32::MAX.into())),
+        PostgresType::BigInt => Some((i64::MIN.into(), i64::MAX.into())),
+        _ => None,
+    }
+}
+
 fn default_can_be_rendered(default: Option<&DefaultValue>) -> bool {
     match default.as_ref().map(|d| d.kind()) {
         None => false,
@@ -1,8 +1,12 @@
 use super::{
-    check::Check, database_inspection_results::DatabaseInspectionResults,
-    unexecutable_step_check::UnexecutableStepCheck, warning_check::SqlMigrationWarningCheck,
+    check::Check,
+    database_inspection_results::DatabaseInspectionResults,
+    enum_value_usages::EnumColumn,
+    narrowing::{Narrowing, NarrowingViolations},
+    unexecutable_step_check::UnexecutableStepCheck,
+    warning_check::SqlMigrationWarningCheck,
 };
-use crate::connection_wrapper::Connection;
+use crate::{connection_wrapper::Connection, flavour::SqlFlavour};
 use migration_connector::{
     ConnectorError, ConnectorResult, DestructiveChangeDiagnostics, MigrationWarning, UnexecutableMigration,
 };
@@ -40,17 +44,22 @@ impl DestructiveCheckPlan {
     /// errors.
     ///
     /// For example, dropping a table that has 0 rows can be considered safe.
-    #[tracing::instrument(skip(conn), level = "debug")]
-    pub(super) async fn execute(&self, conn: &Connection) -> ConnectorResult<DestructiveChangeDiagnostics> {
+    #[tracing::instrument(skip(conn, flavour), level = "debug")]
+    pub(super) async fn execute(
+        &self,
+        conn: &Connection,
+        flavour: &(dyn SqlFlavour + Send + Sync),
+    ) -> ConnectorResult<DestructiveChangeDiagnostics> {
         let mut results = DatabaseInspectionResults::default();
 
         let inspection = async {
             for (unexecutable, _idx) in &self.unexecutable_migrations {
-                self.inspect_for_check(unexecutable, &mut results, conn).await?;
+                self.inspect_for_check(unexecutable, &mut results, conn, flavour)
+                    .await?;
             }
 
             for (warning, _idx) in &self.warnings {
-                self.inspect_for_check(warning, &mut results, conn).await?;
+                self.inspect_for_check(warning, &mut results, conn, flavour).await?;
             }
 
             Ok::<(), ConnectorError>(())
@@ -91,6 +100,7 @@ impl DestructiveCheckPlan {
         check: &(dyn Check + Send + Sync + 'static),
         results: &mut DatabaseInspectionResults,
         conn: &Connection,
+        flavour: &(dyn SqlFlavour + Send + Sync),
     ) -> ConnectorResult<()> {
         if let Some(table) = check.needed_table_row_count() {
             if results.get_row_count(table).is_none() {
@@ -106,6 +116,32 @@ impl DestructiveCheckPlan {
             }
         }
 
+        if let Some((table, column, narrowing)) = check.needed_narrowing_violation_count() {
+            if results.get_narrowing_violations(table, column).is_none() {
+                let violations = count_narrowing_violations(narrowing, column, table, conn, flavour).await?;
+                results.set_narrowing_violations(table.to_owned().into(), column.to_owned().into(), violations);
+            }
+        }
+
+        if let Some((columns, values)) = check.needed_enum_value_usage_count() {
+            for column in columns {
+                if results
+                    .get_enum_value_usage_count(&column.table, &column.column)
+                    .is_none()
+                {
+                    // The table does not necessarily exist yet, for example when evaluating data
+                    // loss on a database the previous migrations were not applied to.
+                    if let Ok(count) = count_enum_value_usages(column, values, conn, flavour).await {
+                        results.set_enum_value_usage_count(
+                            column.table.clone().into(),
+                            column.column.clone().into(),
+                            count,
+                        );
+                    }
+                }
+            }
+        }
+
         Ok(())
     }
 
@@ -192,3 +228,47 @@ async fn count_values_in_column(column_name: &str, table: &str, conn: &Connectio
 
     Ok(values_count)
 }
+
+async fn count_narrowing_violations(
+    narrowing: &Narrowing,
+    column_name: &str,
+    table: &str,
+    conn: &Connection,
+    flavour: &(dyn SqlFlavour + Send + Sync),
+) -> ConnectorResult<NarrowingViolations> {
+    let query = narrowing.render_violations_query(conn.connection_info().schema_name(), table, column_name, flavour);
+    let result_set = conn.query_raw(&query, &[]).await?;
+
+    let counts = result_set.first().map(|row| {
+        (
+            row.at(0).and_then(|count| count.as_i64()),
+            row.at(1).and_then(|count| count.as_i64()),
+        )
+    });
+
+    match counts {
+        Some((Some(overflowing), Some(rounded))) => Ok(NarrowingViolations { overflowing, rounded }),
+        _ => Err(ConnectorError::generic(anyhow::anyhow!(
+            "Unexpected result set shape when checking narrowed columns."
+        ))),
+    }
+}
+
+async fn count_enum_value_usages(
+    column: &EnumColumn,
+    values: &[String],
+    conn: &Connection,
+    flavour: &(dyn SqlFlavour + Send + Sync),
+) -> ConnectorResult<i64> {
+    let query = column.render_usage_count_query(conn.connection_info().schema_name(), values, flavour);
+    let result_set = conn.query_raw(&query, &[]).await?;
+
+    result_set
+        .first()
+        .and_then(|row| row.at(0).and_then(|count| count.as_i64()))
+        .ok_or_else(|| {
+            ConnectorError::generic(anyhow::anyhow!(
+                "Unexpected result set shape when checking removed enum values."
+            ))
+        })
+}
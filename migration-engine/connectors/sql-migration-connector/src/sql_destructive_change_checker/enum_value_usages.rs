@@ -0,0 +1,57 @@
+//! Removing values from an enum is only destructive when rows still hold them,
+//! so the destructive change checker counts these rows instead of warning on
+//! the removed values alone.
+
+use crate::flavour::SqlFlavour;
+
+/// A column holding values of an enum.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct EnumColumn {
+    pub(super) table: String,
+    pub(super) column: String,
+    pub(super) is_list: bool,
+}
+
+impl EnumColumn {
+    /// Render a query counting the rows where the column holds one of the
+    /// `values`. The values are compared as text, so the query stays valid
+    /// for values that are not part of the enum anymore.
+    pub(super) fn render_usage_count_query(
+        &self,
+        schema_name: &str,
+        values: &[String],
+        flavour: &dyn SqlFlavour,
+    ) -> String {
+        let is_postgres = flavour.sql_family().is_postgres();
+        let quoted_column = flavour.quote(&self.column);
+
+        let values = values
+            .iter()
+            .map(|value| render_string_literal(value, is_postgres))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let condition = match (is_postgres, self.is_list) {
+            (true, true) => format!("{}::text[] && ARRAY[{}]::text[]", quoted_column, values),
+            (true, false) => format!("{}::text IN ({})", quoted_column, values),
+            (false, _) => format!("{} IN ({})", quoted_column, values),
+        };
+
+        format!(
+            "SELECT COUNT(*) FROM {}.{} WHERE {}",
+            flavour.quote(schema_name),
+            flavour.quote(&self.table),
+            condition,
+        )
+    }
+}
+
+fn render_string_literal(value: &str, is_postgres: bool) -> String {
+    let escaped = if is_postgres {
+        value.replace('\'', "''")
+    } else {
+        value.replace('\\', "\\\\").replace('\'', "''")
+    };
+
+    format!("'{}'", escaped)
+}
@@ -0,0 +1,115 @@
+//! Narrowing type changes reduce the range of values a column can hold. They
+//! are only destructive when existing values do not fit, so the destructive
+//! change checker counts these values instead of warning on the types alone.
+
+use crate::flavour::SqlFlavour;
+
+/// The range of values a column is narrowed to.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum Narrowing {
+    /// Text values of at most `max_length` characters.
+    Length { max_length: u32 },
+    /// Integers between `min` and `max`, included.
+    IntegerRange { min: i128, max: i128 },
+    /// Decimals with at most `precision` digits, `scale` of them after the decimal point.
+    Decimal { precision: u32, scale: u32 },
+}
+
+impl Narrowing {
+    /// Narrowing from a text type with the previous maximum length (`None` if
+    /// unbounded) to a text type with the next maximum length.
+    pub(super) fn text(previous_max_length: Option<u32>, next_max_length: u32) -> Option<Narrowing> {
+        match previous_max_length {
+            Some(previous_max_length) if previous_max_length <= next_max_length => None,
+            _ => Some(Narrowing::Length {
+                max_length: next_max_length,
+            }),
+        }
+    }
+
+    /// Narrowing between two integer types, given their `(min, max)` ranges.
+    pub(super) fn integer(previous_range: (i128, i128), next_range: (i128, i128)) -> Option<Narrowing> {
+        let ((previous_min, previous_max), (min, max)) = (previous_range, next_range);
+
+        if min <= previous_min && max >= previous_max {
+            return None;
+        }
+
+        Some(Narrowing::IntegerRange { min, max })
+    }
+
+    /// Narrowing between two decimal types, given their `(precision, scale)`.
+    /// `None` stands for the maximum precision.
+    pub(super) fn decimal(previous_params: Option<(u32, u32)>, next_params: (u32, u32)) -> Option<Narrowing> {
+        let (precision, scale) = next_params;
+
+        match previous_params {
+            Some((previous_precision, previous_scale))
+                if previous_precision - previous_scale <= precision - scale && previous_scale <= scale =>
+            {
+                None
+            }
+            _ => Some(Narrowing::Decimal { precision, scale }),
+        }
+    }
+
+    /// Render a query returning two counts: the values in the column that do
+    /// not fit, and would make the migration fail, and the values that would
+    /// be rounded.
+    pub(super) fn render_violations_query(
+        &self,
+        schema_name: &str,
+        table: &str,
+        column: &str,
+        flavour: &dyn SqlFlavour,
+    ) -> String {
+        let quoted_column = flavour.quote(column).to_string();
+
+        let (overflowing, rounded) = match self {
+            Narrowing::Length { max_length } => (format!("CHAR_LENGTH({}) > {}", quoted_column, max_length), None),
+            Narrowing::IntegerRange { min, max } => (
+                format!("{column} < {} OR {column} > {}", min, max, column = quoted_column),
+                None,
+            ),
+            Narrowing::Decimal { precision, scale } => (
+                format!(
+                    "ABS(ROUND({}, {})) >= 1{}",
+                    quoted_column,
+                    scale,
+                    "0".repeat((precision - scale) as usize)
+                ),
+                Some(format!(
+                    "{column} <> ROUND({column}, {})",
+                    scale,
+                    column = quoted_column
+                )),
+            ),
+        };
+
+        format!(
+            "SELECT COUNT(CASE WHEN {} THEN 1 END), {} FROM {}.{}",
+            overflowing,
+            rounded
+                .map(|rounded| format!("COUNT(CASE WHEN {} THEN 1 END)", rounded))
+                .unwrap_or_else(|| "0".to_owned()),
+            flavour.quote(schema_name),
+            flavour.quote(table),
+        )
+    }
+}
+
+/// The number of values that do not fit a narrowed column type.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) struct NarrowingViolations {
+    /// Values out of range, or too long. The migration would fail, or
+    /// truncate them, depending on the database settings.
+    pub(super) overflowing: i64,
+    /// Values with more decimal places than the new scale. They would be rounded.
+    pub(super) rounded: i64,
+}
+
+impl NarrowingViolations {
+    pub(super) fn is_empty(&self) -> bool {
+        self.overflowing == 0 && self.rounded == 0
+    }
+}
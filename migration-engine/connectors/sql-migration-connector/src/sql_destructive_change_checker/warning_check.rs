@@ -1,4 +1,7 @@
-use super::{check::Check, database_inspection_results::DatabaseInspectionResults};
+use super::{
+    check::Check, database_inspection_results::DatabaseInspectionResults, enum_value_usages::EnumColumn,
+    narrowing::Narrowing,
+};
 
 #[derive(Debug)]
 pub(super) enum SqlMigrationWarningCheck {
@@ -19,6 +22,15 @@ pub(super) enum SqlMigrationWarningCheck {
         previous_type: String,
         next_type: String,
     },
+    /// A risky cast to a type with a smaller range of values. The existing
+    /// data is checked against the new range.
+    NarrowingCast {
+        table: String,
+        column: String,
+        previous_type: String,
+        next_type: String,
+        narrowing: Narrowing,
+    },
     NotCastable {
         table: String,
         column: String,
@@ -32,9 +44,12 @@ pub(super) enum SqlMigrationWarningCheck {
         table: String,
         columns: Vec<String>,
     },
+    /// Values removed from an enum. The rows holding them are counted in the
+    /// columns using the enum.
     EnumValueRemoval {
         enm: String,
         values: Vec<String>,
+        columns: Vec<EnumColumn>,
     },
 }
 
@@ -53,6 +68,7 @@ impl Check for SqlMigrationWarningCheck {
         match self {
             SqlMigrationWarningCheck::NonEmptyColumnDrop { table, column }
             | SqlMigrationWarningCheck::RiskyCast { table, column, .. }
+            | SqlMigrationWarningCheck::NarrowingCast { table, column, .. }
             | SqlMigrationWarningCheck::DropAndRecreateColumn { table, column } => Some((table, column)),
 
             SqlMigrationWarningCheck::NonEmptyTableDrop { .. } | SqlMigrationWarningCheck::PrimaryKeyChange { .. } => {
@@ -62,6 +78,25 @@ impl Check for SqlMigrationWarningCheck {
         }
     }
 
+    fn needed_narrowing_violation_count(&self) -> Option<(&str, &str, &Narrowing)> {
+        match self {
+            SqlMigrationWarningCheck::NarrowingCast {
+                table,
+                column,
+                narrowing,
+                ..
+            } => Some((table, column, narrowing)),
+            _ => None,
+        }
+    }
+
+    fn needed_enum_value_usage_count(&self) -> Option<(&[EnumColumn], &[String])> {
+        match self {
+            SqlMigrationWarningCheck::EnumValueRemoval { values, columns, .. } => Some((columns, values)),
+            _ => None,
+        }
+    }
+
     fn evaluate(&self, database_check_results: &DatabaseInspectionResults) -> Option<String> {
         match self {
             SqlMigrationWarningCheck::DropAndRecreateColumn { table, column } => {
@@ -91,6 +126,30 @@ impl Check for SqlMigrationWarningCheck {
                 (_, _) => Some(format!("You are about to alter the column `{column_name}` on the `{table_name}` table. The data in that column could be lost. The data in that column will be cast from `{old_type}` to `{new_type}`.", column_name = column, table_name = table, old_type = previous_type, new_type = next_type)),
 
             },
+            SqlMigrationWarningCheck::NarrowingCast { table, column, previous_type, next_type, narrowing } => match database_check_results.get_narrowing_violations(table, column) {
+                Some(violations) if violations.is_empty() => None, // all the existing values fit in the new type
+                Some(violations) => {
+                    let mut message = format!("You are about to alter the column `{column_name}` on the `{table_name}` table. The data in that column will be cast from `{old_type}` to `{new_type}`.", column_name = column, table_name = table, old_type = previous_type, new_type = next_type);
+
+                    if violations.overflowing > 0 {
+                        message.push_str(&format!(" {overflowing} values do not fit in `{new_type}`. The migration will fail, or they will be truncated.", overflowing = violations.overflowing, new_type = next_type));
+                    }
+
+                    if let Narrowing::Decimal { scale, .. } = narrowing {
+                        if violations.rounded > 0 {
+                            message.push_str(&format!(" {rounded} values have more than {scale} decimal places and will be rounded.", rounded = violations.rounded, scale = scale));
+                        }
+                    }
+
+                    Some(message)
+                },
+                None => match database_check_results.get_row_and_non_null_value_count(table, column) {
+                    (Some(0), _) => None,
+                    (_, Some(0)) => None,
+                    (_, Some(value_count)) => Some(format!("You are about to alter the column `{column_name}` on the `{table_name}` table, which contains {value_count} non-null values. The data in that column will be cast from `{old_type}` to `{new_type}`.", column_name = column, table_name = table, value_count = value_count, old_type = previous_type, new_type = next_type)),
+                    (_, _) => Some(format!("You are about to alter the column `{column_name}` on the `{table_name}` table. The data in that column could be lost. The data in that column will be cast from `{old_type}` to `{new_type}`.", column_name = column, table_name = table, old_type = previous_type, new_type = next_type)),
+                },
+            },
 
             // todo this seems to not be reached when only a table is dropped and recreated
             SqlMigrationWarningCheck::NotCastable { table, column, previous_type, next_type } => match database_check_results.get_row_and_non_null_value_count(table, column) {
@@ -105,7 +164,18 @@ impl Check for SqlMigrationWarningCheck {
                 _ => Some(format!("The migration will change the primary key for the `{table}` table. If it partially fails, the table could be left without primary key constraint.", table = table)),
             },
             SqlMigrationWarningCheck::UniqueConstraintAddition { table, columns } =>  Some(format!("The migration will add a unique constraint covering the columns `{columns}` on the table `{table}`. If there are existing duplicate values, the migration will fail.", table = table, columns = format!("[{}]",columns.join(",")))),
-            SqlMigrationWarningCheck::EnumValueRemoval { enm, values } =>  Some(format!("The migration will remove the values {values} on the enum `{enm}`. If these variants are still used in the database, the migration will fail.", enm = enm, values = format!("[{}]",values.join(",")))),
+            SqlMigrationWarningCheck::EnumValueRemoval { enm, values, columns } => {
+                let usage_count: Option<i64> = columns
+                    .iter()
+                    .map(|column| database_check_results.get_enum_value_usage_count(&column.table, &column.column))
+                    .sum();
+
+                match usage_count {
+                    Some(0) => None, // no row holds the removed values
+                    Some(usage_count) => Some(format!("The migration will remove the values {values} on the enum `{enm}`, which are still used in {usage_count} rows. The migration will fail unless these rows are updated first.", enm = enm, values = format!("[{}]",values.join(",")), usage_count = usage_count)),
+                    None => Some(format!("The migration will remove the values {values} on the enum `{enm}`. If these variants are still used in the database, the migration will fail.", enm = enm, values = format!("[{}]",values.join(",")))),
+                }
+            }
 
         }
     }
@@ -0,0 +1,234 @@
+//! Structured drift reports, built from the steps the differ generates from
+//! the expected schema to the actual database schema.
+
+use crate::{
+    flavour::SqlFlavour,
+    pair::Pair,
+    sql_migration::{
+        AddColumn, AddForeignKey, AlterColumn, AlterEnum, AlterTable, CreateEnum, CreateIndex, CreateTable, DropColumn,
+        DropEnum, DropForeignKey, DropIndex, DropTable, SqlMigrationStep, TableChange,
+    },
+    sql_schema_differ::ColumnChanges,
+};
+use migration_connector::{ChangedColumn, DriftColumn, DriftForeignKey, DriftReport, EnumDrift, TableDrift};
+use sql_schema_describer::{
+    walkers::{ColumnWalker, ForeignKeyWalker, SqlSchemaExt},
+    SqlSchema,
+};
+use std::collections::BTreeMap;
+
+/// Describe the drift from the `previous` schema, expected from the migration
+/// history, to the `next` schema, in the database. The steps must be the
+/// steps between these two schemas.
+pub(crate) fn drift_report(
+    steps: &[SqlMigrationStep],
+    schemas: Pair<&SqlSchema>,
+    flavour: &dyn SqlFlavour,
+) -> DriftReport {
+    let mut report = DriftReport::default();
+    let mut changed_tables: BTreeMap<String, TableDrift> = BTreeMap::new();
+
+    for step in steps {
+        match step {
+            SqlMigrationStep::CreateTable(CreateTable { table_index }) => {
+                report
+                    .added_tables
+                    .push(schemas.next().table_walker_at(*table_index).name().to_owned());
+            }
+            SqlMigrationStep::DropTable(DropTable { table_index }) => {
+                report
+                    .removed_tables
+                    .push(schemas.previous().table_walker_at(*table_index).name().to_owned());
+            }
+            SqlMigrationStep::AlterTable(AlterTable { table_index, changes }) => {
+                let tables = schemas.tables(table_index);
+                let drift = table_drift(&mut changed_tables, tables.next().name());
+
+                for change in changes {
+                    match change {
+                        TableChange::AddColumn(AddColumn { column_index }) => drift
+                            .added_columns
+                            .push(drift_column(&tables.next().column_at(*column_index), flavour)),
+                        TableChange::DropColumn(DropColumn { index }) => drift
+                            .removed_columns
+                            .push(drift_column(&tables.previous().column_at(*index), flavour)),
+                        TableChange::AlterColumn(AlterColumn {
+                            column_index, changes, ..
+                        })
+                        | TableChange::DropAndRecreateColumn { column_index, changes } => drift
+                            .changed_columns
+                            .push(changed_column(tables.columns(column_index), changes, flavour)),
+                        TableChange::DropPrimaryKey | TableChange::AddPrimaryKey { .. } => {
+                            drift.primary_key_changed = true
+                        }
+                    }
+                }
+            }
+            SqlMigrationStep::RedefineTables(redefine_tables) => {
+                for redefine_table in redefine_tables {
+                    let tables = schemas.tables(&redefine_table.table_index);
+                    let drift = table_drift(&mut changed_tables, tables.next().name());
+
+                    for column_index in &redefine_table.added_columns {
+                        drift
+                            .added_columns
+                            .push(drift_column(&tables.next().column_at(*column_index), flavour));
+                    }
+
+                    for column_index in &redefine_table.dropped_columns {
+                        drift
+                            .removed_columns
+                            .push(drift_column(&tables.previous().column_at(*column_index), flavour));
+                    }
+
+                    for (column_index, changes, _) in &redefine_table.column_pairs {
+                        if changes.differs_in_something() {
+                            drift
+                                .changed_columns
+                                .push(changed_column(tables.columns(column_index), changes, flavour));
+                        }
+                    }
+
+                    if redefine_table.dropped_primary_key {
+                        drift.primary_key_changed = true;
+                    }
+                }
+            }
+            // Indexes on new tables are part of the new table.
+            SqlMigrationStep::CreateIndex(CreateIndex {
+                caused_by_create_table: true,
+                ..
+            }) => (),
+            SqlMigrationStep::CreateIndex(CreateIndex {
+                table_index,
+                index_index,
+                ..
+            }) => {
+                let table = schemas.next().table_walker_at(*table_index);
+
+                table_drift(&mut changed_tables, table.name())
+                    .added_indexes
+                    .push(table.index_at(*index_index).name().to_owned());
+            }
+            SqlMigrationStep::DropIndex(DropIndex {
+                table_index,
+                index_index,
+            }) => {
+                let table = schemas.previous().table_walker_at(*table_index);
+
+                // Indexes on dropped tables go away with the table.
+                if schemas.next().table_walker(table.name()).is_some() {
+                    table_drift(&mut changed_tables, table.name())
+                        .removed_indexes
+                        .push(table.index_at(*index_index).name().to_owned());
+                }
+            }
+            SqlMigrationStep::AlterIndex { table, index } | SqlMigrationStep::RedefineIndex { table, index } => {
+                let tables = schemas.tables(table);
+
+                table_drift(&mut changed_tables, tables.next().name())
+                    .changed_indexes
+                    .push(tables.indexes(index).next().name().to_owned());
+            }
+            SqlMigrationStep::AddForeignKey(AddForeignKey {
+                table_index,
+                foreign_key_index,
+            }) => {
+                let table = schemas.next().table_walker_at(*table_index);
+
+                // Foreign keys on new tables are part of the new table.
+                if schemas.previous().table_walker(table.name()).is_some() {
+                    table_drift(&mut changed_tables, table.name())
+                        .added_foreign_keys
+                        .push(drift_foreign_key(&table.foreign_key_at(*foreign_key_index)));
+                }
+            }
+            SqlMigrationStep::DropForeignKey(DropForeignKey {
+                table_index,
+                foreign_key_index,
+                ..
+            }) => {
+                let table = schemas.previous().table_walker_at(*table_index);
+
+                if schemas.next().table_walker(table.name()).is_some() {
+                    table_drift(&mut changed_tables, table.name())
+                        .removed_foreign_keys
+                        .push(drift_foreign_key(&table.foreign_key_at(*foreign_key_index)));
+                }
+            }
+            SqlMigrationStep::CreateEnum(CreateEnum { enum_index }) => {
+                report
+                    .added_enums
+                    .push(schemas.next().enum_walker_at(*enum_index).name().to_owned());
+            }
+            SqlMigrationStep::DropEnum(DropEnum { enum_index }) => {
+                report
+                    .removed_enums
+                    .push(schemas.previous().enum_walker_at(*enum_index).name().to_owned());
+            }
+            SqlMigrationStep::AlterEnum(alter_enum) if alter_enum.is_empty() => {
+                // The enum was renamed.
+                let enums = schemas.enums(&alter_enum.index);
+
+                report.removed_enums.push(enums.previous().name().to_owned());
+                report.added_enums.push(enums.next().name().to_owned());
+            }
+            SqlMigrationStep::AlterEnum(AlterEnum {
+                index,
+                created_variants,
+                dropped_variants,
+                renamed_variants,
+            }) => report.changed_enums.push(EnumDrift {
+                name: schemas.enums(index).next().name().to_owned(),
+                added_values: created_variants
+                    .iter()
+                    .chain(renamed_variants.iter().map(|(_, next)| next))
+                    .cloned()
+                    .collect(),
+                removed_values: dropped_variants
+                    .iter()
+                    .chain(renamed_variants.iter().map(|(previous, _)| previous))
+                    .cloned()
+                    .collect(),
+            }),
+        }
+    }
+
+    report.changed_tables = changed_tables.into_iter().map(|(_, drift)| drift).collect();
+
+    report
+}
+
+fn table_drift<'a>(changed_tables: &'a mut BTreeMap<String, TableDrift>, table_name: &str) -> &'a mut TableDrift {
+    changed_tables
+        .entry(table_name.to_owned())
+        .or_insert_with(|| TableDrift {
+            table: table_name.to_owned(),
+            ..Default::default()
+        })
+}
+
+fn drift_column(column: &ColumnWalker<'_>, flavour: &dyn SqlFlavour) -> DriftColumn {
+    DriftColumn {
+        name: column.name().to_owned(),
+        column_type: flavour.render_column_type(column),
+        required: column.arity().is_required(),
+    }
+}
+
+fn changed_column(columns: Pair<ColumnWalker<'_>>, changes: &ColumnChanges, flavour: &dyn SqlFlavour) -> ChangedColumn {
+    ChangedColumn {
+        before: drift_column(columns.previous(), flavour),
+        after: drift_column(columns.next(), flavour),
+        default_changed: changes.default_changed(),
+    }
+}
+
+fn drift_foreign_key(foreign_key: &ForeignKeyWalker<'_>) -> DriftForeignKey {
+    DriftForeignKey {
+        constraint_name: foreign_key.constraint_name().map(String::from),
+        columns: foreign_key.constrained_column_names().to_owned(),
+        referenced_table: foreign_key.inner().referenced_table.clone(),
+        referenced_columns: foreign_key.referenced_column_names().to_owned(),
+    }
+}
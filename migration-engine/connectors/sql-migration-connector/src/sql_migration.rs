@@ -78,7 +78,7 @@ pub(crate) struct AlterTable {
     pub changes: Vec<TableChange>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(crate) enum TableChange {
     AddColumn(AddColumn),
     AlterColumn(AlterColumn),
@@ -104,24 +104,28 @@ impl TableChange {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(crate) struct AddColumn {
     pub column_index: usize,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(crate) struct DropColumn {
     pub index: usize,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(crate) struct AlterColumn {
     pub column_index: Pair<usize>,
     pub changes: ColumnChanges,
     pub type_change: Option<ColumnTypeChange>,
+    /// Values of the enum the column holds that were renamed, as (previous
+    /// value, next value) pairs. Only used on MySQL, where enums are column
+    /// types.
+    pub renamed_enum_values: Vec<(String, String)>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(crate) enum ColumnTypeChange {
     RiskyCast,
     SafeCast,
@@ -172,11 +176,20 @@ pub(crate) struct AlterEnum {
     pub index: Pair<usize>,
     pub created_variants: Vec<String>,
     pub dropped_variants: Vec<String>,
+    /// Values that were renamed, as (previous value, next value) pairs. The
+    /// existing rows are migrated from the previous to the next value.
+    pub renamed_variants: Vec<(String, String)>,
 }
 
 impl AlterEnum {
+    /// Whether the enum values are unchanged. The enum can still be renamed.
     pub(crate) fn is_empty(&self) -> bool {
-        self.created_variants.is_empty() && self.dropped_variants.is_empty()
+        self.created_variants.is_empty() && self.dropped_variants.is_empty() && self.renamed_variants.is_empty()
+    }
+
+    /// Whether values are only added, so the existing rows don't need to be migrated.
+    pub(crate) fn only_adds_variants(&self) -> bool {
+        !self.created_variants.is_empty() && self.dropped_variants.is_empty() && self.renamed_variants.is_empty()
     }
 }
 
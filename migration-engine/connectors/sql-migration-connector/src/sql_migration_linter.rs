@@ -0,0 +1,343 @@
+//! The SQL implementation of MigrationLinter. Where the destructive change
+//! checker is concerned with data loss, the linter flags DDL that is safe for
+//! the data, but takes heavy locks on existing tables for a long time, which
+//! causes outages on large tables.
+//!
+//! Generated migrations are linted on their `SqlMigrationStep`s. Existing
+//! migration scripts are linted statement by statement, recognizing the DDL
+//! rendered by the engine and the usual Postgres syntax.
+
+use crate::{
+    sql_migration::{AddForeignKey, AlterColumn, AlterTable, CreateIndex, SqlMigrationStep, TableChange},
+    sql_script::split_statements,
+    SqlMigration, SqlMigrationConnector,
+};
+use migration_connector::{LintDiagnostic, LintRules, LintSeverity, MigrationLinter};
+use once_cell::sync::Lazy;
+use regex::{Regex, RegexBuilder};
+use sql_schema_describer::{walkers::SqlSchemaExt, DefaultKind};
+use std::collections::HashSet;
+
+/// A lint rule, identified by its name in the lint configuration.
+struct LintRule {
+    name: &'static str,
+    default_severity: LintSeverity,
+}
+
+const VOLATILE_DEFAULT: LintRule = LintRule {
+    name: "add-required-column-with-volatile-default",
+    default_severity: LintSeverity::Warn,
+};
+
+const NON_CONCURRENT_INDEX: LintRule = LintRule {
+    name: "create-index-non-concurrently",
+    default_severity: LintSeverity::Warn,
+};
+
+const COLUMN_TYPE_CHANGE: LintRule = LintRule {
+    name: "change-column-type",
+    default_severity: LintSeverity::Warn,
+};
+
+const INLINE_FOREIGN_KEY_VALIDATION: LintRule = LintRule {
+    name: "add-foreign-key-validated-inline",
+    default_severity: LintSeverity::Warn,
+};
+
+const RULE_NAMES: &[&str] = &[
+    VOLATILE_DEFAULT.name,
+    NON_CONCURRENT_INDEX.name,
+    COLUMN_TYPE_CHANGE.name,
+    INLINE_FOREIGN_KEY_VALIDATION.name,
+];
+
+impl MigrationLinter<SqlMigration> for SqlMigrationConnector {
+    fn lint(&self, database_migration: &SqlMigration, rules: &LintRules) -> Vec<LintDiagnostic> {
+        let mut lints = Lints::new(rules);
+
+        // SQLite has a single writer, there is nobody to lock out.
+        if self.flavour().sql_family().is_sqlite() {
+            return lints.diagnostics;
+        }
+
+        // Concurrent index creation and `NOT VALID` foreign keys only exist on PostgreSQL.
+        let is_postgres = self.flavour().sql_family().is_postgres();
+        let schemas = database_migration.schemas();
+
+        for (step_index, step) in database_migration.steps.iter().enumerate() {
+            match step {
+                SqlMigrationStep::AlterTable(AlterTable { table_index, changes }) => {
+                    let tables = schemas.tables(table_index);
+
+                    for change in changes {
+                        match change {
+                            TableChange::AddColumn(add_column) => {
+                                let column = tables.next().column_at(add_column.column_index);
+                                let has_volatile_default = column
+                                    .default()
+                                    .map(|default| match default.kind() {
+                                        DefaultKind::DBGENERATED(expression) => is_volatile_default(expression),
+                                        DefaultKind::SEQUENCE(_) => true,
+                                        _ => false,
+                                    })
+                                    .unwrap_or(false);
+
+                                if column.arity().is_required() && has_volatile_default {
+                                    lints.report(&VOLATILE_DEFAULT, step_index, || {
+                                        volatile_default_description(tables.next().name(), column.name())
+                                    });
+                                }
+                            }
+                            TableChange::AlterColumn(AlterColumn {
+                                column_index,
+                                type_change: Some(_),
+                                ..
+                            }) => {
+                                let columns = tables.columns(column_index);
+
+                                lints.report(&COLUMN_TYPE_CHANGE, step_index, || {
+                                    column_type_change_description(tables.next().name(), columns.next().name())
+                                });
+                            }
+                            _ => (),
+                        }
+                    }
+                }
+                SqlMigrationStep::CreateIndex(CreateIndex {
+                    table_index,
+                    index_index,
+                    caused_by_create_table: false,
+                }) if is_postgres && !self.flavour().is_non_transactional_step(step) => {
+                    let table = schemas.next().table_walker_at(*table_index);
+                    let index = table.index_at(*index_index);
+
+                    lints.report(&NON_CONCURRENT_INDEX, step_index, || {
+                        non_concurrent_index_description(table.name(), index.name())
+                    });
+                }
+                SqlMigrationStep::AddForeignKey(AddForeignKey {
+                    table_index,
+                    foreign_key_index,
+                }) if is_postgres => {
+                    let table = schemas.next().table_walker_at(*table_index);
+
+                    // Foreign keys on tables created in the same migration have no rows to validate.
+                    if schemas.previous().table_walker(table.name()).is_none() {
+                        continue;
+                    }
+
+                    let foreign_key = table.foreign_key_at(*foreign_key_index);
+
+                    lints.report(&INLINE_FOREIGN_KEY_VALIDATION, step_index, || {
+                        inline_foreign_key_description(table.name(), foreign_key.referenced_table().name())
+                    });
+                }
+                _ => (),
+            }
+        }
+
+        lints.diagnostics
+    }
+
+    fn lint_script(&self, script: &str, rules: &LintRules) -> Vec<LintDiagnostic> {
+        static CREATE_TABLE_RE: Lazy<Regex> =
+            Lazy::new(|| case_insensitive(r#"^CREATE\s+TABLE\s+(?:IF\s+NOT\s+EXISTS\s+)?([^\s(]+)"#));
+        static CREATE_INDEX_RE: Lazy<Regex> = Lazy::new(|| {
+            case_insensitive(
+                r#"^CREATE\s+(?:UNIQUE\s+)?INDEX\s+(CONCURRENTLY\s+)?(?:IF\s+NOT\s+EXISTS\s+)?(\S+)\s+ON\s+(?:ONLY\s+)?([^\s(]+)"#,
+            )
+        });
+        static ALTER_TABLE_RE: Lazy<Regex> = Lazy::new(|| case_insensitive(r#"^ALTER\s+TABLE\s+(?:ONLY\s+)?(\S+)"#));
+        static ADD_COLUMN_RE: Lazy<Regex> =
+            Lazy::new(|| case_insensitive(r#"ADD\s+COLUMN\s+(?:IF\s+NOT\s+EXISTS\s+)?("[^"]+"|\S+)([^,]*)"#));
+        static REQUIRED_RE: Lazy<Regex> = Lazy::new(|| case_insensitive(r#"\bNOT\s+NULL\b"#));
+        static DEFAULT_RE: Lazy<Regex> = Lazy::new(|| case_insensitive(r#"\bDEFAULT\s+(.*)"#));
+        static SERIAL_RE: Lazy<Regex> = Lazy::new(|| case_insensitive(r#"\b(?:SMALL|BIG)?SERIAL\b"#));
+        static ALTER_COLUMN_TYPE_RE: Lazy<Regex> =
+            Lazy::new(|| case_insensitive(r#"ALTER\s+COLUMN\s+("[^"]+"|\S+)\s+(?:SET\s+DATA\s+)?TYPE\b"#));
+        static ADD_FOREIGN_KEY_RE: Lazy<Regex> = Lazy::new(|| {
+            case_insensitive(r#"ADD\s+(?:CONSTRAINT\s+\S+\s+)?FOREIGN\s+KEY\s*\([^)]*\)\s*REFERENCES\s+([^\s(]+)"#)
+        });
+        static NOT_VALID_RE: Lazy<Regex> = Lazy::new(|| case_insensitive(r#"\bNOT\s+VALID\b"#));
+
+        let mut lints = Lints::new(rules);
+        let is_postgres = self.flavour().sql_family().is_postgres();
+        let statements = split_statements(script);
+
+        let created_tables: HashSet<String> = statements
+            .iter()
+            .filter_map(|statement| CREATE_TABLE_RE.captures(statement))
+            .map(|captures| unquote(&captures[1]))
+            .collect();
+
+        for (statement_index, statement) in statements.iter().enumerate() {
+            if let Some(captures) = CREATE_INDEX_RE.captures(statement) {
+                let table_name = unquote(&captures[3]);
+
+                if is_postgres && captures.get(1).is_none() && !created_tables.contains(&table_name) {
+                    lints.report(&NON_CONCURRENT_INDEX, statement_index, || {
+                        non_concurrent_index_description(&table_name, &unquote(&captures[2]))
+                    });
+                }
+
+                continue;
+            }
+
+            let table_name = match ALTER_TABLE_RE.captures(statement) {
+                Some(captures) => unquote(&captures[1]),
+                None => continue,
+            };
+
+            if created_tables.contains(&table_name) {
+                continue;
+            }
+
+            for captures in ADD_COLUMN_RE.captures_iter(statement) {
+                let definition = &captures[2];
+                let has_volatile_default = DEFAULT_RE
+                    .captures(definition)
+                    .map(|default| is_volatile_default(&default[1]))
+                    .unwrap_or(false);
+
+                if (REQUIRED_RE.is_match(definition) && has_volatile_default) || SERIAL_RE.is_match(definition) {
+                    lints.report(&VOLATILE_DEFAULT, statement_index, || {
+                        volatile_default_description(&table_name, &unquote(&captures[1]))
+                    });
+                }
+            }
+
+            for captures in ALTER_COLUMN_TYPE_RE.captures_iter(statement) {
+                lints.report(&COLUMN_TYPE_CHANGE, statement_index, || {
+                    column_type_change_description(&table_name, &unquote(&captures[1]))
+                });
+            }
+
+            if is_postgres && !NOT_VALID_RE.is_match(statement) {
+                for captures in ADD_FOREIGN_KEY_RE.captures_iter(statement) {
+                    lints.report(&INLINE_FOREIGN_KEY_VALIDATION, statement_index, || {
+                        inline_foreign_key_description(&table_name, &unquote(&captures[1]))
+                    });
+                }
+            }
+        }
+
+        lints.diagnostics
+    }
+
+    fn rule_names(&self) -> &'static [&'static str] {
+        RULE_NAMES
+    }
+}
+
+/// Whether a column default is a function call the database has to evaluate
+/// for every existing row, rewriting the table. The current timestamp is
+/// evaluated once per statement.
+fn is_volatile_default(expression: &str) -> bool {
+    static FUNCTION_CALL_RE: Lazy<Regex> = Lazy::new(|| case_insensitive(r#"^\s*[\w.]+\s*\("#));
+    static NOW_RE: Lazy<Regex> = Lazy::new(|| case_insensitive(r#"^\s*(?:now|CURRENT_TIMESTAMP)\s*\("#));
+
+    FUNCTION_CALL_RE.is_match(expression) && !NOW_RE.is_match(expression)
+}
+
+/// The diagnostics for a migration, reported with the configured severities.
+struct Lints<'a> {
+    rules: &'a LintRules,
+    diagnostics: Vec<LintDiagnostic>,
+}
+
+impl<'a> Lints<'a> {
+    fn new(rules: &'a LintRules) -> Self {
+        Lints {
+            rules,
+            diagnostics: Vec::new(),
+        }
+    }
+
+    fn report(&mut self, rule: &LintRule, step_index: usize, description: impl FnOnce() -> String) {
+        let severity = self.rules.severity(rule.name, rule.default_severity);
+
+        if severity == LintSeverity::Off {
+            return;
+        }
+
+        self.diagnostics.push(LintDiagnostic {
+            rule: rule.name.to_owned(),
+            severity,
+            description: description(),
+            step_index,
+        })
+    }
+}
+
+fn volatile_default_description(table: &str, column: &str) -> String {
+    format!(
+        "Adding the required column `{}` with a volatile default to the `{}` table rewrites the whole table while holding an exclusive lock. Add the column as optional, backfill it, then make it required.",
+        column, table
+    )
+}
+
+fn non_concurrent_index_description(table: &str, index: &str) -> String {
+    format!(
+        "Creating the index `{}` on the `{}` table blocks writes to the table until the index is built. Create it concurrently instead.",
+        index, table
+    )
+}
+
+fn column_type_change_description(table: &str, column: &str) -> String {
+    format!(
+        "Changing the type of the `{}` column on the `{}` table rewrites the whole table while holding an exclusive lock.",
+        column, table
+    )
+}
+
+fn inline_foreign_key_description(table: &str, referenced_table: &str) -> String {
+    format!(
+        "Adding a foreign key from the `{}` table to the `{}` table validates all existing rows while holding a lock on both tables. Add the foreign key as `NOT VALID` and validate it in a separate step.",
+        table, referenced_table
+    )
+}
+
+pub(crate) fn case_insensitive(pattern: &str) -> Regex {
+    RegexBuilder::new(pattern).case_insensitive(true).build().unwrap()
+}
+
+/// Strip the schema and the identifier quotes from a table, column or index name.
+fn unquote(identifier: &str) -> String {
+    let opening_quote = match identifier.chars().last() {
+        Some('"') => '"',
+        Some('`') => '`',
+        Some(']') => '[',
+        _ => return identifier.rsplit('.').next().unwrap_or(identifier).to_owned(),
+    };
+
+    let quoted = &identifier[..identifier.len() - 1];
+
+    match quoted.rfind(opening_quote) {
+        Some(start) => quoted[start + 1..].to_owned(),
+        None => quoted.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unquote_strips_schemas_and_quotes() {
+        assert_eq!(unquote(r#""public"."Cat""#), "Cat");
+        assert_eq!(unquote("`Cat`"), "Cat");
+        assert_eq!(unquote("[dbo].[Cat]"), "Cat");
+        assert_eq!(unquote(r#""Cat.name_index""#), "Cat.name_index");
+        assert_eq!(unquote("public.Cat"), "Cat");
+    }
+
+    #[test]
+    fn is_volatile_default_exempts_the_current_timestamp() {
+        assert!(is_volatile_default("gen_random_uuid()"));
+        assert!(is_volatile_default("public.next_id()"));
+        assert!(!is_volatile_default("now()"));
+        assert!(!is_volatile_default("CURRENT_TIMESTAMP(3) NOT NULL"));
+        assert!(!is_volatile_default("'draft'"));
+        assert!(!is_volatile_default("42"));
+    }
+}
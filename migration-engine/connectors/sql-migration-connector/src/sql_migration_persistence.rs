@@ -19,11 +19,17 @@ impl MigrationPersistence for SqlMigrationConnector {
     async fn initialize(&self) -> ConnectorResult<()> {
         let schema = self.describe_schema().await?;
 
-        if schema
-            .tables
-            .iter()
-            .any(|table| table.name == self.flavour().imperative_migrations_table_name())
-        {
+        let migrations_table_exists = if !self.flavour().migrations_table_is_in_connection_schema() {
+            // The table is not in the described schema.
+            self.list_migrations().await?.is_ok()
+        } else {
+            schema
+                .tables
+                .iter()
+                .any(|table| table.name == self.flavour().imperative_migrations_table_name())
+        };
+
+        if migrations_table_exists {
             return Ok(());
         }
 
@@ -15,10 +15,11 @@ mod postgres_renderer;
 mod sqlite_renderer;
 
 pub(crate) use common::IteratorJoin;
+pub(crate) use mssql_renderer::escape_string_literal as escape_mssql_string_literal;
 
 use crate::{
     pair::Pair,
-    sql_migration::{AlterEnum, AlterTable, RedefineTable},
+    sql_migration::{AlterEnum, AlterTable, RedefineTable, SqlMigrationStep},
 };
 use common::Quoted;
 use sql_schema_describer::{
@@ -33,12 +34,21 @@ use std::borrow::Cow;
 pub(crate) trait SqlRenderer {
     fn quote<'a>(&self, name: &'a str) -> Quoted<&'a str>;
 
+    /// Whether the step is rendered to statements that can't run inside a
+    /// transaction. Migrations containing such steps are non-transactional.
+    fn is_non_transactional_step(&self, _step: &SqlMigrationStep) -> bool {
+        false
+    }
+
     fn render_add_foreign_key(&self, foreign_key: &ForeignKeyWalker<'_>) -> String;
 
     fn render_alter_enum(&self, alter_enum: &AlterEnum, schemas: &Pair<&SqlSchema>) -> Vec<String>;
 
     fn render_column(&self, column: &ColumnWalker<'_>) -> String;
 
+    /// Render the type of a column, as it appears in the column definition.
+    fn render_column_type(&self, column: &ColumnWalker<'_>) -> String;
+
     fn render_references(&self, foreign_key: &ForeignKeyWalker<'_>) -> String;
 
     fn render_default<'a>(&self, default: &'a DefaultValue, family: &ColumnTypeFamily) -> Cow<'a, str>;
@@ -54,6 +64,13 @@ pub(crate) trait SqlRenderer {
 
     fn render_create_index(&self, index: &IndexWalker<'_>) -> String;
 
+    /// Render a `CreateIndex` step that builds the index without blocking
+    /// writes to the table. Only called for steps where
+    /// `is_non_transactional_step` is true.
+    fn render_create_index_concurrently(&self, _index: &IndexWalker<'_>) -> String {
+        unreachable!("unreachable render_create_index_concurrently")
+    }
+
     /// Render a table creation step.
     fn render_create_table(&self, table: &TableWalker<'_>) -> String {
         self.render_create_table_as(table, table.name())
@@ -75,6 +92,12 @@ pub(crate) trait SqlRenderer {
     /// Render a `DropIndex` step.
     fn render_drop_index(&self, index: &IndexWalker<'_>) -> String;
 
+    /// Render a `DropIndex` step that does not block writes to the table. Only
+    /// called for steps where `is_non_transactional_step` is true.
+    fn render_drop_index_concurrently(&self, _index: &IndexWalker<'_>) -> String {
+        unreachable!("unreachable render_drop_index_concurrently")
+    }
+
     /// Render a `DropTable` step.
     fn render_drop_table(&self, table_name: &str) -> Vec<String> {
         vec![format!("DROP TABLE {}", self.quote(&table_name))]
@@ -85,4 +108,47 @@ pub(crate) trait SqlRenderer {
 
     /// Render a table renaming step.
     fn render_rename_table(&self, name: &str, new_name: &str) -> String;
+
+    /// Make the statements rendered for a step skip themselves when `guard`
+    /// says the step was already applied, for idempotent scripts. Guards the
+    /// database can't express are ignored, and the statements are returned
+    /// unchanged.
+    fn render_guarded(&self, statements: Vec<String>, guard: Guard<'_>) -> Vec<String>;
+}
+
+/// The state of the database in which the statements for a step must run in
+/// idempotent scripts. When the database is not in that state, the step was
+/// already applied, and the statements are skipped.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Guard<'a> {
+    TableMissing { table: &'a str },
+    TableExists { table: &'a str },
+    ColumnMissing { table: &'a str, column: &'a str },
+    ColumnExists { table: &'a str, column: &'a str },
+    IndexMissing { table: &'a str, index: &'a str },
+    IndexExists { table: &'a str, index: &'a str },
+    ForeignKeyMissing { table: &'a str, constraint_name: &'a str },
+    ForeignKeyExists { table: &'a str, constraint_name: &'a str },
+    EnumMissing { name: &'a str },
+    EnumExists { name: &'a str },
+    // Values are added to the enum, and some of them are missing.
+    EnumValuesMissing { name: &'a str },
+    // The enum still has some of the values the step removes or renames.
+    EnumValuesExist { name: &'a str, values: &'a [String] },
+}
+
+impl Guard<'_> {
+    /// Whether the statements run when the guarded object exists, rather
+    /// than when it is missing.
+    pub(crate) fn runs_on_existing_object(&self) -> bool {
+        matches!(
+            self,
+            Guard::TableExists { .. }
+                | Guard::ColumnExists { .. }
+                | Guard::IndexExists { .. }
+                | Guard::ForeignKeyExists { .. }
+                | Guard::EnumExists { .. }
+                | Guard::EnumValuesExist { .. }
+        )
+    }
 }
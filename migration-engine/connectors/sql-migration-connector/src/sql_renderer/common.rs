@@ -44,10 +44,11 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Quoted::Double(inner) => write!(f, "\"{}\"", inner),
+            // The closing quote is escaped by doubling it in identifiers.
+            Quoted::Double(inner) => write!(f, "\"{}\"", inner.to_string().replace('"', "\"\"")),
             Quoted::Single(inner) => write!(f, "'{}'", inner),
-            Quoted::Backticks(inner) => write!(f, "`{}`", inner),
-            Quoted::SquareBrackets(inner) => write!(f, "[{}]", inner),
+            Quoted::Backticks(inner) => write!(f, "`{}`", inner.to_string().replace('`', "``")),
+            Quoted::SquareBrackets(inner) => write!(f, "[{}]", inner.to_string().replace(']', "]]")),
         }
     }
 }
@@ -82,6 +83,20 @@ pub(crate) fn format_hex(bytes: &[u8]) -> String {
     out
 }
 
+/// Insert `clause` after the first of the `markers` found in the statement,
+/// e.g. to turn `CREATE TABLE` into `CREATE TABLE IF NOT EXISTS`. The
+/// statement is returned unchanged if it contains none of the markers.
+pub(crate) fn insert_after_marker(statement: &str, markers: &[&str], clause: &str) -> String {
+    let position = markers
+        .iter()
+        .find_map(|marker| statement.find(marker).map(|position| position + marker.len()));
+
+    match position {
+        Some(position) => format!("{}{}{}", &statement[..position], clause, &statement[position..]),
+        None => statement.to_owned(),
+    }
+}
+
 pub(crate) trait IteratorJoin {
     fn join(self, sep: &str) -> String;
 }
@@ -1,7 +1,7 @@
 mod alter_table;
 
 use super::common::render_on_delete;
-use super::{common, IteratorJoin, Quoted, SqlRenderer};
+use super::{common, Guard, IteratorJoin, Quoted, SqlRenderer};
 use crate::{
     flavour::MssqlFlavour,
     pair::Pair,
@@ -59,6 +59,16 @@ impl SqlRenderer for MssqlFlavour {
     fn render_column(&self, column: &ColumnWalker<'_>) -> String {
         let column_name = self.quote(column.name());
 
+        // Computed columns take their type from the expression.
+        if let Some(expression) = column.generation_expression() {
+            return format!(
+                "{} AS ({}) PERSISTED{}",
+                column_name,
+                expression,
+                common::render_nullability(column)
+            );
+        }
+
         let r#type = render_column_type(column);
         let nullability = common::render_nullability(&column);
 
@@ -82,6 +92,10 @@ impl SqlRenderer for MssqlFlavour {
         format!("{} {}{}{}", column_name, r#type, nullability, default)
     }
 
+    fn render_column_type(&self, column: &ColumnWalker<'_>) -> String {
+        render_column_type(column).into_owned()
+    }
+
     fn render_references(&self, foreign_key: &ForeignKeyWalker<'_>) -> String {
         let cols = foreign_key
             .referenced_column_names()
@@ -109,7 +123,9 @@ impl SqlRenderer for MssqlFlavour {
             }
             (DefaultKind::NOW, ColumnTypeFamily::DateTime) => "CURRENT_TIMESTAMP".into(),
             (DefaultKind::NOW, _) => unreachable!("NOW default on non-datetime column"),
-            (DefaultKind::VALUE(val), ColumnTypeFamily::DateTime) => format!("'{}'", val).into(),
+            (DefaultKind::VALUE(val), ColumnTypeFamily::DateTime)
+            | (DefaultKind::VALUE(val), ColumnTypeFamily::Date)
+            | (DefaultKind::VALUE(val), ColumnTypeFamily::Time) => format!("'{}'", val).into(),
             (DefaultKind::VALUE(PrismaValue::String(val)), ColumnTypeFamily::Json) => format!("'{}'", val).into(),
             (DefaultKind::VALUE(PrismaValue::Boolean(val)), ColumnTypeFamily::Boolean) => {
                 Cow::from(if *val { "1" } else { "0" })
@@ -405,6 +421,59 @@ impl SqlRenderer for MssqlFlavour {
     fn render_drop_table(&self, table_name: &str) -> Vec<String> {
         vec![format!("DROP TABLE {}", self.quote_with_schema(&table_name))]
     }
+
+    fn render_guarded(&self, statements: Vec<String>, guard: Guard<'_>) -> Vec<String> {
+        let object_id = |name: &str, object_type: &str| {
+            format!(
+                "OBJECT_ID({}, {})",
+                render_string_literal(&self.quote_with_schema(name).to_string()),
+                render_string_literal(object_type)
+            )
+        };
+
+        let condition = match guard {
+            Guard::TableMissing { table } => format!("{} IS NULL", object_id(table, "U")),
+            Guard::TableExists { table } => format!("{} IS NOT NULL", object_id(table, "U")),
+            Guard::ColumnMissing { table, column } | Guard::ColumnExists { table, column } => format!(
+                "COL_LENGTH({}, {}) IS {}NULL",
+                render_string_literal(&self.quote_with_schema(table).to_string()),
+                render_string_literal(column),
+                if guard.runs_on_existing_object() { "NOT " } else { "" },
+            ),
+            Guard::IndexMissing { table, index } | Guard::IndexExists { table, index } => format!(
+                "{}EXISTS (SELECT * FROM sys.indexes WHERE name = {} AND object_id = {})",
+                if guard.runs_on_existing_object() { "" } else { "NOT " },
+                render_string_literal(&index.replace('.', "_")),
+                object_id(table, "U"),
+            ),
+            Guard::ForeignKeyMissing { constraint_name, .. } => {
+                format!("{} IS NULL", object_id(constraint_name, "F"))
+            }
+            Guard::ForeignKeyExists { constraint_name, .. } => {
+                format!("{} IS NOT NULL", object_id(constraint_name, "F"))
+            }
+            Guard::EnumMissing { .. }
+            | Guard::EnumExists { .. }
+            | Guard::EnumValuesMissing { .. }
+            | Guard::EnumValuesExist { .. } => return statements,
+        };
+
+        let mut block = format!("IF {}\nBEGIN\n", condition);
+
+        for statement in statements {
+            block.push_str(common::SQL_INDENTATION);
+            block.push_str(&statement);
+            block.push_str(";\n");
+        }
+
+        block.push_str("END");
+
+        vec![block]
+    }
+}
+
+fn render_string_literal(s: &str) -> String {
+    format!("N'{}'", s.replace('\'', "''"))
 }
 
 fn render_column_type(column: &ColumnWalker<'_>) -> Cow<'static, str> {
@@ -463,6 +532,6 @@ fn render_column_type(column: &ColumnWalker<'_>) -> Cow<'static, str> {
     }
 }
 
-fn escape_string_literal(s: &str) -> String {
+pub(crate) fn escape_string_literal(s: &str) -> String {
     s.replace('\'', r#"''"#)
 }
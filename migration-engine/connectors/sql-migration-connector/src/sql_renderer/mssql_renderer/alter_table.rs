@@ -66,6 +66,7 @@ impl<'a> AlterTableConstructor<'a> {
                     column_index,
                     changes,
                     type_change: _,
+                    renamed_enum_values: _,
                 }) => {
                     self.alter_column(*column_index, &changes);
                 }
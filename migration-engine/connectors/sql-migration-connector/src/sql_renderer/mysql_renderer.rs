@@ -1,7 +1,7 @@
 use super::{
     common::SQL_INDENTATION,
-    common::{render_nullability, render_on_delete, Quoted},
-    IteratorJoin, SqlRenderer,
+    common::{insert_after_marker, render_nullability, render_on_delete, Quoted},
+    Guard, IteratorJoin, SqlRenderer,
 };
 use crate::{
     flavour::{MysqlFlavour, SqlFlavour, MYSQL_IDENTIFIER_SIZE_LIMIT},
@@ -82,6 +82,8 @@ impl SqlRenderer for MysqlFlavour {
         let tables = schemas.tables(table_index);
 
         let mut lines = Vec::new();
+        // Statements that have to run before the table is altered.
+        let mut before_statements = Vec::new();
 
         for change in changes {
             match change {
@@ -106,10 +108,13 @@ impl SqlRenderer for MysqlFlavour {
                     changes,
                     column_index,
                     type_change: _,
+                    renamed_enum_values,
                 }) => {
                     let columns = tables.columns(column_index);
                     let expanded = MysqlAlterColumn::new(&columns, &changes);
 
+                    before_statements.extend(render_enum_value_renames(&columns, renamed_enum_values));
+
                     match expanded {
                         MysqlAlterColumn::DropDefault => lines.push(format!(
                             "ALTER COLUMN {column} DROP DEFAULT",
@@ -135,22 +140,29 @@ impl SqlRenderer for MysqlFlavour {
         }
 
         if lines.is_empty() {
-            return Vec::new();
+            return before_statements;
         }
 
-        vec![format!(
+        before_statements.push(format!(
             "ALTER TABLE {} {}",
             self.quote(tables.previous().name()),
             lines.join(",\n    ")
-        )]
+        ));
+
+        before_statements
     }
 
     fn render_column(&self, column: &ColumnWalker<'_>) -> String {
         let column_name = self.quote(column.name());
         let tpe_str = render_column_type(&column);
         let nullability_str = render_nullability(&column);
+        let generated_str = column
+            .generation_expression()
+            .map(|expression| format!(" GENERATED ALWAYS AS ({}) STORED", expression))
+            .unwrap_or_else(String::new);
         let default_str = column
             .default()
+            .filter(|_| column.generation_expression().is_none())
             .filter(|default| {
                 !matches!(default.kind(),  DefaultKind::SEQUENCE(_))
                     // We do not want to render JSON defaults because they are not supported by MySQL.
@@ -174,16 +186,20 @@ impl SqlRenderer for MysqlFlavour {
 
         match foreign_key {
             Some(_) => format!(
-                "{}{} {}{}{}",
-                SQL_INDENTATION, column_name, tpe_str, nullability_str, default_str
+                "{}{} {}{}{}{}",
+                SQL_INDENTATION, column_name, tpe_str, generated_str, nullability_str, default_str
             ),
             None => format!(
-                "{}{} {}{}{}{}",
-                SQL_INDENTATION, column_name, tpe_str, nullability_str, default_str, auto_increment_str
+                "{}{} {}{}{}{}{}",
+                SQL_INDENTATION, column_name, tpe_str, generated_str, nullability_str, default_str, auto_increment_str
             ),
         }
     }
 
+    fn render_column_type(&self, column: &ColumnWalker<'_>) -> String {
+        render_column_type(column).into_owned()
+    }
+
     fn render_references(&self, foreign_key: &ForeignKeyWalker<'_>) -> String {
         let referenced_columns = foreign_key
             .referenced_column_names()
@@ -208,7 +224,9 @@ impl SqlRenderer for MysqlFlavour {
             }
             (DefaultKind::NOW, ColumnTypeFamily::DateTime) => "CURRENT_TIMESTAMP(3)".into(),
             (DefaultKind::NOW, _) => unreachable!("NOW default on non-datetime column"),
-            (DefaultKind::VALUE(val), ColumnTypeFamily::DateTime) => format!("'{}'", val).into(),
+            (DefaultKind::VALUE(val), ColumnTypeFamily::DateTime)
+            | (DefaultKind::VALUE(val), ColumnTypeFamily::Date)
+            | (DefaultKind::VALUE(val), ColumnTypeFamily::Time) => format!("'{}'", val).into(),
             (DefaultKind::VALUE(val), _) => format!("{}", val).into(),
             (DefaultKind::SEQUENCE(_), _) => "".into(),
         }
@@ -339,6 +357,66 @@ impl SqlRenderer for MysqlFlavour {
     fn render_create_table(&self, table: &TableWalker<'_>) -> String {
         self.render_create_table_as(table, table.name())
     }
+
+    fn render_guarded(&self, statements: Vec<String>, guard: Guard<'_>) -> Vec<String> {
+        let query = match guard {
+            Guard::TableMissing { .. } => {
+                return statements
+                    .iter()
+                    .map(|statement| insert_after_marker(statement, &["CREATE TABLE "], "IF NOT EXISTS "))
+                    .collect()
+            }
+            Guard::TableExists { .. } => {
+                return statements
+                    .iter()
+                    .map(|statement| insert_after_marker(statement, &["DROP TABLE "], "IF EXISTS "))
+                    .collect()
+            }
+            Guard::ColumnMissing { table, column } | Guard::ColumnExists { table, column } => format!(
+                "SELECT COUNT(*) FROM information_schema.columns \
+                 WHERE table_schema = DATABASE() AND table_name = {} AND column_name = {}",
+                render_string_literal(table),
+                render_string_literal(column),
+            ),
+            Guard::IndexMissing { table, index } | Guard::IndexExists { table, index } => format!(
+                "SELECT COUNT(DISTINCT index_name) FROM information_schema.statistics \
+                 WHERE table_schema = DATABASE() AND table_name = {} AND index_name = {}",
+                render_string_literal(table),
+                render_string_literal(&index[..index.len().min(MYSQL_IDENTIFIER_SIZE_LIMIT)]),
+            ),
+            Guard::ForeignKeyMissing { table, constraint_name }
+            | Guard::ForeignKeyExists { table, constraint_name } => format!(
+                "SELECT COUNT(*) FROM information_schema.table_constraints \
+                 WHERE constraint_schema = DATABASE() AND table_name = {} AND constraint_name = {} \
+                 AND constraint_type = 'FOREIGN KEY'",
+                render_string_literal(table),
+                render_string_literal(constraint_name),
+            ),
+            // Enums are defined on the columns that use them.
+            Guard::EnumMissing { .. }
+            | Guard::EnumExists { .. }
+            | Guard::EnumValuesMissing { .. }
+            | Guard::EnumValuesExist { .. } => return statements,
+        };
+
+        // MySQL has no IF NOT EXISTS for most of these statements, and no
+        // control flow outside of stored programs. We pick the statement to
+        // run with a condition instead, and run it as a prepared statement.
+        let comparison = if guard.runs_on_existing_object() { "> 0" } else { "= 0" };
+        let mut guarded = vec![format!("SET @prisma_guard = ({}) {}", query, comparison)];
+
+        for statement in statements {
+            guarded.push(format!(
+                "SET @prisma_stmt = IF(@prisma_guard, {}, 'SELECT 1')",
+                render_string_literal(&statement)
+            ));
+            guarded.push("PREPARE prisma_stmt FROM @prisma_stmt".to_owned());
+            guarded.push("EXECUTE prisma_stmt".to_owned());
+            guarded.push("DEALLOCATE PREPARE prisma_stmt".to_owned());
+        }
+
+        guarded
+    }
 }
 
 fn render_mysql_modify(
@@ -453,6 +531,51 @@ fn render_column_type(column: &ColumnWalker<'_>) -> Cow<'static, str> {
     }
 }
 
+/// MySQL enums are column types, so renamed enum values are migrated column by
+/// column: the column type is first widened to hold both the previous and the
+/// next values, the rows are updated, and the final `MODIFY` narrows the type.
+fn render_enum_value_renames(columns: &Pair<ColumnWalker<'_>>, renamed_values: &[(String, String)]) -> Vec<String> {
+    let previous_enum = match columns.previous().column_type_family_as_enum() {
+        Some(previous_enum) if !renamed_values.is_empty() => previous_enum,
+        _ => return Vec::new(),
+    };
+
+    let table_name = Quoted::mysql_ident(columns.previous().table().name());
+    let column_name = Quoted::mysql_ident(columns.previous().name());
+
+    let widened_variants = previous_enum
+        .values
+        .iter()
+        .chain(renamed_values.iter().map(|(_, next)| next))
+        .map(|value| format!("'{}'", escape_string_literal(value)))
+        .join(", ");
+
+    let mut statements = vec![format!(
+        "ALTER TABLE {table} MODIFY {column} ENUM({variants}){nullability}",
+        table = table_name,
+        column = column_name,
+        variants = widened_variants,
+        nullability = render_nullability(columns.previous()),
+    )];
+
+    for (previous, next) in renamed_values {
+        statements.push(format!(
+            "UPDATE {table} SET {column} = '{next}' WHERE {column} = '{previous}'",
+            table = table_name,
+            column = column_name,
+            next = escape_string_literal(next),
+            previous = escape_string_literal(previous),
+        ));
+    }
+
+    statements
+}
+
+/// Render a string literal, escaping quotes and backslashes.
+fn render_string_literal(s: &str) -> String {
+    format!("'{}'", escape_string_literal(&s.replace('\\', "\\\\")))
+}
+
 fn escape_string_literal(s: &str) -> Cow<'_, str> {
     static STRING_LITERAL_CHARACTER_TO_ESCAPE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"'"#).unwrap());
 
@@ -1,10 +1,14 @@
-use super::{common::*, SqlRenderer};
+use super::{common::*, Guard, SqlRenderer};
 use crate::{
     flavour::PostgresFlavour,
     pair::Pair,
-    sql_migration::{AddColumn, AlterColumn, AlterEnum, AlterTable, DropColumn, RedefineTable, TableChange},
+    sql_migration::{
+        AddColumn, AlterColumn, AlterEnum, AlterTable, DropColumn, RedefineTable, SqlMigrationStep, TableChange,
+    },
     sql_schema_differ::{ColumnChange, ColumnChanges},
+    SqlFlavour,
 };
+use migration_connector::MigrationFeature;
 use native_types::PostgresType;
 use once_cell::sync::Lazy;
 use prisma_value::PrismaValue;
@@ -18,6 +22,27 @@ impl SqlRenderer for PostgresFlavour {
         Quoted::postgres_ident(name)
     }
 
+    fn is_non_transactional_step(&self, step: &SqlMigrationStep) -> bool {
+        // `ALTER TYPE ... ADD VALUE` can't run in a transaction block before
+        // Postgres 12, and the added values can't be used in the transaction
+        // that adds them.
+        if let SqlMigrationStep::AlterEnum(alter_enum) = step {
+            return alter_enum.only_adds_variants();
+        }
+
+        if !self.features().contains(MigrationFeature::OnlineIndexes) {
+            return false;
+        }
+
+        // Indexes on new tables are built before any write can happen, there
+        // is no need to give up on the transaction for them.
+        match step {
+            SqlMigrationStep::CreateIndex(create_index) => !create_index.caused_by_create_table,
+            SqlMigrationStep::DropIndex(_) => true,
+            _ => false,
+        }
+    }
+
     fn render_add_foreign_key(&self, foreign_key: &ForeignKeyWalker<'_>) -> String {
         ddl::AlterTable {
             table_name: ddl::PostgresIdentifier::Simple(foreign_key.table().name().into()),
@@ -46,24 +71,22 @@ impl SqlRenderer for PostgresFlavour {
     }
 
     fn render_alter_enum(&self, alter_enum: &AlterEnum, schemas: &Pair<&SqlSchema>) -> Vec<String> {
-        if alter_enum.dropped_variants.is_empty() {
-            let stmts: Vec<String> = alter_enum
-                .created_variants
-                .iter()
-                .map(|created_value| {
-                    format!(
-                        "ALTER TYPE {enum_name} ADD VALUE {value}",
-                        enum_name = Quoted::postgres_ident(schemas.enums(&alter_enum.index).previous().name()),
-                        value = Quoted::postgres_string(created_value)
-                    )
-                })
-                .collect();
+        let enums = schemas.enums(&alter_enum.index);
 
-            return stmts;
+        if alter_enum.is_empty() {
+            return vec![format!(
+                "ALTER TYPE {previous_name} RENAME TO {next_name}",
+                previous_name = Quoted::postgres_ident(enums.previous().name()),
+                next_name = Quoted::postgres_ident(enums.next().name()),
+            )];
         }
 
-        let enums = schemas.enums(&alter_enum.index);
+        if alter_enum.only_adds_variants() {
+            return render_added_enum_values(&enums);
+        }
 
+        // Removed or renamed values have to be migrated, so we recreate the
+        // type and cast the existing rows to the new type.
         let mut stmts = Vec::with_capacity(10);
 
         let tmp_name = format!("{}_new", &enums.next().name());
@@ -82,23 +105,58 @@ impl SqlRenderer for PostgresFlavour {
             stmts.push(create_new_enum);
         }
 
-        // alter type of the current columns to new, with a cast
-        {
-            let affected_columns = walk_columns(schemas.next()).filter(|column| matches!(&column.column_type().family, ColumnTypeFamily::Enum(name) if name.as_str() == enums.next().name()));
+        // The columns currently holding values of the enum.
+        let affected_columns: Vec<ColumnWalker<'_>> = walk_columns(schemas.previous())
+            .filter(|column| column.column_type_family().as_enum() == Some(enums.previous().name()))
+            .collect();
+
+        // The defaults can't be cast automatically, they are dropped and set again once the type is swapped.
+        let mut restored_defaults = Vec::new();
 
-            for column in affected_columns {
-                let sql = format!(
-                    "ALTER TABLE {schema_name}.{table_name} \
-                            ALTER COLUMN {column_name} TYPE {tmp_name} \
-                                USING ({column_name}::text::{tmp_name})",
+        // alter type of the current columns to new, with a cast mapping the renamed values
+        for column in &affected_columns {
+            let table_name = Quoted::postgres_ident(column.table().name());
+            let column_name = Quoted::postgres_ident(column.name());
+
+            if column.default().is_some() {
+                stmts.push(format!(
+                    "ALTER TABLE {schema_name}.{table_name} ALTER COLUMN {column_name} DROP DEFAULT",
                     schema_name = Quoted::postgres_ident(self.schema_name()),
-                    table_name = Quoted::postgres_ident(column.table().name()),
-                    column_name = Quoted::postgres_ident(column.name()),
-                    tmp_name = Quoted::postgres_ident(&tmp_name),
-                );
+                    table_name = table_name,
+                    column_name = column_name,
+                ));
 
-                stmts.push(sql);
+                let next_column = schemas
+                    .next()
+                    .table_walker(column.table().name())
+                    .and_then(|table| table.column(column.name()));
+
+                if let Some(next_column) = next_column {
+                    if let Some(default) = next_column.default() {
+                        restored_defaults.push(format!(
+                            "ALTER TABLE {schema_name}.{table_name} ALTER COLUMN {column_name} SET DEFAULT {default}",
+                            schema_name = Quoted::postgres_ident(self.schema_name()),
+                            table_name = table_name,
+                            column_name = column_name,
+                            default = self.render_default(default, next_column.column_type_family()),
+                        ));
+                    }
+                }
             }
+
+            let sql = format!(
+                "ALTER TABLE {schema_name}.{table_name} \
+                        ALTER COLUMN {column_name} TYPE {tmp_name}{array} \
+                            USING ({cast})",
+                schema_name = Quoted::postgres_ident(self.schema_name()),
+                table_name = table_name,
+                column_name = column_name,
+                tmp_name = Quoted::postgres_ident(&tmp_name),
+                array = if column.arity().is_list() { "[]" } else { "" },
+                cast = render_enum_cast(column, &tmp_name, &alter_enum.renamed_variants),
+            );
+
+            stmts.push(sql);
         }
 
         // rename old enum
@@ -133,6 +191,8 @@ impl SqlRenderer for PostgresFlavour {
             stmts.push(sql)
         }
 
+        stmts.extend(restored_defaults);
+
         stmts.push("COMMIT".to_string());
 
         stmts
@@ -185,6 +245,7 @@ impl SqlRenderer for PostgresFlavour {
                     column_index,
                     changes,
                     type_change: _,
+                    renamed_enum_values: _,
                 }) => {
                     let columns = tables.columns(column_index);
 
@@ -233,12 +294,15 @@ impl SqlRenderer for PostgresFlavour {
         let column_name = self.quote(column.name());
         let tpe_str = render_column_type(column);
         let nullability_str = render_nullability(&column);
-        let default_str = column
-            .default()
-            .map(|default| self.render_default(default, column.column_type_family()))
-            .filter(|default| !default.is_empty())
-            .map(|default| format!(" DEFAULT {}", default))
-            .unwrap_or_else(String::new);
+        let default_str = match column.generation_expression() {
+            Some(expression) => format!(" GENERATED ALWAYS AS ({}) STORED", expression),
+            None => column
+                .default()
+                .map(|default| self.render_default(default, column.column_type_family()))
+                .filter(|default| !default.is_empty())
+                .map(|default| format!(" DEFAULT {}", default))
+                .unwrap_or_else(String::new),
+        };
 
         format!(
             "{}{} {}{}{}",
@@ -246,6 +310,10 @@ impl SqlRenderer for PostgresFlavour {
         )
     }
 
+    fn render_column_type(&self, column: &ColumnWalker<'_>) -> String {
+        render_column_type(column).into_owned()
+    }
+
     fn render_references(&self, foreign_key: &ForeignKeyWalker<'_>) -> String {
         let referenced_columns = foreign_key
             .referenced_column_names()
@@ -273,7 +341,9 @@ impl SqlRenderer for PostgresFlavour {
             }
             (DefaultKind::NOW, ColumnTypeFamily::DateTime) => "CURRENT_TIMESTAMP".into(),
             (DefaultKind::NOW, _) => unreachable!("NOW default on non-datetime column"),
-            (DefaultKind::VALUE(val), ColumnTypeFamily::DateTime) => format!("'{}'", val).into(),
+            (DefaultKind::VALUE(val), ColumnTypeFamily::DateTime)
+            | (DefaultKind::VALUE(val), ColumnTypeFamily::Date)
+            | (DefaultKind::VALUE(val), ColumnTypeFamily::Time) => format!("'{}'", val).into(),
             (DefaultKind::VALUE(PrismaValue::String(val)), ColumnTypeFamily::Json) => format!("'{}'", val).into(),
             (DefaultKind::VALUE(val), _) => val.to_string().into(),
             (DefaultKind::SEQUENCE(_), _) => "".into(),
@@ -289,13 +359,11 @@ impl SqlRenderer for PostgresFlavour {
     }
 
     fn render_create_index(&self, index: &IndexWalker<'_>) -> String {
-        CreateIndex {
-            index_name: index.name().into(),
-            is_unique: index.index_type().is_unique(),
-            table_reference: index.table().name().into(),
-            columns: index.columns().map(|c| c.name().into()).collect(),
-        }
-        .to_string()
+        render_create_index(index, false)
+    }
+
+    fn render_create_index_concurrently(&self, index: &IndexWalker<'_>) -> String {
+        render_create_index(index, true)
     }
 
     fn render_create_table_as(&self, table: &TableWalker<'_>, table_name: &str) -> String {
@@ -342,6 +410,10 @@ impl SqlRenderer for PostgresFlavour {
         format!("DROP INDEX {}", self.quote(index.name()))
     }
 
+    fn render_drop_index_concurrently(&self, index: &IndexWalker<'_>) -> String {
+        format!("DROP INDEX CONCURRENTLY {}", self.quote(index.name()))
+    }
+
     fn render_drop_table(&self, table_name: &str) -> Vec<String> {
         vec![format!("DROP TABLE {}", self.quote(&table_name))]
     }
@@ -357,6 +429,73 @@ impl SqlRenderer for PostgresFlavour {
             new_name = self.quote(new_name),
         )
     }
+
+    fn render_guarded(&self, statements: Vec<String>, guard: Guard<'_>) -> Vec<String> {
+        let (markers, clause): (&[&str], &str) = match guard {
+            Guard::TableMissing { .. } => (&["CREATE TABLE "], "IF NOT EXISTS "),
+            Guard::TableExists { .. } => (&["DROP TABLE "], "IF EXISTS "),
+            Guard::ColumnMissing { .. } => (&["ADD COLUMN "], "IF NOT EXISTS "),
+            Guard::ColumnExists { .. } => (&["DROP COLUMN "], "IF EXISTS "),
+            Guard::IndexMissing { .. } => (
+                &[
+                    "CREATE UNIQUE INDEX CONCURRENTLY ",
+                    "CREATE INDEX CONCURRENTLY ",
+                    "CREATE UNIQUE INDEX ",
+                    "CREATE INDEX ",
+                ],
+                "IF NOT EXISTS ",
+            ),
+            Guard::IndexExists { .. } => (&["DROP INDEX CONCURRENTLY ", "DROP INDEX "], "IF EXISTS "),
+            Guard::ForeignKeyExists { .. } => (&["DROP CONSTRAINT "], "IF EXISTS "),
+            Guard::EnumExists { .. } => (&["DROP TYPE "], "IF EXISTS "),
+            Guard::EnumValuesMissing { .. } => (&["ADD VALUE "], "IF NOT EXISTS "),
+            // There is no IF NOT EXISTS for these, we check the catalog in a DO block instead.
+            Guard::ForeignKeyMissing { table, constraint_name } => {
+                let condition = format!(
+                    "SELECT 1 FROM information_schema.table_constraints \
+                     WHERE constraint_schema = current_schema() AND table_name = {} AND constraint_name = {}",
+                    render_string_literal(table),
+                    render_string_literal(constraint_name),
+                );
+
+                return vec![render_do_block_unless_exists(&condition, &statements)];
+            }
+            Guard::EnumMissing { name } => {
+                let condition = format!(
+                    "SELECT 1 FROM pg_type JOIN pg_namespace ON pg_namespace.oid = pg_type.typnamespace \
+                     WHERE pg_namespace.nspname = current_schema() AND pg_type.typname = {}",
+                    render_string_literal(name),
+                );
+
+                return vec![render_do_block_unless_exists(&condition, &statements)];
+            }
+            Guard::EnumValuesExist { name, values } => {
+                let condition = format!(
+                    "SELECT 1 FROM pg_enum \
+                     JOIN pg_type ON pg_type.oid = pg_enum.enumtypid \
+                     JOIN pg_namespace ON pg_namespace.oid = pg_type.typnamespace \
+                     WHERE pg_namespace.nspname = current_schema() AND pg_type.typname = {} \
+                     AND pg_enum.enumlabel IN ({})",
+                    render_string_literal(name),
+                    values.iter().map(|value| render_string_literal(value)).join(", "),
+                );
+
+                // The recreated enum is swapped in its own transaction, the
+                // block is atomic on its own.
+                let statements: Vec<String> = statements
+                    .into_iter()
+                    .filter(|statement| statement != "BEGIN" && statement != "COMMIT")
+                    .collect();
+
+                return vec![render_do_block_if_exists(&condition, &statements)];
+            }
+        };
+
+        statements
+            .iter()
+            .map(|statement| insert_after_marker(statement, markers, clause))
+            .collect()
+    }
 }
 
 pub(crate) fn render_column_type(col: &ColumnWalker<'_>) -> Cow<'static, str> {
@@ -427,12 +566,143 @@ pub(crate) fn render_column_type(col: &ColumnWalker<'_>) -> Cow<'static, str> {
     }
 }
 
+/// Add the created values in place, at their position in the next enum. This
+/// is a non-transactional step.
+fn render_added_enum_values(enums: &Pair<EnumWalker<'_>>) -> Vec<String> {
+    let previous_name = Quoted::postgres_ident(enums.previous().name());
+    let previous_values = enums.previous().values();
+    let next_values = enums.next().values();
+
+    let mut stmts: Vec<String> = next_values
+        .iter()
+        .enumerate()
+        .filter(|(_, value)| !previous_values.contains(value))
+        .map(|(position, value)| {
+            // The value before the added value was either already there or added just before.
+            let placement = match position
+                .checked_sub(1)
+                .map(|previous_position| &next_values[previous_position])
+            {
+                Some(preceding_value) => format!(" AFTER {}", Quoted::postgres_string(preceding_value)),
+                None => previous_values
+                    .first()
+                    .map(|first_value| format!(" BEFORE {}", Quoted::postgres_string(first_value)))
+                    .unwrap_or_default(),
+            };
+
+            format!(
+                "ALTER TYPE {enum_name} ADD VALUE {value}{placement}",
+                enum_name = previous_name,
+                value = Quoted::postgres_string(value),
+                placement = placement,
+            )
+        })
+        .collect();
+
+    if enums.previous().name() != enums.next().name() {
+        stmts.push(format!(
+            "ALTER TYPE {previous_name} RENAME TO {next_name}",
+            previous_name = previous_name,
+            next_name = Quoted::postgres_ident(enums.next().name()),
+        ));
+    }
+
+    stmts
+}
+
+/// Render the `USING` expression casting the values of an enum column to the
+/// new enum type, going through text so the renamed values can be mapped.
+fn render_enum_cast(column: &ColumnWalker<'_>, tmp_name: &str, renamed_variants: &[(String, String)]) -> String {
+    let column_name = Quoted::postgres_ident(column.name());
+    let tmp_name = Quoted::postgres_ident(tmp_name);
+
+    if column.arity().is_list() {
+        let mapped = renamed_variants
+            .iter()
+            .fold(format!("{}::text[]", column_name), |array, (previous, next)| {
+                format!(
+                    "array_replace({}, {}, {})",
+                    array,
+                    Quoted::postgres_string(previous),
+                    Quoted::postgres_string(next)
+                )
+            });
+
+        return format!("{}::{}[]", mapped, tmp_name);
+    }
+
+    if renamed_variants.is_empty() {
+        return format!("{}::text::{}", column_name, tmp_name);
+    }
+
+    let cases = renamed_variants
+        .iter()
+        .map(|(previous, next)| {
+            format!(
+                "WHEN {} THEN {}",
+                Quoted::postgres_string(previous),
+                Quoted::postgres_string(next)
+            )
+        })
+        .join(" ");
+
+    format!(
+        "(CASE {column}::text {cases} ELSE {column}::text END)::{tmp_name}",
+        column = column_name,
+        cases = cases,
+        tmp_name = tmp_name,
+    )
+}
+
 fn escape_string_literal(s: &str) -> Cow<'_, str> {
     static STRING_LITERAL_CHARACTER_TO_ESCAPE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"'|\\"#).unwrap());
 
     STRING_LITERAL_CHARACTER_TO_ESCAPE_RE.replace_all(s, "\\$0")
 }
 
+fn render_string_literal(s: &str) -> String {
+    Quoted::postgres_string(s.replace('\'', "''")).to_string()
+}
+
+/// Wrap the statements in a `DO` block that only runs them when the query
+/// returns no rows.
+fn render_do_block_unless_exists(query: &str, statements: &[String]) -> String {
+    render_do_block(&format!("NOT EXISTS ({})", query), statements)
+}
+
+/// Wrap the statements in a `DO` block that only runs them when the query
+/// returns rows.
+fn render_do_block_if_exists(query: &str, statements: &[String]) -> String {
+    render_do_block(&format!("EXISTS ({})", query), statements)
+}
+
+fn render_do_block(condition: &str, statements: &[String]) -> String {
+    let mut block = format!("DO $$\nBEGIN\n{}IF {} THEN\n", SQL_INDENTATION, condition);
+
+    for statement in statements {
+        block.push_str(SQL_INDENTATION);
+        block.push_str(SQL_INDENTATION);
+        block.push_str(statement);
+        block.push_str(";\n");
+    }
+
+    block.push_str(SQL_INDENTATION);
+    block.push_str("END IF;\nEND $$");
+
+    block
+}
+
+fn render_create_index(index: &IndexWalker<'_>, concurrently: bool) -> String {
+    CreateIndex {
+        index_name: index.name().into(),
+        is_unique: index.index_type().is_unique(),
+        concurrently,
+        table_reference: index.table().name().into(),
+        columns: index.columns().map(|c| c.name().into()).collect(),
+    }
+    .to_string()
+}
+
 fn render_alter_column(
     renderer: &PostgresFlavour,
     columns: &Pair<ColumnWalker<'_>>,
@@ -545,6 +815,7 @@ fn expand_alter_column(columns: &Pair<ColumnWalker<'_>>, column_changes: &Column
                 }
             }
             ColumnChange::Renaming => unreachable!("column renaming"),
+            ColumnChange::GenerationExpression => unreachable!("generation expression change"),
         }
     }
 
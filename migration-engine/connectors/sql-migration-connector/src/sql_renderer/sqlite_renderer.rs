@@ -1,4 +1,4 @@
-use super::{common::*, SqlRenderer};
+use super::{common::*, Guard, SqlRenderer};
 use crate::{
     flavour::SqliteFlavour,
     pair::Pair,
@@ -42,6 +42,10 @@ impl SqlRenderer for SqliteFlavour {
         render_column(column).to_string()
     }
 
+    fn render_column_type(&self, column: &ColumnWalker<'_>) -> String {
+        render_column_type(column.column_type()).to_owned()
+    }
+
     fn render_references(&self, foreign_key: &ForeignKeyWalker<'_>) -> String {
         let referenced_fields = foreign_key
             .referenced_column_names()
@@ -69,7 +73,9 @@ impl SqlRenderer for SqliteFlavour {
             }
             (DefaultKind::NOW, ColumnTypeFamily::DateTime) => "CURRENT_TIMESTAMP".into(),
             (DefaultKind::NOW, _) => unreachable!("NOW default on non-datetime column"),
-            (DefaultKind::VALUE(val), ColumnTypeFamily::DateTime) => format!("'{}'", val).into(),
+            (DefaultKind::VALUE(val), ColumnTypeFamily::DateTime)
+            | (DefaultKind::VALUE(val), ColumnTypeFamily::Date)
+            | (DefaultKind::VALUE(val), ColumnTypeFamily::Time) => format!("'{}'", val).into(),
             (DefaultKind::VALUE(val), _) => format!("{}", val).into(),
             (DefaultKind::SEQUENCE(_), _) => "".into(),
         }
@@ -211,12 +217,31 @@ impl SqlRenderer for SqliteFlavour {
     fn render_rename_table(&self, name: &str, new_name: &str) -> String {
         format!(r#"ALTER TABLE "{}" RENAME TO "{}""#, name, new_name)
     }
+
+    fn render_guarded(&self, statements: Vec<String>, guard: Guard<'_>) -> Vec<String> {
+        // SQLite has no control flow statements, we can only guard the
+        // statements that accept IF (NOT) EXISTS.
+        let (markers, clause): (&[&str], &str) = match guard {
+            Guard::TableMissing { .. } => (&["CREATE TABLE "], "IF NOT EXISTS "),
+            Guard::TableExists { .. } => (&["DROP TABLE "], "IF EXISTS "),
+            Guard::IndexMissing { .. } => (&["CREATE UNIQUE INDEX ", "CREATE INDEX "], "IF NOT EXISTS "),
+            Guard::IndexExists { .. } => (&["DROP INDEX "], "IF EXISTS "),
+            _ => return statements,
+        };
+
+        statements
+            .iter()
+            .map(|statement| insert_after_marker(statement, markers, clause))
+            .collect()
+    }
 }
 
 fn render_column_type<'a>(t: &'a ColumnType) -> &'a str {
     match &t.family {
         ColumnTypeFamily::Boolean => "BOOLEAN",
         ColumnTypeFamily::DateTime => "DATETIME",
+        ColumnTypeFamily::Date => "DATE",
+        ColumnTypeFamily::Time => "TIME",
         ColumnTypeFamily::Float => "REAL",
         ColumnTypeFamily::Decimal => "DECIMAL",
         ColumnTypeFamily::Int => "INTEGER",
@@ -252,12 +277,28 @@ fn copy_current_table_into_new_table(
         return;
     }
 
-    let destination_columns = redefine_table
+    // Generated columns are computed by the database, they can't be inserted into.
+    let column_pairs: Vec<_> = redefine_table
         .column_pairs
+        .iter()
+        .filter(|(column_indexes, _, _)| {
+            tables
+                .next()
+                .column_at(*column_indexes.next())
+                .generation_expression()
+                .is_none()
+        })
+        .collect();
+
+    if column_pairs.is_empty() {
+        return;
+    }
+
+    let destination_columns = column_pairs
         .iter()
         .map(|(column_indexes, _, _)| tables.next().column_at(*column_indexes.next()).name());
 
-    let source_columns = redefine_table.column_pairs.iter().map(|(column_indexes, changes, _)| {
+    let source_columns = column_pairs.iter().map(|(column_indexes, changes, _)| {
         let columns = tables.columns(column_indexes);
 
         let col_became_required_with_a_default =
@@ -297,7 +338,9 @@ fn render_column<'a>(column: &ColumnWalker<'a>) -> ddl::Column<'a> {
         default: column
             .default()
             .filter(|default| !matches!(default.kind(), DefaultKind::SEQUENCE(_)))
+            .filter(|_| column.generation_expression().is_none())
             .map(|default| render_default(default, column.column_type_family())),
+        generated: column.generation_expression().map(Cow::Borrowed),
         name: column.name().into(),
         not_null: !column.arity().is_nullable(),
         primary_key: column.is_single_primary_key(),
@@ -315,7 +358,9 @@ fn render_default<'a>(default: &'a DefaultValue, family: &ColumnTypeFamily) -> C
         (DefaultKind::VALUE(PrismaValue::Bytes(b)), ColumnTypeFamily::Binary) => format!("'{}'", format_hex(b)).into(),
         (DefaultKind::NOW, ColumnTypeFamily::DateTime) => "CURRENT_TIMESTAMP".into(),
         (DefaultKind::NOW, _) => unreachable!("NOW default on non-datetime column"),
-        (DefaultKind::VALUE(val), ColumnTypeFamily::DateTime) => format!("'{}'", val).into(),
+        (DefaultKind::VALUE(val), ColumnTypeFamily::DateTime)
+        | (DefaultKind::VALUE(val), ColumnTypeFamily::Date)
+        | (DefaultKind::VALUE(val), ColumnTypeFamily::Time) => format!("'{}'", val).into(),
         (DefaultKind::VALUE(val), _) => format!("{}", val).into(),
         (DefaultKind::SEQUENCE(_), _) => "".into(),
     }
@@ -203,12 +203,14 @@ fn calculate_relation_tables<'a>(
                     tpe: column_type_for_implicit_relation(&model_a_id, schema),
                     default: None,
                     auto_increment: false,
+                    generation_expression: None,
                 },
                 sql::Column {
                     name: m2m.model_b_column().into(),
                     tpe: column_type_for_implicit_relation(&model_b_id, schema),
                     default: None,
                     auto_increment: false,
+                    generation_expression: None,
                 },
             ];
 
@@ -265,6 +267,7 @@ fn column_for_scalar_field(field: &ScalarFieldWalker<'_>, flavour: &dyn SqlFlavo
                         ))
                     }),
                 auto_increment: false,
+                generation_expression: None,
             }
         }
         TypeWalker::Base(scalar_type) => (scalar_type, flavour.default_native_type_for_scalar_type(&scalar_type)),
@@ -280,6 +283,7 @@ fn column_for_scalar_field(field: &ScalarFieldWalker<'_>, flavour: &dyn SqlFlavo
                 },
                 default: field.default_value().and_then(|v| db_generated(v)),
                 auto_increment: false,
+                generation_expression: None,
             }
         }
     };
@@ -295,6 +299,8 @@ fn column_for_scalar_field(field: &ScalarFieldWalker<'_>, flavour: &dyn SqlFlavo
         ScalarType::Boolean => sql::ColumnTypeFamily::Boolean,
         ScalarType::String => sql::ColumnTypeFamily::String,
         ScalarType::DateTime => sql::ColumnTypeFamily::DateTime,
+        ScalarType::Date => sql::ColumnTypeFamily::Date,
+        ScalarType::Time => sql::ColumnTypeFamily::Time,
         ScalarType::Json => sql::ColumnTypeFamily::Json,
         ScalarType::Bytes => sql::ColumnTypeFamily::Binary,
         ScalarType::Decimal => sql::ColumnTypeFamily::Decimal,
@@ -303,6 +309,7 @@ fn column_for_scalar_field(field: &ScalarFieldWalker<'_>, flavour: &dyn SqlFlavo
 
     sql::Column {
         auto_increment: has_auto_increment_default || flavour.field_is_implicit_autoincrement_primary_key(field),
+        generation_expression: field.generation_expression().map(String::from),
         name: field.db_name().to_owned(),
         tpe: sql::ColumnType {
             full_data_type: String::new(),
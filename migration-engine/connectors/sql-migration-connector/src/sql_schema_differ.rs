@@ -5,6 +5,7 @@ mod sql_schema_differ_flavour;
 mod table;
 
 pub(crate) use column::{ColumnChange, ColumnChanges};
+pub(crate) use enums::enum_was_renamed;
 pub(crate) use sql_schema_differ_flavour::SqlSchemaDifferFlavour;
 
 use crate::{
@@ -15,14 +16,23 @@ use crate::{
     },
     SqlFlavour, SqlSchema,
 };
-use column::ColumnTypeChange;
-use enums::EnumDiffer;
+use column::{ColumnDiffer, ColumnTypeChange};
+use enums::{renamed_enum_values, EnumDiffer};
+use migration_connector::EnumValueRename;
 use sql_schema_describer::walkers::{EnumWalker, ForeignKeyWalker, TableWalker};
 use std::collections::HashSet;
 use table::TableDiffer;
 
-pub(crate) fn calculate_steps(schemas: Pair<&SqlSchema>, flavour: &dyn SqlFlavour) -> Vec<SqlMigrationStep> {
-    let differ = SqlSchemaDiffer { schemas, flavour };
+pub(crate) fn calculate_steps(
+    schemas: Pair<&SqlSchema>,
+    flavour: &dyn SqlFlavour,
+    enum_value_renames: &[EnumValueRename],
+) -> Vec<SqlMigrationStep> {
+    let differ = SqlSchemaDiffer {
+        schemas,
+        flavour,
+        enum_value_renames,
+    };
 
     let tables_to_redefine = differ.flavour.tables_to_redefine(&differ);
     let mut alter_indexes = differ.alter_indexes(&tables_to_redefine);
@@ -92,6 +102,8 @@ pub(crate) fn calculate_steps(schemas: Pair<&SqlSchema>, flavour: &dyn SqlFlavou
 pub(crate) struct SqlSchemaDiffer<'a> {
     schemas: Pair<&'a SqlSchema>,
     flavour: &'a dyn SqlFlavour,
+    /// The enum values the user renamed. Other enum value changes are removals and additions.
+    enum_value_renames: &'a [EnumValueRename],
 }
 
 impl<'schema> SqlSchemaDiffer<'schema> {
@@ -156,13 +168,13 @@ impl<'schema> SqlSchemaDiffer<'schema> {
     ) -> impl Iterator<Item = AlterTable> + 'a {
         self.table_pairs()
             .filter(move |tables| !tables_to_redefine.contains(tables.next().name()))
-            .filter_map(|differ| {
+            .filter_map(move |differ| {
                 // Order matters.
                 let changes: Vec<TableChange> = SqlSchemaDiffer::drop_primary_key(&differ)
                     .into_iter()
                     .chain(SqlSchemaDiffer::drop_columns(&differ))
                     .chain(SqlSchemaDiffer::add_columns(&differ))
-                    .chain(SqlSchemaDiffer::alter_columns(&differ))
+                    .chain(self.alter_columns(&differ))
                     .chain(SqlSchemaDiffer::add_primary_key(&differ))
                     .collect();
 
@@ -195,7 +207,7 @@ impl<'schema> SqlSchemaDiffer<'schema> {
         })
     }
 
-    fn alter_columns<'a>(table_differ: &'a TableDiffer<'schema>) -> impl Iterator<Item = TableChange> + 'a {
+    fn alter_columns<'a>(&'a self, table_differ: &'a TableDiffer<'schema>) -> impl Iterator<Item = TableChange> + 'a {
         table_differ.column_pairs().filter_map(move |column_differ| {
             let (changes, type_change) = column_differ.all_changes();
 
@@ -205,6 +217,13 @@ impl<'schema> SqlSchemaDiffer<'schema> {
 
             let column_index = Pair::new(column_differ.previous.column_index(), column_differ.next.column_index());
 
+            // Generation expressions can't be altered in place.
+            if changes.generation_expression_changed() {
+                return Some(TableChange::DropAndRecreateColumn { column_index, changes });
+            }
+
+            let renamed_enum_values = self.renamed_column_enum_values(&column_differ);
+
             match type_change {
                 Some(ColumnTypeChange::NotCastable) => {
                     Some(TableChange::DropAndRecreateColumn { column_index, changes })
@@ -213,21 +232,40 @@ impl<'schema> SqlSchemaDiffer<'schema> {
                     column_index,
                     changes,
                     type_change: Some(crate::sql_migration::ColumnTypeChange::RiskyCast),
+                    renamed_enum_values,
                 })),
                 Some(ColumnTypeChange::SafeCast) => Some(TableChange::AlterColumn(AlterColumn {
                     column_index,
                     changes,
                     type_change: Some(crate::sql_migration::ColumnTypeChange::SafeCast),
+                    renamed_enum_values,
                 })),
                 None => Some(TableChange::AlterColumn(AlterColumn {
                     column_index,
                     changes,
                     type_change: None,
+                    renamed_enum_values,
                 })),
             }
         })
     }
 
+    /// The renamed values of the enum the column holds, for enums that are column types.
+    fn renamed_column_enum_values(&self, column_differ: &ColumnDiffer<'_>) -> Vec<(String, String)> {
+        match (
+            column_differ.previous.column_type_family_as_enum(),
+            column_differ.next.column_type_family_as_enum(),
+        ) {
+            (Some(previous_enum), Some(next_enum)) => renamed_enum_values(
+                &next_enum.name,
+                &previous_enum.values,
+                &next_enum.values,
+                self.enum_value_renames,
+            ),
+            _ => Vec::new(),
+        }
+    }
+
     fn drop_foreign_keys<'a>(
         &'a self,
         drop_foreign_keys: &mut Vec<DropForeignKey>,
@@ -496,27 +534,38 @@ impl<'schema> SqlSchemaDiffer<'schema> {
     }
 
     fn table_is_ignored(&self, table_name: &str) -> bool {
-        table_name == "_prisma_migrations" || self.flavour.table_should_be_ignored(&table_name)
+        // A table with the name of the migrations table in the migrated schema is a user table when the
+        // migrations table is configured to live in another schema.
+        let is_migrations_table = table_name == self.flavour.imperative_migrations_table_name()
+            && self.flavour.migrations_table_is_in_connection_schema();
+
+        is_migrations_table || self.flavour.table_should_be_ignored(&table_name)
     }
 
     fn enum_pairs(&self) -> impl Iterator<Item = EnumDiffer<'_>> {
         self.previous_enums().filter_map(move |previous| {
             self.next_enums()
-                .find(|next| enums_match(&previous, &next))
+                .find(|next| self.enums_match(&previous, &next))
                 .map(|next| EnumDiffer {
                     enums: Pair::new(previous, next),
+                    enum_value_renames: self.enum_value_renames,
                 })
         })
     }
 
     fn created_enums<'a>(&'a self) -> impl Iterator<Item = EnumWalker<'schema>> + 'a {
         self.next_enums()
-            .filter(move |next| !self.previous_enums().any(|previous| enums_match(&previous, next)))
+            .filter(move |next| !self.previous_enums().any(|previous| self.enums_match(&previous, next)))
     }
 
     fn dropped_enums<'a>(&'a self) -> impl Iterator<Item = EnumWalker<'schema>> + 'a {
         self.previous_enums()
-            .filter(move |previous| !self.next_enums().any(|next| enums_match(previous, &next)))
+            .filter(move |previous| !self.next_enums().any(|next| self.enums_match(previous, &next)))
+    }
+
+    /// Enums match when they have the same name, or when the previous enum was renamed.
+    fn enums_match(&self, previous: &EnumWalker<'_>, next: &EnumWalker<'_>) -> bool {
+        previous.name() == next.name() || enum_was_renamed(&self.schemas, previous.name(), next.name())
     }
 
     fn previous_enums(&self) -> impl Iterator<Item = EnumWalker<'schema>> {
@@ -579,7 +628,3 @@ fn foreign_keys_match(previous: &ForeignKeyWalker<'_>, next: &ForeignKeyWalker<'
         && constrains_same_columns
         && references_same_columns
 }
-
-fn enums_match(previous: &EnumWalker<'_>, next: &EnumWalker<'_>) -> bool {
-    previous.name() == next.name()
-}
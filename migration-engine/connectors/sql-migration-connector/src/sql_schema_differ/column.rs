@@ -35,6 +35,10 @@ impl<'a> ColumnDiffer<'a> {
             changes |= ColumnChange::Sequence;
         };
 
+        if !self.generation_expressions_match() {
+            changes |= ColumnChange::GenerationExpression;
+        };
+
         (ColumnChanges { changes }, column_type_change)
     }
 
@@ -50,6 +54,19 @@ impl<'a> ColumnDiffer<'a> {
         self.previous.is_autoincrement() != self.next.is_autoincrement()
     }
 
+    /// The databases normalize generation expressions (adding parentheses, quoting identifiers and, on
+    /// PostgreSQL, adding casts like `::numeric`), so we compare them modulo whitespace, parentheses,
+    /// identifier quotes and casts outside of string literals.
+    fn generation_expressions_match(&self) -> bool {
+        match (self.previous.generation_expression(), self.next.generation_expression()) {
+            (Some(previous), Some(next)) => {
+                normalize_generation_expression(previous) == normalize_generation_expression(next)
+            }
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
     /// There are workarounds to cope with current migration and introspection limitations.
     ///
     /// - We bail on a number of cases that are too complex to deal with right now or underspecified.
@@ -125,6 +142,7 @@ pub(crate) enum ColumnChange {
     Default = 0b0100,
     TypeChanged = 0b1000,
     Sequence = 0b0010000,
+    GenerationExpression = 0b0100000,
 }
 
 // This should be pub(crate), but SqlMigration is exported, so it has to be
@@ -164,6 +182,10 @@ impl ColumnChanges {
         self.changes == BitFlags::from(ColumnChange::TypeChanged)
     }
 
+    pub(crate) fn generation_expression_changed(&self) -> bool {
+        self.changes.contains(ColumnChange::GenerationExpression)
+    }
+
     pub(crate) fn column_was_renamed(&self) -> bool {
         self.changes.contains(ColumnChange::Renaming)
     }
@@ -175,3 +197,112 @@ pub enum ColumnTypeChange {
     RiskyCast,
     NotCastable,
 }
+
+/// Removes whitespace, parentheses, identifier quotes and `::type` casts from a
+/// generation expression. String literals are kept as they are.
+fn normalize_generation_expression(expression: &str) -> String {
+    let mut result = String::with_capacity(expression.len());
+    let mut rest = expression;
+
+    while let Some(c) = rest.chars().next() {
+        if c == '\'' {
+            let end = string_literal_end(rest);
+            result.push_str(&rest[..end]);
+            rest = &rest[end..];
+        } else if let Some(cast) = rest.strip_prefix("::") {
+            rest = skip_type_name(cast);
+        } else {
+            if !c.is_whitespace() && !matches!(c, '(' | ')' | '"' | '`' | '[' | ']') {
+                result.push(c);
+            }
+
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+
+    result
+}
+
+/// The length of the string literal at the start of `expression`, quotes
+/// included. Quotes in the literal are escaped by doubling them.
+fn string_literal_end(expression: &str) -> usize {
+    let mut chars = expression.char_indices().skip(1).peekable();
+
+    while let Some((position, c)) = chars.next() {
+        if c == '\'' {
+            match chars.peek() {
+                Some((_, '\'')) => {
+                    chars.next();
+                }
+                _ => return position + 1,
+            }
+        }
+    }
+
+    expression.len()
+}
+
+/// Skips the type name of a cast, e.g. `numeric`, `character varying(10)` or
+/// `timestamp without time zone`.
+fn skip_type_name(expression: &str) -> &str {
+    const CONTINUATIONS: &[&str] = &["varying", "precision", "with", "without", "time", "zone"];
+
+    fn word_end(s: &str) -> usize {
+        s.find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+            .unwrap_or(s.len())
+    }
+
+    let mut rest = expression.trim_start();
+    rest = &rest[word_end(rest)..];
+
+    loop {
+        let next = rest.trim_start();
+        let next_word = &next[..word_end(next)];
+
+        if next.starts_with('(') {
+            match next.find(')') {
+                Some(end)
+                    if next[1..end]
+                        .chars()
+                        .all(|c| c.is_ascii_digit() || c == ',' || c.is_whitespace()) =>
+                {
+                    rest = &next[end + 1..];
+                }
+                _ => return rest,
+            }
+        } else if CONTINUATIONS.iter().any(|word| word.eq_ignore_ascii_case(next_word)) {
+            rest = &next[next_word.len()..];
+        } else {
+            return rest;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_generation_expression_strips_casts_and_formatting() {
+        assert_eq!(
+            normalize_generation_expression(r#"(("price" * 2)::numeric(10, 2) + "tax"::double precision)"#),
+            "price*2+tax"
+        );
+        assert_eq!(
+            normalize_generation_expression("(`first` || ' ' || `last`)::character varying"),
+            "first||' '||last"
+        );
+    }
+
+    #[test]
+    fn normalize_generation_expression_keeps_string_literals() {
+        assert_ne!(
+            normalize_generation_expression("name || 'a b'"),
+            normalize_generation_expression("name || 'ab'")
+        );
+        assert_eq!(
+            normalize_generation_expression("name || 'it''s (x)'::text"),
+            "name||'it''s (x)'"
+        );
+    }
+}
@@ -1,9 +1,11 @@
-use sql_schema_describer::walkers::EnumWalker;
+use migration_connector::EnumValueRename;
+use sql_schema_describer::{walkers::EnumWalker, SqlSchema};
 
 use crate::pair::Pair;
 
 pub(crate) struct EnumDiffer<'a> {
     pub(crate) enums: Pair<EnumWalker<'a>>,
+    pub(crate) enum_value_renames: &'a [EnumValueRename],
 }
 
 impl<'a> EnumDiffer<'a> {
@@ -38,6 +40,86 @@ impl<'a> EnumDiffer<'a> {
             })
             .map(String::as_str)
     }
+
+    pub(crate) fn renamed_values(&self) -> Vec<(String, String)> {
+        renamed_enum_values(
+            self.enums.next().name(),
+            self.enums.previous().values(),
+            self.enums.next().values(),
+            self.enum_value_renames,
+        )
+    }
+
+    pub(crate) fn name_changed(&self) -> bool {
+        self.enums.previous().name() != self.enums.next().name()
+    }
+}
+
+/// The values that were renamed between two versions of an enum, as (previous
+/// value, next value) pairs.
+///
+/// We can't tell a rename apart from a removal and an addition, so only the
+/// renames passed in explicitly are considered, and only when the previous
+/// value is not in the next enum anymore and the next value is new.
+pub(crate) fn renamed_enum_values(
+    enum_name: &str,
+    previous_values: &[String],
+    next_values: &[String],
+    enum_value_renames: &[EnumValueRename],
+) -> Vec<(String, String)> {
+    let contains = |values: &[String], value: &str| values.iter().any(|other| values_match(other, value));
+
+    enum_value_renames
+        .iter()
+        .filter(|rename| rename.enum_name == enum_name)
+        .filter(|rename| {
+            contains(previous_values, &rename.previous_value) && !contains(next_values, &rename.previous_value)
+        })
+        .filter(|rename| contains(next_values, &rename.next_value) && !contains(previous_values, &rename.next_value))
+        .map(|rename| (rename.previous_value.clone(), rename.next_value.clone()))
+        .collect()
+}
+
+/// Is the enum named `next_name` in the next schema the enum named
+/// `previous_name` in the previous schema, renamed?
+///
+/// An enum is considered renamed when its previous name disappears, its next
+/// name appears, and both have the same values. When several dropped or
+/// created enums have these values, we can't tell which is which, so none of
+/// them is considered renamed.
+pub(crate) fn enum_was_renamed(schemas: &Pair<&SqlSchema>, previous_name: &str, next_name: &str) -> bool {
+    if previous_name == next_name {
+        return false;
+    }
+
+    let (previous_enum, next_enum) = match (
+        schemas.previous().get_enum(previous_name),
+        schemas.next().get_enum(next_name),
+    ) {
+        (Some(previous_enum), Some(next_enum)) if previous_enum.values == next_enum.values => {
+            (previous_enum, next_enum)
+        }
+        _ => return false,
+    };
+
+    let is_dropped = |name: &str| schemas.next().get_enum(name).is_none();
+    let is_created = |name: &str| schemas.previous().get_enum(name).is_none();
+
+    let dropped_candidates = schemas
+        .previous()
+        .enums
+        .iter()
+        .filter(|enm| is_dropped(&enm.name) && enm.values == previous_enum.values)
+        .count();
+
+    let created_candidates = schemas
+        .next()
+        .enums
+        .iter()
+        .filter(|enm| is_created(&enm.name) && enm.values == next_enum.values)
+        .count();
+
+    is_dropped(previous_name) && is_created(next_name) && dropped_candidates == 1 && created_candidates == 1
 }
 
 fn values_match(previous: &str, next: &str) -> bool {
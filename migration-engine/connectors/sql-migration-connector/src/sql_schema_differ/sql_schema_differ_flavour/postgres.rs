@@ -5,7 +5,7 @@ use crate::{
     sql_migration::AlterEnum,
     sql_schema_differ::{
         column::{ColumnDiffer, ColumnTypeChange},
-        SqlSchemaDiffer,
+        enum_was_renamed, SqlSchemaDiffer,
     },
 };
 use native_types::PostgresType;
@@ -23,13 +23,28 @@ impl SqlSchemaDifferFlavour for PostgresFlavour {
         differ
             .enum_pairs()
             .filter_map(|differ| {
+                let renamed_variants = differ.renamed_values();
+
+                // Renamed values are neither created nor dropped, the existing rows are migrated.
+                let created_variants = differ
+                    .created_values()
+                    .filter(|value| !renamed_variants.iter().any(|(_, next)| next == value))
+                    .map(String::from)
+                    .collect();
+                let dropped_variants = differ
+                    .dropped_values()
+                    .filter(|value| !renamed_variants.iter().any(|(previous, _)| previous == value))
+                    .map(String::from)
+                    .collect();
+
                 let step = AlterEnum {
                     index: differ.enums.as_ref().map(|e| e.enum_index()),
-                    created_variants: differ.created_values().map(String::from).collect(),
-                    dropped_variants: differ.dropped_values().map(String::from).collect(),
+                    created_variants,
+                    dropped_variants,
+                    renamed_variants,
                 };
 
-                if step.is_empty() {
+                if step.is_empty() && !differ.name_changed() {
                     None
                 } else {
                     Some(step)
@@ -72,7 +87,10 @@ impl SqlSchemaDifferFlavour for PostgresFlavour {
             .map(|column| column.column_type_family_as_enum())
             .as_tuple()
         {
-            if previous_enum.name == next_enum.name {
+            let schemas = differ.as_pair().map(|column| column.schema());
+
+            if previous_enum.name == next_enum.name || enum_was_renamed(&schemas, &previous_enum.name, &next_enum.name)
+            {
                 return None;
             }
         }
@@ -29,6 +29,8 @@ impl SqlSchemaDifferFlavour for SqliteFlavour {
                     || differ.dropped_primary_key().is_some()
                     || differ.dropped_columns().next().is_some()
                     || differ.added_columns().any(|col| col.arity().is_required())
+                    // Stored generated columns can't be added with ALTER TABLE.
+                    || differ.added_columns().any(|col| col.generation_expression().is_some())
                     || differ.column_pairs().any(|columns| columns.all_changes().0.differs_in_something())
                     // ALTER INDEX does not exist on SQLite
                     || differ.index_pairs().any(|pair| self.index_should_be_renamed(&pair))
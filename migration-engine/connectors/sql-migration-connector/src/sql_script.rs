@@ -0,0 +1,232 @@
+//! Statement-level handling of migration scripts, for the connector features
+//! that can't treat a script as one opaque command.
+
+use crate::sql_migration_linter::case_insensitive;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// Split a script into its statements, without the comments. Semicolons in
+/// string literals, quoted identifiers, Postgres dollar-quoted strings and
+/// comments do not end statements.
+pub(crate) fn split_statements(script: &str) -> Vec<String> {
+    let mut statements = Vec::new();
+    let mut statement = String::new();
+    let mut chars = script.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '-' if chars.peek() == Some(&'-') => {
+                while let Some(c) = chars.next() {
+                    if c == '\n' {
+                        statement.push(' ');
+                        break;
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+
+                while let Some(c) = chars.next() {
+                    if c == '*' && chars.peek() == Some(&'/') {
+                        chars.next();
+                        statement.push(' ');
+                        break;
+                    }
+                }
+            }
+            '\'' | '"' | '`' => {
+                statement.push(c);
+
+                for quoted in chars.by_ref() {
+                    statement.push(quoted);
+
+                    if quoted == c {
+                        break;
+                    }
+                }
+            }
+            // Dollar signs inside identifiers do not start a quote.
+            '$' if !statement.ends_with(|c: char| c.is_alphanumeric() || c == '_') => {
+                statement.push(c);
+
+                // A dollar quote tag, like `$$` or `$body$`. Tags can't start
+                // with a digit, that would be a positional parameter (`$1`).
+                let mut tag = String::from("$");
+
+                while let Some(&next) = chars.peek() {
+                    let is_tag_char = next.is_alphanumeric() || next == '_';
+                    let is_parameter = tag == "$" && next.is_numeric();
+
+                    if next == '$' || (is_tag_char && !is_parameter) {
+                        chars.next();
+                        tag.push(next);
+                        statement.push(next);
+
+                        if next == '$' {
+                            break;
+                        }
+                    } else {
+                        break;
+                    }
+                }
+
+                if tag.len() < 2 || !tag.ends_with('$') {
+                    continue;
+                }
+
+                let mut quoted = String::new();
+
+                for c in chars.by_ref() {
+                    quoted.push(c);
+                    statement.push(c);
+
+                    if quoted.ends_with(tag.as_str()) {
+                        break;
+                    }
+                }
+            }
+            ';' => statements.push(std::mem::take(&mut statement)),
+            _ => statement.push(c),
+        }
+    }
+
+    statements.push(statement);
+
+    statements
+        .into_iter()
+        .map(|statement| statement.trim().to_owned())
+        .filter(|statement| !statement.is_empty())
+        .collect()
+}
+
+/// The name of the index created by a `CREATE INDEX CONCURRENTLY` statement,
+/// as the database stores it: unquoted, and folded to lower case if it was
+/// not quoted in the statement.
+pub(crate) fn concurrently_created_index(statement: &str) -> Option<String> {
+    static CREATE_INDEX_CONCURRENTLY_RE: Lazy<Regex> = Lazy::new(|| {
+        case_insensitive(
+            r#"^CREATE\s+(?:UNIQUE\s+)?INDEX\s+CONCURRENTLY\s+(?:IF\s+NOT\s+EXISTS\s+)?("(?:[^"]|"")+"|[^\s"]+)\s+ON\b"#,
+        )
+    });
+
+    CREATE_INDEX_CONCURRENTLY_RE
+        .captures(statement.trim_start())
+        .and_then(|captures| captures.get(1))
+        .map(|index_name| {
+            let index_name = index_name.as_str();
+
+            match index_name.strip_prefix('"').and_then(|name| name.strip_suffix('"')) {
+                Some(quoted_name) => quoted_name.replace("\"\"", "\""),
+                None => index_name.to_lowercase(),
+            }
+        })
+}
+
+/// The statements of a script that change something else than the tables,
+/// enums and indexes described by the Prisma schema: data changes, views,
+/// triggers, functions, permissions... The statements the engine renders to
+/// copy rows when it redefines a table, and the `DO` blocks guarding the
+/// statements of idempotent scripts, are not reported.
+pub(crate) fn non_schema_statements(script: &str) -> Vec<String> {
+    static NON_SCHEMA_STATEMENT_RE: Lazy<Regex> = Lazy::new(|| {
+        case_insensitive(
+            r#"^(?:INSERT|UPDATE|DELETE|MERGE|COPY|TRUNCATE|SELECT|CALL|GRANT|REVOKE|DO|COMMENT\s+ON|(?:CREATE|ALTER|DROP)\s+(?:OR\s+(?:REPLACE|ALTER)\s+)?(?:MATERIALIZED\s+)?(?:VIEW|TRIGGER|FUNCTION|PROCEDURE|RULE|POLICY|EXTENSION|AGGREGATE))\b"#,
+        )
+    });
+    static TABLE_REDEFINITION_COPY_RE: Lazy<Regex> =
+        Lazy::new(|| case_insensitive(r#"^INSERT\s+INTO\s+"new_[^"]+"\s*\([^)]*\)\s*SELECT\b"#));
+    static IDEMPOTENCY_GUARD_RE: Lazy<Regex> =
+        Lazy::new(|| case_insensitive(r#"^DO\s+\$\$\s*BEGIN\s+IF\s+(?:NOT\s+)?EXISTS\s*\("#));
+
+    split_statements(script)
+        .into_iter()
+        .filter(|statement| NON_SCHEMA_STATEMENT_RE.is_match(statement))
+        .filter(|statement| {
+            !TABLE_REDEFINITION_COPY_RE.is_match(statement) && !IDEMPOTENCY_GUARD_RE.is_match(statement)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_statements_skips_comments_and_quoted_semicolons() {
+        let script = r#"
+            -- CreateTable; with a comment
+            CREATE TABLE "Cat" ("name" TEXT NOT NULL DEFAULT 'a;b');
+
+            /* Warnings: ; */
+            CREATE INDEX "Cat.name_index" ON "Cat"("name");
+        "#;
+
+        assert_eq!(
+            split_statements(script),
+            &[
+                r#"CREATE TABLE "Cat" ("name" TEXT NOT NULL DEFAULT 'a;b')"#,
+                r#"CREATE INDEX "Cat.name_index" ON "Cat"("name")"#,
+            ]
+        );
+    }
+
+    #[test]
+    fn split_statements_keeps_dollar_quoted_bodies_together() {
+        let script = r#"
+            CREATE FUNCTION meow() RETURNS trigger AS $body$ BEGIN NEW.sound = 'meow'; RETURN NEW; END; $body$ LANGUAGE plpgsql;
+            DO $$ BEGIN PERFORM 1; END $$;
+            PREPARE meow (int) AS SELECT $1;
+        "#;
+
+        assert_eq!(
+            split_statements(script),
+            &[
+                r#"CREATE FUNCTION meow() RETURNS trigger AS $body$ BEGIN NEW.sound = 'meow'; RETURN NEW; END; $body$ LANGUAGE plpgsql"#,
+                "DO $$ BEGIN PERFORM 1; END $$",
+                "PREPARE meow (int) AS SELECT $1",
+            ]
+        );
+    }
+
+    #[test]
+    fn concurrently_created_index_finds_the_index_name() {
+        assert_eq!(
+            concurrently_created_index(r#"CREATE UNIQUE INDEX CONCURRENTLY "Cat.name_unique" ON "Cat"("name")"#),
+            Some("Cat.name_unique".to_owned())
+        );
+        assert_eq!(
+            concurrently_created_index(r#"CREATE INDEX CONCURRENTLY "Cat ""name""" ON "Cat"("name")"#),
+            Some(r#"Cat "name""#.to_owned())
+        );
+        assert_eq!(
+            concurrently_created_index("create index concurrently if not exists Cat_Name on cat(name)"),
+            Some("cat_name".to_owned())
+        );
+        assert_eq!(
+            concurrently_created_index(r#"CREATE INDEX "Cat.name_index" ON "Cat"("name")"#),
+            None
+        );
+    }
+    #[test]
+    fn non_schema_statements_finds_data_changes_views_and_functions() {
+        let script = r#"
+            CREATE TABLE "Role" ("name" TEXT NOT NULL);
+            INSERT INTO "Role" ("name") VALUES ('admin');
+            CREATE OR REPLACE VIEW "Admins" AS SELECT * FROM "Role" WHERE "name" = 'admin';
+            CREATE FUNCTION meow() RETURNS trigger AS $$ BEGIN RETURN NEW; END; $$ LANGUAGE plpgsql;
+            INSERT INTO "new_Role" ("name") SELECT "name" FROM "Role";
+            DO $$ BEGIN IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'Mood') THEN CREATE TYPE "Mood" AS ENUM ('HAPPY'); END IF; END $$;
+            DO $$ BEGIN IF EXISTS (SELECT 1 FROM pg_enum WHERE enumlabel = 'SAD') THEN DROP TYPE "Mood_old"; END IF; END $$;
+            CREATE UNIQUE INDEX "Role.name_unique" ON "Role"("name");
+        "#;
+
+        assert_eq!(
+            non_schema_statements(script),
+            &[
+                r#"INSERT INTO "Role" ("name") VALUES ('admin')"#,
+                r#"CREATE OR REPLACE VIEW "Admins" AS SELECT * FROM "Role" WHERE "name" = 'admin'"#,
+                r#"CREATE FUNCTION meow() RETURNS trigger AS $$ BEGIN RETURN NEW; END; $$ LANGUAGE plpgsql"#,
+            ]
+        );
+    }
+}
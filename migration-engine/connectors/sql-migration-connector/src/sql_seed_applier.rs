@@ -0,0 +1,170 @@
+//! The SQL implementation of SeedApplier. Records are upserted one by one: the
+//! existing record is looked up by the unique columns, then updated or
+//! inserted. This is the same on all the SQL databases, and applying a seed
+//! again updates the records in place instead of duplicating them.
+
+use crate::{connection_wrapper::Connection, SqlMigrationConnector};
+use migration_connector::{ConnectorError, ConnectorResult, ModelSeed, ModelSeedResult, SeedApplier};
+use prisma_value::PrismaValue;
+use quaint::ast::*;
+use std::collections::BTreeMap;
+
+#[async_trait::async_trait]
+impl SeedApplier for SqlMigrationConnector {
+    async fn apply_seed(&self, seeds: &[ModelSeed]) -> ConnectorResult<Vec<ModelSeedResult>> {
+        let conn = self.conn();
+
+        // A seed is applied entirely or not at all.
+        let begin = if conn.connection_info().sql_family().is_mssql() {
+            "BEGIN TRAN"
+        } else {
+            "BEGIN"
+        };
+
+        conn.raw_cmd(begin).await?;
+
+        match upsert_records(seeds, conn).await {
+            Ok(results) => {
+                conn.raw_cmd("COMMIT").await?;
+
+                Ok(results)
+            }
+            Err(err) => {
+                if let Err(rollback_err) = conn.raw_cmd("ROLLBACK").await {
+                    tracing::warn!(
+                        "Failed to roll back the seed transaction: {}",
+                        ConnectorError::from(rollback_err)
+                    );
+                }
+
+                Err(err)
+            }
+        }
+    }
+}
+
+async fn upsert_records(seeds: &[ModelSeed], conn: &Connection) -> ConnectorResult<Vec<ModelSeedResult>> {
+    let mut results = Vec::with_capacity(seeds.len());
+
+    for seed in seeds {
+        let mut result = ModelSeedResult {
+            model: seed.model.clone(),
+            inserted: 0,
+            updated: 0,
+        };
+
+        for record in &seed.records {
+            if record_exists(seed, record, conn).await? {
+                update_record(seed, record, conn).await?;
+                result.updated += 1;
+            } else {
+                insert_record(seed, record, conn).await?;
+                result.inserted += 1;
+            }
+        }
+
+        tracing::debug!(model = %seed.model, inserted = result.inserted, updated = result.updated, "Applied seed");
+
+        results.push(result);
+    }
+
+    Ok(results)
+}
+
+async fn record_exists(
+    seed: &ModelSeed,
+    record: &BTreeMap<String, PrismaValue>,
+    conn: &Connection,
+) -> ConnectorResult<bool> {
+    let query = Select::from_table(conn.table_name(&seed.table))
+        .value(count(asterisk()))
+        .so_that(unique_condition(seed, record));
+
+    let count = conn
+        .query(query)
+        .await?
+        .first()
+        .and_then(|row| row.at(0).and_then(|count| count.as_i64()))
+        .ok_or_else(|| {
+            ConnectorError::generic(anyhow::anyhow!(
+                "No count was returned when looking up a seed record in the `{}` table.",
+                seed.table
+            ))
+        })?;
+
+    Ok(count > 0)
+}
+
+async fn insert_record(
+    seed: &ModelSeed,
+    record: &BTreeMap<String, PrismaValue>,
+    conn: &Connection,
+) -> ConnectorResult<()> {
+    let insert = record.iter().fold(
+        Insert::single_into(conn.table_name(&seed.table)),
+        |insert, (column, value)| insert.value(column.as_str(), quaint_value(value)),
+    );
+
+    conn.execute(insert).await?;
+
+    Ok(())
+}
+
+async fn update_record(
+    seed: &ModelSeed,
+    record: &BTreeMap<String, PrismaValue>,
+    conn: &Connection,
+) -> ConnectorResult<()> {
+    let mut changed_columns = record
+        .iter()
+        .filter(|(column, _)| !seed.unique_columns.contains(column))
+        .peekable();
+
+    // Nothing to update besides the unique columns.
+    if changed_columns.peek().is_none() {
+        return Ok(());
+    }
+
+    let update = changed_columns.fold(
+        Update::table(conn.table_name(&seed.table)).so_that(unique_condition(seed, record)),
+        |update, (column, value)| update.set(column.as_str(), quaint_value(value)),
+    );
+
+    conn.execute(update).await?;
+
+    Ok(())
+}
+
+fn unique_condition<'a>(seed: &'a ModelSeed, record: &BTreeMap<String, PrismaValue>) -> ConditionTree<'a> {
+    ConditionTree::And(
+        seed.unique_columns
+            .iter()
+            .map(|column| {
+                Column::from(column.as_str())
+                    .equals(quaint_value(&record[column]))
+                    .into()
+            })
+            .collect(),
+    )
+}
+
+fn quaint_value(value: &PrismaValue) -> Value<'static> {
+    match value {
+        PrismaValue::String(s) => s.clone().into(),
+        PrismaValue::Boolean(b) => (*b).into(),
+        PrismaValue::Enum(e) => Value::Enum(Some(e.clone().into())),
+        PrismaValue::Int(i) | PrismaValue::BigInt(i) => (*i).into(),
+        PrismaValue::Float(f) | PrismaValue::Decimal(f) => f.clone().into(),
+        PrismaValue::DateTime(d) => d.with_timezone(&chrono::Utc).into(),
+        PrismaValue::Date(d) => Value::Date(Some(*d)),
+        PrismaValue::Time(t) => Value::Time(Some(*t)),
+        PrismaValue::Json(s) => Value::Json(serde_json::from_str(s).ok()),
+        PrismaValue::Bytes(b) => Value::Bytes(Some(b.clone().into())),
+        PrismaValue::List(values) => Value::Array(Some(values.iter().map(quaint_value).collect())),
+        PrismaValue::Uuid(u) => u.to_string().into(),
+        PrismaValue::Xml(s) => Value::Xml(Some(s.clone().into())),
+        PrismaValue::UnsignedBigInt(_) => unreachable!("Seed validation does not produce unsigned big integers."),
+        // The databases accept untyped nulls in any column.
+        PrismaValue::Null => Value::Text(None),
+    }
+}
@@ -36,6 +36,11 @@ pub trait GenericApi: Send + Sync + 'static {
     async fn version(&self, input: &serde_json::Value) -> CoreResult<String>;
     async fn apply_migrations(&self, input: &ApplyMigrationsInput) -> CoreResult<ApplyMigrationsOutput>;
     async fn apply_script(&self, input: &ApplyScriptInput) -> CoreResult<ApplyScriptOutput>;
+    async fn apply_seed(&self, input: &ApplySeedInput) -> CoreResult<ApplySeedOutput>;
+    async fn create_baseline_migration(
+        &self,
+        input: &CreateBaselineMigrationInput,
+    ) -> CoreResult<CreateBaselineMigrationOutput>;
     async fn create_migration(&self, input: &CreateMigrationInput) -> CoreResult<CreateMigrationOutput>;
     async fn debug_panic(&self, input: &()) -> CoreResult<()>;
     async fn dev_diagnostic(&self, input: &DevDiagnosticInput) -> CoreResult<DevDiagnosticOutput>;
@@ -44,6 +49,7 @@ pub trait GenericApi: Send + Sync + 'static {
         input: &DiagnoseMigrationHistoryInput,
     ) -> CoreResult<DiagnoseMigrationHistoryOutput>;
     async fn evaluate_data_loss(&self, input: &EvaluateDataLossInput) -> CoreResult<EvaluateDataLossOutput>;
+    async fn lint_migrations(&self, input: &LintMigrationsInput) -> CoreResult<LintMigrationsOutput>;
     async fn list_migration_directories(
         &self,
         input: &ListMigrationDirectoriesInput,
@@ -57,6 +63,7 @@ pub trait GenericApi: Send + Sync + 'static {
     async fn plan_migration(&self, input: &PlanMigrationInput) -> CoreResult<PlanMigrationOutput>;
     async fn reset(&self, input: &()) -> CoreResult<()>;
     async fn schema_push(&self, input: &SchemaPushInput) -> CoreResult<SchemaPushOutput>;
+    async fn squash_migrations(&self, input: &SquashMigrationsInput) -> CoreResult<SquashMigrationsOutput>;
 }
 
 #[async_trait::async_trait]
@@ -79,6 +86,27 @@ impl<C: MigrationConnector> GenericApi for MigrationApi<C> {
             .await
     }
 
+    async fn apply_seed(&self, input: &ApplySeedInput) -> CoreResult<ApplySeedOutput> {
+        self.handle_command::<ApplySeedCommand>(input)
+            .instrument(tracing::info_span!(
+                "ApplySeed",
+                seed_file_path = input.seed_file_path.as_str()
+            ))
+            .await
+    }
+
+    async fn create_baseline_migration(
+        &self,
+        input: &CreateBaselineMigrationInput,
+    ) -> CoreResult<CreateBaselineMigrationOutput> {
+        self.handle_command::<CreateBaselineMigrationCommand>(input)
+            .instrument(tracing::info_span!(
+                "CreateBaselineMigration",
+                migration_name = input.migration_name.as_str()
+            ))
+            .await
+    }
+
     async fn create_migration(&self, input: &CreateMigrationInput) -> CoreResult<CreateMigrationOutput> {
         self.handle_command::<CreateMigrationCommand>(input)
             .instrument(tracing::info_span!(
@@ -116,6 +144,12 @@ impl<C: MigrationConnector> GenericApi for MigrationApi<C> {
             .await
     }
 
+    async fn lint_migrations(&self, input: &LintMigrationsInput) -> CoreResult<LintMigrationsOutput> {
+        self.handle_command::<LintMigrationsCommand>(input)
+            .instrument(tracing::info_span!("LintMigrations"))
+            .await
+    }
+
     async fn list_migration_directories(
         &self,
         input: &ListMigrationDirectoriesInput,
@@ -166,4 +200,10 @@ impl<C: MigrationConnector> GenericApi for MigrationApi<C> {
             .instrument(tracing::info_span!("SchemaPush"))
             .await
     }
+
+    async fn squash_migrations(&self, input: &SquashMigrationsInput) -> CoreResult<SquashMigrationsOutput> {
+        self.handle_command::<SquashMigrationsCommand>(input)
+            .instrument(tracing::info_span!("SquashMigrations"))
+            .await
+    }
 }
@@ -13,18 +13,22 @@ pub struct RpcApi {
 enum RpcCommand {
     ApplyMigrations,
     ApplyScript,
+    ApplySeed,
+    CreateBaselineMigration,
     CreateMigration,
     DebugPanic,
     DevDiagnostic,
     DiagnoseMigrationHistory,
     EvaluateDataLoss,
     GetDatabaseVersion,
+    LintMigrations,
     ListMigrationDirectories,
     MarkMigrationApplied,
     MarkMigrationRolledBack,
     PlanMigration,
     Reset,
     SchemaPush,
+    SquashMigrations,
 }
 
 impl RpcCommand {
@@ -32,18 +36,22 @@ impl RpcCommand {
         match self {
             RpcCommand::ApplyMigrations => "applyMigrations",
             RpcCommand::ApplyScript => "applyScript",
+            RpcCommand::ApplySeed => "applySeed",
+            RpcCommand::CreateBaselineMigration => "createBaselineMigration",
             RpcCommand::CreateMigration => "createMigration",
             RpcCommand::DebugPanic => "debugPanic",
             RpcCommand::DevDiagnostic => "devDiagnostic",
             RpcCommand::DiagnoseMigrationHistory => "diagnoseMigrationHistory",
             RpcCommand::EvaluateDataLoss => "evaluateDataLoss",
             RpcCommand::GetDatabaseVersion => "getDatabaseVersion",
+            RpcCommand::LintMigrations => "lintMigrations",
             RpcCommand::ListMigrationDirectories => "listMigrationDirectories",
             RpcCommand::MarkMigrationApplied => "markMigrationApplied",
             RpcCommand::MarkMigrationRolledBack => "markMigrationRolledBack",
             RpcCommand::PlanMigration => "planMigration",
             RpcCommand::Reset => "reset",
             RpcCommand::SchemaPush => "schemaPush",
+            RpcCommand::SquashMigrations => "squashMigrations",
         }
     }
 }
@@ -51,18 +59,22 @@ impl RpcCommand {
 const AVAILABLE_COMMANDS: &[RpcCommand] = &[
     RpcCommand::ApplyMigrations,
     RpcCommand::ApplyScript,
+    RpcCommand::ApplySeed,
+    RpcCommand::CreateBaselineMigration,
     RpcCommand::CreateMigration,
     RpcCommand::DebugPanic,
     RpcCommand::DevDiagnostic,
     RpcCommand::DiagnoseMigrationHistory,
     RpcCommand::EvaluateDataLoss,
     RpcCommand::GetDatabaseVersion,
+    RpcCommand::LintMigrations,
     RpcCommand::ListMigrationDirectories,
     RpcCommand::MarkMigrationApplied,
     RpcCommand::MarkMigrationRolledBack,
     RpcCommand::PlanMigration,
     RpcCommand::Reset,
     RpcCommand::SchemaPush,
+    RpcCommand::SquashMigrations,
 ];
 
 impl RpcApi {
@@ -115,6 +127,8 @@ impl RpcApi {
         Ok(match cmd {
             RpcCommand::ApplyScript => render(executor.apply_script(&params.parse()?).await?),
             RpcCommand::ApplyMigrations => render(executor.apply_migrations(&params.parse()?).await?),
+            RpcCommand::ApplySeed => render(executor.apply_seed(&params.parse()?).await?),
+            RpcCommand::CreateBaselineMigration => render(executor.create_baseline_migration(&params.parse()?).await?),
             RpcCommand::CreateMigration => render(executor.create_migration(&params.parse()?).await?),
             RpcCommand::DevDiagnostic => render(executor.dev_diagnostic(&params.parse()?).await?),
             RpcCommand::DebugPanic => render(executor.debug_panic(&()).await?),
@@ -123,6 +137,7 @@ impl RpcApi {
             }
             RpcCommand::EvaluateDataLoss => render(executor.evaluate_data_loss(&params.parse()?).await?),
             RpcCommand::GetDatabaseVersion => render(executor.version(&serde_json::Value::Null).await?),
+            RpcCommand::LintMigrations => render(executor.lint_migrations(&params.parse()?).await?),
             RpcCommand::ListMigrationDirectories => {
                 render(executor.list_migration_directories(&params.parse()?).await?)
             }
@@ -131,6 +146,7 @@ impl RpcApi {
             RpcCommand::PlanMigration => render(executor.plan_migration(&params.parse()?).await?),
             RpcCommand::Reset => render(executor.reset(&()).await?),
             RpcCommand::SchemaPush => render(executor.schema_push(&params.parse()?).await?),
+            RpcCommand::SquashMigrations => render(executor.squash_migrations(&params.parse()?).await?),
         })
     }
 }
@@ -3,23 +3,31 @@
 
 mod apply_migrations;
 mod apply_script;
+mod apply_seed;
 mod command;
+mod create_baseline_migration;
 mod create_migration;
 mod debug_panic;
 mod dev_diagnostic;
 mod diagnose_migration_history;
 mod evaluate_data_loss;
 mod get_database_version;
+mod lint_migrations;
 mod list_migration_directories;
 mod mark_migration_applied;
 mod mark_migration_rolled_back;
 mod plan_migration;
 mod reset;
 mod schema_push;
+mod squash_migrations;
 
 pub use apply_migrations::{ApplyMigrationsCommand, ApplyMigrationsInput, ApplyMigrationsOutput};
 pub use apply_script::{ApplyScriptCommand, ApplyScriptInput, ApplyScriptOutput};
+pub use apply_seed::{ApplySeedCommand, ApplySeedInput, ApplySeedOutput};
 pub use command::MigrationCommand;
+pub use create_baseline_migration::{
+    CreateBaselineMigrationCommand, CreateBaselineMigrationInput, CreateBaselineMigrationOutput,
+};
 pub use create_migration::{CreateMigrationCommand, CreateMigrationInput, CreateMigrationOutput};
 pub use debug_panic::DebugPanicCommand;
 pub use dev_diagnostic::{DevAction, DevDiagnosticCommand, DevDiagnosticInput, DevDiagnosticOutput};
@@ -29,6 +37,7 @@ pub use diagnose_migration_history::{
 };
 pub use evaluate_data_loss::*;
 pub use get_database_version::*;
+pub use lint_migrations::{LintMigrationsCommand, LintMigrationsInput, LintMigrationsOutput, LintedMigration};
 pub use list_migration_directories::*;
 pub use mark_migration_applied::{MarkMigrationAppliedCommand, MarkMigrationAppliedInput, MarkMigrationAppliedOutput};
 pub use mark_migration_rolled_back::{
@@ -37,3 +46,4 @@ pub use mark_migration_rolled_back::{
 pub use plan_migration::{PlanMigrationCommand, PlanMigrationInput, PlanMigrationOutput};
 pub use reset::ResetCommand;
 pub use schema_push::{SchemaPushCommand, SchemaPushInput, SchemaPushOutput};
+pub use squash_migrations::{DroppedStatement, SquashMigrationsCommand, SquashMigrationsInput, SquashMigrationsOutput};
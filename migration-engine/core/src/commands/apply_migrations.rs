@@ -1,8 +1,11 @@
 use crate::{api::MigrationApi, CoreError, CoreResult};
-use migration_connector::{ConnectorError, MigrationDirectory, MigrationRecord, PersistenceNotInitializedError};
+use migration_connector::{
+    is_non_transactional_script, squashed_migration_names, ConnectorError, MigrationDirectory, MigrationRecord,
+    PersistenceNotInitializedError,
+};
 use serde::{Deserialize, Serialize};
 use std::path::Path;
-use user_facing_errors::migration_engine::FoundFailedMigrations;
+use user_facing_errors::migration_engine::{FoundFailedMigrations, SquashedMigrationPartiallyApplied};
 
 use super::MigrationCommand;
 
@@ -12,6 +15,10 @@ use super::MigrationCommand;
 pub struct ApplyMigrationsInput {
     /// The location of the migrations directory.
     pub migrations_directory_path: String,
+    /// Resume the failed non-transactional migrations from the statement that
+    /// failed. Without it, they are reported like other failed migrations.
+    #[serde(default)]
+    pub resume_failed_migrations: bool,
 }
 
 /// The output of the `ApplyMigrations` command.
@@ -52,23 +59,37 @@ impl<'a> MigrationCommand for ApplyMigrationsCommand {
             .await?
             .map_err(PersistenceNotInitializedError::into_connector_error)?;
 
-        detect_failed_migrations(&migrations_from_database)?;
+        detect_failed_migrations(
+            &migrations_from_database,
+            &migrations_from_filesystem,
+            input.resume_failed_migrations,
+        )?;
 
         // We are now on the Happy Path™.
         tracing::debug!("Migration history is OK, applying unapplied migrations.");
-        let unapplied_migrations: Vec<&MigrationDirectory> = migrations_from_filesystem
+        let unapplied_migrations: Vec<(&MigrationDirectory, Option<&MigrationRecord>)> = migrations_from_filesystem
             .iter()
-            .filter(|fs_migration| {
-                !migrations_from_database
+            .filter_map(|fs_migration| {
+                let mut db_migrations = migrations_from_database
                     .iter()
                     .filter(|db_migration| db_migration.rolled_back_at.is_none())
-                    .any(|db_migration| fs_migration.migration_name() == db_migration.migration_name)
+                    .filter(|db_migration| fs_migration.migration_name() == db_migration.migration_name);
+
+                match db_migrations.next() {
+                    None => Some((fs_migration, None)),
+                    Some(db_migration)
+                        if input.resume_failed_migrations && is_resumable(db_migration, fs_migration) =>
+                    {
+                        Some((fs_migration, Some(db_migration)))
+                    }
+                    Some(_) => None,
+                }
             })
             .collect();
 
         let mut applied_migration_names: Vec<String> = Vec::with_capacity(unapplied_migrations.len());
 
-        for unapplied_migration in unapplied_migrations {
+        for (unapplied_migration, failed_attempt) in unapplied_migrations {
             let span = tracing::info_span!(
                 "Applying migration",
                 migration_name = unapplied_migration.migration_name(),
@@ -85,6 +106,51 @@ impl<'a> MigrationCommand for ApplyMigrationsCommand {
                 unapplied_migration.migration_name()
             );
 
+            if failed_attempt.is_none() {
+                if let Some(unapplied_squashed_migrations) =
+                    unapplied_squashed_migrations(&script, &migrations_from_database)
+                {
+                    if unapplied_squashed_migrations.is_empty() {
+                        tracing::info!(
+                            "The migrations squashed in `{}` were already applied, marking it as applied.",
+                            unapplied_migration.migration_name()
+                        );
+
+                        migration_persistence
+                            .mark_migration_applied(unapplied_migration.migration_name(), &script)
+                            .await?;
+
+                        continue;
+                    }
+
+                    if unapplied_squashed_migrations.len() < squashed_migration_names(&script).len() {
+                        return Err(CoreError::user_facing(SquashedMigrationPartiallyApplied {
+                            migration_name: unapplied_migration.migration_name().to_owned(),
+                            unapplied_migration_names: unapplied_squashed_migrations.join(", "),
+                        }));
+                    }
+                }
+            }
+
+            if is_non_transactional_script(&script) {
+                let (migration_id, applied_statements_count) = match failed_attempt {
+                    Some(failed_attempt) => (failed_attempt.id.clone(), failed_attempt.applied_steps_count as usize),
+                    None => {
+                        let migration_id = migration_persistence
+                            .record_migration_started(unapplied_migration.migration_name(), &script)
+                            .await?;
+
+                        (migration_id, 0)
+                    }
+                };
+
+                apply_non_transactional_script(&script, &migration_id, applied_statements_count, connector).await?;
+                migration_persistence.record_migration_finished(&migration_id).await?;
+                applied_migration_names.push(unapplied_migration.migration_name().to_owned());
+
+                continue;
+            }
+
             let migration_id = migration_persistence
                 .record_migration_started(unapplied_migration.migration_name(), &script)
                 .await?;
@@ -114,7 +180,112 @@ impl<'a> MigrationCommand for ApplyMigrationsCommand {
     }
 }
 
-fn detect_failed_migrations(migrations_from_database: &[MigrationRecord]) -> CoreResult<()> {
+/// Apply a non-transactional migration script statement by statement, skipping
+/// the statements that were applied by a previous attempt. The number of
+/// applied statements is recorded after each statement, so a failed attempt can
+/// be resumed where it stopped.
+async fn apply_non_transactional_script<C>(
+    script: &str,
+    migration_id: &str,
+    applied_statements_count: usize,
+    connector: &C,
+) -> CoreResult<()>
+where
+    C: migration_connector::MigrationConnector,
+{
+    let applier = connector.database_migration_step_applier();
+    let migration_persistence = connector.migration_persistence();
+    let statements = applier.split_script(script);
+
+    if applied_statements_count > 0 {
+        tracing::info!(
+            "Resuming the migration from statement {} of {}.",
+            applied_statements_count + 1,
+            statements.len()
+        );
+    }
+
+    for (statement_index, statement) in statements.iter().enumerate().skip(applied_statements_count) {
+        match applier.apply_script(statement).await {
+            Ok(()) => migration_persistence.record_successful_step(migration_id).await?,
+            Err(err) => {
+                tracing::debug!("Failed to apply statement {} of the script.", statement_index + 1);
+
+                if let Err(clean_up_err) = applier.clean_up_failed_statement(statement).await {
+                    tracing::warn!("Failed to clean up after the failed statement: {}", clean_up_err);
+                }
+
+                let logs = format!(
+                    "Statement {statement_number} of {statements_count} failed. The migration is not transactional, the {applied_count} statements before it were applied and will not be run again when the migration is resumed.\n\n{statement}\n\n{error}",
+                    statement_number = statement_index + 1,
+                    statements_count = statements.len(),
+                    applied_count = statement_index,
+                    statement = statement,
+                    error = err,
+                );
+
+                migration_persistence.record_failed_step(migration_id, &logs).await?;
+
+                return Err(err.into());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// The migrations replaced by a squashed migration script that were not
+/// applied to the database. `None` if the script was not squashed.
+pub(super) fn unapplied_squashed_migrations<'a>(
+    script: &'a str,
+    migrations_from_database: &[MigrationRecord],
+) -> Option<Vec<&'a str>> {
+    let squashed_migration_names = squashed_migration_names(script);
+
+    if squashed_migration_names.is_empty() {
+        return None;
+    }
+
+    let unapplied_migration_names = squashed_migration_names
+        .into_iter()
+        .filter(|name| {
+            !migrations_from_database.iter().any(|db_migration| {
+                db_migration.migration_name == *name
+                    && db_migration.finished_at.is_some()
+                    && db_migration.rolled_back_at.is_none()
+            })
+        })
+        .collect();
+
+    Some(unapplied_migration_names)
+}
+
+/// Failed non-transactional migrations can be resumed from the first statement
+/// that did not complete, as long as their script was not edited since.
+fn is_resumable(db_migration: &MigrationRecord, fs_migration: &MigrationDirectory) -> bool {
+    db_migration.finished_at.is_none()
+        && db_migration.rolled_back_at.is_none()
+        && fs_migration.matches_checksum(&db_migration.checksum).unwrap_or(false)
+        && fs_migration
+            .read_migration_script()
+            .map(|script| is_non_transactional_script(&script))
+            .unwrap_or(false)
+}
+
+fn is_resumable_on_filesystem(
+    db_migration: &MigrationRecord,
+    migrations_from_filesystem: &[MigrationDirectory],
+) -> bool {
+    migrations_from_filesystem.iter().any(|fs_migration| {
+        fs_migration.migration_name() == db_migration.migration_name && is_resumable(db_migration, fs_migration)
+    })
+}
+
+fn detect_failed_migrations(
+    migrations_from_database: &[MigrationRecord],
+    migrations_from_filesystem: &[MigrationDirectory],
+    resume_failed_migrations: bool,
+) -> CoreResult<()> {
     use std::fmt::Write as _;
 
     tracing::debug!("Checking for failed migrations.");
@@ -122,6 +293,9 @@ fn detect_failed_migrations(migrations_from_database: &[MigrationRecord]) -> Cor
     let mut failed_migrations = migrations_from_database
         .iter()
         .filter(|migration| migration.finished_at.is_none() && migration.rolled_back_at.is_none())
+        .filter(|migration| {
+            !resume_failed_migrations || !is_resumable_on_filesystem(migration, migrations_from_filesystem)
+        })
         .peekable();
 
     if failed_migrations.peek().is_none() {
@@ -143,6 +317,14 @@ fn detect_failed_migrations(migrations_from_database: &[MigrationRecord]) -> Cor
             }
         )
         .unwrap();
+
+        if is_resumable_on_filesystem(failed_migration, migrations_from_filesystem) {
+            writeln!(
+                details,
+                "The migration is not transactional. Resume it from the statement that failed once the cause of the failure is fixed."
+            )
+            .unwrap();
+        }
     }
 
     Err(CoreError::user_facing(FoundFailedMigrations { details }))
@@ -0,0 +1,65 @@
+use super::MigrationCommand;
+use crate::{api::MigrationApi, parse_datamodel, CoreError, CoreResult};
+use migration_connector::{MigrationConnector, ModelSeedResult};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use user_facing_errors::migration_engine::InvalidSeedFile;
+
+/// The input to the `applySeed` command.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplySeedInput {
+    /// The prisma schema the seed file is validated against.
+    pub schema: String,
+    /// The filesystem path of the seed file. Files with a `.yaml` or `.yml`
+    /// extension are read as YAML, other files as JSON.
+    pub seed_file_path: String,
+}
+
+/// The output of the `applySeed` command.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplySeedOutput {
+    /// The number of inserted and updated records for each seeded model, in
+    /// the order they were seeded.
+    pub seeded_models: Vec<ModelSeedResult>,
+}
+
+/// Upsert the records of a seed file into the database. Seed files map model
+/// names to arrays of records. Existing records are matched by their id or
+/// unique fields, so the command is meant to be run again after each
+/// `applyMigrations` or `schemaPush`.
+pub struct ApplySeedCommand;
+
+#[async_trait::async_trait]
+impl<'a> MigrationCommand for ApplySeedCommand {
+    type Input = ApplySeedInput;
+    type Output = ApplySeedOutput;
+
+    async fn execute<C: MigrationConnector>(input: &Self::Input, engine: &MigrationApi<C>) -> CoreResult<Self::Output> {
+        let datamodel = parse_datamodel(&input.schema)?;
+        let invalid_seed_file = |reason: String| {
+            CoreError::user_facing(InvalidSeedFile {
+                seed_file_path: input.seed_file_path.clone(),
+                reason,
+            })
+        };
+
+        let path = Path::new(&input.seed_file_path);
+        let contents = std::fs::read_to_string(path).map_err(|err| invalid_seed_file(err.to_string()))?;
+
+        let seed: serde_json::Value = match path.extension().and_then(|extension| extension.to_str()) {
+            Some("yaml") | Some("yml") => {
+                serde_yaml::from_str(&contents).map_err(|err| invalid_seed_file(err.to_string()))?
+            }
+            _ => serde_json::from_str(&contents).map_err(|err| invalid_seed_file(err.to_string()))?,
+        };
+
+        let seeds = migration_connector::validate_seed(&datamodel, &seed)
+            .map_err(|errors| invalid_seed_file(errors.join("\n")))?;
+
+        let seeded_models = engine.connector().seed_applier().apply_seed(&seeds).await?;
+
+        Ok(ApplySeedOutput { seeded_models })
+    }
+}
@@ -0,0 +1,106 @@
+use super::MigrationCommand;
+use crate::{api::MigrationApi, CoreError, CoreResult};
+use migration_connector::{DatabaseMigrationMarker, MigrationConnector, ScriptMode};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use user_facing_errors::migration_engine::{CannotBaselineMigrationHistory, MigrationNameTooLong};
+
+/// The input to the `createBaselineMigration` command.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateBaselineMigrationInput {
+    /// The filesystem path of the migrations directory to use.
+    pub migrations_directory_path: String,
+    /// The user-given name for the migration. This will be used in the migration directory.
+    pub migration_name: String,
+}
+
+/// The output of the `createBaselineMigration` command.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateBaselineMigrationOutput {
+    /// The name of the newly generated migration directory.
+    pub generated_migration_name: String,
+}
+
+/// Adopt migrations on an existing database: create an initial migration
+/// recreating the current database schema, and record it as applied in the
+/// database.
+pub struct CreateBaselineMigrationCommand;
+
+#[async_trait::async_trait]
+impl<'a> MigrationCommand for CreateBaselineMigrationCommand {
+    type Input = CreateBaselineMigrationInput;
+    type Output = CreateBaselineMigrationOutput;
+
+    async fn execute<C: MigrationConnector>(input: &Self::Input, engine: &MigrationApi<C>) -> CoreResult<Self::Output> {
+        let connector = engine.connector();
+        let inferrer = connector.database_migration_inferrer();
+        let applier = connector.database_migration_step_applier();
+        let checker = connector.destructive_change_checker();
+        let migration_persistence = connector.migration_persistence();
+
+        if input.migration_name.len() > 200 {
+            return Err(CoreError::user_facing(MigrationNameTooLong));
+        }
+
+        migration_connector::error_on_changed_provider(&input.migrations_directory_path, connector.connector_type())?;
+
+        let migrations_directory_path = Path::new(&input.migrations_directory_path);
+
+        if migrations_directory_path.exists()
+            && !migration_connector::list_migrations(migrations_directory_path)?.is_empty()
+        {
+            return Err(CoreError::user_facing(CannotBaselineMigrationHistory {
+                reason: "The migrations directory already contains migrations.".into(),
+            }));
+        }
+
+        let has_migrations_table = match migration_persistence.list_migrations().await? {
+            Ok(migrations) if !migrations.is_empty() => {
+                return Err(CoreError::user_facing(CannotBaselineMigrationHistory {
+                    reason: "The database already has migrations recorded in the migrations table.".into(),
+                }))
+            }
+            Ok(_) => true,
+            Err(_) => false,
+        };
+
+        let migration = inferrer.infer_baseline_migration().await?;
+        let destructive_change_diagnostics = checker.pure_check(&migration);
+        let migration_script = applier.render_script(&migration, &destructive_change_diagnostics, ScriptMode::Default);
+
+        let directory =
+            migration_connector::create_migration_directory(migrations_directory_path, &input.migration_name)
+                .map_err(|_| CoreError::Generic(anyhow::anyhow!("Failed to create a new migration directory.")))?;
+
+        directory
+            .write_migration_script(&migration_script, C::DatabaseMigration::FILE_EXTENSION)
+            .map_err(|err| {
+                CoreError::Generic(anyhow::Error::new(err).context(format!(
+                    "Failed to write the migration script to `{:?}`",
+                    directory.path(),
+                )))
+            })?;
+
+        migration_connector::write_migration_lock_file(&input.migrations_directory_path, connector.connector_type())
+            .map_err(|err| {
+                CoreError::Generic(anyhow::Error::new(err).context(format!(
+                    "Failed to write the migration lock file to `{:?}`",
+                    &input.migrations_directory_path
+                )))
+            })?;
+
+        if !has_migrations_table {
+            migration_persistence.baseline_initialize().await?;
+        }
+
+        migration_persistence
+            .mark_migration_applied(directory.migration_name(), &migration_script)
+            .await?;
+
+        Ok(CreateBaselineMigrationOutput {
+            generated_migration_name: directory.migration_name().to_owned(),
+        })
+    }
+}
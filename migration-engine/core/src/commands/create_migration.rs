@@ -1,9 +1,11 @@
 use super::MigrationCommand;
 use crate::{api::MigrationApi, parse_datamodel, CoreError, CoreResult};
-use migration_connector::{DatabaseMigrationMarker, MigrationConnector};
+use migration_connector::{
+    DatabaseMigrationMarker, EnumValueRename, LintDiagnostic, LintRules, MigrationConnector, ScriptMode,
+};
 use serde::{Deserialize, Serialize};
 use std::path::Path;
-use user_facing_errors::migration_engine::{MigrationNameTooLong, ProviderSwitchedError};
+use user_facing_errors::migration_engine::{MigrationLintFailed, MigrationNameTooLong, ProviderSwitchedError};
 
 /// Create and potentially apply a new migration.
 pub struct CreateMigrationCommand;
@@ -20,6 +22,16 @@ pub struct CreateMigrationInput {
     pub migration_name: String,
     /// If true, always generate a migration, but do not apply.
     pub draft: bool,
+    /// The severities of the migration lint rules, by rule name.
+    #[serde(default)]
+    pub lint_rules: LintRules,
+    /// How the migration script is rendered. Idempotent scripts can be run
+    /// again after a partial run, when migrations are applied manually.
+    #[serde(default)]
+    pub script_mode: ScriptMode,
+    /// The enum values that were renamed, to migrate the rows holding them.
+    #[serde(default)]
+    pub enum_value_renames: Vec<EnumValueRename>,
 }
 
 /// The output of the `createMigration` command.
@@ -28,6 +40,8 @@ pub struct CreateMigrationInput {
 pub struct CreateMigrationOutput {
     /// The name of the newly generated migration directory, if any.
     pub generated_migration_name: Option<String>,
+    /// Lint rule violations in the generated migration.
+    pub lints: Vec<LintDiagnostic>,
 }
 
 #[async_trait::async_trait]
@@ -40,6 +54,9 @@ impl<'a> MigrationCommand for CreateMigrationCommand {
         let database_migration_inferrer = engine.connector().database_migration_inferrer();
         let applier = engine.connector().database_migration_step_applier();
         let checker = engine.connector().destructive_change_checker();
+        let linter = engine.connector().migration_linter();
+
+        input.lint_rules.check_rule_names(linter.rule_names())?;
 
         if input.migration_name.len() > 200 {
             return Err(CoreError::user_facing(MigrationNameTooLong));
@@ -61,7 +78,7 @@ impl<'a> MigrationCommand for CreateMigrationCommand {
         let target_schema = parse_datamodel(&input.prisma_schema)?;
 
         let migration = database_migration_inferrer
-            .infer_next_migration(&previous_migrations, &target_schema)
+            .infer_next_migration(&previous_migrations, &target_schema, &input.enum_value_renames)
             .await?;
 
         if migration.is_empty() && !input.draft {
@@ -69,12 +86,27 @@ impl<'a> MigrationCommand for CreateMigrationCommand {
 
             return Ok(CreateMigrationOutput {
                 generated_migration_name: None,
+                lints: Vec::new(),
             });
         }
 
+        let lints = linter.lint(&migration, &input.lint_rules);
+
+        // Drafts are meant to be edited, so lint errors only block regular migrations.
+        if !input.draft && lints.iter().any(|lint| lint.is_error()) {
+            let details = lints
+                .iter()
+                .filter(|lint| lint.is_error())
+                .map(|lint| format!("  - {} ({})", lint.description, lint.rule))
+                .collect::<Vec<_>>()
+                .join("\n");
+
+            return Err(CoreError::user_facing(MigrationLintFailed { details }));
+        }
+
         let destructive_change_diagnostics = checker.pure_check(&migration);
 
-        let migration_script = applier.render_script(&migration, &destructive_change_diagnostics);
+        let migration_script = applier.render_script(&migration, &destructive_change_diagnostics, input.script_mode);
 
         // Write the migration script to a file.
         let directory = migration_connector::create_migration_directory(
@@ -103,6 +135,7 @@ impl<'a> MigrationCommand for CreateMigrationCommand {
 
         Ok(CreateMigrationOutput {
             generated_migration_name: Some(directory.migration_name().to_owned()),
+            lints,
         })
     }
 }
@@ -84,7 +84,7 @@ fn check_for_reset_conditions(output: &DiagnoseMigrationHistoryOutput) -> Option
         ))
     }
 
-    if let Some(DriftDiagnostic::DriftDetected { rollback: _ }) = &output.drift {
+    if let Some(DriftDiagnostic::DriftDetected { .. }) = &output.drift {
         reset_reasons
             .push("Drift detected: Your database schema is not in sync with your migration history.".to_owned())
     }
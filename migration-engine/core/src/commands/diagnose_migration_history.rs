@@ -1,8 +1,11 @@
-use std::path::Path;
+use std::{collections::HashSet, path::Path};
 
-use super::MigrationCommand;
+use super::{apply_migrations::unapplied_squashed_migrations, MigrationCommand};
 use crate::{api::MigrationApi, CoreResult};
-use migration_connector::{MigrationConnector, MigrationDirectory, MigrationRecord, PersistenceNotInitializedError};
+use migration_connector::{
+    ConnectorError, Drift, DriftReport, MigrationConnector, MigrationDirectory, MigrationRecord,
+    PersistenceNotInitializedError,
+};
 use serde::{Deserialize, Serialize};
 
 /// The input to the `DiagnoseMigrationHistory` command.
@@ -88,6 +91,28 @@ impl<'a> MigrationCommand for DiagnoseMigrationHistoryCommand {
 
         let mut diagnostics = Diagnostics::new(&migrations_from_filesystem);
 
+        // Squashed migrations stand for the migrations they replace, in both histories.
+        let scripts_from_filesystem = migrations_from_filesystem
+            .iter()
+            .map(|fs_migration| fs_migration.read_migration_script())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(ConnectorError::from)?;
+
+        let squashed_migration_names: HashSet<&str> = scripts_from_filesystem
+            .iter()
+            .flat_map(|script| migration_connector::squashed_migration_names(script))
+            .collect();
+
+        let applied_through_squashed_migrations: Vec<bool> = scripts_from_filesystem
+            .iter()
+            .map(|script| {
+                matches!(
+                    unapplied_squashed_migrations(script, &migrations_from_database),
+                    Some(unapplied_migration_names) if unapplied_migration_names.is_empty()
+                )
+            })
+            .collect();
+
         // Check filesystem history against database history.
         for (index, fs_migration) in migrations_from_filesystem.iter().enumerate() {
             let corresponding_db_migration = migrations_from_database
@@ -103,6 +128,7 @@ impl<'a> MigrationCommand for DiagnoseMigrationHistoryCommand {
                     diagnostics.edited_migrations.push(db_migration);
                 }
                 Some(_) => (),
+                None if applied_through_squashed_migrations[index] => (),
                 None => diagnostics.fs_migrations_not_in_db.push((index, fs_migration)),
             }
         }
@@ -116,7 +142,9 @@ impl<'a> MigrationCommand for DiagnoseMigrationHistoryCommand {
                 diagnostics.failed_migrations.push(db_migration);
             }
 
-            if corresponding_fs_migration.is_none() {
+            if corresponding_fs_migration.is_none()
+                && !squashed_migration_names.contains(db_migration.migration_name.as_str())
+            {
                 diagnostics.db_migrations_not_in_fs.push((index, db_migration))
             }
         }
@@ -124,19 +152,27 @@ impl<'a> MigrationCommand for DiagnoseMigrationHistoryCommand {
         // Detect drift
         let applied_migrations: Vec<_> = migrations_from_filesystem
             .iter()
-            .filter(|fs_migration| {
-                migrations_from_database
-                    .iter()
-                    .filter(|db_migration| db_migration.finished_at.is_some() && db_migration.rolled_back_at.is_none())
-                    .any(|db_migration| db_migration.migration_name == fs_migration.migration_name())
+            .enumerate()
+            .filter(|(index, fs_migration)| {
+                applied_through_squashed_migrations[*index]
+                    || migrations_from_database
+                        .iter()
+                        .filter(|db_migration| {
+                            db_migration.finished_at.is_some() && db_migration.rolled_back_at.is_none()
+                        })
+                        .any(|db_migration| db_migration.migration_name == fs_migration.migration_name())
             })
-            .cloned()
+            .map(|(_, fs_migration)| fs_migration.clone())
             .collect();
 
         let (drift, error_in_unapplied_migration) = {
             if input.opt_in_to_shadow_database {
                 let drift = match migration_inferrer.calculate_drift(&applied_migrations).await {
-                    Ok(Some(rollback)) => Some(DriftDiagnostic::DriftDetected { rollback }),
+                    Ok(Some(Drift { rollback, report })) => Some(DriftDiagnostic::DriftDetected {
+                        summary: report.to_string(),
+                        rollback,
+                        report,
+                    }),
                     Err(error) => Some(DriftDiagnostic::MigrationFailedToApply {
                         error: error.to_user_facing(),
                     }),
@@ -289,6 +325,10 @@ pub enum DriftDiagnostic {
     DriftDetected {
         /// A database script to correct the drift by reverting to the expected schema.
         rollback: String,
+        /// What drifted, table by table.
+        report: DriftReport,
+        /// A human-readable summary of the report, one line per difference.
+        summary: String,
     },
     /// When a migration fails to apply cleanly to a temporary database.
     #[serde(rename_all = "camelCase")]
@@ -302,7 +342,7 @@ impl DriftDiagnostic {
     /// For tests.
     pub fn unwrap_drift_detected(self) -> String {
         match self {
-            DriftDiagnostic::DriftDetected { rollback } => rollback,
+            DriftDiagnostic::DriftDetected { rollback, .. } => rollback,
             other => panic!("unwrap_drift_detected on {:?}", other),
         }
     }
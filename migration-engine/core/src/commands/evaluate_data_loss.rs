@@ -1,6 +1,8 @@
 use super::MigrationCommand;
 use crate::{api::MigrationApi, parse_datamodel, CoreResult};
-use migration_connector::{list_migrations, MigrationConnector};
+use migration_connector::{
+    list_migrations, EnumValueRename, LintDiagnostic, LintRules, MigrationConnector, ScriptMode,
+};
 use serde::{Deserialize, Serialize};
 
 /// Development command for migrations. Evaluate the data loss induced by the
@@ -18,6 +20,15 @@ pub struct EvaluateDataLossInput {
     pub migrations_directory_path: String,
     /// The prisma schema to migrate to.
     pub prisma_schema: String,
+    /// The severities of the migration lint rules, by rule name.
+    #[serde(default)]
+    pub lint_rules: LintRules,
+    /// How the migration steps are rendered.
+    #[serde(default)]
+    pub script_mode: ScriptMode,
+    /// The enum values that were renamed, to migrate the rows holding them.
+    #[serde(default)]
+    pub enum_value_renames: Vec<EnumValueRename>,
 }
 
 /// The output of the `evaluateDataLoss` command.
@@ -33,6 +44,9 @@ pub struct EvaluateDataLossOutput {
     /// Steps that cannot be executed on the local database in the migration
     /// that would be generated.
     pub unexecutable_steps: Vec<MigrationFeedback>,
+    /// Lint rule violations in the migration that would be generated, for
+    /// operations that are risky to run against a live database.
+    pub lints: Vec<LintDiagnostic>,
 }
 
 /// A data loss warning or an unexecutable migration error, associated with the step that triggered it.
@@ -55,6 +69,9 @@ impl MigrationCommand for EvaluateDataLoss {
         let inferrer = connector.database_migration_inferrer();
         let applier = connector.database_migration_step_applier();
         let checker = connector.destructive_change_checker();
+        let linter = connector.migration_linter();
+
+        input.lint_rules.check_rule_names(linter.rule_names())?;
 
         //Validate Provider
         migration_connector::error_on_changed_provider(&input.migrations_directory_path, connector.connector_type())?;
@@ -63,16 +80,17 @@ impl MigrationCommand for EvaluateDataLoss {
         let target_schema = parse_datamodel(&input.prisma_schema)?;
 
         let migration = inferrer
-            .infer_next_migration(&migrations_from_directory, &target_schema)
+            .infer_next_migration(&migrations_from_directory, &target_schema, &input.enum_value_renames)
             .await?;
 
         let rendered_migration_steps = applier
-            .render_steps_pretty(&migration)?
+            .render_steps_pretty(&migration, input.script_mode)?
             .into_iter()
             .map(|pretty_step| pretty_step.raw)
             .collect();
 
         let diagnostics = checker.check(&migration).await?;
+        let lints = linter.lint(&migration, &input.lint_rules);
 
         let warnings = diagnostics
             .warnings
@@ -96,6 +114,7 @@ impl MigrationCommand for EvaluateDataLoss {
             migration_steps: rendered_migration_steps,
             warnings,
             unexecutable_steps,
+            lints,
         })
     }
 }
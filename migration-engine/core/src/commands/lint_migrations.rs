@@ -0,0 +1,73 @@
+use super::MigrationCommand;
+use crate::{api::MigrationApi, CoreResult};
+use migration_connector::{ConnectorError, LintDiagnostic, LintRules, MigrationConnector};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// The input to the `lintMigrations` command.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LintMigrationsInput {
+    /// The location of the migrations directory.
+    pub migrations_directory_path: String,
+    /// The severities of the migration lint rules, by rule name.
+    #[serde(default)]
+    pub lint_rules: LintRules,
+}
+
+/// The output of the `lintMigrations` command.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LintMigrationsOutput {
+    /// The migrations with lint rule violations, in the order of the migrations directory.
+    pub migrations: Vec<LintedMigration>,
+}
+
+/// The lint rule violations in a migration script.
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LintedMigration {
+    /// The name of the migration directory.
+    pub migration_name: String,
+    /// The lint rule violations. The step indexes are the indexes of the statements in the script.
+    pub lints: Vec<LintDiagnostic>,
+}
+
+/// Lint the scripts in an existing migrations directory, without touching the database.
+pub struct LintMigrationsCommand;
+
+#[async_trait::async_trait]
+impl<'a> MigrationCommand for LintMigrationsCommand {
+    type Input = LintMigrationsInput;
+    type Output = LintMigrationsOutput;
+
+    async fn execute<C: MigrationConnector>(input: &Self::Input, engine: &MigrationApi<C>) -> CoreResult<Self::Output> {
+        let linter = engine.connector().migration_linter();
+
+        input.lint_rules.check_rule_names(linter.rule_names())?;
+
+        migration_connector::error_on_changed_provider(
+            &input.migrations_directory_path,
+            engine.connector().connector_type(),
+        )?;
+
+        let migrations_from_filesystem =
+            migration_connector::list_migrations(&Path::new(&input.migrations_directory_path))?;
+
+        let mut migrations = Vec::new();
+
+        for migration in migrations_from_filesystem {
+            let script = migration.read_migration_script().map_err(ConnectorError::from)?;
+            let lints = linter.lint_script(&script, &input.lint_rules);
+
+            if !lints.is_empty() {
+                migrations.push(LintedMigration {
+                    migration_name: migration.migration_name().to_owned(),
+                    lints,
+                });
+            }
+        }
+
+        Ok(LintMigrationsOutput { migrations })
+    }
+}
@@ -1,6 +1,6 @@
 use super::MigrationCommand;
 use crate::{api::MigrationApi, parse_datamodel, CoreResult};
-use migration_connector::{ConnectorError, MigrationConnector};
+use migration_connector::{ConnectorError, EnumValueRename, MigrationConnector};
 use serde::{Deserialize, Serialize};
 
 /// Command to bring the local database in sync with the prisma schema, without
@@ -22,7 +22,7 @@ impl MigrationCommand for SchemaPushCommand {
         let database_migration = if input.assume_empty {
             inferrer.infer_from_empty(&schema)?
         } else {
-            inferrer.infer(&schema).await?
+            inferrer.infer(&schema, &input.enum_value_renames).await?
         };
 
         if let Some(err) = connector.check_database_version_compatibility(&schema) {
@@ -70,6 +70,9 @@ pub struct SchemaPushInput {
     /// Expect the schema to be empty, skipping describing the existing schema.
     #[serde(default)]
     pub assume_empty: bool,
+    /// The enum values that were renamed, to migrate the rows holding them.
+    #[serde(default)]
+    pub enum_value_renames: Vec<EnumValueRename>,
 }
 
 /// Output of the `schemaPush` command.
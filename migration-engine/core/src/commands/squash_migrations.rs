@@ -0,0 +1,252 @@
+use super::MigrationCommand;
+use crate::{api::MigrationApi, CoreError, CoreResult};
+use migration_connector::{
+    ConnectorError, DatabaseMigrationMarker, MigrationConnector, MigrationDirectory, ScriptMode,
+    SQUASHED_MIGRATION_DIRECTIVE,
+};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use user_facing_errors::migration_engine::{
+    MigrationNameTooLong, MigrationToSquashNotFound, SquashedMigrationDirectoryExists,
+    SquashedMigrationsHaveNonSchemaStatements,
+};
+
+/// The input to the `squashMigrations` command.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SquashMigrationsInput {
+    /// The location of the migrations directory.
+    pub migrations_directory_path: String,
+    /// The name of the first migration to squash. Defaults to the first
+    /// migration in the migrations directory.
+    pub from_migration_name: Option<String>,
+    /// The name of the last migration to squash, included. Defaults to the
+    /// last migration in the migrations directory.
+    pub to_migration_name: Option<String>,
+    /// The user-given name for the squashed migration. This will be used in the migration directory.
+    pub migration_name: String,
+    /// Squash the migrations even if some of their statements, like data
+    /// changes, can't be carried over to the squashed migration.
+    #[serde(default)]
+    pub force: bool,
+}
+
+/// The output of the `squashMigrations` command.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SquashMigrationsOutput {
+    /// The name of the new migration directory.
+    pub generated_migration_name: String,
+    /// The names of the migrations that were replaced by the new migration, in order.
+    pub squashed_migration_names: Vec<String>,
+    /// Whether the new migration was marked as applied in the database,
+    /// because the database had already applied all the squashed migrations.
+    pub marked_applied: bool,
+    /// The statements of the squashed migrations that were not carried over
+    /// to the new migration. Only non-empty when squashing with `force`.
+    pub dropped_statements: Vec<DroppedStatement>,
+}
+
+/// A statement of a squashed migration that the squashed migration does not
+/// carry over.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DroppedStatement {
+    /// The name of the squashed migration the statement comes from.
+    pub migration_name: String,
+    /// The statement.
+    pub statement: String,
+}
+
+/// Replace a range of migrations in the migrations directory by a single
+/// migration with the same effect on the database schema. Only the schema is
+/// carried over: the command refuses to drop data changes, views, triggers or
+/// functions from the squashed migration scripts, unless it is forced to.
+///
+/// The squashed migration lists the migrations it replaces in its header.
+/// Databases that already applied all of them record it as applied instead of
+/// running it, here for the current database, and in `applyMigrations` for the
+/// others.
+pub struct SquashMigrationsCommand;
+
+#[async_trait::async_trait]
+impl<'a> MigrationCommand for SquashMigrationsCommand {
+    type Input = SquashMigrationsInput;
+    type Output = SquashMigrationsOutput;
+
+    async fn execute<C: MigrationConnector>(input: &Self::Input, engine: &MigrationApi<C>) -> CoreResult<Self::Output> {
+        let connector = engine.connector();
+        let inferrer = connector.database_migration_inferrer();
+        let applier = connector.database_migration_step_applier();
+        let checker = connector.destructive_change_checker();
+        let migration_persistence = connector.migration_persistence();
+
+        if input.migration_name.len() > 200 {
+            return Err(CoreError::user_facing(MigrationNameTooLong));
+        }
+
+        migration_connector::error_on_changed_provider(&input.migrations_directory_path, connector.connector_type())?;
+
+        let migrations_directory_path = Path::new(&input.migrations_directory_path);
+        let migrations_from_filesystem = migration_connector::list_migrations(migrations_directory_path)?;
+
+        let from_index = match &input.from_migration_name {
+            Some(name) => find_migration(&migrations_from_filesystem, name)?,
+            None => 0,
+        };
+
+        let to_index = match &input.to_migration_name {
+            Some(name) => find_migration(&migrations_from_filesystem, name)?,
+            None => migrations_from_filesystem.len().saturating_sub(1),
+        };
+
+        if migrations_from_filesystem.is_empty() || from_index > to_index {
+            return Err(CoreError::Generic(anyhow::anyhow!(
+                "There are no migrations to squash between `{}` and `{}`.",
+                input.from_migration_name.as_deref().unwrap_or("the first migration"),
+                input.to_migration_name.as_deref().unwrap_or("the last migration"),
+            )));
+        }
+
+        let squashed_migrations = &migrations_from_filesystem[from_index..=to_index];
+        let last_squashed_migration = &squashed_migrations[squashed_migrations.len() - 1];
+
+        // The squashed migration reuses the timestamp of the last squashed
+        // migration, so it can't take the same name.
+        let squashed_migration_directory_name =
+            migration_connector::squashed_migration_directory_name(last_squashed_migration, &input.migration_name);
+
+        if migrations_directory_path
+            .join(&squashed_migration_directory_name)
+            .exists()
+        {
+            return Err(CoreError::user_facing(SquashedMigrationDirectoryExists {
+                migration_name: squashed_migration_directory_name,
+            }));
+        }
+
+        let mut dropped_statements = Vec::new();
+
+        for squashed_migration in squashed_migrations {
+            let script = squashed_migration
+                .read_migration_script()
+                .map_err(ConnectorError::from)?;
+
+            dropped_statements.extend(applier.non_schema_statements(&script).into_iter().map(|statement| {
+                DroppedStatement {
+                    migration_name: squashed_migration.migration_name().to_owned(),
+                    statement,
+                }
+            }));
+        }
+
+        if !dropped_statements.is_empty() && !input.force {
+            let statements = dropped_statements
+                .iter()
+                .map(|dropped| format!("- `{}`: {}", dropped.migration_name, dropped.statement))
+                .collect::<Vec<_>>()
+                .join("\n");
+
+            return Err(CoreError::user_facing(SquashedMigrationsHaveNonSchemaStatements {
+                statements,
+            }));
+        }
+
+        let migration = inferrer
+            .infer_squashed_migration(
+                &migrations_from_filesystem[..from_index],
+                &migrations_from_filesystem[..=to_index],
+            )
+            .await?;
+
+        let destructive_change_diagnostics = checker.pure_check(&migration);
+
+        let mut migration_script = String::new();
+
+        for squashed_migration in squashed_migrations {
+            migration_script.push_str(SQUASHED_MIGRATION_DIRECTIVE);
+            migration_script.push(' ');
+            migration_script.push_str(squashed_migration.migration_name());
+            migration_script.push('\n');
+        }
+
+        migration_script.push_str(&applier.render_script(
+            &migration,
+            &destructive_change_diagnostics,
+            ScriptMode::Default,
+        ));
+
+        // Write the squashed migration before removing anything.
+        let directory = migration_connector::create_squashed_migration_directory(
+            migrations_directory_path,
+            last_squashed_migration,
+            &input.migration_name,
+        )
+        .map_err(|err| {
+            CoreError::Generic(anyhow::Error::new(err).context("Failed to create the squashed migration directory."))
+        })?;
+
+        directory
+            .write_migration_script(&migration_script, C::DatabaseMigration::FILE_EXTENSION)
+            .map_err(|err| {
+                CoreError::Generic(anyhow::Error::new(err).context(format!(
+                    "Failed to write the migration script to `{:?}`",
+                    directory.path(),
+                )))
+            })?;
+
+        for squashed_migration in squashed_migrations {
+            std::fs::remove_dir_all(squashed_migration.path()).map_err(|err| {
+                CoreError::Generic(anyhow::Error::new(err).context(format!(
+                    "Failed to remove the squashed migration directory at `{:?}`",
+                    squashed_migration.path(),
+                )))
+            })?;
+        }
+
+        let squashed_migration_names: Vec<String> = squashed_migrations
+            .iter()
+            .map(|migration| migration.migration_name().to_owned())
+            .collect();
+
+        // Keep the migrations table consistent with the new migrations directory.
+        let migrations_from_database = migration_persistence.list_migrations().await?.unwrap_or_default();
+
+        let all_squashed_migrations_are_applied = squashed_migration_names.iter().all(|name| {
+            migrations_from_database.iter().any(|db_migration| {
+                &db_migration.migration_name == name
+                    && db_migration.finished_at.is_some()
+                    && db_migration.rolled_back_at.is_none()
+            })
+        });
+
+        if all_squashed_migrations_are_applied {
+            tracing::info!(
+                "All the squashed migrations were applied to the database, marking `{}` as applied.",
+                directory.migration_name()
+            );
+
+            migration_persistence
+                .mark_migration_applied(directory.migration_name(), &migration_script)
+                .await?;
+        }
+
+        Ok(SquashMigrationsOutput {
+            generated_migration_name: directory.migration_name().to_owned(),
+            squashed_migration_names,
+            marked_applied: all_squashed_migrations_are_applied,
+            dropped_statements,
+        })
+    }
+}
+
+fn find_migration(migrations: &[MigrationDirectory], migration_name: &str) -> CoreResult<usize> {
+    migrations
+        .iter()
+        .position(|migration| migration.migration_name() == migration_name)
+        .ok_or_else(|| {
+            CoreError::user_facing(MigrationToSquashNotFound {
+                migration_name: migration_name.to_owned(),
+            })
+        })
+}
@@ -20,7 +20,7 @@ use datamodel::{
     Configuration,
 };
 use migration_connector::{features, ConnectorError};
-use sql_migration_connector::SqlMigrationConnector;
+use sql_migration_connector::{MigrationsTable, SqlMigrationConnector};
 use std::sync::Arc;
 use user_facing_errors::{common::InvalidDatabaseString, migration_engine::DeprecatedProviderArray, KnownError};
 
@@ -39,6 +39,8 @@ pub async fn migration_api(datamodel: &str) -> CoreResult<Arc<dyn api::GenericAp
         })
         .unwrap_or_else(|| Err(CoreError::Generic(anyhow!("There is no datasource in the schema."))))?;
 
+    let migrations_table = MigrationsTable::new(source.migrations_table(), source.migrations_schema());
+
     let connector = match &source.active_provider {
         #[cfg(feature = "sql")]
         provider if POSTGRES_SOURCE_NAME == provider => {
@@ -69,11 +71,11 @@ pub async fn migration_api(datamodel: &str) -> CoreResult<Arc<dyn api::GenericAp
                 u.query_pairs_mut().append_pair("statement_cache_size", "0");
             }
 
-            SqlMigrationConnector::new(u.as_str(), features).await?
+            SqlMigrationConnector::new(u.as_str(), features, migrations_table).await?
         }
         #[cfg(feature = "sql")]
         provider if [MYSQL_SOURCE_NAME, SQLITE_SOURCE_NAME, MSSQL_SOURCE_NAME].contains(&provider.as_str()) => {
-            SqlMigrationConnector::new(&source.url().value, features).await?
+            SqlMigrationConnector::new(&source.url().value, features, migrations_table).await?
         }
         x => unimplemented!("Connector {} is not supported yet", x),
     };
@@ -155,7 +157,9 @@ pub async fn qe_setup(prisma_schema: &str) -> CoreResult<()> {
         {
             // 1. creates schema & database
             SqlMigrationConnector::qe_setup(&source.url().value).await?;
-            SqlMigrationConnector::new(&source.url().value, features).await?
+            let migrations_table = MigrationsTable::new(source.migrations_table(), source.migrations_schema());
+
+            SqlMigrationConnector::new(&source.url().value, features, migrations_table).await?
         }
         x => unimplemented!("Connector {} is not supported yet", x),
     };
@@ -167,6 +171,7 @@ pub async fn qe_setup(prisma_schema: &str) -> CoreResult<()> {
         schema: prisma_schema.to_string(),
         assume_empty: true,
         force: true,
+        enum_value_renames: Vec::new(),
     };
 
     SchemaPushCommand::execute(&schema_push_input, &engine).await?;
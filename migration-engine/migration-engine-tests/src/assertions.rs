@@ -372,6 +372,16 @@ impl<'a> ColumnAssertion<'a> {
         Ok(self)
     }
 
+    pub fn assert_is_generated(self) -> AssertionResult<Self> {
+        anyhow::ensure!(
+            self.0.generation_expression.is_some(),
+            "Assertion failed. Expected column `{}` to be a generated column.",
+            self.0.name,
+        );
+
+        Ok(self)
+    }
+
     pub fn assert_native_type(self, expected: &str, connector: &dyn Connector) -> AssertionResult<Self> {
         let found = connector.render_native_type(self.0.tpe.native_type.clone().unwrap());
         anyhow::ensure!(
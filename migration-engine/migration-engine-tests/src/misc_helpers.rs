@@ -1,6 +1,6 @@
 use enumflags2::BitFlags;
 use migration_connector::*;
-use sql_migration_connector::SqlMigrationConnector;
+use sql_migration_connector::{MigrationsTable, SqlMigrationConnector};
 use test_setup::*;
 
 pub type TestResult = Result<(), anyhow::Error>;
@@ -10,7 +10,9 @@ pub(super) async fn mysql_migration_connector(
     features: BitFlags<MigrationFeature>,
 ) -> SqlMigrationConnector {
     create_mysql_database(&url_str.parse().unwrap()).await.unwrap();
-    SqlMigrationConnector::new(url_str, features).await.unwrap()
+    SqlMigrationConnector::new(url_str, features, MigrationsTable::default())
+        .await
+        .unwrap()
 }
 
 pub(super) async fn postgres_migration_connector(
@@ -18,7 +20,9 @@ pub(super) async fn postgres_migration_connector(
     features: BitFlags<MigrationFeature>,
 ) -> SqlMigrationConnector {
     create_postgres_database(&url_str.parse().unwrap()).await.unwrap();
-    SqlMigrationConnector::new(url_str, features).await.unwrap()
+    SqlMigrationConnector::new(url_str, features, MigrationsTable::default())
+        .await
+        .unwrap()
 }
 
 pub(super) async fn sqlite_migration_connector(
@@ -27,5 +31,7 @@ pub(super) async fn sqlite_migration_connector(
 ) -> SqlMigrationConnector {
     let database_url = sqlite_test_url(db_name);
 
-    SqlMigrationConnector::new(&database_url, features).await.unwrap()
+    SqlMigrationConnector::new(&database_url, features, MigrationsTable::default())
+        .await
+        .unwrap()
 }
@@ -1,21 +1,29 @@
 mod apply_migrations;
+mod apply_seed;
+mod create_baseline_migration;
 mod create_migration;
 mod dev_diagnostic;
 mod diagnose_migration_history;
 mod evaluate_data_loss;
+mod lint_migrations;
 mod list_migration_directories;
 mod mark_migration_applied;
 mod mark_migration_rolled_back;
 mod reset;
 mod schema_push;
+mod squash_migrations;
 
 pub use apply_migrations::ApplyMigrations;
+pub use apply_seed::ApplySeed;
+pub use create_baseline_migration::CreateBaselineMigration;
 pub use create_migration::CreateMigration;
 pub use diagnose_migration_history::DiagnoseMigrationHistory;
 pub use evaluate_data_loss::EvaluateDataLoss;
+pub use lint_migrations::LintMigrations;
 pub use mark_migration_applied::MarkMigrationApplied;
 pub use reset::Reset;
 pub use schema_push::SchemaPush;
+pub use squash_migrations::SquashMigrations;
 
 use self::{dev_diagnostic::DevDiagnostic, mark_migration_rolled_back::MarkMigrationRolledBack};
 use super::{
@@ -34,9 +42,9 @@ use quaint::{
     prelude::{ConnectionInfo, Queryable, SqlFamily},
     single::Quaint,
 };
-use sql_migration_connector::SqlMigrationConnector;
+use sql_migration_connector::{MigrationsTable, SqlMigrationConnector};
 use sql_schema_describer::*;
-use std::fmt::Write;
+use std::{fmt::Write, path::Path};
 use tempfile::TempDir;
 use test_setup::*;
 
@@ -46,6 +54,8 @@ pub struct TestApi {
     database: Quaint,
     api: MigrationApi<SqlMigrationConnector>,
     tags: BitFlags<Tags>,
+    connection_string: String,
+    features: BitFlags<MigrationFeature>,
 }
 
 impl TestApi {
@@ -115,6 +125,20 @@ impl TestApi {
         }
     }
 
+    /// Connect again to the same database, with the applied migrations
+    /// recorded in the given table.
+    pub async fn with_migrations_table(&self, migrations_table: MigrationsTable) -> anyhow::Result<TestApi> {
+        let connector = SqlMigrationConnector::new(&self.connection_string, self.features, migrations_table).await?;
+
+        Ok(TestApi {
+            database: connector.quaint().clone(),
+            api: MigrationApi::new(connector),
+            tags: self.tags,
+            connection_string: self.connection_string.clone(),
+            features: self.features,
+        })
+    }
+
     /// Create a temporary directory to serve as a test migrations directory.
     pub fn create_migrations_directory(&self) -> anyhow::Result<TempDir> {
         Ok(tempfile::tempdir()?)
@@ -149,6 +173,11 @@ impl TestApi {
         ApplyMigrations::new(&self.api, migrations_directory)
     }
 
+    /// Builder and assertions to call the `lintMigrations` command.
+    pub fn lint_migrations<'a>(&'a self, migrations_directory: &'a TempDir) -> LintMigrations<'a> {
+        LintMigrations::new(&self.api, migrations_directory)
+    }
+
     pub fn list_migration_directories<'a>(&'a self, migrations_directory: &'a TempDir) -> ListMigrationDirectories<'a> {
         ListMigrationDirectories::new(&self.api, migrations_directory)
     }
@@ -161,6 +190,20 @@ impl TestApi {
         Ok(())
     }
 
+    /// Builder and assertions to call the `applySeed` command.
+    pub fn apply_seed<'a>(&'a self, schema: impl Into<String>, seed_file_path: &'a Path) -> ApplySeed<'a> {
+        ApplySeed::new(&self.api, schema.into(), seed_file_path)
+    }
+
+    /// Builder and assertions to call the `createBaselineMigration` command.
+    pub fn create_baseline_migration<'a>(
+        &'a self,
+        migration_name: impl Into<String>,
+        migrations_directory: &'a TempDir,
+    ) -> CreateBaselineMigration<'a> {
+        CreateBaselineMigration::new(&self.api, migration_name.into(), migrations_directory)
+    }
+
     /// Convenient builder and assertions for the CreateMigration command.
     pub fn create_migration<'a>(
         &'a self,
@@ -209,6 +252,15 @@ impl TestApi {
         SchemaPush::new(&self.api, dm.into())
     }
 
+    /// Builder and assertions to call the `squashMigrations` command.
+    pub fn squash_migrations<'a>(
+        &'a self,
+        migration_name: impl Into<String>,
+        migrations_directory: &'a TempDir,
+    ) -> SquashMigrations<'a> {
+        SquashMigrations::new(&self.api, migration_name.into(), migrations_directory)
+    }
+
     pub fn barrel(&self) -> BarrelMigrationExecutor<'_> {
         BarrelMigrationExecutor {
             api: self,
@@ -345,6 +397,8 @@ pub async fn mysql_8_test_api(args: TestAPIArgs) -> TestApi {
         database: connector.quaint().clone(),
         api: MigrationApi::new(connector),
         tags: args.test_tag,
+        connection_string: url,
+        features,
     }
 }
 
@@ -358,6 +412,8 @@ pub async fn mysql_5_6_test_api(args: TestAPIArgs) -> TestApi {
         database: connector.quaint().clone(),
         api: MigrationApi::new(connector),
         tags: args.test_tag,
+        connection_string: url,
+        features,
     }
 }
 
@@ -371,6 +427,8 @@ pub async fn mysql_test_api(args: TestAPIArgs) -> TestApi {
         database: connector.quaint().clone(),
         api: MigrationApi::new(connector),
         tags: args.test_tag,
+        connection_string: url,
+        features,
     }
 }
 
@@ -384,6 +442,8 @@ pub async fn mysql_mariadb_test_api(args: TestAPIArgs) -> TestApi {
         database: connector.quaint().clone(),
         api: MigrationApi::new(connector),
         tags: args.test_tag,
+        connection_string: url,
+        features,
     }
 }
 
@@ -397,6 +457,8 @@ pub async fn postgres9_test_api(args: TestAPIArgs) -> TestApi {
         database: connector.quaint().clone(),
         api: MigrationApi::new(connector),
         tags: args.test_tag,
+        connection_string: url,
+        features,
     }
 }
 
@@ -410,6 +472,8 @@ pub async fn postgres_test_api(args: TestAPIArgs) -> TestApi {
         database: connector.quaint().clone(),
         api: MigrationApi::new(connector),
         tags: args.test_tag,
+        connection_string: url,
+        features,
     }
 }
 
@@ -423,6 +487,8 @@ pub async fn postgres11_test_api(args: TestAPIArgs) -> TestApi {
         database: connector.quaint().clone(),
         api: MigrationApi::new(connector),
         tags: args.test_tag,
+        connection_string: url,
+        features,
     }
 }
 
@@ -435,6 +501,8 @@ pub async fn postgres12_test_api(args: TestAPIArgs) -> TestApi {
         database: connector.quaint().clone(),
         api: MigrationApi::new(connector),
         tags: args.test_tag,
+        connection_string: url,
+        features,
     }
 }
 
@@ -447,6 +515,8 @@ pub async fn postgres13_test_api(args: TestAPIArgs) -> TestApi {
         database: connector.quaint().clone(),
         api: MigrationApi::new(connector),
         tags: args.test_tag,
+        connection_string: url,
+        features,
     }
 }
 
@@ -459,6 +529,8 @@ pub async fn sqlite_test_api(args: TestAPIArgs) -> TestApi {
         database: connector.quaint().clone(),
         api: MigrationApi::new(connector),
         tags: args.test_tag,
+        connection_string: sqlite_test_url(db_name),
+        features,
     }
 }
 
@@ -477,20 +549,22 @@ async fn mssql_test_api(connection_string: String, args: TestAPIArgs) -> TestApi
 
     let database = Quaint::new(&connection_string).await.unwrap();
     connectors::mssql::reset_schema(&database, schema).await.unwrap();
-    let connector = SqlMigrationConnector::new(&connection_string, features).await.unwrap();
+    let connector = SqlMigrationConnector::new(&connection_string, features, MigrationsTable::default())
+        .await
+        .unwrap();
 
     TestApi {
         database: connector.quaint().clone(),
         api: MigrationApi::new(connector),
         tags: args.test_tag,
+        connection_string,
+        features,
     }
 }
 
 fn preview_features(features: BitFlags<Features>) -> BitFlags<MigrationFeature> {
-    features.iter().fold(BitFlags::empty(), |acc, feature| {
-        match feature {
-            Features::Other => return acc,
-        };
+    features.iter().fold(BitFlags::empty(), |acc, feature| match feature {
+        Features::OnlineIndexes => acc | MigrationFeature::OnlineIndexes,
     })
 }
 
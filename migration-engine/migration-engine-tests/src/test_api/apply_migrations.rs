@@ -7,6 +7,7 @@ use crate::AssertionResult;
 pub struct ApplyMigrations<'a> {
     api: &'a dyn GenericApi,
     migrations_directory: &'a TempDir,
+    resume_failed_migrations: bool,
 }
 
 impl<'a> ApplyMigrations<'a> {
@@ -14,14 +15,22 @@ impl<'a> ApplyMigrations<'a> {
         ApplyMigrations {
             api,
             migrations_directory,
+            resume_failed_migrations: false,
         }
     }
 
+    pub fn resume_failed_migrations(mut self, resume_failed_migrations: bool) -> Self {
+        self.resume_failed_migrations = resume_failed_migrations;
+
+        self
+    }
+
     pub async fn send(self) -> CoreResult<ApplyMigrationsAssertion<'a>> {
         let output = self
             .api
             .apply_migrations(&ApplyMigrationsInput {
                 migrations_directory_path: self.migrations_directory.path().to_str().unwrap().to_owned(),
+                resume_failed_migrations: self.resume_failed_migrations,
             })
             .await?;
 
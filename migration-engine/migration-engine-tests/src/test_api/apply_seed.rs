@@ -0,0 +1,66 @@
+use crate::AssertionResult;
+use migration_core::{
+    commands::{ApplySeedInput, ApplySeedOutput},
+    CoreResult, GenericApi,
+};
+use std::path::Path;
+
+#[must_use = "This struct does nothing on its own. See ApplySeed::send()"]
+pub struct ApplySeed<'a> {
+    api: &'a dyn GenericApi,
+    schema: String,
+    seed_file_path: &'a Path,
+}
+
+impl<'a> ApplySeed<'a> {
+    pub fn new(api: &'a dyn GenericApi, schema: String, seed_file_path: &'a Path) -> Self {
+        ApplySeed {
+            api,
+            schema,
+            seed_file_path,
+        }
+    }
+
+    pub async fn send(self) -> CoreResult<ApplySeedAssertion> {
+        let output = self
+            .api
+            .apply_seed(&ApplySeedInput {
+                schema: self.schema,
+                seed_file_path: self.seed_file_path.to_str().unwrap().to_owned(),
+            })
+            .await?;
+
+        Ok(ApplySeedAssertion { output })
+    }
+}
+
+#[derive(Debug)]
+pub struct ApplySeedAssertion {
+    output: ApplySeedOutput,
+}
+
+impl ApplySeedAssertion {
+    /// Assert on the models that were seeded, in order, with their inserted
+    /// and updated record counts.
+    pub fn assert_seeded(self, expected: &[(&str, u64, u64)]) -> AssertionResult<Self> {
+        let actual: Vec<(&str, u64, u64)> = self
+            .output
+            .seeded_models
+            .iter()
+            .map(|model| (model.model.as_str(), model.inserted, model.updated))
+            .collect();
+
+        anyhow::ensure!(
+            actual == expected,
+            "Assertion failed. Expected the seeded models to be {:?}, found {:?}",
+            expected,
+            actual
+        );
+
+        Ok(self)
+    }
+
+    pub fn into_output(self) -> ApplySeedOutput {
+        self.output
+    }
+}
@@ -0,0 +1,69 @@
+use crate::AssertionResult;
+use migration_core::{
+    commands::{CreateBaselineMigrationInput, CreateBaselineMigrationOutput},
+    CoreResult, GenericApi,
+};
+use tempfile::TempDir;
+
+#[must_use = "This struct does nothing on its own. See CreateBaselineMigration::send()"]
+pub struct CreateBaselineMigration<'a> {
+    api: &'a dyn GenericApi,
+    migrations_directory: &'a TempDir,
+    migration_name: String,
+}
+
+impl<'a> CreateBaselineMigration<'a> {
+    pub fn new(api: &'a dyn GenericApi, migration_name: String, migrations_directory: &'a TempDir) -> Self {
+        CreateBaselineMigration {
+            api,
+            migrations_directory,
+            migration_name,
+        }
+    }
+
+    pub async fn send(self) -> CoreResult<CreateBaselineMigrationAssertion<'a>> {
+        let output = self
+            .api
+            .create_baseline_migration(&CreateBaselineMigrationInput {
+                migrations_directory_path: self.migrations_directory.path().to_str().unwrap().to_owned(),
+                migration_name: self.migration_name,
+            })
+            .await?;
+
+        Ok(CreateBaselineMigrationAssertion {
+            output,
+            migrations_directory: self.migrations_directory,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct CreateBaselineMigrationAssertion<'a> {
+    output: CreateBaselineMigrationOutput,
+    migrations_directory: &'a TempDir,
+}
+
+impl<'a> CreateBaselineMigrationAssertion<'a> {
+    /// Assert that the baseline migration script contains the given substring.
+    pub fn assert_script_contains(self, expected: &str) -> AssertionResult<Self> {
+        let script = std::fs::read_to_string(
+            self.migrations_directory
+                .path()
+                .join(&self.output.generated_migration_name)
+                .join("migration.sql"),
+        )?;
+
+        anyhow::ensure!(
+            script.contains(expected),
+            "Assertion failed. Expected the baseline migration script to contain `{}`, found:\n{}",
+            expected,
+            script
+        );
+
+        Ok(self)
+    }
+
+    pub fn into_output(self) -> CreateBaselineMigrationOutput {
+        self.output
+    }
+}
@@ -1,7 +1,8 @@
 use anyhow::Context;
+use migration_connector::{EnumValueRename, LintRules, ScriptMode};
 use migration_core::{commands::CreateMigrationInput, commands::CreateMigrationOutput, GenericApi};
 use pretty_assertions::assert_eq;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use tempfile::TempDir;
 
 use crate::AssertionResult;
@@ -12,6 +13,9 @@ pub struct CreateMigration<'a> {
     migrations_directory: &'a TempDir,
     draft: bool,
     name: &'a str,
+    lint_rules: LintRules,
+    script_mode: ScriptMode,
+    enum_value_renames: Vec<EnumValueRename>,
 }
 
 impl<'a> CreateMigration<'a> {
@@ -22,6 +26,9 @@ impl<'a> CreateMigration<'a> {
             migrations_directory,
             draft: false,
             name,
+            lint_rules: LintRules::default(),
+            script_mode: ScriptMode::Default,
+            enum_value_renames: Vec::new(),
         }
     }
 
@@ -31,6 +38,24 @@ impl<'a> CreateMigration<'a> {
         self
     }
 
+    pub fn lint_rules(mut self, lint_rules: LintRules) -> Self {
+        self.lint_rules = lint_rules;
+
+        self
+    }
+
+    pub fn script_mode(mut self, script_mode: ScriptMode) -> Self {
+        self.script_mode = script_mode;
+
+        self
+    }
+
+    pub fn enum_value_renames(mut self, enum_value_renames: Vec<EnumValueRename>) -> Self {
+        self.enum_value_renames = enum_value_renames;
+
+        self
+    }
+
     pub async fn send(self) -> anyhow::Result<CreateMigrationAssertion<'a>> {
         let output = self
             .api
@@ -39,6 +64,9 @@ impl<'a> CreateMigration<'a> {
                 prisma_schema: self.schema.to_owned(),
                 draft: self.draft,
                 migration_name: self.name.to_owned(),
+                lint_rules: self.lint_rules,
+                script_mode: self.script_mode,
+                enum_value_renames: self.enum_value_renames,
             })
             .await?;
 
@@ -123,17 +151,18 @@ impl<'a> CreateMigrationAssertion<'a> {
         &self.output
     }
 
+    /// The contents of the generated migration script.
+    pub fn migration_script(&self) -> AssertionResult<String> {
+        Ok(std::fs::read_to_string(self.migration_script_path()).context("Reading migration script")?)
+    }
+
     pub fn modify_migration<F>(self, modify: F) -> AssertionResult<Self>
     where
         F: FnOnce(&mut String),
     {
         use std::io::Write as _;
 
-        let migration_script_path = self
-            .migrations_directory
-            .path()
-            .join(self.output.generated_migration_name.as_ref().unwrap())
-            .join("migration.sql");
+        let migration_script_path = self.migration_script_path();
 
         let new_contents = {
             let mut contents = std::fs::read_to_string(&migration_script_path).context("Reading migration script")?;
@@ -152,6 +181,13 @@ impl<'a> CreateMigrationAssertion<'a> {
     pub fn into_output(self) -> CreateMigrationOutput {
         self.output
     }
+
+    fn migration_script_path(&self) -> PathBuf {
+        self.migrations_directory
+            .path()
+            .join(self.output.generated_migration_name.as_ref().unwrap())
+            .join("migration.sql")
+    }
 }
 
 pub struct MigrationAssertion<'a> {
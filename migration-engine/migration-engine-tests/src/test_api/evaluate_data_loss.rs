@@ -1,4 +1,5 @@
 use crate::AssertionResult;
+use migration_connector::{EnumValueRename, LintRules, ScriptMode};
 use migration_core::{
     commands::{EvaluateDataLossInput, EvaluateDataLossOutput},
     GenericApi,
@@ -11,6 +12,9 @@ pub struct EvaluateDataLoss<'a> {
     api: &'a dyn GenericApi,
     migrations_directory: &'a TempDir,
     prisma_schema: String,
+    lint_rules: LintRules,
+    script_mode: ScriptMode,
+    enum_value_renames: Vec<EnumValueRename>,
 }
 
 impl<'a> EvaluateDataLoss<'a> {
@@ -19,15 +23,39 @@ impl<'a> EvaluateDataLoss<'a> {
             api,
             migrations_directory,
             prisma_schema,
+            lint_rules: LintRules::default(),
+            script_mode: ScriptMode::Default,
+            enum_value_renames: Vec::new(),
         }
     }
 
+    pub fn lint_rules(mut self, lint_rules: LintRules) -> Self {
+        self.lint_rules = lint_rules;
+
+        self
+    }
+
+    pub fn script_mode(mut self, script_mode: ScriptMode) -> Self {
+        self.script_mode = script_mode;
+
+        self
+    }
+
+    pub fn enum_value_renames(mut self, enum_value_renames: Vec<EnumValueRename>) -> Self {
+        self.enum_value_renames = enum_value_renames;
+
+        self
+    }
+
     pub async fn send(self) -> anyhow::Result<EvaluateDataLossAssertion<'a>> {
         let output = self
             .api
             .evaluate_data_loss(&EvaluateDataLossInput {
                 migrations_directory_path: self.migrations_directory.path().to_str().unwrap().to_owned(),
                 prisma_schema: self.prisma_schema,
+                lint_rules: self.lint_rules,
+                script_mode: self.script_mode,
+                enum_value_renames: self.enum_value_renames,
             })
             .await?;
 
@@ -148,6 +176,26 @@ impl<'a> EvaluateDataLossAssertion<'a> {
         Ok(self)
     }
 
+    /// Assert on the names of the violated lint rules, with the index of the step they pertain to.
+    pub fn assert_lints(self, lints: &[(&str, usize)]) -> AssertionResult<Self> {
+        let found: Vec<(&str, usize)> = self
+            .output
+            .lints
+            .iter()
+            .map(|lint| (lint.rule.as_str(), lint.step_index))
+            .collect();
+
+        anyhow::ensure!(
+            found == lints,
+            "Assertion failed. Expected lints {:?}, found {:?}.\n{:#?}",
+            lints,
+            found,
+            self.output.lints
+        );
+
+        Ok(self)
+    }
+
     pub fn into_output(self) -> EvaluateDataLossOutput {
         self.output
     }
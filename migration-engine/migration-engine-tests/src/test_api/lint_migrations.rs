@@ -0,0 +1,79 @@
+use crate::AssertionResult;
+use migration_connector::LintRules;
+use migration_core::{
+    commands::{LintMigrationsInput, LintMigrationsOutput},
+    CoreResult, GenericApi,
+};
+use tempfile::TempDir;
+
+#[must_use = "This struct does nothing on its own. See LintMigrations::send()"]
+pub struct LintMigrations<'a> {
+    api: &'a dyn GenericApi,
+    migrations_directory: &'a TempDir,
+    lint_rules: LintRules,
+}
+
+impl<'a> LintMigrations<'a> {
+    pub fn new(api: &'a dyn GenericApi, migrations_directory: &'a TempDir) -> Self {
+        LintMigrations {
+            api,
+            migrations_directory,
+            lint_rules: LintRules::default(),
+        }
+    }
+
+    pub fn lint_rules(mut self, lint_rules: LintRules) -> Self {
+        self.lint_rules = lint_rules;
+
+        self
+    }
+
+    pub async fn send(self) -> CoreResult<LintMigrationsAssertion> {
+        let output = self
+            .api
+            .lint_migrations(&LintMigrationsInput {
+                migrations_directory_path: self.migrations_directory.path().to_str().unwrap().to_owned(),
+                lint_rules: self.lint_rules,
+            })
+            .await?;
+
+        Ok(LintMigrationsAssertion { output })
+    }
+}
+
+#[derive(Debug)]
+pub struct LintMigrationsAssertion {
+    output: LintMigrationsOutput,
+}
+
+impl LintMigrationsAssertion {
+    /// Assert on the rules violated by each linted migration, ignoring the timestamp prefixes of the migration names.
+    pub fn assert_lints(self, expected: &[(&str, &[&str])]) -> AssertionResult<Self> {
+        let found: Vec<(&str, Vec<&str>)> = self
+            .output
+            .migrations
+            .iter()
+            .map(|migration| {
+                (
+                    &migration.migration_name[15..],
+                    migration.lints.iter().map(|lint| lint.rule.as_str()).collect(),
+                )
+            })
+            .collect();
+
+        let expected: Vec<(&str, Vec<&str>)> = expected.iter().map(|(name, rules)| (*name, rules.to_vec())).collect();
+
+        anyhow::ensure!(
+            found == expected,
+            "Assertion failed. The lints do not match the expectations. ({:?} vs {:?})",
+            found,
+            expected
+        );
+
+        Ok(self)
+    }
+
+    pub fn into_output(self) -> LintMigrationsOutput {
+        self.output
+    }
+}
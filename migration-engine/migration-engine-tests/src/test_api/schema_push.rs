@@ -1,4 +1,5 @@
 use super::super::assertions::AssertionResult;
+use migration_connector::EnumValueRename;
 use migration_core::{
     api::GenericApi,
     commands::{SchemaPushInput, SchemaPushOutput},
@@ -11,6 +12,7 @@ pub struct SchemaPush<'a> {
     api: &'a dyn GenericApi,
     schema: String,
     force: bool,
+    enum_value_renames: Vec<EnumValueRename>,
     /// Purely for logging diagnostics.
     migration_id: Option<&'a str>,
 }
@@ -21,6 +23,7 @@ impl<'a> SchemaPush<'a> {
             api,
             schema,
             force: false,
+            enum_value_renames: Vec::new(),
             migration_id: None,
         }
     }
@@ -30,6 +33,11 @@ impl<'a> SchemaPush<'a> {
         self
     }
 
+    pub fn enum_value_renames(mut self, enum_value_renames: Vec<EnumValueRename>) -> Self {
+        self.enum_value_renames = enum_value_renames;
+        self
+    }
+
     pub fn migration_id(mut self, migration_id: Option<&'a str>) -> Self {
         self.migration_id = migration_id;
         self
@@ -40,6 +48,7 @@ impl<'a> SchemaPush<'a> {
             schema: self.schema,
             force: self.force,
             assume_empty: false,
+            enum_value_renames: self.enum_value_renames,
         };
 
         let output = self
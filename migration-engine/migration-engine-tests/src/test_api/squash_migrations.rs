@@ -0,0 +1,123 @@
+use crate::AssertionResult;
+use migration_core::{
+    commands::{SquashMigrationsInput, SquashMigrationsOutput},
+    CoreResult, GenericApi,
+};
+use tempfile::TempDir;
+
+#[must_use = "This struct does nothing on its own. See SquashMigrations::send()"]
+pub struct SquashMigrations<'a> {
+    api: &'a dyn GenericApi,
+    migrations_directory: &'a TempDir,
+    migration_name: String,
+    from_migration_name: Option<String>,
+    to_migration_name: Option<String>,
+    force: bool,
+}
+
+impl<'a> SquashMigrations<'a> {
+    pub fn new(api: &'a dyn GenericApi, migration_name: String, migrations_directory: &'a TempDir) -> Self {
+        SquashMigrations {
+            api,
+            migrations_directory,
+            migration_name,
+            from_migration_name: None,
+            to_migration_name: None,
+            force: false,
+        }
+    }
+
+    pub fn from_migration(mut self, migration_name: impl Into<String>) -> Self {
+        self.from_migration_name = Some(migration_name.into());
+
+        self
+    }
+
+    pub fn to_migration(mut self, migration_name: impl Into<String>) -> Self {
+        self.to_migration_name = Some(migration_name.into());
+
+        self
+    }
+
+    pub fn force(mut self, force: bool) -> Self {
+        self.force = force;
+
+        self
+    }
+
+    pub async fn send(self) -> CoreResult<SquashMigrationsAssertion> {
+        let output = self
+            .api
+            .squash_migrations(&SquashMigrationsInput {
+                migrations_directory_path: self.migrations_directory.path().to_str().unwrap().to_owned(),
+                from_migration_name: self.from_migration_name,
+                to_migration_name: self.to_migration_name,
+                migration_name: self.migration_name,
+                force: self.force,
+            })
+            .await?;
+
+        Ok(SquashMigrationsAssertion { output })
+    }
+}
+
+#[derive(Debug)]
+pub struct SquashMigrationsAssertion {
+    output: SquashMigrationsOutput,
+}
+
+impl SquashMigrationsAssertion {
+    /// Assert on the names of the squashed migrations, ignoring their timestamp prefixes.
+    pub fn assert_squashed_migrations(self, expected: &[&str]) -> AssertionResult<Self> {
+        let found: Vec<&str> = self
+            .output
+            .squashed_migration_names
+            .iter()
+            .map(|name| &name[15..])
+            .collect();
+
+        anyhow::ensure!(
+            found == expected,
+            "Assertion failed. The squashed migrations do not match the expectations. ({:?} vs {:?})",
+            found,
+            expected
+        );
+
+        Ok(self)
+    }
+
+    pub fn assert_marked_applied(self, expected: bool) -> AssertionResult<Self> {
+        anyhow::ensure!(
+            self.output.marked_applied == expected,
+            "Assertion failed. Expected marked_applied to be {}, found {}.",
+            expected,
+            self.output.marked_applied,
+        );
+
+        Ok(self)
+    }
+
+    /// Assert on the statements that were not carried over, with the names of
+    /// their migrations without the timestamp prefixes.
+    pub fn assert_dropped_statements(self, expected: &[(&str, &str)]) -> AssertionResult<Self> {
+        let found: Vec<(&str, &str)> = self
+            .output
+            .dropped_statements
+            .iter()
+            .map(|dropped| (&dropped.migration_name[15..], dropped.statement.as_str()))
+            .collect();
+
+        anyhow::ensure!(
+            found == expected,
+            "Assertion failed. The dropped statements do not match the expectations. ({:?} vs {:?})",
+            found,
+            expected
+        );
+
+        Ok(self)
+    }
+
+    pub fn into_output(self) -> SquashMigrationsOutput {
+        self.output
+    }
+}
@@ -239,3 +239,221 @@ async fn migrations_should_succeed_on_an_uninitialized_nonempty_database_with_po
 
     Ok(())
 }
+
+#[test_each_connector(tags("postgres"), features("onlineIndexes"))]
+async fn online_indexes_are_created_and_dropped_outside_of_a_transaction(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm1, &migrations_directory)
+        .send()
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+
+            @@index([name])
+        }
+    "#;
+
+    api.create_migration("add-index", dm2, &migrations_directory)
+        .send()
+        .await?
+        .assert_migration("add-index", |migration| {
+            migration.assert_contents(
+                "-- prisma:no-transaction\n-- CreateIndex\nCREATE INDEX CONCURRENTLY \"Cat.name_index\" ON \"Cat\"(\"name\");\n",
+            )
+        })?;
+
+    api.create_migration("drop-index", dm1, &migrations_directory)
+        .send()
+        .await?
+        .assert_migration("drop-index", |migration| {
+            migration.assert_contents(
+                "-- prisma:no-transaction\n-- DropIndex\nDROP INDEX CONCURRENTLY \"Cat.name_index\";\n",
+            )
+        })?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial", "add-index", "drop-index"])?;
+
+    let migrations = api.migration_persistence().list_migrations().await?.unwrap();
+
+    for migration in migrations {
+        migration.assert_applied_steps_count(1)?.assert_success()?;
+    }
+
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| table.assert_indexes_count(0))?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn failed_non_transactional_migrations_are_resumed_where_they_stopped(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm1, &migrations_directory)
+        .send()
+        .await?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial"])?;
+
+    // The duplicate names make the unique index creation in the second migration fail.
+    api.database()
+        .raw_cmd(r#"INSERT INTO "Cat" ("id", "name") VALUES (1, 'Garfield'), (2, 'Garfield')"#)
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id      Int @id
+            name    String @unique
+            age     Int?
+        }
+    "#;
+
+    let script = r#"-- prisma:no-transaction
+ALTER TABLE "Cat" ADD COLUMN "age" INTEGER;
+CREATE UNIQUE INDEX CONCURRENTLY "Cat.name_unique" ON "Cat"("name");
+"#;
+
+    api.create_migration("second-migration", dm2, &migrations_directory)
+        .send()
+        .await?
+        .modify_migration(|contents| *contents = script.to_owned())?;
+
+    let result = api.apply_migrations(&migrations_directory).send().await;
+
+    assert!(result.is_err());
+
+    let second = api
+        .migration_persistence()
+        .list_migrations()
+        .await?
+        .unwrap()
+        .pop()
+        .unwrap();
+
+    assert!(second.logs.as_deref().unwrap().starts_with("Statement 2 of 2 failed."));
+
+    second
+        .assert_migration_name("second-migration")?
+        .assert_applied_steps_count(1)?
+        .assert_failed()?;
+
+    // The invalid index left behind by the failed statement was dropped.
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| table.assert_indexes_count(0))?;
+
+    api.database().raw_cmd(r#"DELETE FROM "Cat" WHERE "id" = 2"#).await?;
+
+    // The failed migration is only resumed on request.
+    let error = api
+        .apply_migrations(&migrations_directory)
+        .send()
+        .await
+        .unwrap_err()
+        .to_string();
+
+    assert!(error.contains("The migration is not transactional."), "{}", error);
+
+    // Adding the column a second time would fail.
+    api.apply_migrations(&migrations_directory)
+        .resume_failed_migrations(true)
+        .send()
+        .await?
+        .assert_applied_migrations(&["second-migration"])?;
+
+    let mut migrations = api.migration_persistence().list_migrations().await?.unwrap();
+
+    assert_eq!(migrations.len(), 2);
+
+    migrations
+        .pop()
+        .unwrap()
+        .assert_migration_name("second-migration")?
+        .assert_applied_steps_count(2)?
+        .assert_success()?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table
+            .assert_has_column("age")?
+            .assert_index_on_columns(&["name"], |index| index.assert_is_unique())
+    })?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn failed_concurrent_index_creation_does_not_drop_an_existing_valid_index(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm1, &migrations_directory)
+        .send()
+        .await?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial"])?;
+
+    // An index with the same name makes the index creation in the second migration fail.
+    api.database()
+        .raw_cmd(r#"CREATE UNIQUE INDEX "Cat.name_unique" ON "Cat"("name")"#)
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id      Int @id
+            name    String @unique
+        }
+    "#;
+
+    let script = r#"-- prisma:no-transaction
+CREATE UNIQUE INDEX CONCURRENTLY "Cat.name_unique" ON "Cat"("name");
+"#;
+
+    api.create_migration("second-migration", dm2, &migrations_directory)
+        .send()
+        .await?
+        .modify_migration(|contents| *contents = script.to_owned())?;
+
+    let result = api.apply_migrations(&migrations_directory).send().await;
+
+    assert!(result.is_err());
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table.assert_index_on_columns(&["name"], |index| index.assert_is_unique())
+    })?;
+
+    Ok(())
+}
@@ -0,0 +1,144 @@
+use crate::*;
+use user_facing_errors::{migration_engine::InvalidSeedFile, UserFacingError};
+
+const DM: &str = r#"
+    model Author {
+        id    Int    @id
+        name  String
+        books Book[]
+    }
+
+    model Book {
+        isbn     String @unique
+        title    String
+        authorId Int
+        author   Author @relation(fields: [authorId], references: [id])
+        id       Int    @id @default(autoincrement())
+    }
+"#;
+
+#[test_each_connector]
+async fn applying_a_seed_inserts_then_updates_the_records(api: &TestApi) -> TestResult {
+    api.schema_push(DM).send().await?.assert_green()?;
+
+    let seed_directory = api.create_migrations_directory()?;
+    let seed_file_path = seed_directory.path().join("seed.json");
+
+    // Books come first in the file, but they reference authors.
+    std::fs::write(
+        &seed_file_path,
+        r#"{
+            "Book": [{ "isbn": "978-0140449136", "title": "Crime and Punishment", "authorId": 1 }],
+            "Author": [{ "id": 1, "name": "Fyodor Dostoevsky" }]
+        }"#,
+    )?;
+
+    api.apply_seed(DM, &seed_file_path)
+        .send()
+        .await?
+        .assert_seeded(&[("Author", 1, 0), ("Book", 1, 0)])?;
+
+    std::fs::write(
+        &seed_file_path,
+        r#"{
+            "Author": [{ "id": 1, "name": "Fyodor Dostoyevsky" }, { "id": 2, "name": "Leo Tolstoy" }]
+        }"#,
+    )?;
+
+    api.apply_seed(DM, &seed_file_path)
+        .send()
+        .await?
+        .assert_seeded(&[("Author", 1, 1)])?;
+
+    api.dump_table("Author").await?.assert_row_count(2)?;
+
+    api.dump_table("Book").await?.assert_single_row(|row| {
+        row.assert_text_value("isbn", "978-0140449136")?
+            .assert_text_value("title", "Crime and Punishment")?
+            .assert_int_value("authorId", 1)
+    })?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn seed_files_can_be_written_in_yaml(api: &TestApi) -> TestResult {
+    api.schema_push(DM).send().await?.assert_green()?;
+
+    let seed_directory = api.create_migrations_directory()?;
+    let seed_file_path = seed_directory.path().join("seed.yaml");
+
+    std::fs::write(
+        &seed_file_path,
+        "Author:\n  - id: 1\n    name: Leo Tolstoy\n  - id: 2\n    name: Anton Chekhov\n",
+    )?;
+
+    api.apply_seed(DM, &seed_file_path)
+        .send()
+        .await?
+        .assert_seeded(&[("Author", 2, 0)])?;
+
+    api.dump_table("Author").await?.assert_row_count(2)?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn invalid_seed_files_are_rejected(api: &TestApi) -> TestResult {
+    api.schema_push(DM).send().await?.assert_green()?;
+
+    let seed_directory = api.create_migrations_directory()?;
+    let seed_file_path = seed_directory.path().join("seed.json");
+
+    std::fs::write(
+        &seed_file_path,
+        r#"{
+            "Author": [{ "id": 1, "name": "Leo Tolstoy", "birthYear": 1828 }],
+            "Publisher": []
+        }"#,
+    )?;
+
+    let known_error = api
+        .apply_seed(DM, &seed_file_path)
+        .send()
+        .await
+        .unwrap_err()
+        .render_user_facing()
+        .unwrap_known();
+
+    assert_eq!(known_error.error_code, InvalidSeedFile::ERROR_CODE);
+    assert!(known_error
+        .message
+        .contains("The field `birthYear` in the record 0 of model `Author` does not exist in the schema."));
+    assert!(known_error
+        .message
+        .contains("The model `Publisher` does not exist in the schema."));
+
+    api.dump_table("Author").await?.assert_row_count(0)?;
+
+    Ok(())
+}
+
+#[test_each_connector(ignore("sqlite"))]
+async fn seeds_are_applied_in_a_transaction(api: &TestApi) -> TestResult {
+    api.schema_push(DM).send().await?.assert_green()?;
+
+    let seed_directory = api.create_migrations_directory()?;
+    let seed_file_path = seed_directory.path().join("seed.json");
+
+    // The author is inserted first, then the book fails on its foreign key.
+    std::fs::write(
+        &seed_file_path,
+        r#"{
+            "Author": [{ "id": 1, "name": "Fyodor Dostoevsky" }],
+            "Book": [{ "isbn": "978-0140449136", "title": "Crime and Punishment", "authorId": 2 }]
+        }"#,
+    )?;
+
+    assert!(api.apply_seed(DM, &seed_file_path).send().await.is_err());
+
+    api.dump_table("Author").await?.assert_row_count(0)?;
+    api.dump_table("Book").await?.assert_row_count(0)?;
+
+    Ok(())
+}
@@ -0,0 +1,89 @@
+use crate::*;
+use user_facing_errors::{migration_engine::CannotBaselineMigrationHistory, UserFacingError};
+
+const CATS: &str = r#"
+    model Cat {
+        id   Int    @id
+        name String @unique
+    }
+"#;
+
+#[test_each_connector]
+async fn baselining_an_existing_database_creates_an_applied_initial_migration(api: &TestApi) -> TestResult {
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.schema_push(CATS).send().await?.assert_green()?;
+
+    api.create_baseline_migration("01-baseline", &migrations_directory)
+        .send()
+        .await?
+        .assert_script_contains("Cat")?;
+
+    api.list_migration_directories(&migrations_directory)
+        .send()
+        .await?
+        .assert_listed_directories(&["01-baseline"])?;
+
+    assert!(migrations_directory.path().join("migration_lock.toml").exists());
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&[])?;
+
+    let diagnostic = api
+        .diagnose_migration_history(&migrations_directory)
+        .opt_in_to_shadow_database(true)
+        .send()
+        .await?
+        .into_output();
+
+    assert!(diagnostic.is_empty());
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn baseline_migrations_recreate_the_schema_on_fresh_databases(api: &TestApi) -> TestResult {
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.schema_push(CATS).send().await?.assert_green()?;
+
+    api.create_baseline_migration("01-baseline", &migrations_directory)
+        .send()
+        .await?;
+
+    api.reset().send().await?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["01-baseline"])?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| table.assert_has_column("name"))?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn baselining_is_refused_when_there_is_a_migration_history(api: &TestApi) -> TestResult {
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("01-cats", CATS, &migrations_directory)
+        .send()
+        .await?;
+
+    let known_error = api
+        .create_baseline_migration("02-baseline", &migrations_directory)
+        .send()
+        .await
+        .unwrap_err()
+        .render_user_facing()
+        .unwrap_known();
+
+    assert_eq!(known_error.error_code, CannotBaselineMigrationHistory::ERROR_CODE);
+
+    Ok(())
+}
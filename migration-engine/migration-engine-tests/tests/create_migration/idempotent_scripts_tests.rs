@@ -0,0 +1,250 @@
+use crate::*;
+use migration_connector::ScriptMode;
+use migration_engine_tests::{test_each_connector, TestResult};
+
+#[test_each_connector]
+async fn idempotent_scripts_for_new_tables_can_be_applied_twice(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id      Int @id
+            name    String
+            ownerId Int
+            owner   Human @relation(fields: [ownerId], references: [id])
+
+            @@index([name])
+        }
+
+        model Human {
+            id   Int @id
+            cats Cat[]
+        }
+    "#;
+
+    let dir = api.create_migrations_directory()?;
+
+    let script = api
+        .create_migration("create-cats", dm, &dir)
+        .script_mode(ScriptMode::Idempotent)
+        .send()
+        .await?
+        .migration_script()?;
+
+    api.database().raw_cmd(&script).await?;
+    api.database().raw_cmd(&script).await?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| {
+            table
+                .assert_index_on_columns(&["name"], |index| index.assert_is_not_unique())?
+                .assert_fk_on_columns(&["ownerId"], |fk| fk.assert_references("Human", &["id"]))
+        })?
+        .assert_has_table("Human")?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn idempotent_scripts_can_be_applied_again_after_a_partial_run(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id      Int @id
+            name    String
+            ownerId Int
+            owner   Human @relation(fields: [ownerId], references: [id])
+
+            @@index([name])
+        }
+
+        model Human {
+            id   Int @id
+            cats Cat[]
+        }
+    "#;
+
+    let dir = api.create_migrations_directory()?;
+
+    let script = api
+        .create_migration("create-cats", dm, &dir)
+        .script_mode(ScriptMode::Idempotent)
+        .send()
+        .await?
+        .migration_script()?;
+
+    // The first run stops after the tables are created, before the indexes and foreign keys.
+    let tables_only = &script[..script.find("-- CreateIndex").unwrap()];
+
+    api.database().raw_cmd(tables_only).await?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| table.assert_indexes_count(0))?;
+
+    api.database().raw_cmd(&script).await?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| {
+            table
+                .assert_index_on_columns(&["name"], |index| index.assert_is_not_unique())?
+                .assert_fk_on_columns(&["ownerId"], |fk| fk.assert_references("Human", &["id"]))
+        })?
+        .assert_has_table("Human")?;
+
+    Ok(())
+}
+
+#[test_each_connector(ignore("sqlite"))]
+async fn idempotent_scripts_for_column_changes_can_be_applied_twice(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id   Int @id
+            name String
+        }
+    "#;
+
+    let dir = api.create_migrations_directory()?;
+
+    api.create_migration("create-cats", dm1, &dir).send().await?;
+    api.apply_migrations(&dir).send().await?;
+
+    let dm2 = r#"
+        model Cat {
+            id  Int @id
+            age Int?
+        }
+    "#;
+
+    let script = api
+        .create_migration("replace-name-with-age", dm2, &dir)
+        .script_mode(ScriptMode::Idempotent)
+        .send()
+        .await?
+        .migration_script()?;
+
+    api.database().raw_cmd(&script).await?;
+    api.database().raw_cmd(&script).await?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table.assert_has_column("age")?.assert_does_not_have_column("name")
+    })?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn idempotent_scripts_guard_enum_creation_on_postgres(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id   Int @id
+            mood Mood
+        }
+
+        enum Mood {
+            HUNGRY
+            SLEEPY
+        }
+    "#;
+
+    let dir = api.create_migrations_directory()?;
+
+    let script = api
+        .create_migration("create-cats", dm, &dir)
+        .script_mode(ScriptMode::Idempotent)
+        .send()
+        .await?
+        .migration_script()?;
+
+    assert!(script.contains("DO $$"));
+
+    api.database().raw_cmd(&script).await?;
+    api.database().raw_cmd(&script).await?;
+
+    api.assert_schema()
+        .await?
+        .assert_enum("Mood", |enm| enm.assert_values(&["HUNGRY", "SLEEPY"]))?
+        .assert_has_table("Cat")?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn idempotent_scripts_guard_enum_value_changes_on_postgres(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id   Int @id
+            mood Mood
+        }
+
+        enum Mood {
+            HUNGRY
+            SLEEPY
+        }
+    "#;
+
+    let dir = api.create_migrations_directory()?;
+
+    api.create_migration("create-cats", dm1, &dir).send().await?;
+    api.apply_migrations(&dir).send().await?;
+
+    let dm2 = r#"
+        model Cat {
+            id   Int @id
+            mood Mood
+        }
+
+        enum Mood {
+            HUNGRY
+            SLEEPY
+            HAPPY
+        }
+    "#;
+
+    let script = api
+        .create_migration("add-happy", dm2, &dir)
+        .script_mode(ScriptMode::Idempotent)
+        .send()
+        .await?
+        .migration_script()?;
+
+    // Added values are added outside of a transaction, one statement at a time.
+    for _ in 0..2 {
+        for statement in script.split(";\n").filter(|statement| !statement.trim().is_empty()) {
+            api.database().raw_cmd(statement).await?;
+        }
+    }
+
+    api.assert_schema()
+        .await?
+        .assert_enum("Mood", |enm| enm.assert_values(&["HUNGRY", "SLEEPY", "HAPPY"]))?;
+
+    let dm3 = r#"
+        model Cat {
+            id   Int @id
+            mood Mood
+        }
+
+        enum Mood {
+            HUNGRY
+            HAPPY
+        }
+    "#;
+
+    let script = api
+        .create_migration("remove-sleepy", dm3, &dir)
+        .script_mode(ScriptMode::Idempotent)
+        .send()
+        .await?
+        .migration_script()?;
+
+    api.database().raw_cmd(&script).await?;
+    api.database().raw_cmd(&script).await?;
+
+    api.assert_schema()
+        .await?
+        .assert_enum("Mood", |enm| enm.assert_values(&["HUNGRY", "HAPPY"]))?
+        .assert_has_no_enum("Mood_old")?
+        .assert_has_no_enum("Mood_new")?;
+
+    Ok(())
+}
@@ -1 +1,2 @@
 mod create_migration_tests;
+mod idempotent_scripts_tests;
@@ -351,7 +351,7 @@ async fn diagnose_migrations_history_can_detect_when_the_folder_is_behind(api: &
 
     assert!(failed_migration_names.is_empty());
     assert!(edited_migration_names.is_empty());
-    assert!(matches!(drift, Some(DriftDiagnostic::DriftDetected { rollback: _ })));
+    assert!(matches!(drift, Some(DriftDiagnostic::DriftDetected { .. })));
     assert_eq!(
         history,
         Some(HistoryDiagnostic::MigrationsDirectoryIsBehind {
@@ -441,7 +441,7 @@ async fn diagnose_migrations_history_can_detect_when_history_diverges(api: &Test
 
     assert!(failed_migration_names.is_empty());
     assert!(edited_migration_names.is_empty());
-    assert!(matches!(drift, Some(DriftDiagnostic::DriftDetected { rollback: _ })));
+    assert!(matches!(drift, Some(DriftDiagnostic::DriftDetected { .. })));
     assert_eq!(
         history,
         Some(HistoryDiagnostic::HistoriesDiverge {
@@ -784,7 +784,7 @@ async fn drift_can_be_detected_without_migrations_table(api: &TestApi) -> TestRe
         .await?
         .into_output();
 
-    assert!(matches!(drift, Some(DriftDiagnostic::DriftDetected { rollback: _ })));
+    assert!(matches!(drift, Some(DriftDiagnostic::DriftDetected { .. })));
     assert!(
         matches!(history, Some(HistoryDiagnostic::DatabaseIsBehind { unapplied_migration_names: migs }) if migs.len() == 1)
     );
@@ -796,6 +796,65 @@ async fn drift_can_be_detected_without_migrations_table(api: &TestApi) -> TestRe
     Ok(())
 }
 
+#[test_each_connector(tags("postgres"))]
+async fn drift_reports_describe_what_drifted(api: &TestApi) -> TestResult {
+    let directory = api.create_migrations_directory()?;
+
+    let dm = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    api.create_migration("initial", dm, &directory).send().await?;
+
+    api.apply_migrations(&directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial"])?;
+
+    api.database()
+        .raw_cmd(
+            r#"
+            ALTER TABLE "Cat" ALTER COLUMN "name" TYPE VARCHAR(191), ADD COLUMN "nickname" TEXT;
+            CREATE TABLE "Dog" ("id" INTEGER PRIMARY KEY);
+            "#,
+        )
+        .await?;
+
+    let drift = api
+        .diagnose_migration_history(&directory)
+        .opt_in_to_shadow_database(true)
+        .send()
+        .await?
+        .into_output()
+        .drift;
+
+    let (report, summary) = match drift {
+        Some(DriftDiagnostic::DriftDetected { report, summary, .. }) => (report, summary),
+        other => panic!("Expected drift, found {:?}", other),
+    };
+
+    assert_eq!(report.added_tables, &["Dog"]);
+    assert_eq!(report.changed_tables.len(), 1);
+
+    let cat = &report.changed_tables[0];
+
+    assert_eq!(cat.table, "Cat");
+    assert_eq!(cat.added_columns[0].name, "nickname");
+    assert_eq!(cat.changed_columns[0].before.column_type, "TEXT");
+    assert_eq!(cat.changed_columns[0].after.column_type, "VARCHAR(191)");
+
+    let expected_summary = "Added table `Dog`\n\
+        Added column `Cat.nickname` (TEXT)\n\
+        Column `Cat.name` changed from TEXT to VARCHAR(191)\n";
+
+    assert_eq!(summary, expected_summary);
+
+    Ok(())
+}
+
 #[test_each_connector(tags("mysql_8"))]
 async fn shadow_database_creation_error_is_special_cased_mysql(api: &TestApi) -> TestResult {
     let directory = api.create_migrations_directory()?;
@@ -21,6 +21,7 @@ async fn evaluate_data_loss_with_an_up_to_date_database_returns_no_step(api: &Te
         migration_steps: vec![],
         warnings: vec![],
         unexecutable_steps: vec![],
+        lints: vec![],
     };
 
     assert_eq!(output, expected_output);
@@ -715,11 +715,8 @@ async fn enum_variants_can_be_dropped_without_data_loss(api: &TestApi) -> TestRe
         .send()
         .await?;
 
-    if api.sql_family().is_mysql() {
-        res.assert_warnings(&["The migration will remove the values [OUTRAGED] on the enum `Cat_mood`. If these variants are still used in the database, the migration will fail.".into(), "The migration will remove the values [OUTRAGED] on the enum `Human_mood`. If these variants are still used in the database, the migration will fail.".into()])?;
-    } else {
-        res.assert_warnings(&["The migration will remove the values [OUTRAGED] on the enum `Mood`. If these variants are still used in the database, the migration will fail.".into()])?;
-    }
+    // No row holds the removed value.
+    res.assert_no_warning()?;
 
     // Assertions
     {
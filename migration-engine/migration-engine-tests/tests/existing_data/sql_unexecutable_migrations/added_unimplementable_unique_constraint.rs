@@ -100,7 +100,7 @@ async fn dropping_enum_values_should_warn(api: &TestApi) -> TestResult {
         .force(false)
         .send()
         .await?
-        .assert_warnings(&["The migration will remove the values [george] on the enum `Test_name`. If these variants are still used in the database, the migration will fail.".into()])?;
+        .assert_warnings(&["The migration will remove the values [george] on the enum `Test_name`, which are still used in 2 rows. The migration will fail unless these rows are updated first.".into()])?;
 
     let rows = api.select("Test").column("id").column("name").send_debug().await?;
 
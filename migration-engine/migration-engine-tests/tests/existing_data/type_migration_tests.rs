@@ -16,7 +16,7 @@ async fn altering_the_type_of_a_column_in_a_non_empty_table_warns(api: &TestApi)
     let insert = quaint::ast::Insert::single_into(api.render_table_name("User"))
         .value("id", "abc")
         .value("name", "Shinzo")
-        .value("dogs", 7);
+        .value("dogs", 3_000_000_000i64);
 
     api.database().query(insert.into()).await?;
 
@@ -30,7 +30,9 @@ async fn altering_the_type_of_a_column_in_a_non_empty_table_warns(api: &TestApi)
 
     api.schema_push(dm2).send().await?.assert_warnings(&[
         if api.is_postgres() {
-            "You are about to alter the column `dogs` on the `User` table, which contains 1 non-null values. The data in that column will be cast from `BigInt` to `Integer`.".into()
+            "You are about to alter the column `dogs` on the `User` table. The data in that column will be cast from `BigInt` to `Integer`. 1 values do not fit in `Integer`. The migration will fail, or they will be truncated.".into()
+        } else if api.is_mysql() {
+            "You are about to alter the column `dogs` on the `User` table. The data in that column will be cast from `BigInt` to `Int`. 1 values do not fit in `Int`. The migration will fail, or they will be truncated.".into()
         } else {
             "You are about to alter the column `dogs` on the `User` table, which contains 1 non-null values. The data in that column will be cast from `BigInt` to `Int`.".into()
         }
@@ -38,7 +40,7 @@ async fn altering_the_type_of_a_column_in_a_non_empty_table_warns(api: &TestApi)
 
     let rows = api.select("User").column("dogs").send().await?;
 
-    rows.assert_single_row(|row| row.assert_int_value("dogs", 7))?;
+    rows.assert_single_row(|row| row.assert_int_value("dogs", 3_000_000_000))?;
 
     api.assert_schema().await?.assert_table("User", |table| {
         table.assert_column("dogs", |col| col.assert_type_is_bigint()?.assert_is_required())
@@ -0,0 +1,223 @@
+use crate::*;
+use migration_connector::{LintRules, LintSeverity};
+use std::io::Write as _;
+
+const CATS: &str = r#"
+    model Cat {
+        id   Int    @id
+        name String
+    }
+"#;
+
+const INDEXED_CATS: &str = r#"
+    model Cat {
+        id   Int    @id
+        name String
+
+        @@index([name])
+    }
+"#;
+
+#[test_each_connector(tags("postgres"))]
+async fn evaluate_data_loss_lints_indexes_on_existing_tables(api: &TestApi) -> TestResult {
+    let directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", CATS, &directory).send().await?;
+    api.apply_migrations(&directory).send().await?;
+
+    api.evaluate_data_loss(&directory, INDEXED_CATS)
+        .send()
+        .await?
+        .assert_lints(&[("create-index-non-concurrently", 0)])?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn indexes_on_new_tables_are_not_linted(api: &TestApi) -> TestResult {
+    let directory = api.create_migrations_directory()?;
+
+    api.evaluate_data_loss(&directory, INDEXED_CATS)
+        .send()
+        .await?
+        .assert_lints(&[])?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn lint_rules_can_be_turned_off(api: &TestApi) -> TestResult {
+    let directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", CATS, &directory).send().await?;
+    api.apply_migrations(&directory).send().await?;
+
+    let mut lint_rules = LintRules::default();
+    lint_rules.set("create-index-non-concurrently", LintSeverity::Off);
+
+    api.evaluate_data_loss(&directory, INDEXED_CATS)
+        .lint_rules(lint_rules)
+        .send()
+        .await?
+        .assert_lints(&[])?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn lint_errors_prevent_migration_creation_unless_draft(api: &TestApi) -> TestResult {
+    let directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", CATS, &directory).send().await?;
+    api.apply_migrations(&directory).send().await?;
+
+    let mut lint_rules = LintRules::default();
+    lint_rules.set("create-index-non-concurrently", LintSeverity::Error);
+
+    let error = api
+        .create_migration("add-index", INDEXED_CATS, &directory)
+        .lint_rules(lint_rules.clone())
+        .send()
+        .await
+        .unwrap_err();
+
+    assert!(
+        error.to_string().contains("create-index-non-concurrently"),
+        error.to_string()
+    );
+
+    let output = api
+        .create_migration("add-index", INDEXED_CATS, &directory)
+        .lint_rules(lint_rules)
+        .draft(true)
+        .send()
+        .await?
+        .assert_migration_directories_count(2)?
+        .into_output();
+
+    assert_eq!(output.lints.len(), 1);
+    assert!(output.lints[0].is_error());
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn lint_migrations_lints_existing_migration_scripts(api: &TestApi) -> TestResult {
+    let directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", CATS, &directory).send().await?;
+
+    let migration_directory = directory.path().join("20210101000000_risky-changes");
+    std::fs::create_dir(&migration_directory)?;
+    let mut file = std::fs::File::create(migration_directory.join("migration.sql"))?;
+    write!(
+        file,
+        r#"
+        -- CreateIndex
+        CREATE INDEX "Cat.name_index" ON "Cat"("name");
+
+        -- AlterTable
+        ALTER TABLE "Cat" ALTER COLUMN "name" SET DATA TYPE VARCHAR(40);
+
+        -- CreateIndex
+        CREATE INDEX CONCURRENTLY "Cat.id_name_index" ON "Cat"("id", "name");
+        "#
+    )?;
+
+    api.lint_migrations(&directory).send().await?.assert_lints(&[(
+        "risky-changes",
+        &["create-index-non-concurrently", "change-column-type"],
+    )])?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql", "mssql"))]
+async fn postgres_specific_lints_are_not_reported_on_other_databases(api: &TestApi) -> TestResult {
+    let directory = api.create_migrations_directory()?;
+
+    let dm1 = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+
+        model Human {
+            id Int @id
+        }
+    "#;
+
+    api.create_migration("initial", dm1, &directory).send().await?;
+    api.apply_migrations(&directory).send().await?;
+
+    let dm2 = r#"
+        model Cat {
+            id      Int    @id
+            name    String
+            ownerId Int?
+            owner   Human? @relation(fields: [ownerId], references: [id])
+
+            @@index([name])
+        }
+
+        model Human {
+            id   Int @id
+            cats Cat[]
+        }
+    "#;
+
+    api.evaluate_data_loss(&directory, dm2)
+        .send()
+        .await?
+        .assert_lints(&[])?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn required_columns_defaulting_to_the_current_timestamp_are_not_linted(api: &TestApi) -> TestResult {
+    let directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", CATS, &directory).send().await?;
+    api.apply_migrations(&directory).send().await?;
+
+    let dm2 = r#"
+        model Cat {
+            id        Int      @id
+            name      String
+            createdAt DateTime @default(dbgenerated("now()"))
+            token     String   @default(dbgenerated("md5(random()::text)"))
+        }
+    "#;
+
+    api.evaluate_data_loss(&directory, dm2)
+        .send()
+        .await?
+        .assert_lints(&[("add-required-column-with-volatile-default", 0)])?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn unknown_lint_rules_are_rejected(api: &TestApi) -> TestResult {
+    let directory = api.create_migrations_directory()?;
+
+    let mut lint_rules = LintRules::default();
+    lint_rules.set("create-index-non-concurently", LintSeverity::Off);
+
+    let error = api
+        .lint_migrations(&directory)
+        .lint_rules(lint_rules)
+        .send()
+        .await
+        .unwrap_err()
+        .to_string();
+
+    assert!(
+        error.contains("Unknown migration lint rules: create-index-non-concurently."),
+        "{}",
+        error
+    );
+
+    Ok(())
+}
@@ -1,4 +1,6 @@
 mod apply_migrations;
+mod apply_seed;
+mod create_baseline_migration;
 mod create_migration;
 mod diagnose_migration_history;
 mod errors;
@@ -6,11 +8,13 @@ mod evaluate_data_loss;
 mod existing_data;
 mod existing_databases;
 mod initialization;
+mod lint_migrations;
 mod list_migration_directories;
 mod migrations;
 mod native_types;
 mod reset;
 mod schema_push;
+mod squash_migrations;
 
 use migration_engine_tests::sql::*;
 use pretty_assertions::assert_eq;
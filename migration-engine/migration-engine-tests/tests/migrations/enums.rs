@@ -1,3 +1,4 @@
+use migration_connector::EnumValueRename;
 use migration_engine_tests::sql::*;
 
 #[test_each_connector(capabilities("enums"))]
@@ -139,7 +140,7 @@ async fn variants_can_be_removed_from_an_existing_enum(api: &TestApi) -> TestRes
         .force(true)
         .send()
         .await?
-        .assert_warnings(&[format!("The migration will remove the values [HAPPY] on the enum `{}`. If these variants are still used in the database, the migration will fail.", enum_name).into()])?
+        .assert_no_warning()?
         .assert_executable()?;
 
     api.assert_schema()
@@ -148,3 +149,271 @@ async fn variants_can_be_removed_from_an_existing_enum(api: &TestApi) -> TestRes
 
     Ok(())
 }
+
+#[test_each_connector(capabilities("enums"))]
+async fn enum_values_can_be_renamed_with_existing_data(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id String @id
+            mood CatMood
+        }
+
+        enum CatMood {
+            HAPPY
+            HUNGRY
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    api.insert("Cat")
+        .value("id", "felix")
+        .value("mood", "HUNGRY")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id String @id
+            mood CatMood
+        }
+
+        enum CatMood {
+            HAPPY
+            STARVING
+        }
+    "#;
+
+    let enum_name = if api.sql_family().is_mysql() {
+        "Cat_mood"
+    } else {
+        "CatMood"
+    };
+
+    // We can't tell a rename apart from a removal and an addition without being told.
+    api.schema_push(dm2)
+        .send()
+        .await?
+        .assert_warnings(&[format!("The migration will remove the values [HUNGRY] on the enum `{}`, which are still used in 1 rows. The migration will fail unless these rows are updated first.", enum_name).into()])?;
+
+    let rename = EnumValueRename {
+        enum_name: enum_name.to_owned(),
+        previous_value: "HUNGRY".to_owned(),
+        next_value: "STARVING".to_owned(),
+    };
+
+    api.schema_push(dm2)
+        .enum_value_renames(vec![rename])
+        .send()
+        .await?
+        .assert_green()?;
+
+    api.assert_schema()
+        .await?
+        .assert_enum(enum_name, |enm| enm.assert_values(&["HAPPY", "STARVING"]))?;
+
+    let rows = api.select("Cat").column("id").column("mood").send_debug().await?;
+
+    if api.sql_family().is_mysql() {
+        assert_eq!(rows, &[&[r#"Text(Some("felix"))"#, r#"Text(Some("STARVING"))"#]]);
+    } else {
+        assert_eq!(rows, &[&[r#"Text(Some("felix"))"#, r#"Enum(Some("STARVING"))"#]]);
+    }
+
+    Ok(())
+}
+
+#[test_each_connector(capabilities("enums"))]
+async fn removing_enum_values_used_by_existing_rows_warns_with_the_row_count(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id String @id
+            mood CatMood
+        }
+
+        enum CatMood {
+            HAPPY
+            HUNGRY
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    for (id, mood) in &[("felix", "HAPPY"), ("mittens", "HAPPY"), ("garfield", "HUNGRY")] {
+        api.insert("Cat")
+            .value("id", *id)
+            .value("mood", *mood)
+            .result_raw()
+            .await?;
+    }
+
+    let dm2 = r#"
+        model Cat {
+            id String @id
+            mood CatMood
+        }
+
+        enum CatMood {
+            HUNGRY
+        }
+    "#;
+
+    let enum_name = if api.sql_family().is_mysql() {
+        "Cat_mood"
+    } else {
+        "CatMood"
+    };
+
+    api.schema_push(dm2)
+        .send()
+        .await?
+        .assert_warnings(&[format!("The migration will remove the values [HAPPY] on the enum `{}`, which are still used in 2 rows. The migration will fail unless these rows are updated first.", enum_name).into()])?;
+
+    api.assert_schema()
+        .await?
+        .assert_enum(enum_name, |enm| enm.assert_values(&["HAPPY", "HUNGRY"]))?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn enums_can_be_renamed_without_recreating_them(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id String @id
+            mood CatMood
+        }
+
+        enum CatMood {
+            HAPPY
+            HUNGRY
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    api.insert("Cat")
+        .value("id", "felix")
+        .value("mood", "HUNGRY")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id String @id
+            mood Mood
+        }
+
+        enum Mood {
+            HAPPY
+            HUNGRY
+        }
+    "#;
+
+    api.schema_push(dm2).send().await?.assert_green()?;
+
+    api.assert_schema()
+        .await?
+        .assert_has_no_enum("CatMood")?
+        .assert_enum("Mood", |enm| enm.assert_values(&["HAPPY", "HUNGRY"]))?;
+
+    let rows = api.select("Cat").column("id").column("mood").send_debug().await?;
+
+    assert_eq!(rows, &[&[r#"Text(Some("felix"))"#, r#"Enum(Some("HUNGRY"))"#]]);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn added_enum_values_can_be_used_as_defaults_in_the_same_migration(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id String @id
+            mood CatMood @default(HAPPY)
+        }
+
+        enum CatMood {
+            HAPPY
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Cat {
+            id String @id
+            mood CatMood @default(HUNGRY)
+        }
+
+        enum CatMood {
+            HAPPY
+            HUNGRY
+        }
+    "#;
+
+    api.schema_push(dm2).send().await?.assert_green()?;
+
+    api.assert_schema()
+        .await?
+        .assert_enum("CatMood", |enm| enm.assert_values(&["HAPPY", "HUNGRY"]))?
+        .assert_table("Cat", |table| {
+            table.assert_column("mood", |col| {
+                col.assert_default_value(&prisma_value::PrismaValue::Enum("HUNGRY".into()))
+            })
+        })?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn added_enum_values_are_added_in_place_outside_of_a_transaction(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id String @id
+            mood CatMood
+        }
+
+        enum CatMood {
+            HAPPY
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm1, &migrations_directory)
+        .send()
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id String @id
+            mood CatMood
+        }
+
+        enum CatMood {
+            HUNGRY
+            HAPPY
+            SLEEPY
+        }
+    "#;
+
+    api.create_migration("add-values", dm2, &migrations_directory)
+        .send()
+        .await?
+        .assert_migration("add-values", |migration| {
+            migration.assert_contents(
+                "-- prisma:no-transaction\n-- AlterEnum\nALTER TYPE \"CatMood\" ADD VALUE 'HUNGRY' BEFORE 'HAPPY';\nALTER TYPE \"CatMood\" ADD VALUE 'SLEEPY' AFTER 'HAPPY';\n",
+            )
+        })?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial", "add-values"])?;
+
+    api.assert_schema()
+        .await?
+        .assert_enum("CatMood", |enm| enm.assert_values(&["HUNGRY", "HAPPY", "SLEEPY"]))?;
+
+    Ok(())
+}
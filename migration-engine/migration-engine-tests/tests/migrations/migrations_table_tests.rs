@@ -0,0 +1,184 @@
+use crate::*;
+use sql_migration_connector::MigrationsTable;
+
+const DM: &str = r#"
+    model Cat {
+        id   Int    @id
+        name String
+    }
+"#;
+
+#[test_each_connector]
+async fn applied_migrations_are_recorded_in_the_configured_table(api: &TestApi) -> TestResult {
+    let api = api
+        .with_migrations_table(MigrationsTable::new(Some("schema_history"), None))
+        .await?;
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("01-init", DM, &migrations_directory)
+        .send()
+        .await?
+        .assert_migration_directories_count(1)?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["01-init"])?;
+
+    api.assert_schema()
+        .await?
+        .assert_tables_count(2)?
+        .assert_has_table("Cat")?
+        .assert_has_table("schema_history")?;
+
+    let applied_migrations = api.migration_persistence().list_migrations().await?.unwrap();
+
+    assert_eq!(applied_migrations.len(), 1);
+    assert!(applied_migrations[0].migration_name.ends_with("01-init"));
+
+    // The migrations table is neither drift nor a change to migrate.
+    api.create_migration("02-empty", DM, &migrations_directory)
+        .send()
+        .await?
+        .assert_migration_directories_count(1)?;
+
+    let diagnostic = api
+        .diagnose_migration_history(&migrations_directory)
+        .opt_in_to_shadow_database(true)
+        .send()
+        .await?
+        .into_output();
+
+    assert!(diagnostic.is_empty());
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn the_migrations_table_can_live_in_another_schema(api: &TestApi) -> TestResult {
+    let migrations_schema = format!("{}_admin", api.schema_name());
+
+    api.database()
+        .raw_cmd(&format!("DROP SCHEMA IF EXISTS \"{}\" CASCADE", migrations_schema))
+        .await?;
+
+    let api = api
+        .with_migrations_table(MigrationsTable::new(None, Some(&migrations_schema)))
+        .await?;
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("01-init", DM, &migrations_directory)
+        .send()
+        .await?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["01-init"])?;
+
+    api.assert_schema()
+        .await?
+        .assert_tables_count(1)?
+        .assert_has_table("Cat")?;
+
+    let applied_migrations = api
+        .database()
+        .query_raw(
+            &format!(
+                "SELECT migration_name FROM \"{}\"._prisma_migrations",
+                migrations_schema
+            ),
+            &[],
+        )
+        .await?;
+
+    assert_eq!(applied_migrations.len(), 1);
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&[])?;
+
+    let diagnostic = api
+        .diagnose_migration_history(&migrations_directory)
+        .opt_in_to_shadow_database(true)
+        .send()
+        .await?
+        .into_output();
+
+    assert!(diagnostic.is_empty());
+
+    api.reset().send().await?;
+
+    assert!(api.migration_persistence().list_migrations().await?.is_err());
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn models_can_be_named_like_a_migrations_table_that_lives_in_another_schema(api: &TestApi) -> TestResult {
+    let migrations_schema = format!("{}_admin", api.schema_name());
+
+    api.database()
+        .raw_cmd(&format!("DROP SCHEMA IF EXISTS \"{}\" CASCADE", migrations_schema))
+        .await?;
+
+    let api = api
+        .with_migrations_table(MigrationsTable::new(None, Some(&migrations_schema)))
+        .await?;
+
+    let dm = r#"
+        model prisma_migration {
+            id   Int    @id
+            name String
+
+            @@map("_prisma_migrations")
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    api.assert_schema().await?.assert_has_table("_prisma_migrations")?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mssql"))]
+async fn the_migrations_schema_name_is_escaped(api: &TestApi) -> TestResult {
+    let migrations_schema = format!("{}_o'admin \"history\"", api.schema_name());
+
+    let cleanup = if api.is_mssql() {
+        format!(
+            "DROP TABLE IF EXISTS [{0}].[_prisma_migrations]; DROP SCHEMA IF EXISTS [{0}]",
+            migrations_schema
+        )
+    } else {
+        format!(
+            "DROP SCHEMA IF EXISTS \"{}\" CASCADE",
+            migrations_schema.replace('"', "\"\"")
+        )
+    };
+
+    api.database().raw_cmd(&cleanup).await?;
+
+    let api = api
+        .with_migrations_table(MigrationsTable::new(None, Some(&migrations_schema)))
+        .await?;
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("01-init", DM, &migrations_directory)
+        .send()
+        .await?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["01-init"])?;
+
+    let applied_migrations = api.migration_persistence().list_migrations().await?.unwrap();
+
+    assert_eq!(applied_migrations.len(), 1);
+
+    Ok(())
+}
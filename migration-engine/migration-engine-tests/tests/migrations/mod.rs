@@ -7,6 +7,7 @@ mod mariadb;
 mod mark_migration_applied_tests;
 mod mark_migration_rolled_back_tests;
 mod migration_persistence_tests;
+mod migrations_table_tests;
 mod mysql;
 mod postgres;
 mod sql;
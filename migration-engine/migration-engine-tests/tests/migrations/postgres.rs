@@ -175,3 +175,58 @@ async fn native_type_columns_can_be_created(api: &TestApi) -> TestResult {
 
     Ok(())
 }
+
+#[test_each_connector(tags("postgres"))]
+async fn generated_columns_can_be_created_and_changed(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Order {
+            id       Int @id
+            price    Int
+            quantity Int
+            total    Int @generated("price * quantity")
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+    api.assert_schema().await?.assert_table("Order", |table| {
+        table.assert_column("total", |col| col.assert_is_generated())
+    })?;
+
+    api.schema_push(dm1).send().await?.assert_green()?.assert_no_steps()?;
+
+    let dm2 = r#"
+        model Order {
+            id       Int @id
+            price    Int
+            quantity Int
+            total    Int @generated("price * quantity + 1")
+        }
+    "#;
+
+    api.schema_push(dm2)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+    api.schema_push(dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn generated_columns_with_casts_are_idempotent(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Product {
+            id    Int    @id
+            name  String
+            price Int
+            label String @generated("name || '!'")
+            gross Float  @generated("price * 1.2")
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
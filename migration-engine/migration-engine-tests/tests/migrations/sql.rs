@@ -1,3 +1,4 @@
+use migration_connector::EnumValueRename;
 use migration_engine_tests::sql::*;
 use quaint::prelude::Queryable;
 use std::borrow::Cow;
@@ -132,16 +133,46 @@ async fn enum_value_with_database_names_must_work(api: &TestApi) -> TestResult {
         }
     "##;
 
+    api.insert("Cat")
+        .value("id", "felix")
+        .value("mood", "hongry")
+        .result_raw()
+        .await?;
+
+    let enum_name = if api.is_mysql() { "Cat_mood" } else { "CatMood" };
+
+    // Without an explicit rename, the value is dropped and created.
+    api.schema_push(dm)
+        .send()
+        .await?
+        .assert_warnings(&[format!("The migration will remove the values [hongry] on the enum `{}`, which are still used in 1 rows. The migration will fail unless these rows are updated first.", enum_name).into()])?;
+
+    api.assert_schema()
+        .await?
+        .assert_enum(enum_name, |enm| enm.assert_values(&["ANGRY", "hongry"]))?;
+
+    let rename = EnumValueRename {
+        enum_name: enum_name.to_owned(),
+        previous_value: "hongry".to_owned(),
+        next_value: "hongery".to_owned(),
+    };
+
+    api.schema_push(dm)
+        .enum_value_renames(vec![rename])
+        .send()
+        .await?
+        .assert_green()?;
+
+    api.assert_schema()
+        .await?
+        .assert_enum(enum_name, |enm| enm.assert_values(&["ANGRY", "hongery"]))?;
+
+    let rows = api.select("Cat").column("id").column("mood").send_debug().await?;
+
     if api.is_mysql() {
-        api.schema_push(dm).force(true).send().await?.assert_warnings(&["The migration will remove the values [hongry] on the enum `Cat_mood`. If these variants are still used in the database, the migration will fail.".into()])?;
-        api.assert_schema()
-            .await?
-            .assert_enum("Cat_mood", |enm| enm.assert_values(&["ANGRY", "hongery"]))?;
+        assert_eq!(rows, &[&[r#"Text(Some("felix"))"#, r#"Text(Some("hongery"))"#]]);
     } else {
-        api.schema_push(dm).force(true).send().await?.assert_warnings(&["The migration will remove the values [hongry] on the enum `CatMood`. If these variants are still used in the database, the migration will fail.".into()])?;
-        api.assert_schema()
-            .await?
-            .assert_enum("CatMood", |enm| enm.assert_values(&["ANGRY", "hongery"]))?;
+        assert_eq!(rows, &[&[r#"Text(Some("felix"))"#, r#"Enum(Some("hongery"))"#]]);
     }
 
     Ok(())
@@ -389,3 +420,42 @@ async fn dropping_mutually_referencing_tables_works(api: &TestApi) -> TestResult
 
     Ok(())
 }
+
+#[test_each_connector(tags("mssql", "postgres"))]
+async fn required_generated_columns_are_idempotent(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Order {
+            id       Int    @id
+            price    Int
+            quantity Int
+            total    Int    @generated("price * quantity")
+            note     String @generated("'a b'")
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Order", |table| {
+        table.assert_column("total", |col| col.assert_is_generated()?.assert_is_required())
+    })?;
+
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    let dm2 = r#"
+        model Order {
+            id       Int    @id
+            price    Int
+            quantity Int
+            total    Int    @generated("price * quantity")
+            note     String @generated("'ab'")
+        }
+    "#;
+
+    api.schema_push(dm2)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+
+    Ok(())
+}
@@ -272,21 +272,6 @@ const SAFE_CASTS: Cases = &[
 ];
 
 const RISKY_CASTS: Cases = &[
-    (
-        "BigInt",
-        quaint::Value::Integer(Some(100)),
-        &[
-            "Int",
-            "MediumInt",
-            "SmallInt",
-            "TinyInt",
-            "UnsignedBigInt",
-            "UnsignedInt",
-            "UnsignedMediumInt",
-            "UnsignedSmallInt",
-            "UnsignedTinyInt",
-        ],
-    ),
     ("BigInt", quaint::Value::Integer(Some(2000)), &["Year"]),
     (
         "Binary(8)",
@@ -392,6 +377,34 @@ const RISKY_CASTS: Cases = &[
     ),
 ];
 
+const NARROWING_CASTS: Cases = &[
+    (
+        "BigInt",
+        quaint::Value::Integer(Some(100)),
+        &[
+            "Int",
+            "MediumInt",
+            "SmallInt",
+            "TinyInt",
+            "UnsignedBigInt",
+            "UnsignedInt",
+            "UnsignedMediumInt",
+            "UnsignedSmallInt",
+            "UnsignedTinyInt",
+        ],
+    ),
+    (
+        "VarChar(20)",
+        quaint::Value::Text(Some(Cow::Borrowed("abc"))),
+        &["Char(5)", "VarChar(3)"],
+    ),
+    (
+        "Decimal(10,2)",
+        quaint::Value::Text(Some(Cow::Borrowed("12.5"))),
+        &["Decimal(5,1)"],
+    ),
+];
+
 const IMPOSSIBLE_CASTS: Cases = &[
     (
         "BigInt",
@@ -866,6 +879,90 @@ async fn risky_casts_with_existing_data_should_warn(api: &TestApi) -> TestResult
     Ok(())
 }
 
+#[test_each_connector(tags("mysql"))]
+async fn narrowing_casts_with_fitting_data_should_not_warn(api: &TestApi) -> TestResult {
+    let connector = sql_datamodel_connector::MySqlDatamodelConnector::new();
+    let mut dm1 = String::with_capacity(256);
+    let mut dm2 = String::with_capacity(256);
+    let colnames = colnames_for_cases(NARROWING_CASTS);
+    let conn = api.database();
+
+    for (from_type, test_value, to_types) in NARROWING_CASTS.iter() {
+        let span = tracing::info_span!("NarrowingCasts", from = %from_type, to = ?to_types);
+        let _span = span.enter();
+
+        tracing::info!("initial migration");
+
+        let insert = expand_cases(
+            api,
+            from_type,
+            test_value,
+            (*to_types, false),
+            &mut dm1,
+            &mut dm2,
+            &colnames,
+        );
+
+        api.schema_push(&dm1).send().await?.assert_green()?;
+
+        conn.insert(insert.into()).await?;
+
+        tracing::info!("cast migration");
+        api.schema_push(&dm2).send().await?.assert_green()?;
+
+        api.assert_schema().await?.assert_table("Test", |table| {
+            to_types.iter().enumerate().fold(Ok(table), |result, (idx, to_type)| {
+                result.and_then(|table| {
+                    table.assert_column(&colnames[idx], |col| col.assert_native_type(to_type, &connector))
+                })
+            })
+        })?;
+
+        conn.raw_cmd("DROP TABLE `Test`").await?;
+    }
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn narrowing_casts_with_data_that_does_not_fit_should_warn(api: &TestApi) -> TestResult {
+    let dm1 = api.native_types_datamodel(
+        r#"
+        model Test {
+            id Int @id
+            name String @test_db.VarChar(10)
+            count BigInt @test_db.BigInt
+            price Decimal @test_db.Decimal(10,4)
+        }
+        "#,
+    );
+
+    api.schema_push(&dm1).send().await?.assert_green()?;
+
+    api.database()
+        .raw_cmd("INSERT INTO `Test` (id, name, count, price) VALUES (1, 'Shinzo', 4, 12.5), (2, 'Bob', -300, 1.2345)")
+        .await?;
+
+    let dm2 = api.native_types_datamodel(
+        r#"
+        model Test {
+            id Int @id
+            name String @test_db.VarChar(3)
+            count Int @test_db.UnsignedSmallInt
+            price Decimal @test_db.Decimal(10,2)
+        }
+        "#,
+    );
+
+    api.schema_push(&dm2).send().await?.assert_executable()?.assert_warnings(&[
+        "You are about to alter the column `name` on the `Test` table. The data in that column will be cast from `VarChar(10)` to `VarChar(3)`. 1 values do not fit in `VarChar(3)`. The migration will fail, or they will be truncated.".into(),
+        "You are about to alter the column `count` on the `Test` table. The data in that column will be cast from `BigInt` to `UnsignedSmallInt`. 1 values do not fit in `UnsignedSmallInt`. The migration will fail, or they will be truncated.".into(),
+        "You are about to alter the column `price` on the `Test` table. The data in that column will be cast from `Decimal(10,4)` to `Decimal(10,2)`. 1 values have more than 2 decimal places and will be rounded.".into(),
+    ])?;
+
+    Ok(())
+}
+
 #[test_each_connector(tags("mysql"))]
 async fn impossible_casts_with_existing_data_should_warn(api: &TestApi) -> TestResult {
     let connector = sql_datamodel_connector::MySqlDatamodelConnector::new();
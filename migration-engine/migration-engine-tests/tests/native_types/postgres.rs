@@ -223,8 +223,6 @@ static RISKY_CASTS: Lazy<Vec<(&str, Value, &[&str])>> = Lazy::new(|| {
                 "Char(1)",
             ],
         ),
-        ("VarChar(5)", Value::text("t"), &["VarChar(3)", "Char(1)"]),
-        ("Text", Value::text("t"), &["VarChar(3)", "Char(1)"]),
         ("ByteA", Value::bytes(vec![1]), &["VarChar(4)", "Char(5)"]),
         ("VarBit(5)", Value::text("001"), &["Bit(3)"]),
         ("Xml", Value::xml("[]"), &["VarChar(100)", "Char(100)"]),
@@ -241,6 +239,19 @@ static RISKY_CASTS: Lazy<Vec<(&str, Value, &[&str])>> = Lazy::new(|| {
     ]
 });
 
+static NARROWING_CASTS: Lazy<Vec<(&str, Value, &[&str])>> = Lazy::new(|| {
+    vec![
+        ("VarChar(5)", Value::text("t"), &["VarChar(3)", "Char(1)"]),
+        ("Text", Value::text("t"), &["VarChar(3)", "Char(1)"]),
+        ("BigInt", Value::integer(100), &["SmallInt", "Integer"]),
+        (
+            "Decimal(10,2)",
+            Value::numeric(BigDecimal::from_str("12.5").unwrap()),
+            &["Decimal(5,1)"],
+        ),
+    ]
+});
+
 static NOT_CASTABLE: Lazy<Vec<(&str, Value, &[&str])>> = Lazy::new(|| {
     vec![
         (
@@ -993,6 +1004,128 @@ async fn risky_casts_with_existing_data_should_warn(api: &TestApi) -> TestResult
     Ok(())
 }
 
+#[test_each_connector(tags("postgres"))]
+async fn narrowing_casts_with_fitting_data_should_not_warn(api: &TestApi) -> TestResult {
+    let connector = SqlDatamodelConnectors::postgres();
+
+    for (from, seed, casts) in NARROWING_CASTS.iter() {
+        let mut previous_columns = "".to_string();
+        let mut next_columns = "".to_string();
+        let mut insert = Insert::single_into((api.schema_name(), "A"));
+        let mut next_assertions = vec![];
+
+        for (idx, to) in casts.iter().enumerate() {
+            println!("From `{}` to `{}` with seed `{:?}`", from, to, seed);
+
+            let column_name = format!("column_{}", idx);
+
+            previous_columns.push_str(&format!(
+                "{column_name}  {prisma_type}? @test_db.{native_type} \n",
+                prisma_type = prisma_type(from),
+                native_type = from,
+                column_name = column_name
+            ));
+
+            next_columns.push_str(&format!(
+                "{column_name}  {prisma_type}? @test_db.{native_type}\n",
+                prisma_type = prisma_type(to),
+                native_type = to,
+                column_name = column_name
+            ));
+
+            insert = insert.value(column_name.clone(), seed.clone());
+            next_assertions.push((column_name.clone(), *to));
+        }
+
+        let dm1 = api.native_types_datamodel(&format!(
+            r#"
+                model A {{
+                    id Int @id @default(autoincrement()) @test_db.Integer
+                    {columns}
+                }}
+                "#,
+            columns = previous_columns
+        ));
+
+        api.schema_push(&dm1).send().await?.assert_green()?;
+        api.database().insert(insert.into()).await?;
+
+        let dm2 = api.native_types_datamodel(&format!(
+            r#"
+                model A {{
+                    id Int @id @default(autoincrement()) @test_db.Integer
+                    {columns}
+                }}
+                "#,
+            columns = next_columns
+        ));
+
+        api.schema_push(&dm2).send().await?.assert_green()?;
+
+        api.assert_schema().await?.assert_table("A", |table| {
+            next_assertions.iter().fold(
+                table.assert_column_count(next_assertions.len() + 1),
+                |acc, (column_name, expected)| {
+                    acc.and_then(|table| {
+                        table.assert_column(column_name, |c| c.assert_native_type(expected, &connector))
+                    })
+                },
+            )
+        })?;
+
+        api.database()
+            .raw_cmd(&format!("DROP TABLE \"{}\".\"A\"", api.schema_name()))
+            .await?;
+    }
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn narrowing_casts_with_data_that_does_not_fit_should_warn(api: &TestApi) -> TestResult {
+    let dm1 = api.native_types_datamodel(
+        r#"
+        model A {
+            id Int @id @test_db.Integer
+            name String @test_db.VarChar(10)
+            count BigInt @test_db.BigInt
+            price Decimal @test_db.Decimal(10,4)
+        }
+        "#,
+    );
+
+    api.schema_push(&dm1).send().await?.assert_green()?;
+
+    for (id, name, count, price) in &[(1, "Shinzo", 4, "12.5"), (2, "Bob", 3_000_000_000i64, "1.2345")] {
+        let insert = Insert::single_into((api.schema_name(), "A"))
+            .value("id", *id)
+            .value("name", *name)
+            .value("count", *count)
+            .value("price", Value::numeric(BigDecimal::from_str(price).unwrap()));
+
+        api.database().insert(insert.into()).await?;
+    }
+
+    let dm2 = api.native_types_datamodel(
+        r#"
+        model A {
+            id Int @id @test_db.Integer
+            name String @test_db.VarChar(3)
+            count Int @test_db.Integer
+            price Decimal @test_db.Decimal(10,2)
+        }
+        "#,
+    );
+
+    api.schema_push(&dm2).send().await?.assert_executable()?.assert_warnings(&[
+            "You are about to alter the column `name` on the `A` table. The data in that column will be cast from `VarChar(10)` to `VarChar(3)`. 1 values do not fit in `VarChar(3)`. The migration will fail, or they will be truncated.".into(),
+            "You are about to alter the column `count` on the `A` table. The data in that column will be cast from `BigInt` to `Integer`. 1 values do not fit in `Integer`. The migration will fail, or they will be truncated.".into(),
+            "You are about to alter the column `price` on the `A` table. The data in that column will be cast from `Decimal(10,4)` to `Decimal(10,2)`. 1 values have more than 2 decimal places and will be rounded.".into(),
+    ])?;
+
+    Ok(())
+}
+
 #[test_each_connector(tags("postgres"))]
 async fn not_castable_with_existing_data_should_warn(api: &TestApi) -> TestResult {
     let connector = SqlDatamodelConnectors::postgres();
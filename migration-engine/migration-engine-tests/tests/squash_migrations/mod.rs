@@ -0,0 +1,312 @@
+use crate::*;
+use user_facing_errors::{
+    migration_engine::{
+        SquashedMigrationDirectoryExists, SquashedMigrationPartiallyApplied, SquashedMigrationsHaveNonSchemaStatements,
+    },
+    UserFacingError,
+};
+
+const CATS: &str = r#"
+    model Cat {
+        id   Int    @id
+        name String
+    }
+"#;
+
+const CATS_AND_DOGS: &str = r#"
+    model Cat {
+        id   Int    @id
+        name String
+    }
+
+    model Dog {
+        id   Int    @id
+        name String @unique
+    }
+"#;
+
+const CATS_DOGS_AND_MICE: &str = r#"
+    model Cat {
+        id   Int    @id
+        name String
+    }
+
+    model Dog {
+        id   Int    @id
+        name String @unique
+    }
+
+    model Mouse {
+        id   Int    @id
+    }
+"#;
+
+/// Returns the names of the migrations.
+async fn create_three_migrations(
+    api: &TestApi,
+    migrations_directory: &tempfile::TempDir,
+) -> anyhow::Result<Vec<String>> {
+    let mut migration_names = Vec::with_capacity(3);
+
+    for (name, dm) in &[
+        ("01-cats", CATS),
+        ("02-dogs", CATS_AND_DOGS),
+        ("03-mice", CATS_DOGS_AND_MICE),
+    ] {
+        let output = api
+            .create_migration(*name, *dm, migrations_directory)
+            .send()
+            .await?
+            .into_output();
+
+        migration_names.push(output.generated_migration_name.unwrap());
+    }
+
+    Ok(migration_names)
+}
+
+#[test_each_connector]
+async fn squashing_applied_migrations_replaces_them_and_marks_the_squashed_migration_applied(
+    api: &TestApi,
+) -> TestResult {
+    let migrations_directory = api.create_migrations_directory()?;
+
+    let migration_names = create_three_migrations(api, &migrations_directory).await?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["01-cats", "02-dogs", "03-mice"])?;
+
+    let output = api
+        .squash_migrations("02-squashed", &migrations_directory)
+        .to_migration(&migration_names[1])
+        .send()
+        .await?
+        .assert_squashed_migrations(&["01-cats", "02-dogs"])?
+        .assert_marked_applied(true)?
+        .into_output();
+
+    api.list_migration_directories(&migrations_directory)
+        .send()
+        .await?
+        .assert_listed_directories(&["02-squashed", "03-mice"])?;
+
+    let script = std::fs::read_to_string(
+        migrations_directory
+            .path()
+            .join(&output.generated_migration_name)
+            .join("migration.sql"),
+    )?;
+
+    assert!(script.starts_with(&format!(
+        "-- prisma:squashed {}\n-- prisma:squashed {}\n",
+        output.squashed_migration_names[0], output.squashed_migration_names[1]
+    )));
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&[])?;
+
+    let diagnostic = api
+        .diagnose_migration_history(&migrations_directory)
+        .opt_in_to_shadow_database(true)
+        .send()
+        .await?
+        .into_output();
+
+    assert!(diagnostic.is_empty());
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn squashed_migrations_are_applied_on_fresh_databases(api: &TestApi) -> TestResult {
+    let migrations_directory = api.create_migrations_directory()?;
+
+    let migration_names = create_three_migrations(api, &migrations_directory).await?;
+
+    api.squash_migrations("02-squashed", &migrations_directory)
+        .to_migration(&migration_names[1])
+        .send()
+        .await?
+        .assert_squashed_migrations(&["01-cats", "02-dogs"])?
+        .assert_marked_applied(false)?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["02-squashed", "03-mice"])?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| table.assert_has_column("name"))?
+        .assert_table("Dog", |table| {
+            table.assert_index_on_columns(&["name"], |index| index.assert_is_unique())
+        })?
+        .assert_has_table("Mouse")?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn squashed_migrations_are_marked_applied_on_databases_that_applied_the_originals(api: &TestApi) -> TestResult {
+    let migrations_directory = api.create_migrations_directory()?;
+
+    create_three_migrations(api, &migrations_directory).await?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["01-cats", "02-dogs", "03-mice"])?;
+
+    api.squash_migrations("03-squashed", &migrations_directory)
+        .send()
+        .await?
+        .assert_squashed_migrations(&["01-cats", "02-dogs", "03-mice"])?;
+
+    // Pretend the database is another environment, that applied the original
+    // migrations and never saw the squash.
+    api.database()
+        .raw_cmd(r#"DELETE FROM "_prisma_migrations" WHERE "migration_name" LIKE '%_03-squashed'"#)
+        .await?;
+
+    let diagnostic = api
+        .diagnose_migration_history(&migrations_directory)
+        .opt_in_to_shadow_database(true)
+        .send()
+        .await?
+        .into_output();
+
+    assert!(diagnostic.is_empty());
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&[])?;
+
+    let squashed_migration = api
+        .migration_persistence()
+        .list_migrations()
+        .await?
+        .unwrap()
+        .pop()
+        .unwrap();
+
+    assert!(squashed_migration.migration_name.ends_with("_03-squashed"));
+    squashed_migration.assert_success()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn squashed_migrations_are_not_applied_on_databases_that_applied_some_of_the_originals(
+    api: &TestApi,
+) -> TestResult {
+    let migrations_directory = api.create_migrations_directory()?;
+
+    create_three_migrations(api, &migrations_directory).await?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["01-cats", "02-dogs", "03-mice"])?;
+
+    api.squash_migrations("03-squashed", &migrations_directory)
+        .send()
+        .await?
+        .assert_marked_applied(true)?;
+
+    // Pretend the database is another environment, that only applied the first migration.
+    api.database()
+        .raw_cmd(r#"DELETE FROM "_prisma_migrations" WHERE "migration_name" NOT LIKE '%_01-cats'"#)
+        .await?;
+
+    let known_error = api
+        .apply_migrations(&migrations_directory)
+        .send()
+        .await
+        .unwrap_err()
+        .render_user_facing()
+        .unwrap_known();
+
+    assert_eq!(known_error.error_code, SquashedMigrationPartiallyApplied::ERROR_CODE);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn squashing_migrations_with_data_changes_requires_force(api: &TestApi) -> TestResult {
+    let migrations_directory = api.create_migrations_directory()?;
+
+    let migration_names = create_three_migrations(api, &migrations_directory).await?;
+
+    let insert = r#"INSERT INTO "Dog" ("id", "name") VALUES (1, 'Rex')"#;
+
+    let dogs_script_path = migrations_directory
+        .path()
+        .join(&migration_names[1])
+        .join("migration.sql");
+    let mut dogs_script = std::fs::read_to_string(&dogs_script_path)?;
+    dogs_script.push_str(insert);
+    dogs_script.push_str(";\n");
+    std::fs::write(&dogs_script_path, dogs_script)?;
+
+    let known_error = api
+        .squash_migrations("03-squashed", &migrations_directory)
+        .send()
+        .await
+        .unwrap_err()
+        .render_user_facing()
+        .unwrap_known();
+
+    assert_eq!(
+        known_error.error_code,
+        SquashedMigrationsHaveNonSchemaStatements::ERROR_CODE
+    );
+    assert!(known_error.message.contains(insert), "{}", known_error.message);
+
+    api.list_migration_directories(&migrations_directory)
+        .send()
+        .await?
+        .assert_listed_directories(&["01-cats", "02-dogs", "03-mice"])?;
+
+    api.squash_migrations("03-squashed", &migrations_directory)
+        .force(true)
+        .send()
+        .await?
+        .assert_squashed_migrations(&["01-cats", "02-dogs", "03-mice"])?
+        .assert_dropped_statements(&[("02-dogs", insert)])?;
+
+    api.list_migration_directories(&migrations_directory)
+        .send()
+        .await?
+        .assert_listed_directories(&["03-squashed"])?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn squashed_migrations_can_not_take_the_name_of_the_last_squashed_migration(api: &TestApi) -> TestResult {
+    let migrations_directory = api.create_migrations_directory()?;
+
+    create_three_migrations(api, &migrations_directory).await?;
+
+    let known_error = api
+        .squash_migrations("03-mice", &migrations_directory)
+        .send()
+        .await
+        .unwrap_err()
+        .render_user_facing()
+        .unwrap_known();
+
+    assert_eq!(known_error.error_code, SquashedMigrationDirectoryExists::ERROR_CODE);
+
+    api.list_migration_directories(&migrations_directory)
+        .send()
+        .await?
+        .assert_listed_directories(&["01-cats", "02-dogs", "03-mice"])?;
+
+    Ok(())
+}
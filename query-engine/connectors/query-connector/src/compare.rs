@@ -1,4 +1,4 @@
-use crate::filter::Filter;
+use crate::filter::{Filter, ScalarCondition};
 use prisma_models::PrismaValue;
 
 /// Comparing methods for scalar fields.
@@ -78,6 +78,8 @@ pub trait RelationCompare {
     where
         T: Into<Filter>;
 
+    fn related_count(&self, condition: ScalarCondition) -> Filter;
+
     fn one_relation_is_null(&self) -> Filter;
 }
 
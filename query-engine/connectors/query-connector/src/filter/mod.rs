@@ -14,6 +14,9 @@ pub use list::*;
 pub use relation::*;
 pub use scalar::*;
 
+use crate::compare::ScalarCompare;
+use prisma_models::{ModelRef, PrismaValue};
+
 #[derive(Debug, Clone, Eq, Hash, PartialEq)]
 pub enum Filter {
     And(Vec<Filter>),
@@ -54,6 +57,21 @@ impl Filter {
         Filter::Empty
     }
 
+    /// Matches the records that are not soft deleted, for models with a
+    /// `@@softDelete` attribute.
+    pub fn not_soft_deleted(model: &ModelRef) -> Option<Self> {
+        model.soft_delete_field().map(|field| field.equals(PrismaValue::Null))
+    }
+
+    /// Restricts the filter to the records that are not soft deleted.
+    pub fn without_soft_deleted(self, model: &ModelRef) -> Self {
+        match Self::not_soft_deleted(model) {
+            Some(not_deleted) if self == Filter::Empty => not_deleted,
+            Some(not_deleted) => Filter::and(vec![self, not_deleted]),
+            None => self,
+        }
+    }
+
     /// Returns the size of the topmost filter elements (does not recursively compute the size).
     pub fn size(&self) -> usize {
         match self {
@@ -1,5 +1,5 @@
 use crate::compare::RelationCompare;
-use crate::filter::Filter;
+use crate::filter::{Filter, ScalarCondition};
 use prisma_models::RelationField;
 use std::sync::Arc;
 
@@ -15,16 +15,19 @@ pub struct OneRelationIsNullFilter {
     pub field: Arc<RelationField>,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum RelationCondition {
     EveryRelatedRecord,
     AtLeastOneRelatedRecord,
     NoRelatedRecord,
     ToOneRelatedRecord,
+
+    /// The number of related records matches the condition.
+    RelatedRecordsCount(ScalarCondition),
 }
 
 impl RelationCondition {
-    pub fn invert_of_subselect(self) -> bool {
+    pub fn invert_of_subselect(&self) -> bool {
         matches!(self, RelationCondition::EveryRelatedRecord)
     }
 }
@@ -78,6 +81,15 @@ impl RelationCompare for Arc<RelationField> {
         })
     }
 
+    /// The number of related records matches the condition.
+    fn related_count(&self, condition: ScalarCondition) -> Filter {
+        Filter::from(RelationFilter {
+            field: Arc::clone(self),
+            nested_filter: Box::new(Filter::empty()),
+            condition: RelationCondition::RelatedRecordsCount(condition),
+        })
+    }
+
     /// One of the relations is `Null`.
     fn one_relation_is_null(&self) -> Filter {
         Filter::from(OneRelationIsNullFilter {
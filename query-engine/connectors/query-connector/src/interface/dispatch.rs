@@ -28,6 +28,40 @@ impl<'conn, 'tx> ReadOperations for ConnectionLike<'conn, 'tx> {
         }
     }
 
+    fn supports_distinct(&self) -> bool {
+        match self {
+            Self::Connection(c) => c.supports_distinct(),
+            Self::Transaction(tx) => tx.supports_distinct(),
+        }
+    }
+
+    fn joins_relations(&self, strategy: Option<RelationLoadStrategy>) -> bool {
+        match self {
+            Self::Connection(c) => c.joins_relations(strategy),
+            Self::Transaction(tx) => tx.joins_relations(strategy),
+        }
+    }
+
+    async fn get_many_records_with_relations(
+        &self,
+        model: &ModelRef,
+        query_arguments: QueryArguments,
+        selected_fields: &ModelProjection,
+        relations: &[RelationLoad],
+        strategy: Option<RelationLoadStrategy>,
+    ) -> crate::Result<Option<RecordsWithRelations>> {
+        match self {
+            Self::Connection(c) => {
+                c.get_many_records_with_relations(model, query_arguments, selected_fields, relations, strategy)
+                    .await
+            }
+            Self::Transaction(tx) => {
+                tx.get_many_records_with_relations(model, query_arguments, selected_fields, relations, strategy)
+                    .await
+            }
+        }
+    }
+
     async fn get_related_m2m_record_ids(
         &self,
         from_field: &RelationFieldRef,
@@ -44,7 +78,7 @@ impl<'conn, 'tx> ReadOperations for ConnectionLike<'conn, 'tx> {
         model: &ModelRef,
         query_arguments: QueryArguments,
         selections: Vec<AggregationSelection>,
-        group_by: Vec<ScalarFieldRef>,
+        group_by: Vec<GroupingKey>,
         having: Option<Filter>,
     ) -> crate::Result<Vec<AggregationRow>> {
         match self {
@@ -100,6 +134,56 @@ impl<'conn, 'tx> WriteOperations for ConnectionLike<'conn, 'tx> {
         }
     }
 
+    async fn create_records_returning(
+        &self,
+        model: &ModelRef,
+        args: Vec<WriteArgs>,
+        skip_duplicates: bool,
+        selected_fields: &ModelProjection,
+    ) -> crate::Result<ManyRecords> {
+        match self {
+            Self::Connection(c) => {
+                c.create_records_returning(model, args, skip_duplicates, selected_fields)
+                    .await
+            }
+            Self::Transaction(tx) => {
+                tx.create_records_returning(model, args, skip_duplicates, selected_fields)
+                    .await
+            }
+        }
+    }
+
+    async fn update_records_returning(
+        &self,
+        model: &ModelRef,
+        record_filter: RecordFilter,
+        args: WriteArgs,
+        selected_fields: &ModelProjection,
+    ) -> crate::Result<ManyRecords> {
+        match self {
+            Self::Connection(c) => {
+                c.update_records_returning(model, record_filter, args, selected_fields)
+                    .await
+            }
+            Self::Transaction(tx) => {
+                tx.update_records_returning(model, record_filter, args, selected_fields)
+                    .await
+            }
+        }
+    }
+
+    async fn delete_records_returning(
+        &self,
+        model: &ModelRef,
+        record_filter: RecordFilter,
+        selected_fields: &ModelProjection,
+    ) -> crate::Result<ManyRecords> {
+        match self {
+            Self::Connection(c) => c.delete_records_returning(model, record_filter, selected_fields).await,
+            Self::Transaction(tx) => tx.delete_records_returning(model, record_filter, selected_fields).await,
+        }
+    }
+
     async fn connect(
         &self,
         field: &RelationFieldRef,
@@ -1,7 +1,10 @@
 mod dispatch;
+mod routing;
+
 pub use dispatch::*;
+pub use routing::*;
 
-use crate::{Filter, QueryArguments, WriteArgs};
+use crate::{Filter, QueryArguments, RecordsWithRelations, RelationLoad, RelationLoadStrategy, WriteArgs};
 use async_trait::async_trait;
 use dml::FieldArity;
 use prisma_models::*;
@@ -12,6 +15,12 @@ pub trait Connector {
     /// Returns a connection to a data source.
     async fn get_connection(&self) -> crate::Result<Box<dyn Connection>>;
 
+    /// Returns a connection for operations that only read data outside of a transaction.
+    /// Connectors able to serve reads from elsewhere (e.g. replicas) can override this.
+    async fn get_read_connection(&self) -> crate::Result<Box<dyn Connection>> {
+        self.get_connection().await
+    }
+
     /// Returns name of the connector.
     fn name(&self) -> String;
 }
@@ -89,6 +98,10 @@ pub enum AggregationSelection {
     /// Single field selector. Only valid in the context of group by statements.
     Field(ScalarFieldRef),
 
+    /// Selects the value of a grouping expression of a field, e.g. a truncated
+    /// date, in place of the field. Only valid in the context of group by statements.
+    Expression(GroupingKey),
+
     /// Counts records of the model that match the query.
     /// `all` indicates that an all-records selection has been made (e.g. SQL *).
     /// `fields` are specific fields to count on. By convention, if `all` is true,
@@ -106,12 +119,30 @@ pub enum AggregationSelection {
 
     /// Compute maximum for each field contained.
     Max(Vec<ScalarFieldRef>),
+
+    /// Counts the distinct non-null values of each field contained.
+    CountDistinct(Vec<ScalarFieldRef>),
+
+    /// Concatenates the non-null values of each field contained, separated by
+    /// commas. The order of the values is up to the database.
+    StringAgg(Vec<ScalarFieldRef>),
+
+    /// Compute the median for each field contained, interpolating between the
+    /// two middle values if their number is even.
+    Median(Vec<ScalarFieldRef>),
+
+    /// Compute the population standard deviation for each field contained.
+    StdDev(Vec<ScalarFieldRef>),
+
+    /// Compute the population variance for each field contained.
+    Variance(Vec<ScalarFieldRef>),
 }
 
 impl AggregationSelection {
     pub fn identifiers(&self) -> Vec<(TypeIdentifier, FieldArity)> {
         match self {
             AggregationSelection::Field(field) => vec![(field.type_identifier.clone(), FieldArity::Required)],
+            AggregationSelection::Expression(key) => vec![(key.field().type_identifier.clone(), FieldArity::Required)],
             AggregationSelection::Count { all, fields } => {
                 let mut mapped = Self::map_field_types(&fields, Some(TypeIdentifier::Int));
 
@@ -121,13 +152,30 @@ impl AggregationSelection {
 
                 mapped
             }
-            AggregationSelection::Average(fields) => Self::map_field_types(&fields, Some(TypeIdentifier::Float)),
+            AggregationSelection::Average(fields) => Self::map_statistic_types(&fields),
             AggregationSelection::Sum(fields) => Self::map_field_types(&fields, None),
             AggregationSelection::Min(fields) => Self::map_field_types(&fields, None),
             AggregationSelection::Max(fields) => Self::map_field_types(&fields, None),
+            AggregationSelection::CountDistinct(fields) => Self::map_field_types(&fields, Some(TypeIdentifier::Int)),
+            AggregationSelection::StringAgg(fields) => Self::map_field_types(&fields, Some(TypeIdentifier::String)),
+            AggregationSelection::Median(fields) => Self::map_statistic_types(&fields),
+            AggregationSelection::StdDev(fields) => Self::map_statistic_types(&fields),
+            AggregationSelection::Variance(fields) => Self::map_statistic_types(&fields),
         }
     }
 
+    /// Statistics of decimals stay decimals, to keep their precision. All
+    /// other numbers become floats.
+    fn map_statistic_types(fields: &[ScalarFieldRef]) -> Vec<(TypeIdentifier, FieldArity)> {
+        fields
+            .iter()
+            .map(|f| match f.type_identifier {
+                TypeIdentifier::Decimal => (TypeIdentifier::Decimal, FieldArity::Required),
+                _ => (TypeIdentifier::Float, FieldArity::Required),
+            })
+            .collect()
+    }
+
     fn map_field_types(
         fields: &[ScalarFieldRef],
         fixed_type: Option<TypeIdentifier>,
@@ -144,6 +192,61 @@ impl AggregationSelection {
     }
 }
 
+/// A key the records of a group by aggregation are grouped by.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GroupingKey {
+    /// The values of the field.
+    Field(ScalarFieldRef),
+
+    /// The values of a date time field, truncated to the start of a unit in
+    /// UTC or the given time zone.
+    DateTrunc {
+        field: ScalarFieldRef,
+        unit: DateTruncUnit,
+        time_zone: Option<TimeZone>,
+    },
+
+    /// The values of a numeric field, rounded down to a multiple of `width`.
+    Bucket { field: ScalarFieldRef, width: PrismaValue },
+}
+
+impl GroupingKey {
+    /// The field the key is computed from.
+    pub fn field(&self) -> &ScalarFieldRef {
+        match self {
+            GroupingKey::Field(field) => field,
+            GroupingKey::DateTrunc { field, .. } => field,
+            GroupingKey::Bucket { field, .. } => field,
+        }
+    }
+
+    /// Whether the key is an expression over the field instead of its plain values.
+    pub fn is_expression(&self) -> bool {
+        !matches!(self, GroupingKey::Field(_))
+    }
+}
+
+/// The units dates can be truncated to. Weeks start on Monday.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DateTruncUnit {
+    Year,
+    Month,
+    Week,
+    Day,
+    Hour,
+    Minute,
+}
+
+/// The time zone dates are truncated in.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TimeZone {
+    /// A fixed offset from UTC in minutes.
+    Offset(i32),
+
+    /// A time zone of the IANA database, e.g. `Europe/Berlin`.
+    Named(String),
+}
+
 pub type AggregationRow = Vec<AggregationResult>;
 
 /// Result of an aggregation operation on a model or field.
@@ -157,6 +260,11 @@ pub enum AggregationResult {
     Sum(ScalarFieldRef, PrismaValue),
     Min(ScalarFieldRef, PrismaValue),
     Max(ScalarFieldRef, PrismaValue),
+    CountDistinct(ScalarFieldRef, PrismaValue),
+    StringAgg(ScalarFieldRef, PrismaValue),
+    Median(ScalarFieldRef, PrismaValue),
+    StdDev(ScalarFieldRef, PrismaValue),
+    Variance(ScalarFieldRef, PrismaValue),
 }
 
 #[async_trait]
@@ -187,6 +295,35 @@ pub trait ReadOperations {
         selected_fields: &ModelProjection,
     ) -> crate::Result<ManyRecords>;
 
+    /// Whether `get_many_records` applies the `distinct` of the query arguments
+    /// in the database. Otherwise, the core reads all records and applies
+    /// distinct and pagination in memory.
+    fn supports_distinct(&self) -> bool;
+
+    /// Whether `get_many_records_with_relations` loads relations with joins
+    /// for the given strategy, or the default strategy of the connector.
+    fn joins_relations(&self, _strategy: Option<RelationLoadStrategy>) -> bool {
+        false
+    }
+
+    /// Gets multiple records together with the given nested relations in a
+    /// single roundtrip, if the connector supports it.
+    ///
+    /// - The `strategy` overrides the default strategy of the connector.
+    /// - Returns `None` if the relations should be loaded with separate queries
+    ///   instead, e.g. because the strategy or the shape of the query is not
+    ///   supported by the connector.
+    async fn get_many_records_with_relations(
+        &self,
+        _model: &ModelRef,
+        _query_arguments: QueryArguments,
+        _selected_fields: &ModelProjection,
+        _relations: &[RelationLoad],
+        _strategy: Option<RelationLoadStrategy>,
+    ) -> crate::Result<Option<RecordsWithRelations>> {
+        Ok(None)
+    }
+
     /// Retrieves pairs of IDs that belong together from a intermediate join
     /// table.
     ///
@@ -209,7 +346,7 @@ pub trait ReadOperations {
         model: &ModelRef,
         query_arguments: QueryArguments,
         selections: Vec<AggregationSelection>,
-        group_by: Vec<ScalarFieldRef>,
+        group_by: Vec<GroupingKey>,
         having: Option<Filter>,
     ) -> crate::Result<Vec<AggregationRow>>;
 }
@@ -239,6 +376,35 @@ pub trait WriteOperations {
     /// Delete records in the `Model` with the given `Filter`.
     async fn delete_records(&self, model: &ModelRef, record_filter: RecordFilter) -> crate::Result<usize>;
 
+    /// Inserts many records at once into the database, returning the
+    /// `selected_fields` of the inserted records.
+    async fn create_records_returning(
+        &self,
+        model: &ModelRef,
+        args: Vec<WriteArgs>,
+        skip_duplicates: bool,
+        selected_fields: &ModelProjection,
+    ) -> crate::Result<ManyRecords>;
+
+    /// Update records in the `Model` with the given `WriteArgs` filtered by the
+    /// `Filter`, returning the `selected_fields` of the records after the update.
+    async fn update_records_returning(
+        &self,
+        model: &ModelRef,
+        record_filter: RecordFilter,
+        args: WriteArgs,
+        selected_fields: &ModelProjection,
+    ) -> crate::Result<ManyRecords>;
+
+    /// Delete records in the `Model` with the given `Filter`, returning the
+    /// `selected_fields` of the deleted records.
+    async fn delete_records_returning(
+        &self,
+        model: &ModelRef,
+        record_filter: RecordFilter,
+        selected_fields: &ModelProjection,
+    ) -> crate::Result<ManyRecords>;
+
     // We plan to remove the methods below in the future. We want emulate them with the ones above. Those should suffice.
 
     /// Connect the children to the parent.
@@ -0,0 +1,58 @@
+use super::*;
+use async_trait::async_trait;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// A connector that sends read-only work to a pool of replicas and
+/// everything else to the primary database.
+///
+/// Replicas are picked in round-robin order. Without any replicas, all
+/// connections are checked out from the primary.
+pub struct RoutingConnector<C> {
+    primary: C,
+    replicas: Vec<C>,
+    next_replica: AtomicUsize,
+}
+
+impl<C> RoutingConnector<C>
+where
+    C: Connector + Send + Sync,
+{
+    pub fn new(primary: C, replicas: Vec<C>) -> Self {
+        Self {
+            primary,
+            replicas,
+            next_replica: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn primary(&self) -> &C {
+        &self.primary
+    }
+
+    pub fn replicas(&self) -> &[C] {
+        &self.replicas
+    }
+}
+
+#[async_trait]
+impl<C> Connector for RoutingConnector<C>
+where
+    C: Connector + Send + Sync,
+{
+    async fn get_connection(&self) -> crate::Result<Box<dyn Connection>> {
+        self.primary.get_connection().await
+    }
+
+    async fn get_read_connection(&self) -> crate::Result<Box<dyn Connection>> {
+        if self.replicas.is_empty() {
+            return self.primary.get_connection().await;
+        }
+
+        let idx = self.next_replica.fetch_add(1, Ordering::Relaxed) % self.replicas.len();
+        self.replicas[idx].get_connection().await
+    }
+
+    fn name(&self) -> String {
+        self.primary.name()
+    }
+}
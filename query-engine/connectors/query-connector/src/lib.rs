@@ -2,16 +2,19 @@
 
 pub mod error;
 pub mod filter;
+pub mod query_log;
 
 mod compare;
 mod interface;
 mod query_arguments;
+mod relation_load;
 mod write_args;
 
 pub use compare::*;
 pub use filter::*;
 pub use interface::*;
 pub use query_arguments::*;
+pub use relation_load::*;
 pub use write_args::*;
 
 use once_cell::sync::Lazy;
@@ -60,8 +60,10 @@ impl QueryArguments {
     /// This is a marker that generally expresses whether or not a set of records can be
     /// retrieved by the connector or if it requires the query engine to fetch a raw set
     /// of records and perform certain operations itself, in-memory.
+    /// Distinct is not part of this marker: It is processed in memory only if the connector can't apply it
+    /// (see `ReadOperations::supports_distinct`).
     pub fn requires_inmemory_processing(&self) -> bool {
-        self.distinct.is_some() || self.contains_unstable_cursor() || self.contains_null_cursor()
+        self.contains_unstable_cursor() || self.contains_null_cursor()
     }
 
     /// An unstable cursor is a cursor that is used in conjunction with an unstable (non-unique) combination of orderBys.
@@ -94,8 +96,11 @@ impl QueryArguments {
         self.take.clone().map(|t| if t < 0 { -t } else { t })
     }
 
+    /// Distinct records can't be read in batches, as every batch would be distinct on its own.
     pub fn can_batch(&self) -> bool {
-        self.filter.as_ref().map(|filter| filter.can_batch()).unwrap_or(false) && self.cursor.is_none()
+        self.filter.as_ref().map(|filter| filter.can_batch()).unwrap_or(false)
+            && self.cursor.is_none()
+            && self.distinct.is_none()
     }
 
     pub fn batched(self) -> Vec<Self> {
@@ -133,6 +138,16 @@ impl QueryArguments {
     pub fn model(&self) -> &ModelRef {
         &self.model
     }
+
+    /// Excludes soft deleted records, if the model has a `@@softDelete` attribute.
+    pub fn exclude_soft_deleted(mut self) -> Self {
+        if self.model.soft_delete_field().is_some() {
+            let filter = self.filter.unwrap_or(Filter::Empty);
+            self.filter = Some(filter.without_soft_deleted(&self.model));
+        }
+
+        self
+    }
 }
 
 impl<T> From<(ModelRef, T)> for QueryArguments
@@ -0,0 +1,174 @@
+//! Opt-in structured log of the statements a connector sends to the database.
+//!
+//! Logging is scoped to a single request: the server wraps the execution of a
+//! request with `query_log::scope`, and connectors report every statement they
+//! run through `query_log::record`. Outside of a scope, `record` is a no-op.
+use serde::Serialize;
+use std::{
+    future::Future,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+tokio::task_local! {
+    static SCOPE: QueryLogScope;
+}
+
+/// Replacement for bound parameters when redaction is enabled.
+static REDACTED: &str = "[REDACTED]";
+
+/// Where the query events of a request are written to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum QueryLogTarget {
+    /// Events are emitted as structured `query_log` tracing events.
+    Log,
+
+    /// Events are collected and returned in the `extensions` of the response.
+    Extensions,
+}
+
+#[derive(Debug, Clone)]
+pub struct QueryLogConfig {
+    pub target: QueryLogTarget,
+
+    /// Replaces all bound parameters with a placeholder.
+    pub redact_parameters: bool,
+
+    /// Only statements running at least this long are reported.
+    pub slow_query_threshold: Option<Duration>,
+}
+
+impl QueryLogConfig {
+    pub fn new(target: QueryLogTarget) -> Self {
+        Self {
+            target,
+            redact_parameters: false,
+            slow_query_threshold: None,
+        }
+    }
+}
+
+/// A single statement executed in the database.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryEvent {
+    /// The SQL text of the statement.
+    pub query: String,
+
+    /// Bound parameters, in order of appearance.
+    pub params: Vec<serde_json::Value>,
+
+    /// Execution time in milliseconds.
+    pub duration_ms: u64,
+
+    /// Number of rows returned or modified by the statement, if known.
+    pub affected_rows: Option<u64>,
+
+    /// The name of the operation (e.g. `findManyUser`) that issued the statement.
+    pub operation: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+struct QueryLogScope {
+    config: Arc<QueryLogConfig>,
+    operation: Option<String>,
+    events: Arc<Mutex<Vec<QueryEvent>>>,
+}
+
+/// Runs `fut` with query logging enabled, returning its output and all collected events.
+/// Events are only collected if the target is `QueryLogTarget::Extensions`.
+pub async fn scope<F>(config: Arc<QueryLogConfig>, fut: F) -> (F::Output, Vec<QueryEvent>)
+where
+    F: Future,
+{
+    let scope = QueryLogScope {
+        config,
+        operation: None,
+        events: Arc::new(Mutex::new(Vec::new())),
+    };
+
+    let events = scope.events.clone();
+    let output = SCOPE.scope(scope, fut).await;
+    let events = std::mem::take(&mut *events.lock().unwrap());
+
+    (output, events)
+}
+
+/// Attributes all statements executed by `fut` to the given operation.
+/// Noop if query logging is not enabled for the current task.
+pub async fn with_operation<F>(operation: &str, fut: F) -> F::Output
+where
+    F: Future,
+{
+    match SCOPE.try_with(Clone::clone) {
+        Ok(mut scope) => {
+            scope.operation = Some(operation.to_owned());
+            SCOPE.scope(scope, fut).await
+        }
+        Err(_) => fut.await,
+    }
+}
+
+/// Carries the query log scope of the current task over to `fut`.
+/// Required for futures that run on a spawned task.
+pub fn propagate<F>(fut: F) -> impl Future<Output = F::Output>
+where
+    F: Future,
+{
+    let scope = SCOPE.try_with(Clone::clone).ok();
+
+    async move {
+        match scope {
+            Some(scope) => SCOPE.scope(scope, fut).await,
+            None => fut.await,
+        }
+    }
+}
+
+/// Returns true if statements executed on the current task should be reported.
+/// Connectors should check this before doing any work to render an event.
+pub fn is_enabled() -> bool {
+    SCOPE.try_with(|_| ()).is_ok()
+}
+
+/// Reports an executed statement. Noop if query logging is not enabled for the
+/// current task, or if the statement ran faster than the slow query threshold.
+pub fn record(query: String, params: Vec<serde_json::Value>, duration: Duration, affected_rows: Option<u64>) {
+    let _ = SCOPE.try_with(|scope| {
+        if let Some(threshold) = scope.config.slow_query_threshold {
+            if duration < threshold {
+                return;
+            }
+        }
+
+        let params = if scope.config.redact_parameters {
+            params.iter().map(|_| serde_json::Value::from(REDACTED)).collect()
+        } else {
+            params
+        };
+
+        let event = QueryEvent {
+            query,
+            params,
+            duration_ms: duration.as_millis() as u64,
+            affected_rows,
+            operation: scope.operation.clone(),
+        };
+
+        match scope.config.target {
+            QueryLogTarget::Log => {
+                let params = serde_json::to_string(&event.params).unwrap_or_default();
+
+                tracing::info!(
+                    target: "query_log",
+                    query = event.query.as_str(),
+                    params = params.as_str(),
+                    duration_ms = event.duration_ms,
+                    affected_rows = ?event.affected_rows,
+                    operation = ?event.operation,
+                );
+            }
+            QueryLogTarget::Extensions => scope.events.lock().unwrap().push(event),
+        }
+    });
+}
@@ -0,0 +1,59 @@
+use crate::QueryArguments;
+use prisma_models::*;
+use std::str::FromStr;
+
+/// Defines how nested relation selections of a read are loaded.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RelationLoadStrategy {
+    /// Every relation level is loaded with a separate query and joined in memory.
+    Query,
+
+    /// The whole relation tree is loaded with a single statement, using joins
+    /// and JSON aggregation in the database.
+    Join,
+}
+
+impl Default for RelationLoadStrategy {
+    fn default() -> Self {
+        Self::Query
+    }
+}
+
+impl FromStr for RelationLoadStrategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "query" => Ok(Self::Query),
+            "join" => Ok(Self::Join),
+            _ => Err(format!("Invalid relation load strategy: `{}`.", s)),
+        }
+    }
+}
+
+/// A nested relation selection to be loaded together with its parent records.
+#[derive(Debug, Clone)]
+pub struct RelationLoad {
+    /// The relation field on the parent model.
+    pub parent_field: RelationFieldRef,
+    pub args: QueryArguments,
+    pub selected_fields: ModelProjection,
+    pub nested: Vec<RelationLoad>,
+}
+
+impl RelationLoad {
+    pub fn related_model(&self) -> ModelRef {
+        self.parent_field.related_model()
+    }
+}
+
+/// Records loaded with all of their nested relations.
+#[derive(Debug, Clone)]
+pub struct RecordsWithRelations {
+    pub records: ManyRecords,
+
+    /// The related records, one entry per requested `RelationLoad`, in order.
+    /// The `parent_id` of every related record is set to the primary
+    /// identifier of its parent record.
+    pub nested: Vec<RecordsWithRelations>,
+}
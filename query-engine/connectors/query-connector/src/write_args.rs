@@ -179,6 +179,19 @@ impl WriteArgs {
         }
     }
 
+    /// The arguments of the update soft deleting records of the model, if it
+    /// has a `@@softDelete` attribute.
+    pub fn soft_delete(model: &ModelRef) -> Option<Self> {
+        model.soft_delete_field().map(|field| {
+            let mut args = Self::new();
+
+            args.insert(field, PrismaValue::DateTime(Utc::now().into()));
+            args.update_datetimes(model.clone());
+
+            args
+        })
+    }
+
     pub fn as_record_projection(&self, model_projection: ModelProjection) -> Option<RecordProjection> {
         let pairs: Vec<_> = model_projection
             .scalar_fields()
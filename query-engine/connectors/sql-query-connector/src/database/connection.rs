@@ -1,9 +1,10 @@
 use super::transaction::SqlConnectorTransaction;
-use crate::{database::operations::*, sql_info::SqlInfo, QueryExt, SqlError};
+use crate::{database::operations::*, query_builder::returning, sql_info::SqlInfo, QueryExt, SqlError};
 use async_trait::async_trait;
 use connector_interface::{
-    self as connector, filter::Filter, AggregationRow, AggregationSelection, Connection, QueryArguments,
-    ReadOperations, RecordFilter, Transaction, WriteArgs, WriteOperations,
+    self as connector, filter::Filter, AggregationRow, AggregationSelection, Connection, GroupingKey, QueryArguments,
+    ReadOperations, RecordFilter, RecordsWithRelations, RelationLoad, RelationLoadStrategy, Transaction, WriteArgs,
+    WriteOperations,
 };
 use prisma_models::prelude::*;
 use prisma_value::PrismaValue;
@@ -13,6 +14,9 @@ use std::future::Future;
 pub struct SqlConnection<C> {
     inner: C,
     connection_info: ConnectionInfo,
+    relation_load_strategy: RelationLoadStrategy,
+    join_loading_supported: bool,
+    window_functions_supported: bool,
 }
 
 impl<C> SqlConnection<C>
@@ -21,14 +25,44 @@ where
 {
     pub fn new(inner: C, connection_info: &ConnectionInfo) -> Self {
         let connection_info = connection_info.clone();
-        Self { inner, connection_info }
+
+        Self {
+            inner,
+            connection_info,
+            relation_load_strategy: RelationLoadStrategy::default(),
+            join_loading_supported: true,
+            window_functions_supported: true,
+        }
+    }
+
+    /// Sets the strategy used for loading nested relations, unless overridden by the query.
+    pub fn with_relation_load_strategy(mut self, strategy: RelationLoadStrategy) -> Self {
+        self.relation_load_strategy = strategy;
+        self
+    }
+
+    /// Sets whether the database can run the join relation load strategy.
+    /// When it can't, relations are loaded with separate queries, whatever
+    /// the strategy.
+    pub fn with_join_loading_supported(mut self, supported: bool) -> Self {
+        self.join_loading_supported = supported;
+        self
+    }
+
+    /// Sets whether the database has window functions. Without them, distinct
+    /// reads are processed in memory by the core.
+    pub fn with_window_functions_supported(mut self, supported: bool) -> Self {
+        self.window_functions_supported = supported;
+        self
     }
 
     async fn catch<O>(
         &self,
         fut: impl Future<Output = Result<O, SqlError>>,
     ) -> Result<O, connector_interface::error::ConnectorError> {
-        match fut.await {
+        let family = self.connection_info.sql_family();
+
+        match crate::query_ext::SQL_FAMILY.scope(family, fut).await {
             Ok(o) => Ok(o),
             Err(err) => Err(err.into_connector_error(&self.connection_info)),
         }
@@ -43,10 +77,18 @@ where
     async fn start_transaction<'a>(&'a self) -> connector::Result<Box<dyn Transaction + 'a>> {
         let fut_tx = self.inner.start_transaction();
         let connection_info = &self.connection_info;
+        let relation_load_strategy = self.relation_load_strategy;
+        let join_loading_supported = self.join_loading_supported;
+        let window_functions_supported = self.window_functions_supported;
 
         self.catch(async move {
             let tx: quaint::connector::Transaction = fut_tx.await.map_err(SqlError::from)?;
-            Ok(Box::new(SqlConnectorTransaction::new(tx, &connection_info)) as Box<dyn Transaction>)
+            let tx = SqlConnectorTransaction::new(tx, &connection_info)
+                .with_relation_load_strategy(relation_load_strategy)
+                .with_join_loading_supported(join_loading_supported)
+                .with_window_functions_supported(window_functions_supported);
+
+            Ok(Box::new(tx) as Box<dyn Transaction>)
         })
         .await
     }
@@ -73,8 +115,45 @@ where
         query_arguments: QueryArguments,
         selected_fields: &ModelProjection,
     ) -> connector::Result<ManyRecords> {
-        self.catch(async move { read::get_many_records(&self.inner, model, query_arguments, selected_fields).await })
+        self.catch(async move {
+            let sql_info = SqlInfo::from(&self.connection_info);
+            read::get_many_records(&self.inner, &sql_info, model, query_arguments, selected_fields).await
+        })
+        .await
+    }
+
+    fn supports_distinct(&self) -> bool {
+        self.window_functions_supported
+    }
+
+    fn joins_relations(&self, strategy: Option<RelationLoadStrategy>) -> bool {
+        self.join_loading_supported && strategy.unwrap_or(self.relation_load_strategy) == RelationLoadStrategy::Join
+    }
+
+    async fn get_many_records_with_relations(
+        &self,
+        model: &ModelRef,
+        query_arguments: QueryArguments,
+        selected_fields: &ModelProjection,
+        relations: &[RelationLoad],
+        strategy: Option<RelationLoadStrategy>,
+    ) -> connector::Result<Option<RecordsWithRelations>> {
+        if !self.joins_relations(strategy) {
+            return Ok(None);
+        }
+
+        self.catch(async move {
+            read::get_many_records_with_relations(
+                &self.inner,
+                SqlInfo::from(&self.connection_info),
+                model,
+                query_arguments,
+                selected_fields,
+                relations,
+            )
             .await
+        })
+        .await
     }
 
     async fn get_related_m2m_record_ids(
@@ -91,12 +170,22 @@ where
         model: &ModelRef,
         query_arguments: QueryArguments,
         selections: Vec<AggregationSelection>,
-        group_by: Vec<ScalarFieldRef>,
+        group_by: Vec<GroupingKey>,
         having: Option<Filter>,
     ) -> connector::Result<Vec<AggregationRow>> {
-        self.catch(
-            async move { read::aggregate(&self.inner, model, query_arguments, selections, group_by, having).await },
-        )
+        self.catch(async move {
+            let sql_info = SqlInfo::from(&self.connection_info);
+            read::aggregate(
+                &self.inner,
+                &sql_info,
+                model,
+                query_arguments,
+                selections,
+                group_by,
+                having,
+            )
+            .await
+        })
         .await
     }
 }
@@ -104,7 +193,7 @@ where
 #[async_trait]
 impl<C> WriteOperations for SqlConnection<C>
 where
-    C: QueryExt + Send + Sync + 'static,
+    C: QueryExt + TransactionCapable + Send + Sync + 'static,
 {
     async fn create_record(&self, model: &ModelRef, args: WriteArgs) -> connector::Result<RecordProjection> {
         self.catch(async move { write::create_record(&self.inner, model, args).await })
@@ -145,6 +234,87 @@ where
             .await
     }
 
+    async fn create_records_returning(
+        &self,
+        model: &ModelRef,
+        args: Vec<WriteArgs>,
+        skip_duplicates: bool,
+        selected_fields: &ModelProjection,
+    ) -> connector::Result<ManyRecords> {
+        self.catch(async move {
+            let sql_info = SqlInfo::from(&self.connection_info);
+
+            if returning::returns_written_rows(&sql_info.family) {
+                return write::create_records_returning(
+                    &self.inner,
+                    sql_info,
+                    model,
+                    args,
+                    skip_duplicates,
+                    selected_fields,
+                )
+                .await;
+            }
+
+            // The records are inserted one by one, which must not leave some of them behind on failure.
+            let tx = self.inner.start_transaction().await.map_err(SqlError::from)?;
+            let result =
+                write::create_records_returning(&tx, sql_info, model, args, skip_duplicates, selected_fields).await;
+
+            match result {
+                Ok(records) => {
+                    tx.commit().await.map_err(SqlError::from)?;
+                    Ok(records)
+                }
+                Err(err) => {
+                    tx.rollback().await.map_err(SqlError::from)?;
+                    Err(err)
+                }
+            }
+        })
+        .await
+    }
+
+    async fn update_records_returning(
+        &self,
+        model: &ModelRef,
+        record_filter: RecordFilter,
+        args: WriteArgs,
+        selected_fields: &ModelProjection,
+    ) -> connector::Result<ManyRecords> {
+        self.catch(async move {
+            write::update_records_returning(
+                &self.inner,
+                SqlInfo::from(&self.connection_info),
+                model,
+                record_filter,
+                args,
+                selected_fields,
+            )
+            .await
+        })
+        .await
+    }
+
+    async fn delete_records_returning(
+        &self,
+        model: &ModelRef,
+        record_filter: RecordFilter,
+        selected_fields: &ModelProjection,
+    ) -> connector::Result<ManyRecords> {
+        self.catch(async move {
+            write::delete_records_returning(
+                &self.inner,
+                SqlInfo::from(&self.connection_info),
+                model,
+                record_filter,
+                selected_fields,
+            )
+            .await
+        })
+        .await
+    }
+
     async fn connect(
         &self,
         field: &RelationFieldRef,
@@ -4,22 +4,35 @@ use async_trait::async_trait;
 use connector_interface::{
     self as connector,
     error::{ConnectorError, ErrorKind},
-    Connection, Connector,
+    Connection, Connector, RelationLoadStrategy,
 };
 use datamodel::Datasource;
-use quaint::{pooled::Quaint, prelude::ConnectionInfo};
-use std::time::Duration;
+use quaint::{
+    pooled::Quaint,
+    prelude::{ConnectionInfo, Queryable},
+};
+use std::{
+    sync::atomic::{AtomicU8, Ordering},
+    time::Duration,
+};
+
+/// Flags of the server features, once detected.
+const FEATURES_DETECTED: u8 = 0b001;
+const CORRELATED_DERIVED_TABLES: u8 = 0b010;
+const WINDOW_FUNCTIONS: u8 = 0b100;
 
 pub struct Mysql {
     pool: Quaint,
     connection_info: ConnectionInfo,
+    relation_load_strategy: RelationLoadStrategy,
+    /// The features of the server, detected from its version on the first
+    /// connection.
+    features: AtomicU8,
 }
 
-#[async_trait]
-impl FromSource for Mysql {
-    async fn from_source(source: &Datasource) -> connector_interface::Result<Self> {
-        let database_str = &source.url().value;
-
+impl Mysql {
+    /// Creates a connector for the given connection string, e.g. for a read replica.
+    pub async fn from_url(database_str: &str) -> connector_interface::Result<Self> {
         let connection_info = ConnectionInfo::from_url(database_str).map_err(|err| {
             ConnectorError::from_kind(ErrorKind::InvalidDatabaseUrl {
                 details: err.to_string(),
@@ -27,7 +40,7 @@ impl FromSource for Mysql {
             })
         })?;
 
-        let mut builder = Quaint::builder(&source.url().value)
+        let mut builder = Quaint::builder(database_str)
             .map_err(SqlError::from)
             .map_err(|sql_error| sql_error.into_connector_error(&connection_info))?;
 
@@ -38,7 +51,89 @@ impl FromSource for Mysql {
         let pool = builder.build();
         let connection_info = pool.connection_info().to_owned();
 
-        Ok(Mysql { pool, connection_info })
+        Ok(Mysql {
+            pool,
+            connection_info,
+            relation_load_strategy: RelationLoadStrategy::default(),
+            features: AtomicU8::new(0),
+        })
+    }
+
+    /// Sets the default strategy for loading nested relations.
+    pub fn with_relation_load_strategy(mut self, strategy: RelationLoadStrategy) -> Self {
+        self.relation_load_strategy = strategy;
+        self
+    }
+
+    pub fn relation_load_strategy(&self) -> RelationLoadStrategy {
+        self.relation_load_strategy
+    }
+
+    async fn features(&self, conn: &dyn Queryable) -> crate::Result<u8> {
+        let features = self.features.load(Ordering::Relaxed);
+
+        if features & FEATURES_DETECTED != 0 {
+            return Ok(features);
+        }
+
+        let version = conn.version().await.map_err(SqlError::from)?;
+        let features = FEATURES_DETECTED | version.as_deref().map(detect_features).unwrap_or(0);
+
+        self.features.store(features, Ordering::Relaxed);
+
+        Ok(features)
+    }
+}
+
+/// Detects the features of the server from its version:
+/// - The join relation load strategy relies on derived tables referencing the
+///   columns of their parent select, available from MySQL 8.0.14. MariaDB
+///   doesn't support them.
+/// - Distinct reads rely on window functions, available from MySQL 8.0 and
+///   MariaDB 10.2.
+fn detect_features(version: &str) -> u8 {
+    let mut numbers = version
+        .split(|c: char| !c.is_ascii_digit())
+        .take(3)
+        .map(|number| number.parse::<u32>().unwrap_or(0));
+
+    let major = numbers.next().unwrap_or(0);
+    let minor = numbers.next().unwrap_or(0);
+    let patch = numbers.next().unwrap_or(0);
+    let version_number = (major, minor, patch);
+
+    let mut features = 0;
+
+    if version.contains("MariaDB") {
+        if version_number >= (10, 2, 0) {
+            features |= WINDOW_FUNCTIONS;
+        }
+
+        return features;
+    }
+
+    if version_number >= (8, 0, 0) {
+        features |= WINDOW_FUNCTIONS;
+    }
+
+    if version_number >= (8, 0, 14) {
+        features |= CORRELATED_DERIVED_TABLES;
+    }
+
+    features
+}
+
+#[async_trait]
+impl FromSource for Mysql {
+    async fn from_source(source: &Datasource) -> connector_interface::Result<Self> {
+        let strategy = source
+            .relation_load_strategy()
+            .and_then(|strategy| strategy.parse().ok())
+            .unwrap_or_default();
+
+        Ok(Self::from_url(&source.url().value)
+            .await?
+            .with_relation_load_strategy(strategy))
     }
 }
 
@@ -47,7 +142,11 @@ impl Connector for Mysql {
     async fn get_connection<'a>(&'a self) -> connector::Result<Box<dyn Connection + 'static>> {
         super::catch(&self.connection_info, async move {
             let conn = self.pool.check_out().await.map_err(SqlError::from)?;
-            let conn = SqlConnection::new(conn, &self.connection_info);
+            let features = self.features(&conn).await?;
+            let conn = SqlConnection::new(conn, &self.connection_info)
+                .with_relation_load_strategy(self.relation_load_strategy)
+                .with_join_loading_supported(features & CORRELATED_DERIVED_TABLES != 0)
+                .with_window_functions_supported(features & WINDOW_FUNCTIONS != 0);
 
             Ok(Box::new(conn) as Box<dyn Connection>)
         })
@@ -58,3 +157,27 @@ impl Connector for Mysql {
         "mysql".to_owned()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn features_are_detected_from_the_server_version() {
+        let supports = |version: &str, feature: u8| detect_features(version) & feature != 0;
+
+        assert!(supports("8.0.14", CORRELATED_DERIVED_TABLES));
+        assert!(supports("8.0.21", CORRELATED_DERIVED_TABLES));
+        assert!(supports("8.1.0", CORRELATED_DERIVED_TABLES));
+        assert!(!supports("8.0.13", CORRELATED_DERIVED_TABLES));
+        assert!(!supports("5.7.31-log", CORRELATED_DERIVED_TABLES));
+        assert!(!supports("5.6.50", CORRELATED_DERIVED_TABLES));
+        assert!(!supports("10.5.8-MariaDB", CORRELATED_DERIVED_TABLES));
+
+        assert!(supports("8.0.0", WINDOW_FUNCTIONS));
+        assert!(supports("10.2.0-MariaDB", WINDOW_FUNCTIONS));
+        assert!(!supports("5.7.31-log", WINDOW_FUNCTIONS));
+        assert!(!supports("5.6.50", WINDOW_FUNCTIONS));
+        assert!(!supports("10.1.48-MariaDB", WINDOW_FUNCTIONS));
+    }
+}
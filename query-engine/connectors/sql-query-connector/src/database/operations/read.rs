@@ -1,13 +1,19 @@
 use crate::{
     column_metadata,
     query_arguments_ext::QueryArgumentsExt,
-    query_builder::{self, read},
-    QueryExt, SqlError,
+    query_builder::{
+        self, distinct, read,
+        relation_load::{self, RelationLevel},
+    },
+    row::{column_value_to_prisma_value, json_to_column_value},
+    sql_info::{SqlFamily, SqlInfo},
+    QueryExt, SqlError, ToSqlRow,
 };
 use connector_interface::*;
 use futures::stream::{FuturesUnordered, StreamExt};
 use prisma_models::*;
 use quaint::ast::*;
+use std::io;
 
 pub async fn get_single_record(
     conn: &dyn QueryExt,
@@ -35,6 +41,7 @@ pub async fn get_single_record(
 
 pub async fn get_many_records(
     conn: &dyn QueryExt,
+    sql_info: &SqlInfo,
     model: &ModelRef,
     mut query_arguments: QueryArguments,
     selected_fields: &ModelProjection,
@@ -51,6 +58,18 @@ pub async fn get_many_records(
         return Ok(records);
     };
 
+    if let Some((sql, params)) = distinct::build(sql_info, model, &query_arguments, selected_fields) {
+        for row in conn.logged_query_raw(&sql, &params).await?.into_iter() {
+            records.push(Record::from(row.to_sql_row(&meta)?))
+        }
+
+        if reversed {
+            records.reverse();
+        }
+
+        return Ok(records);
+    }
+
     // Todo: This can't work for all cases. Cursor-based pagination will not work, because it relies on the ordering
     // to determine the right queries to fire, and will default to incorrect orderings if no ordering is found.
     // The can_batch has been adjusted to reflect that as a band-aid, but deeper investigation is necessary.
@@ -90,6 +109,160 @@ pub async fn get_many_records(
     Ok(records)
 }
 
+/// Loads records with all nested relations in a single statement.
+/// Returns `None` if the relations have to be loaded with separate queries.
+pub async fn get_many_records_with_relations(
+    conn: &dyn QueryExt,
+    sql_info: SqlInfo,
+    model: &ModelRef,
+    query_arguments: QueryArguments,
+    selected_fields: &ModelProjection,
+    relations: &[RelationLoad],
+) -> crate::Result<Option<RecordsWithRelations>> {
+    let reversed = query_arguments.needs_reversed_order();
+
+    let query = match relation_load::build(&sql_info, model, query_arguments, selected_fields, relations) {
+        Some(query) => query,
+        None => return Ok(None),
+    };
+
+    let field_names: Vec<_> = selected_fields.db_names().collect();
+    let idents = selected_fields.type_identifiers_with_arities();
+    let meta = column_metadata::create(field_names.as_slice(), idents.as_slice());
+    let model_id = model.primary_identifier();
+
+    let mut result = RecordsWithRelations {
+        records: ManyRecords::new(field_names.clone()),
+        nested: query.nested.iter().map(|(_, level)| empty_level(level)).collect(),
+    };
+
+    let result_set = conn.logged_query_raw(&query.sql, &query.params).await?;
+
+    for row in result_set.into_iter() {
+        let mut values: Vec<_> = row.into_iter().collect();
+        let nested_values = values.split_off(meta.len());
+
+        let values = values
+            .into_iter()
+            .zip(meta.iter())
+            .map(|(value, meta)| column_value_to_prisma_value(value, *meta))
+            .collect::<crate::Result<Vec<_>>>()?;
+
+        let record = Record::new(values);
+        let record_id = record.projection(&field_names, &model_id)?;
+
+        for ((nested_value, (_, level)), nested) in nested_values
+            .into_iter()
+            .zip(query.nested.iter())
+            .zip(result.nested.iter_mut())
+        {
+            let json = match nested_value {
+                Value::Json(Some(json)) => json,
+                Value::Text(Some(text)) => serde_json::from_str(&text)?,
+                Value::Bytes(Some(bytes)) => serde_json::from_slice(&bytes)?,
+                value if value.is_null() => serde_json::Value::Array(Vec::new()),
+                value => {
+                    return Err(invalid_relation_data(format!(
+                        "Expected a JSON array, got {:?}.",
+                        value
+                    )))
+                }
+            };
+
+            read_related_records(json, level, &record_id, nested)?;
+        }
+
+        result.records.push(record);
+    }
+
+    if reversed {
+        result.records.reverse();
+    }
+
+    for (nested, (_, level)) in result.nested.iter_mut().zip(query.nested.iter()) {
+        order_level(nested, level);
+    }
+
+    Ok(Some(result))
+}
+
+fn empty_level(level: &RelationLevel) -> RecordsWithRelations {
+    RecordsWithRelations {
+        records: ManyRecords::new(level.selected_fields.db_names().collect()),
+        nested: level.nested.iter().map(|(_, level)| empty_level(level)).collect(),
+    }
+}
+
+/// Reads the JSON array of related records of a single parent record.
+fn read_related_records(
+    json: serde_json::Value,
+    level: &RelationLevel,
+    parent_id: &RecordProjection,
+    into: &mut RecordsWithRelations,
+) -> crate::Result<()> {
+    let objects = match json {
+        serde_json::Value::Array(objects) => objects,
+        json => return Err(invalid_relation_data(format!("Expected a JSON array, got {}.", json))),
+    };
+
+    let field_names: Vec<_> = level.selected_fields.db_names().collect();
+    let idents = level.selected_fields.type_identifiers_with_arities();
+    let meta = column_metadata::create(field_names.as_slice(), idents.as_slice());
+    let model_id = level.model.primary_identifier();
+
+    for object in objects {
+        let mut object = match object {
+            serde_json::Value::Object(object) => object,
+            json => return Err(invalid_relation_data(format!("Expected a JSON object, got {}.", json))),
+        };
+
+        let values = field_names
+            .iter()
+            .zip(meta.iter())
+            .map(|(name, meta)| {
+                let json = object.remove(name).unwrap_or(serde_json::Value::Null);
+                column_value_to_prisma_value(json_to_column_value(json, *meta), *meta)
+            })
+            .collect::<crate::Result<Vec<_>>>()?;
+
+        let mut record = Record::new(values);
+        let record_id = record.projection(&field_names, &model_id)?;
+
+        for ((key, nested_level), nested) in level.nested.iter().zip(into.nested.iter_mut()) {
+            let json = object.remove(key).unwrap_or(serde_json::Value::Null);
+            let json = match json {
+                serde_json::Value::Null => serde_json::Value::Array(Vec::new()),
+                json => json,
+            };
+
+            read_related_records(json, nested_level, &record_id, nested)?;
+        }
+
+        record.parent_id = Some(parent_id.clone());
+        into.records.push(record);
+    }
+
+    Ok(())
+}
+
+/// Applies the ordering of the relation levels, which is not guaranteed to be
+/// preserved by the JSON aggregation. Records of different parents are kept
+/// apart by their `parent_id`, so ordering all records of a level at once is sufficient.
+fn order_level(records: &mut RecordsWithRelations, level: &RelationLevel) {
+    if !level.order_by.is_empty() {
+        records.records.order_by(&level.order_by);
+    }
+
+    for (nested, (_, level)) in records.nested.iter_mut().zip(level.nested.iter()) {
+        order_level(nested, level);
+    }
+}
+
+fn invalid_relation_data(message: String) -> SqlError {
+    let error = io::Error::new(io::ErrorKind::InvalidData, message);
+    SqlError::ConversionError(error.into())
+}
+
 pub async fn get_related_m2m_record_ids(
     conn: &dyn QueryExt,
     from_field: &RelationFieldRef,
@@ -159,29 +332,34 @@ pub async fn get_related_m2m_record_ids(
 
 pub async fn aggregate(
     conn: &dyn QueryExt,
+    sql_info: &SqlInfo,
     model: &ModelRef,
     query_arguments: QueryArguments,
     selections: Vec<AggregationSelection>,
-    group_by: Vec<ScalarFieldRef>,
+    group_by: Vec<GroupingKey>,
     having: Option<Filter>,
 ) -> crate::Result<Vec<AggregationRow>> {
     if !group_by.is_empty() {
-        group_by_aggregate(conn, model, query_arguments, selections, group_by, having).await
+        let query = read::group_by_aggregate(model, query_arguments, &selections, &group_by, having);
+
+        query_aggregation(conn, sql_info, query, selections, group_by).await
     } else {
-        plain_aggregate(conn, model, query_arguments, selections)
-            .await
-            .map(|v| vec![v])
+        let query = read::aggregate(model, &selections, query_arguments);
+        let mut rows = query_aggregation(conn, sql_info, query, selections, group_by).await?;
+
+        Ok(vec![rows
+            .pop()
+            .expect("Expected exactly one return row for aggregation query.")])
     }
 }
 
-async fn plain_aggregate(
+async fn query_aggregation(
     conn: &dyn QueryExt,
-    model: &ModelRef,
-    query_arguments: QueryArguments,
+    sql_info: &SqlInfo,
+    query: Select<'static>,
     selections: Vec<AggregationSelection>,
-) -> crate::Result<Vec<AggregationResult>> {
-    let query = read::aggregate(model, &selections, query_arguments);
-
+    group_by: Vec<GroupingKey>,
+) -> crate::Result<Vec<AggregationRow>> {
     let idents: Vec<_> = selections
         .iter()
         .flat_map(|aggregator| aggregator.identifiers())
@@ -189,34 +367,66 @@ async fn plain_aggregate(
 
     let meta = column_metadata::create_anonymous(&idents);
 
-    let mut rows = conn.filter(query.into(), meta.as_slice()).await?;
-    let row = rows
-        .pop()
-        .expect("Expected exactly one return row for aggregation query.");
-
-    Ok(row.into_aggregation_results(&selections))
-}
-
-async fn group_by_aggregate(
-    conn: &dyn QueryExt,
-    model: &ModelRef,
-    query_arguments: QueryArguments,
-    selections: Vec<AggregationSelection>,
-    group_by: Vec<ScalarFieldRef>,
-    having: Option<Filter>,
-) -> crate::Result<Vec<AggregationRow>> {
-    let query = read::group_by_aggregate(model, query_arguments, &selections, group_by, having);
+    // Grouping expressions and some aggregate functions are rendered into the
+    // statement after building it.
+    let rows = if query_builder::needs_raw_aggregation(&selections, &group_by) {
+        validate_time_zones(conn, sql_info, &group_by).await?;
 
-    let idents: Vec<_> = selections
-        .iter()
-        .flat_map(|aggregator| aggregator.identifiers())
-        .collect();
+        let (sql, params) = query_builder::render_aggregation(&sql_info.family, query, &selections, &group_by)?;
 
-    let meta = column_metadata::create_anonymous(&idents);
-    let rows = conn.filter(query.into(), meta.as_slice()).await?;
+        conn.logged_query_raw(&sql, &params)
+            .await?
+            .into_iter()
+            .map(|row| row.to_sql_row(&meta))
+            .collect::<crate::Result<Vec<_>>>()?
+    } else {
+        conn.filter(query.into(), meta.as_slice()).await?
+    };
 
     Ok(rows
         .into_iter()
         .map(|row| row.into_aggregation_results(&selections))
         .collect())
 }
+
+/// MySQL's `CONVERT_TZ` returns NULL for time zones it doesn't know, which
+/// includes all named time zones if the time zone tables aren't loaded.
+/// Grouping would silently put every record under NULL.
+async fn validate_time_zones(conn: &dyn QueryExt, sql_info: &SqlInfo, group_by: &[GroupingKey]) -> crate::Result<()> {
+    if !matches!(sql_info.family, SqlFamily::MySQL) {
+        return Ok(());
+    }
+
+    for key in group_by {
+        if let GroupingKey::DateTrunc {
+            time_zone: Some(TimeZone::Named(name)),
+            ..
+        } = key
+        {
+            let result_set = conn
+                .logged_query_raw(
+                    "SELECT CONVERT_TZ('2000-01-01 00:00:00', '+00:00', ?) IS NULL",
+                    &[name.as_str().into()],
+                )
+                .await?;
+
+            let is_unknown = result_set
+                .into_single()
+                .ok()
+                .and_then(|row| row.at(0).and_then(|value| value.as_i64()))
+                != Some(0);
+
+            if is_unknown {
+                return Err(SqlError::QueryError(
+                    format!(
+                        "The time zone `{}` is unknown to the database. Load the MySQL time zone tables or use an offset from UTC instead.",
+                        name
+                    )
+                    .into(),
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
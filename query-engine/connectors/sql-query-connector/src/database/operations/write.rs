@@ -1,9 +1,22 @@
-use crate::{error::SqlError, query_builder::write, sql_info::SqlInfo, QueryExt};
+use super::read;
+use crate::{
+    column_metadata,
+    error::SqlError,
+    query_builder::{
+        returning::{self, Returned},
+        write,
+    },
+    sql_info::SqlInfo,
+    QueryExt, ToSqlRow,
+};
 use connector_interface::*;
 use itertools::Itertools;
 use prisma_models::*;
 use prisma_value::PrismaValue;
-use quaint::error::ErrorKind;
+use quaint::{
+    ast::{Insert, Query},
+    error::ErrorKind,
+};
 use std::{collections::HashMap, convert::TryFrom, usize};
 use tracing::log::trace;
 use user_facing_errors::query_engine::DatabaseConstraint;
@@ -13,7 +26,7 @@ use user_facing_errors::query_engine::DatabaseConstraint;
 pub async fn create_record(conn: &dyn QueryExt, model: &ModelRef, args: WriteArgs) -> crate::Result<RecordProjection> {
     let (insert, returned_id) = write::create_record(model, args);
 
-    let result_set = match conn.insert(insert).await {
+    let result_set = match conn.logged_query(insert.into()).await {
         Ok(id) => id,
         Err(e) => match e.kind() {
             ErrorKind::UniqueConstraintViolation { constraint } => match constraint {
@@ -84,6 +97,26 @@ pub async fn create_records(
         return Ok(0);
     }
 
+    let partitioned_batches = partition_create_args(&sql_info, args);
+
+    trace!("Total of {} batches to be executed.", partitioned_batches.len());
+    trace!(
+        "Batch sizes: {:?}",
+        partitioned_batches.iter().map(|b| b.len()).collect_vec()
+    );
+
+    let mut count = 0;
+    for batch in partitioned_batches {
+        let stmt = write::create_records(model, batch, skip_duplicates);
+        count += conn.logged_execute(stmt.into()).await?;
+    }
+
+    Ok(count as usize)
+}
+
+/// Splits the rows of an insert into batches that stay within the limits of
+/// the database for parameters and rows per statement.
+fn partition_create_args(sql_info: &SqlInfo, args: Vec<WriteArgs>) -> Vec<Vec<WriteArgs>> {
     let batches = if let Some(max_params) = sql_info.max_bind_values {
         // We need to split inserts if they are above a parameter threshold, as well as split based on number of rows.
         // -> Horizontal partitioning by row number, vertical by number of args.
@@ -125,7 +158,7 @@ pub async fn create_records(
         vec![args]
     };
 
-    let partitioned_batches = if let Some(max_rows) = sql_info.max_rows {
+    if let Some(max_rows) = sql_info.max_rows {
         let capacity = batches.len();
         batches
             .into_iter()
@@ -146,21 +179,7 @@ pub async fn create_records(
             })
     } else {
         batches
-    };
-
-    trace!("Total of {} batches to be executed.", partitioned_batches.len());
-    trace!(
-        "Batch sizes: {:?}",
-        partitioned_batches.iter().map(|b| b.len()).collect_vec()
-    );
-
-    let mut count = 0;
-    for batch in partitioned_batches {
-        let stmt = write::create_records(model, batch, skip_duplicates);
-        count += conn.execute(stmt.into()).await?;
     }
-
-    Ok(count as usize)
 }
 
 /// Update multiple records in a database defined in `conn` and the records
@@ -185,7 +204,7 @@ pub async fn update_records(
     };
 
     for update in updates {
-        conn.query(update).await?;
+        conn.logged_execute(update).await?;
     }
 
     Ok(merge_write_args(ids, id_args))
@@ -206,12 +225,185 @@ pub async fn delete_records(
     }
 
     for delete in write::delete_many(model, ids.as_slice()) {
-        conn.query(delete).await?;
+        conn.logged_execute(delete).await?;
     }
 
     Ok(count)
 }
 
+/// Inserts many records, returning the `selected_fields` of the inserted
+/// records. Records skipped as duplicates are not returned.
+///
+/// If the database can't return the inserted rows, the records are inserted
+/// one by one and read back by their ids. The caller must run this in a
+/// transaction.
+pub async fn create_records_returning(
+    conn: &dyn QueryExt,
+    sql_info: SqlInfo,
+    model: &ModelRef,
+    args: Vec<WriteArgs>,
+    skip_duplicates: bool,
+    selected_fields: &ModelProjection,
+) -> crate::Result<ManyRecords> {
+    if args.is_empty() {
+        return Ok(ManyRecords::new(selected_fields.db_names().collect()));
+    }
+
+    if !returning::returns_written_rows(&sql_info.family) {
+        let mut ids = Vec::with_capacity(args.len());
+
+        for args in args {
+            let id = args.as_record_projection(model.primary_identifier());
+            let insert = write::create_records(model, vec![args], skip_duplicates);
+
+            match id {
+                // Nothing is inserted if the record is skipped as a duplicate.
+                Some(id) if !id.misses_autogen_value() => {
+                    if conn.logged_execute(insert.into()).await? > 0 {
+                        ids.push(id);
+                    }
+                }
+                id => {
+                    let result_set = conn.logged_query(insert.into()).await?;
+
+                    match (id, result_set.last_insert_id()) {
+                        (Some(mut id), Some(num)) if num > 0 => {
+                            id.add_autogen_value(num as i64);
+                            ids.push(id);
+                        }
+                        // Skipped as a duplicate.
+                        _ => (),
+                    }
+                }
+            }
+        }
+
+        return read_records(conn, &sql_info, model, ids, selected_fields).await;
+    }
+
+    let mut records = ManyRecords::new(selected_fields.db_names().collect());
+
+    // Records without any value are inserted one by one with `DEFAULT VALUES`,
+    // as inserting `DEFAULT` into every column fails on identity columns.
+    let (defaulted, args): (Vec<_>, Vec<_>) = args.into_iter().partition(WriteArgs::is_empty);
+
+    for _ in defaulted {
+        let insert = Insert::from(Insert::single_into(model.as_table()));
+        let rows = query_returning(conn, &sql_info, insert.into(), selected_fields, Returned::Inserted).await?;
+
+        records.records.extend(rows);
+    }
+
+    for batch in partition_create_args(&sql_info, args) {
+        let insert = write::create_records(model, batch, skip_duplicates);
+        let rows = query_returning(conn, &sql_info, insert.into(), selected_fields, Returned::Inserted).await?;
+
+        records.records.extend(rows);
+    }
+
+    Ok(records)
+}
+
+/// Updates multiple records, returning the `selected_fields` of the records
+/// after the update. If the database can't return the updated rows, the
+/// records are read by their ids after the update, in the same transaction.
+pub async fn update_records_returning(
+    conn: &dyn QueryExt,
+    sql_info: SqlInfo,
+    model: &ModelRef,
+    record_filter: RecordFilter,
+    args: WriteArgs,
+    selected_fields: &ModelProjection,
+) -> crate::Result<ManyRecords> {
+    if args.args.is_empty() || !returning::returns_written_rows(&sql_info.family) {
+        let ids = update_records(conn, model, record_filter, args).await?;
+        return read_records(conn, &sql_info, model, ids, selected_fields).await;
+    }
+
+    let ids = conn.filter_selectors(model, record_filter).await?;
+    let ids: Vec<&RecordProjection> = ids.iter().collect();
+    let mut records = ManyRecords::new(selected_fields.db_names().collect());
+
+    for update in write::update_many(model, ids.as_slice(), args)? {
+        let rows = query_returning(conn, &sql_info, update, selected_fields, Returned::Inserted).await?;
+        records.records.extend(rows);
+    }
+
+    Ok(records)
+}
+
+/// Deletes multiple records, returning the `selected_fields` of the deleted
+/// records. If the database can't return the deleted rows, the records are
+/// read by their ids before the delete, in the same transaction.
+pub async fn delete_records_returning(
+    conn: &dyn QueryExt,
+    sql_info: SqlInfo,
+    model: &ModelRef,
+    record_filter: RecordFilter,
+    selected_fields: &ModelProjection,
+) -> crate::Result<ManyRecords> {
+    let ids = conn.filter_selectors(model, record_filter).await?;
+
+    if !returning::returns_written_rows(&sql_info.family) {
+        let records = read_records(conn, &sql_info, model, ids.clone(), selected_fields).await?;
+        delete_records(conn, model, RecordFilter::from(ids)).await?;
+
+        return Ok(records);
+    }
+
+    let ids: Vec<&RecordProjection> = ids.iter().collect();
+    let mut records = ManyRecords::new(selected_fields.db_names().collect());
+
+    for delete in write::delete_many(model, ids.as_slice()) {
+        let rows = query_returning(conn, &sql_info, delete, selected_fields, Returned::Deleted).await?;
+        records.records.extend(rows);
+    }
+
+    Ok(records)
+}
+
+/// Runs a write statement, returning the `selected_fields` of the written rows.
+async fn query_returning(
+    conn: &dyn QueryExt,
+    sql_info: &SqlInfo,
+    query: Query<'static>,
+    selected_fields: &ModelProjection,
+    returned: Returned,
+) -> crate::Result<Vec<Record>> {
+    let field_names: Vec<_> = selected_fields.db_names().collect();
+    let idents = selected_fields.type_identifiers_with_arities();
+    let meta = column_metadata::create(field_names.as_slice(), idents.as_slice());
+
+    let (sql, params) = returning::render(&sql_info.family, query, &field_names, returned)
+        .ok_or_else(|| SqlError::QueryError("The database can't return the rows of the write statement.".into()))?;
+
+    let result_set = conn.logged_query_raw(&sql, &params).await?;
+    let mut records = Vec::with_capacity(result_set.len());
+
+    for row in result_set {
+        records.push(Record::from(row.to_sql_row(&meta)?));
+    }
+
+    Ok(records)
+}
+
+/// Reads the `selected_fields` of the records with the given ids.
+async fn read_records(
+    conn: &dyn QueryExt,
+    sql_info: &SqlInfo,
+    model: &ModelRef,
+    ids: Vec<RecordProjection>,
+    selected_fields: &ModelProjection,
+) -> crate::Result<ManyRecords> {
+    if ids.is_empty() {
+        return Ok(ManyRecords::new(selected_fields.db_names().collect()));
+    }
+
+    let args = QueryArguments::from((model.clone(), ids.filter()));
+
+    read::get_many_records(conn, sql_info, model, args, selected_fields).await
+}
+
 /// Connect relations defined in `child_ids` to a parent defined in `parent_id`.
 /// The relation information is in the `RelationFieldRef`.
 pub async fn connect(
@@ -221,7 +413,7 @@ pub async fn connect(
     child_ids: &[RecordProjection],
 ) -> crate::Result<()> {
     let query = write::create_relation_table_records(field, parent_id, child_ids);
-    conn.query(query).await?;
+    conn.logged_execute(query).await?;
 
     Ok(())
 }
@@ -235,7 +427,7 @@ pub async fn disconnect(
     child_ids: &[RecordProjection],
 ) -> crate::Result<()> {
     let query = write::delete_relation_table_records(field, parent_id, child_ids);
-    conn.delete(query).await?;
+    conn.logged_execute(query.into()).await?;
 
     Ok(())
 }
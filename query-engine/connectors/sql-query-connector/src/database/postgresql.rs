@@ -3,7 +3,7 @@ use crate::{FromSource, SqlError};
 use async_trait::async_trait;
 use connector_interface::{
     error::{ConnectorError, ErrorKind},
-    Connection, Connector,
+    Connection, Connector, RelationLoadStrategy,
 };
 use datamodel::Datasource;
 use quaint::{pooled::Quaint, prelude::ConnectionInfo};
@@ -12,13 +12,12 @@ use std::time::Duration;
 pub struct PostgreSql {
     pool: Quaint,
     connection_info: ConnectionInfo,
+    relation_load_strategy: RelationLoadStrategy,
 }
 
-#[async_trait]
-impl FromSource for PostgreSql {
-    async fn from_source(source: &Datasource) -> connector_interface::Result<Self> {
-        let database_str = &source.url().value;
-
+impl PostgreSql {
+    /// Creates a connector for the given connection string, e.g. for a read replica.
+    pub async fn from_url(database_str: &str) -> connector_interface::Result<Self> {
         let connection_info = ConnectionInfo::from_url(database_str).map_err(|err| {
             ConnectorError::from_kind(ErrorKind::InvalidDatabaseUrl {
                 details: err.to_string(),
@@ -26,7 +25,7 @@ impl FromSource for PostgreSql {
             })
         })?;
 
-        let mut builder = Quaint::builder(&source.url().value)
+        let mut builder = Quaint::builder(database_str)
             .map_err(SqlError::from)
             .map_err(|sql_error| sql_error.into_connector_error(&connection_info))?;
 
@@ -36,7 +35,35 @@ impl FromSource for PostgreSql {
 
         let pool = builder.build();
         let connection_info = pool.connection_info().to_owned();
-        Ok(PostgreSql { pool, connection_info })
+        Ok(PostgreSql {
+            pool,
+            connection_info,
+            relation_load_strategy: RelationLoadStrategy::default(),
+        })
+    }
+
+    /// Sets the default strategy for loading nested relations.
+    pub fn with_relation_load_strategy(mut self, strategy: RelationLoadStrategy) -> Self {
+        self.relation_load_strategy = strategy;
+        self
+    }
+
+    pub fn relation_load_strategy(&self) -> RelationLoadStrategy {
+        self.relation_load_strategy
+    }
+}
+
+#[async_trait]
+impl FromSource for PostgreSql {
+    async fn from_source(source: &Datasource) -> connector_interface::Result<Self> {
+        let strategy = source
+            .relation_load_strategy()
+            .and_then(|strategy| strategy.parse().ok())
+            .unwrap_or_default();
+
+        Ok(Self::from_url(&source.url().value)
+            .await?
+            .with_relation_load_strategy(strategy))
     }
 }
 
@@ -45,7 +72,8 @@ impl Connector for PostgreSql {
     async fn get_connection<'a>(&'a self) -> connector_interface::Result<Box<dyn Connection + 'static>> {
         super::catch(&self.connection_info, async move {
             let conn = self.pool.check_out().await.map_err(SqlError::from)?;
-            let conn = SqlConnection::new(conn, &self.connection_info);
+            let conn = SqlConnection::new(conn, &self.connection_info)
+                .with_relation_load_strategy(self.relation_load_strategy);
             Ok(Box::new(conn) as Box<dyn Connection>)
         })
         .await
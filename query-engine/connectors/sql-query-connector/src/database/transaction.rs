@@ -2,8 +2,9 @@ use crate::SqlError;
 use crate::{database::operations::*, sql_info::SqlInfo};
 use async_trait::async_trait;
 use connector_interface::{
-    self as connector, filter::Filter, AggregationRow, AggregationSelection, QueryArguments, ReadOperations,
-    RecordFilter, Transaction, WriteArgs, WriteOperations,
+    self as connector, filter::Filter, AggregationRow, AggregationSelection, GroupingKey, QueryArguments,
+    ReadOperations, RecordFilter, RecordsWithRelations, RelationLoad, RelationLoadStrategy, Transaction, WriteArgs,
+    WriteOperations,
 };
 use prisma_models::prelude::*;
 use prisma_value::PrismaValue;
@@ -12,6 +13,9 @@ use quaint::prelude::ConnectionInfo;
 pub struct SqlConnectorTransaction<'tx> {
     inner: quaint::connector::Transaction<'tx>,
     connection_info: ConnectionInfo,
+    relation_load_strategy: RelationLoadStrategy,
+    join_loading_supported: bool,
+    window_functions_supported: bool,
 }
 
 impl<'tx> SqlConnectorTransaction<'tx> {
@@ -20,14 +24,40 @@ impl<'tx> SqlConnectorTransaction<'tx> {
         Self {
             inner: tx,
             connection_info,
+            relation_load_strategy: RelationLoadStrategy::default(),
+            join_loading_supported: true,
+            window_functions_supported: true,
         }
     }
 
+    /// Sets the strategy used for loading nested relations, unless overridden by the query.
+    pub fn with_relation_load_strategy(mut self, strategy: RelationLoadStrategy) -> Self {
+        self.relation_load_strategy = strategy;
+        self
+    }
+
+    /// Sets whether the database can run the join relation load strategy.
+    /// When it can't, relations are loaded with separate queries, whatever
+    /// the strategy.
+    pub fn with_join_loading_supported(mut self, supported: bool) -> Self {
+        self.join_loading_supported = supported;
+        self
+    }
+
+    /// Sets whether the database has window functions. Without them, distinct
+    /// reads are processed in memory by the core.
+    pub fn with_window_functions_supported(mut self, supported: bool) -> Self {
+        self.window_functions_supported = supported;
+        self
+    }
+
     async fn catch<O>(
         &self,
         fut: impl std::future::Future<Output = Result<O, SqlError>>,
     ) -> Result<O, connector_interface::error::ConnectorError> {
-        match fut.await {
+        let family = self.connection_info.sql_family();
+
+        match crate::query_ext::SQL_FAMILY.scope(family, fut).await {
             Ok(o) => Ok(o),
             Err(err) => Err(err.into_connector_error(&self.connection_info)),
         }
@@ -65,8 +95,45 @@ impl<'tx> ReadOperations for SqlConnectorTransaction<'tx> {
         query_arguments: QueryArguments,
         selected_fields: &ModelProjection,
     ) -> connector::Result<ManyRecords> {
-        self.catch(async move { read::get_many_records(&self.inner, model, query_arguments, selected_fields).await })
+        self.catch(async move {
+            let sql_info = SqlInfo::from(&self.connection_info);
+            read::get_many_records(&self.inner, &sql_info, model, query_arguments, selected_fields).await
+        })
+        .await
+    }
+
+    fn supports_distinct(&self) -> bool {
+        self.window_functions_supported
+    }
+
+    fn joins_relations(&self, strategy: Option<RelationLoadStrategy>) -> bool {
+        self.join_loading_supported && strategy.unwrap_or(self.relation_load_strategy) == RelationLoadStrategy::Join
+    }
+
+    async fn get_many_records_with_relations(
+        &self,
+        model: &ModelRef,
+        query_arguments: QueryArguments,
+        selected_fields: &ModelProjection,
+        relations: &[RelationLoad],
+        strategy: Option<RelationLoadStrategy>,
+    ) -> connector::Result<Option<RecordsWithRelations>> {
+        if !self.joins_relations(strategy) {
+            return Ok(None);
+        }
+
+        self.catch(async move {
+            read::get_many_records_with_relations(
+                &self.inner,
+                SqlInfo::from(&self.connection_info),
+                model,
+                query_arguments,
+                selected_fields,
+                relations,
+            )
             .await
+        })
+        .await
     }
 
     async fn get_related_m2m_record_ids(
@@ -83,12 +150,22 @@ impl<'tx> ReadOperations for SqlConnectorTransaction<'tx> {
         model: &ModelRef,
         query_arguments: QueryArguments,
         selections: Vec<AggregationSelection>,
-        group_by: Vec<ScalarFieldRef>,
+        group_by: Vec<GroupingKey>,
         having: Option<Filter>,
     ) -> connector::Result<Vec<AggregationRow>> {
-        self.catch(
-            async move { read::aggregate(&self.inner, model, query_arguments, selections, group_by, having).await },
-        )
+        self.catch(async move {
+            let sql_info = SqlInfo::from(&self.connection_info);
+            read::aggregate(
+                &self.inner,
+                &sql_info,
+                model,
+                query_arguments,
+                selections,
+                group_by,
+                having,
+            )
+            .await
+        })
         .await
     }
 }
@@ -134,6 +211,67 @@ impl<'tx> WriteOperations for SqlConnectorTransaction<'tx> {
             .await
     }
 
+    async fn create_records_returning(
+        &self,
+        model: &ModelRef,
+        args: Vec<WriteArgs>,
+        skip_duplicates: bool,
+        selected_fields: &ModelProjection,
+    ) -> connector::Result<ManyRecords> {
+        self.catch(async move {
+            write::create_records_returning(
+                &self.inner,
+                SqlInfo::from(&self.connection_info),
+                model,
+                args,
+                skip_duplicates,
+                selected_fields,
+            )
+            .await
+        })
+        .await
+    }
+
+    async fn update_records_returning(
+        &self,
+        model: &ModelRef,
+        record_filter: RecordFilter,
+        args: WriteArgs,
+        selected_fields: &ModelProjection,
+    ) -> connector::Result<ManyRecords> {
+        self.catch(async move {
+            write::update_records_returning(
+                &self.inner,
+                SqlInfo::from(&self.connection_info),
+                model,
+                record_filter,
+                args,
+                selected_fields,
+            )
+            .await
+        })
+        .await
+    }
+
+    async fn delete_records_returning(
+        &self,
+        model: &ModelRef,
+        record_filter: RecordFilter,
+        selected_fields: &ModelProjection,
+    ) -> connector::Result<ManyRecords> {
+        self.catch(async move {
+            write::delete_records_returning(
+                &self.inner,
+                SqlInfo::from(&self.connection_info),
+                model,
+                record_filter,
+                selected_fields,
+            )
+            .await
+        })
+        .await
+    }
+
     async fn connect(
         &self,
         field: &RelationFieldRef,
@@ -132,6 +132,46 @@ impl AliasedCondition for Filter {
     }
 }
 
+/// Converts the `having` filter of a group by aggregation. Scalar filters on a
+/// field that is grouped by an expression compare the expression, given as its
+/// column, instead of the values of the field.
+pub fn having_cond(filter: Filter, expressions: &[(ScalarFieldRef, Column<'static>)]) -> ConditionTree<'static> {
+    let convert_all = |filters: Vec<Filter>, negate: bool| -> Vec<Expression<'static>> {
+        filters
+            .into_iter()
+            .map(|f| having_cond(f, expressions))
+            .map(|cond| if negate { cond.not() } else { cond })
+            .map(Expression::from)
+            .collect()
+    };
+
+    match filter {
+        Filter::And(filters) if filters.is_empty() => ConditionTree::NoCondition,
+        Filter::Or(filters) if filters.is_empty() => ConditionTree::NegativeCondition,
+        Filter::Not(filters) if filters.is_empty() => ConditionTree::NoCondition,
+
+        Filter::And(filters) => ConditionTree::And(convert_all(filters, false)),
+        Filter::Or(filters) => ConditionTree::Or(convert_all(filters, false)),
+        Filter::Not(filters) => ConditionTree::And(convert_all(filters, true)),
+
+        Filter::Scalar(ScalarFilter {
+            projection: ScalarProjection::Single(field),
+            condition,
+            mode,
+        }) => match expressions.iter().find(|(grouped, _)| grouped == &field) {
+            Some((_, column)) => convert_scalar_filter(column.clone(), condition, mode, &[field]),
+            None => ScalarFilter {
+                projection: ScalarProjection::Single(field),
+                condition,
+                mode,
+            }
+            .aliased_cond(None),
+        },
+
+        filter => filter.aliased_cond(None),
+    }
+}
+
 impl AliasedCondition for ScalarFilter {
     /// Conversion from a `ScalarFilter` to a query condition tree. Aliased when in a nested `SELECT`.
     fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
@@ -206,13 +246,17 @@ fn convert_scalar_list_filter(
 impl AliasedCondition for RelationFilter {
     /// Conversion from a `RelationFilter` to a query condition tree. Aliased when in a nested `SELECT`.
     fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
+        if let RelationCondition::RelatedRecordsCount(condition) = self.condition {
+            return related_count_condition(&self.field, alias, condition);
+        }
+
         let ids = self.field.model().primary_identifier().as_columns();
         let columns: Vec<Column<'static>> = match alias {
             Some(alias) => ids.map(|c| c.table(alias.to_string(None))).collect(),
             None => ids.collect(),
         };
 
-        let condition = self.condition;
+        let condition = self.condition.clone();
         let sub_select = self.aliased_sel(alias.map(|a| a.inc(AliasMode::Table)));
 
         let comparison = match condition {
@@ -220,12 +264,73 @@ impl AliasedCondition for RelationFilter {
             RelationCondition::EveryRelatedRecord => Row::from(columns).not_in_selection(sub_select),
             RelationCondition::NoRelatedRecord => Row::from(columns).not_in_selection(sub_select),
             RelationCondition::ToOneRelatedRecord => Row::from(columns).in_selection(sub_select),
+            RelationCondition::RelatedRecordsCount(_) => unreachable!(),
         };
 
         comparison.into()
     }
 }
 
+/// Compares the number of related records of every record with a correlated
+/// sub-select:
+/// ```sql
+/// (SELECT COUNT(*) FROM "Post" AS "t1" WHERE ("t1"."authorId") = ("User"."id")) > 5
+/// ```
+/// Records without related records are counted as well, which a grouped join
+/// would drop.
+fn related_count_condition(
+    field: &RelationFieldRef,
+    alias: Option<Alias>,
+    condition: ScalarCondition,
+) -> ConditionTree<'static> {
+    let sub_alias = alias
+        .map(|a| a.inc(AliasMode::Table))
+        .unwrap_or_default()
+        .to_string(None);
+
+    // Many-to-many relations count the rows of the relation table, all others
+    // the related records holding the foreign key.
+    let (table, related_columns, parent_columns) = if field.relation().is_many_to_many() {
+        let parent = field.model().primary_identifier().as_columns();
+        (field.as_table(), field.identifier_columns(), parent)
+    } else {
+        let related = field.related_field().linking_fields().as_columns();
+        (field.related_model().as_table(), related, field.join_columns())
+    };
+
+    let related_columns: Vec<Column<'static>> = related_columns.map(|c| c.table(sub_alias.clone())).collect();
+    let parent_columns: Vec<Column<'static>> = match alias {
+        Some(alias) => parent_columns.map(|c| c.table(alias.to_string(None))).collect(),
+        None => parent_columns.collect(),
+    };
+
+    let sub_select = Select::from_table(table.alias(sub_alias.clone()))
+        .value(count(asterisk()))
+        .so_that(Row::from(related_columns).equals(Row::from(parent_columns)));
+
+    // Soft deleted related records are not counted.
+    let sub_select = match field.related_model().soft_delete_field() {
+        Some(deleted_at) if field.relation().is_many_to_many() => {
+            let related_model = field.related_model();
+            let related_ids: Vec<Column<'static>> = field
+                .related_field()
+                .identifier_columns()
+                .map(|c| c.table(sub_alias.clone()))
+                .collect();
+
+            let not_deleted = Select::from_table(related_model.as_table())
+                .columns(related_model.primary_identifier().as_columns())
+                .so_that(deleted_at.as_column().is_null());
+
+            sub_select.and_where(Row::from(related_ids).in_selection(not_deleted))
+        }
+        Some(deleted_at) => sub_select.and_where(deleted_at.as_column().table(sub_alias).is_null()),
+        None => sub_select,
+    };
+
+    convert_scalar_filter(Expression::from(sub_select), condition, QueryMode::Default, &[])
+}
+
 impl AliasedSelect for RelationFilter {
     /// The subselect part of the `RelationFilter` `ConditionTree`.
     fn aliased_sel<'a>(self, alias: Option<Alias>) -> Select<'static> {
@@ -504,7 +609,11 @@ fn insensitive_scalar_filter(
     ConditionTree::single(condition)
 }
 fn convert_first_value<'a>(fields: &[ScalarFieldRef], value: PrismaValue) -> Value<'a> {
-    fields.first().unwrap().value(value)
+    match fields.first() {
+        Some(field) => field.value(value),
+        // Comparisons without a field, e.g. of the number of related records.
+        None => convert_lossy(value),
+    }
 }
 
 fn convert_value<'a>(field: &ScalarFieldRef, value: PrismaValue) -> Value<'a> {
@@ -523,7 +632,9 @@ fn convert_values<'a>(fields: &[ScalarFieldRef], values: Vec<PrismaValue>) -> Ve
             .map(|(field, value)| field.value(value))
             .collect()
     } else {
-        let field = fields.first().unwrap();
-        values.into_iter().map(|value| field.value(value)).collect()
+        values
+            .into_iter()
+            .map(|value| convert_first_value(fields, value))
+            .collect()
     }
 }
@@ -4,6 +4,7 @@ mod database;
 mod error;
 mod filter_conversion;
 mod ordering;
+mod preview;
 mod query_arguments_ext;
 mod query_builder;
 mod query_ext;
@@ -17,5 +18,6 @@ use row::*;
 
 pub use database::*;
 pub use error::SqlError;
+pub use preview::*;
 
 type Result<T> = std::result::Result<T, error::SqlError>;
@@ -0,0 +1,163 @@
+//! Renders the statements the query builders emit for reads without running
+//! them, to inspect the SQL an operation produces.
+use crate::{
+    query_builder::{self, distinct, read, relation_load},
+    sql_info::{SqlFamily, SqlInfo},
+};
+use connector_interface::{
+    error::{ConnectorError, ErrorKind},
+    filter::Filter,
+    AggregationSelection, GroupingKey, QueryArguments, RelationLoad,
+};
+use prisma_models::*;
+use quaint::{
+    ast::*,
+    visitor::{self, Visitor},
+};
+use std::convert::TryFrom;
+
+/// A rendered statement and its bound parameters.
+#[derive(Debug, Clone)]
+pub struct RenderedStatement {
+    pub sql: String,
+    pub params: Vec<PrismaValue>,
+}
+
+/// Renders read statements in the dialect of a SQL connector.
+pub struct SqlRenderer {
+    sql_info: SqlInfo,
+}
+
+impl SqlRenderer {
+    /// The renderer for a loaded connector, by the name of the connector.
+    pub fn for_connector(name: &str) -> Option<Self> {
+        let sql_info = match name {
+            "postgres" => SqlInfo::postgres(),
+            "mysql" => SqlInfo::mysql(),
+            "sqlite" => SqlInfo::sqlite(),
+            "mssql" => SqlInfo::mssql(),
+            _ => return None,
+        };
+
+        Some(Self { sql_info })
+    }
+
+    /// The statements reading many records. Arguments that are too large for
+    /// a single statement are split up, as they are when running the read.
+    /// Distinct reads on PostgreSQL are rendered with `DISTINCT ON`.
+    pub fn many_records(
+        &self,
+        model: &ModelRef,
+        args: QueryArguments,
+        selected_fields: &ModelProjection,
+    ) -> connector_interface::Result<Vec<RenderedStatement>> {
+        if let Some(0) = args.take {
+            return Ok(Vec::new());
+        }
+
+        if let Some((sql, params)) = distinct::build(&self.sql_info, model, &args, selected_fields) {
+            return Ok(vec![Self::statement(sql, params)?]);
+        }
+
+        if args.can_batch() {
+            let mut args = args;
+            args.order_by = Vec::new();
+
+            args.batched()
+                .into_iter()
+                .map(|args| self.render(read::get_records(model, selected_fields.as_columns(), args)))
+                .collect()
+        } else {
+            let select = read::get_records(model, selected_fields.as_columns(), args);
+            Ok(vec![self.render(select)?])
+        }
+    }
+
+    /// The single statement reading many records together with their
+    /// relations, as run by `get_many_records_with_relations`. Returns `None`
+    /// if the relations are read with separate statements instead.
+    pub fn many_records_with_relations(
+        &self,
+        model: &ModelRef,
+        args: QueryArguments,
+        selected_fields: &ModelProjection,
+        relations: &[RelationLoad],
+    ) -> connector_interface::Result<Option<RenderedStatement>> {
+        relation_load::build(&self.sql_info, model, args, selected_fields, relations)
+            .map(|query| Self::statement(query.sql, query.params))
+            .transpose()
+    }
+
+    /// The statement reading a single record. Limited to one row, like the
+    /// statement executed by `get_single_record`.
+    pub fn single_record(
+        &self,
+        model: &ModelRef,
+        filter: &Filter,
+        selected_fields: &ModelProjection,
+    ) -> connector_interface::Result<RenderedStatement> {
+        self.render(read::get_records(model, selected_fields.as_columns(), filter).limit(1))
+    }
+
+    /// The statement reading an aggregation.
+    pub fn aggregate(
+        &self,
+        model: &ModelRef,
+        args: QueryArguments,
+        selections: &[AggregationSelection],
+        group_by: Vec<GroupingKey>,
+        having: Option<Filter>,
+    ) -> connector_interface::Result<RenderedStatement> {
+        let select = if group_by.is_empty() {
+            read::aggregate(model, selections, args)
+        } else {
+            read::group_by_aggregate(model, args, selections, &group_by, having)
+        };
+
+        if !query_builder::needs_raw_aggregation(selections, &group_by) {
+            return self.render(select);
+        }
+
+        let (sql, params) = query_builder::render_aggregation(&self.sql_info.family, select, selections, &group_by)
+            .map_err(|err| ConnectorError::from_kind(ErrorKind::QueryError(err.to_string().into())))?;
+
+        Self::statement(sql, params)
+    }
+
+    /// Wraps a statement to return its query plan instead of its rows.
+    /// Returns `None` if the database has no plain `EXPLAIN` statement.
+    pub fn explain(&self, statement: &RenderedStatement) -> Option<RenderedStatement> {
+        let prefix = match self.sql_info.family {
+            SqlFamily::Postgres | SqlFamily::MySQL => "EXPLAIN",
+            SqlFamily::SQLite => "EXPLAIN QUERY PLAN",
+            SqlFamily::MSSQL => return None,
+        };
+
+        Some(RenderedStatement {
+            sql: format!("{} {}", prefix, statement.sql),
+            params: statement.params.clone(),
+        })
+    }
+
+    fn render<'a>(&self, query: impl Into<Query<'a>>) -> connector_interface::Result<RenderedStatement> {
+        let (sql, params) = match self.sql_info.family {
+            SqlFamily::Postgres => visitor::Postgres::build(query),
+            SqlFamily::MySQL => visitor::Mysql::build(query),
+            SqlFamily::SQLite => visitor::Sqlite::build(query),
+            SqlFamily::MSSQL => visitor::Mssql::build(query),
+        }
+        .map_err(|err| ConnectorError::from_kind(ErrorKind::QueryError(Box::new(err))))?;
+
+        Self::statement(sql, params)
+    }
+
+    fn statement(sql: String, params: Vec<Value<'_>>) -> connector_interface::Result<RenderedStatement> {
+        let params = params
+            .into_iter()
+            .map(PrismaValue::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| ConnectorError::from_kind(ErrorKind::ConversionError(err.into())))?;
+
+        Ok(RenderedStatement { sql, params })
+    }
+}
@@ -0,0 +1,117 @@
+//! Renders the aggregate functions quaint has no AST for, like counting
+//! distinct values, concatenating strings or statistics over numbers.
+//!
+//! The query selects a placeholder column per function and field, which is
+//! replaced with the function call in the dialect of the database after
+//! rendering the query, like the expressions of `grouping`.
+use super::render_column;
+use crate::{sql_info::SqlFamily, SqlError};
+use connector_interface::AggregationSelection;
+use prisma_models::*;
+use quaint::ast::*;
+
+/// Prefix of the placeholder columns of aggregate functions.
+const PLACEHOLDER_PREFIX: &str = "__prisma_aggregate_";
+
+/// Whether the selection is computed by a function quaint has no AST for.
+pub fn is_raw(selection: &AggregationSelection) -> bool {
+    matches!(
+        selection,
+        AggregationSelection::CountDistinct(_)
+            | AggregationSelection::StringAgg(_)
+            | AggregationSelection::Median(_)
+            | AggregationSelection::StdDev(_)
+            | AggregationSelection::Variance(_)
+    )
+}
+
+/// The column selected in place of the function of the selection over the field.
+pub fn placeholder(selection: &AggregationSelection, field: &ScalarFieldRef) -> Column<'static> {
+    Column::from(format!("{}{}_{}", PLACEHOLDER_PREFIX, name(selection), field.db_name()))
+}
+
+/// Replaces the placeholder columns of the functions in a rendered aggregation.
+/// Group by aggregations are `qualified` with the table of the model, plain
+/// aggregations select from a sub-select with relative columns.
+pub fn replace_placeholders(
+    family: &SqlFamily,
+    mut sql: String,
+    selections: &[AggregationSelection],
+    qualified: bool,
+) -> crate::Result<String> {
+    for selection in selections.iter().filter(|selection| is_raw(selection)) {
+        for field in fields(selection) {
+            let column = if qualified {
+                field.as_column()
+            } else {
+                Column::from(field.db_name().to_owned())
+            };
+
+            let placeholder = render_column(family, placeholder(selection, field))?;
+            let column = render_column(family, column)?;
+
+            sql = sql.replace(&placeholder, &function(family, selection, &column)?);
+        }
+    }
+
+    Ok(sql)
+}
+
+fn name(selection: &AggregationSelection) -> &'static str {
+    match selection {
+        AggregationSelection::CountDistinct(_) => "count_distinct",
+        AggregationSelection::StringAgg(_) => "string_agg",
+        AggregationSelection::Median(_) => "median",
+        AggregationSelection::StdDev(_) => "stddev",
+        AggregationSelection::Variance(_) => "variance",
+        selection => unreachable!("{:?} is rendered by quaint.", selection),
+    }
+}
+
+fn fields(selection: &AggregationSelection) -> &[ScalarFieldRef] {
+    match selection {
+        AggregationSelection::CountDistinct(fields)
+        | AggregationSelection::StringAgg(fields)
+        | AggregationSelection::Median(fields)
+        | AggregationSelection::StdDev(fields)
+        | AggregationSelection::Variance(fields) => fields,
+        _ => &[],
+    }
+}
+
+/// The schema only offers functions the connector has the capability for, the
+/// errors guard against unsupported combinations reaching the database anyway.
+fn function(family: &SqlFamily, selection: &AggregationSelection, column: &str) -> crate::Result<String> {
+    let function = match (selection, family) {
+        (AggregationSelection::CountDistinct(_), _) => format!("COUNT(DISTINCT {})", column),
+
+        (AggregationSelection::StringAgg(_), SqlFamily::Postgres) => format!("string_agg({}, ',')", column),
+        (AggregationSelection::StringAgg(_), SqlFamily::MySQL) => format!("GROUP_CONCAT({} SEPARATOR ',')", column),
+        (AggregationSelection::StringAgg(_), SqlFamily::SQLite) => format!("group_concat({}, ',')", column),
+        (AggregationSelection::StringAgg(_), SqlFamily::MSSQL) => format!("STRING_AGG({}, ',')", column),
+
+        (AggregationSelection::Median(_), SqlFamily::Postgres) => {
+            format!("percentile_cont(0.5) WITHIN GROUP (ORDER BY {})", column)
+        }
+
+        (AggregationSelection::StdDev(_), SqlFamily::Postgres) => format!("stddev_pop({})", column),
+        (AggregationSelection::StdDev(_), SqlFamily::MySQL) => format!("STDDEV_POP({})", column),
+        (AggregationSelection::StdDev(_), SqlFamily::MSSQL) => format!("STDEVP({})", column),
+
+        (AggregationSelection::Variance(_), SqlFamily::Postgres) => format!("var_pop({})", column),
+        (AggregationSelection::Variance(_), SqlFamily::MySQL) => format!("VAR_POP({})", column),
+        (AggregationSelection::Variance(_), SqlFamily::MSSQL) => format!("VARP({})", column),
+
+        (selection, _) => {
+            return Err(SqlError::QueryError(
+                format!(
+                    "The aggregate function `{}` is not supported by the database.",
+                    name(selection)
+                )
+                .into(),
+            ))
+        }
+    };
+
+    Ok(function)
+}
@@ -0,0 +1,101 @@
+//! Builds reads of distinct records with `DISTINCT ON` on PostgreSQL.
+//!
+//! Every other database numbers the records of a distinct combination with
+//! `ROW_NUMBER()` in the select of the read (see `read::get_records`).
+//! PostgreSQL picks the first record of every combination with `DISTINCT ON`
+//! instead, which quaint can't render: The read selects from a placeholder
+//! table that is replaced with the rendered `DISTINCT ON` sub-select.
+use super::{read, shift_placeholders};
+use crate::sql_info::{SqlFamily, SqlInfo};
+use connector_interface::{filter::Filter, QueryArguments};
+use prisma_models::*;
+use quaint::{
+    ast::*,
+    visitor::{self, Visitor},
+};
+
+/// Alias of the derived table holding the ids of the distinct records.
+const DISTINCT_ALIAS: &str = "__prisma_distinct_on";
+
+/// Builds the read of the distinct records. Returns `None` if the read has
+/// no distinct fields or the database has no `DISTINCT ON`, in which case it
+/// is built with `read::get_records`.
+pub fn build(
+    sql_info: &SqlInfo,
+    model: &ModelRef,
+    args: &QueryArguments,
+    selected_fields: &ModelProjection,
+) -> Option<(String, Vec<Value<'static>>)> {
+    let distinct = match (&sql_info.family, &args.distinct) {
+        (SqlFamily::Postgres, Some(distinct)) => distinct,
+        _ => return None,
+    };
+
+    let id_names: Vec<String> = model.primary_identifier().db_names().collect();
+    let id_columns: Vec<Column<'static>> = model.primary_identifier().as_columns().collect();
+
+    let firsts = id_names
+        .iter()
+        .fold(Select::from_table(DISTINCT_ALIAS), |acc, name| acc.column(name.clone()));
+
+    let mut outer_args = args.clone();
+    outer_args.distinct = None;
+
+    let outer = read::get_records(model, selected_fields.as_columns(), outer_args)
+        .and_where(Row::from(id_columns.clone()).in_selection(firsts));
+
+    let (sql, mut params) = visitor::Postgres::build(outer).ok()?;
+    let (inner_sql, inner_params) = distinct_on(model, args, distinct, id_columns)?;
+
+    let placeholder = format!("FROM \"{}\"", DISTINCT_ALIAS);
+    let inner_sql = shift_placeholders(&inner_sql, params.len());
+    let sql = sql.replacen(
+        &placeholder,
+        &format!("FROM ({}) AS \"{}\"", inner_sql, DISTINCT_ALIAS),
+        1,
+    );
+
+    params.extend(inner_params);
+
+    match sql_info.max_bind_values {
+        Some(max) if params.len() > max => None,
+        _ => Some((sql, params)),
+    }
+}
+
+/// Renders the sub-select of the ids of the first record of every distinct
+/// combination, in the order of the query:
+/// ```sql
+/// SELECT DISTINCT ON ("Table"."name") "Table"."id"
+/// FROM "Table"
+/// WHERE <filter>
+/// ORDER BY "Table"."name", "Table"."age", "Table"."id"
+/// ```
+fn distinct_on(
+    model: &ModelRef,
+    args: &QueryArguments,
+    distinct: &ModelProjection,
+    id_columns: Vec<Column<'static>>,
+) -> Option<(String, Vec<Value<'static>>)> {
+    let (orderings, joins) = read::distinct_ordering(model, args);
+    let filter = args.filter.clone().unwrap_or_else(Filter::empty);
+
+    // `DISTINCT ON` requires the distinct fields to lead the ordering.
+    let select = read::get_records(model, id_columns.into_iter(), filter);
+    let select = distinct
+        .as_columns()
+        .fold(select, |acc, column| acc.order_by(column.ascend()));
+    let select = orderings
+        .into_iter()
+        .fold(select, |acc, ordering| acc.order_by(ordering));
+    let select = joins.into_iter().fold(select, |acc, join| acc.left_join(join));
+
+    let on_columns: Vec<Column<'static>> = distinct.as_columns().collect();
+    let (on, _) = visitor::Postgres::build(Select::default().columns(on_columns)).ok()?;
+    let (sql, params) = visitor::Postgres::build(select).ok()?;
+
+    let on = on.strip_prefix("SELECT ")?;
+    let sql = sql.replacen("SELECT ", &format!("SELECT DISTINCT ON ({}) ", on), 1);
+
+    Some((sql, params))
+}
@@ -0,0 +1,200 @@
+//! Renders group by aggregations grouping by expressions over fields, like
+//! dates truncated to a unit or numbers rounded down to buckets.
+//!
+//! quaint can't express these in its AST: The query selects, groups, orders
+//! and filters by a placeholder column per expression, which is replaced with
+//! the expression in the dialect of the database after rendering the query.
+use super::render_column;
+use crate::{sql_info::SqlFamily, SqlError};
+use connector_interface::{DateTruncUnit, GroupingKey, TimeZone};
+use prisma_models::*;
+use quaint::ast::*;
+
+/// Prefix of the placeholder columns of grouping expressions.
+const PLACEHOLDER_PREFIX: &str = "__prisma_grouping_";
+
+/// The column a key is selected, grouped, ordered and filtered by. Expressions
+/// are represented by a placeholder column until the query is rendered.
+pub fn key_column(key: &GroupingKey) -> Column<'static> {
+    match key {
+        GroupingKey::Field(field) => field.as_column(),
+        _ => Column::from(format!("{}{}", PLACEHOLDER_PREFIX, key.field().db_name())),
+    }
+}
+
+/// Replaces the placeholder columns of the expression keys in a rendered
+/// group by aggregation built with `read::group_by_aggregate`.
+pub fn replace_placeholders(family: &SqlFamily, mut sql: String, group_by: &[GroupingKey]) -> crate::Result<String> {
+    for key in group_by.iter().filter(|key| key.is_expression()) {
+        let placeholder = render_column(family, key_column(key))?;
+        let column = render_column(family, key.field().as_column())?;
+
+        sql = sql.replace(&placeholder, &expression(family, key, &column)?);
+    }
+
+    Ok(sql)
+}
+
+fn expression(family: &SqlFamily, key: &GroupingKey, column: &str) -> crate::Result<String> {
+    match key {
+        GroupingKey::Field(_) => Ok(column.to_owned()),
+        GroupingKey::DateTrunc { field, unit, time_zone } => {
+            let with_time_zone = has_time_zone(family, field);
+            let local = to_time_zone(family, column, time_zone.as_ref(), with_time_zone, false)?;
+            let truncated = date_trunc(family, *unit, &local);
+
+            to_time_zone(family, &truncated, time_zone.as_ref(), with_time_zone, true)
+        }
+        GroupingKey::Bucket { field, width } => Ok(bucket(family, field, column, &render_number(width))),
+    }
+}
+
+/// Whether the column stores an absolute point in time rather than a UTC date
+/// time without a time zone, like `timestamptz` on PostgreSQL.
+fn has_time_zone(family: &SqlFamily, field: &ScalarFieldRef) -> bool {
+    matches!(family, SqlFamily::Postgres) && field.native_type.as_deref() == Some("Timestamptz")
+}
+
+/// Moves a date time from UTC into the time zone, or back into UTC if `back`
+/// is set. SQLite stores date times as milliseconds since the epoch.
+fn to_time_zone(
+    family: &SqlFamily,
+    column: &str,
+    time_zone: Option<&TimeZone>,
+    with_time_zone: bool,
+    back: bool,
+) -> crate::Result<String> {
+    if with_time_zone {
+        return Ok(to_time_zone_from_timestamptz(column, time_zone, back));
+    }
+
+    let expression = match time_zone {
+        None => column.to_owned(),
+        Some(TimeZone::Offset(minutes)) => {
+            let minutes = if back { -minutes } else { *minutes };
+
+            match family {
+                SqlFamily::Postgres => format!("({} + INTERVAL '{} minutes')", column, minutes),
+                SqlFamily::MySQL => format!("DATE_ADD({}, INTERVAL {} MINUTE)", column, minutes),
+                SqlFamily::SQLite => format!("({} + {})", column, i64::from(minutes) * 60_000),
+                SqlFamily::MSSQL => format!("DATEADD(minute, {}, {})", minutes, column),
+            }
+        }
+        Some(TimeZone::Named(name)) => match (family, back) {
+            (SqlFamily::Postgres, false) => format!("(({} AT TIME ZONE 'UTC') AT TIME ZONE '{}')", column, name),
+            (SqlFamily::Postgres, true) => format!("(({} AT TIME ZONE '{}') AT TIME ZONE 'UTC')", column, name),
+            (SqlFamily::MySQL, false) => format!("CONVERT_TZ({}, '+00:00', '{}')", column, name),
+            (SqlFamily::MySQL, true) => format!("CONVERT_TZ({}, '{}', '+00:00')", column, name),
+            _ => {
+                return Err(SqlError::QueryError(
+                    format!(
+                        "Named time zones like `{}` are not supported by the database. Use an offset from UTC instead.",
+                        name
+                    )
+                    .into(),
+                ))
+            }
+        },
+    };
+
+    Ok(expression)
+}
+
+/// `AT TIME ZONE` turns a `timestamptz` into the local time in the zone, and a
+/// local time in the zone back into a `timestamptz`. Without it, `date_trunc`
+/// would truncate in the time zone of the session.
+fn to_time_zone_from_timestamptz(column: &str, time_zone: Option<&TimeZone>, back: bool) -> String {
+    match (time_zone, back) {
+        (None, _) => format!("({} AT TIME ZONE 'UTC')", column),
+        (Some(TimeZone::Offset(minutes)), false) => {
+            format!("(({} AT TIME ZONE 'UTC') + INTERVAL '{} minutes')", column, minutes)
+        }
+        (Some(TimeZone::Offset(minutes)), true) => {
+            format!("(({} + INTERVAL '{} minutes') AT TIME ZONE 'UTC')", column, -minutes)
+        }
+        (Some(TimeZone::Named(name)), _) => format!("({} AT TIME ZONE '{}')", column, name),
+    }
+}
+
+fn date_trunc(family: &SqlFamily, unit: DateTruncUnit, date: &str) -> String {
+    match family {
+        SqlFamily::Postgres => {
+            let unit = match unit {
+                DateTruncUnit::Year => "year",
+                DateTruncUnit::Month => "month",
+                DateTruncUnit::Week => "week",
+                DateTruncUnit::Day => "day",
+                DateTruncUnit::Hour => "hour",
+                DateTruncUnit::Minute => "minute",
+            };
+
+            format!("date_trunc('{}', {})", unit, date)
+        }
+        SqlFamily::MySQL => {
+            let format = match unit {
+                DateTruncUnit::Week => {
+                    return format!("CAST(DATE_SUB(DATE({0}), INTERVAL WEEKDAY({0}) DAY) AS DATETIME)", date)
+                }
+                DateTruncUnit::Year => "%Y-01-01",
+                DateTruncUnit::Month => "%Y-%m-01",
+                DateTruncUnit::Day => "%Y-%m-%d",
+                DateTruncUnit::Hour => "%Y-%m-%d %H:00:00",
+                DateTruncUnit::Minute => "%Y-%m-%d %H:%i:00",
+            };
+
+            format!("CAST(DATE_FORMAT({}, '{}') AS DATETIME)", date, format)
+        }
+        SqlFamily::SQLite => {
+            let start = match unit {
+                DateTruncUnit::Week => format!("date({} / 1000, 'unixepoch', '-6 days', 'weekday 1')", date),
+                DateTruncUnit::Year => format!("strftime('%Y-01-01', {} / 1000, 'unixepoch')", date),
+                DateTruncUnit::Month => format!("strftime('%Y-%m-01', {} / 1000, 'unixepoch')", date),
+                DateTruncUnit::Day => format!("strftime('%Y-%m-%d', {} / 1000, 'unixepoch')", date),
+                DateTruncUnit::Hour => format!("strftime('%Y-%m-%d %H:00:00', {} / 1000, 'unixepoch')", date),
+                DateTruncUnit::Minute => format!("strftime('%Y-%m-%d %H:%M:00', {} / 1000, 'unixepoch')", date),
+            };
+
+            format!("(CAST(strftime('%s', {}) AS INTEGER) * 1000)", start)
+        }
+        // Counts the units since 1900-01-01, which was a Monday.
+        SqlFamily::MSSQL => match unit {
+            DateTruncUnit::Week => format!("DATEADD(day, (DATEDIFF(day, 0, {}) / 7) * 7, 0)", date),
+            DateTruncUnit::Year => format!("DATEADD(year, DATEDIFF(year, 0, {}), 0)", date),
+            DateTruncUnit::Month => format!("DATEADD(month, DATEDIFF(month, 0, {}), 0)", date),
+            DateTruncUnit::Day => format!("DATEADD(day, DATEDIFF(day, 0, {}), 0)", date),
+            DateTruncUnit::Hour => format!("DATEADD(hour, DATEDIFF(hour, 0, {}), 0)", date),
+            DateTruncUnit::Minute => format!("DATEADD(minute, DATEDIFF(minute, 0, {}), 0)", date),
+        },
+    }
+}
+
+/// Rounds the value down to a multiple of the width. Integer fields stay
+/// integers.
+fn bucket(family: &SqlFamily, field: &ScalarFieldRef, column: &str, width: &str) -> String {
+    let quotient = format!("({} * 1.0 / {})", column, width);
+
+    // SQLite only has `FLOOR` if compiled with the math functions.
+    let floored = match family {
+        SqlFamily::SQLite => format!("(CAST({0} AS INTEGER) - ({0} < CAST({0} AS INTEGER)))", quotient),
+        _ => format!("FLOOR({})", quotient),
+    };
+
+    let bucket = format!("({} * {})", floored, width);
+
+    match (family, &field.type_identifier) {
+        (SqlFamily::SQLite, _) => bucket,
+        (SqlFamily::MySQL, TypeIdentifier::Int) | (SqlFamily::MySQL, TypeIdentifier::BigInt) => {
+            format!("CAST({} AS SIGNED)", bucket)
+        }
+        (_, TypeIdentifier::Int) | (_, TypeIdentifier::BigInt) => format!("CAST({} AS BIGINT)", bucket),
+        _ => bucket,
+    }
+}
+
+fn render_number(value: &PrismaValue) -> String {
+    match value {
+        PrismaValue::Int(i) | PrismaValue::BigInt(i) => i.to_string(),
+        PrismaValue::Float(f) | PrismaValue::Decimal(f) => f.to_string(),
+        value => unreachable!("Bucket widths are validated to be numbers, got {:?}.", value),
+    }
+}
@@ -1,11 +1,21 @@
+pub mod aggregate_functions;
+pub mod distinct;
+pub mod grouping;
 pub mod read;
+pub mod relation_load;
+pub mod returning;
 pub mod write;
 
 pub use read::*;
 pub use write::*;
 
+use crate::sql_info::SqlFamily;
+use connector_interface::{AggregationSelection, GroupingKey};
 use prisma_models::{RecordProjection, RecordProjectionExt};
-use quaint::ast::{Column, Comparable, ConditionTree, Query, Row, Values};
+use quaint::{
+    ast::{Column, Comparable, ConditionTree, Query, Row, Select, Value, Values},
+    visitor::{self, Visitor},
+};
 
 const PARAMETER_LIMIT: usize = 2000;
 
@@ -40,3 +50,78 @@ pub(super) fn conditions<'a>(
 
     Row::from(columns.to_vec()).in_selection(values).into()
 }
+
+/// Whether an aggregation groups by expressions or selects aggregate functions
+/// quaint has no AST for, which are rendered into the statement after building it.
+pub fn needs_raw_aggregation(selections: &[AggregationSelection], group_by: &[GroupingKey]) -> bool {
+    group_by.iter().any(GroupingKey::is_expression) || selections.iter().any(aggregate_functions::is_raw)
+}
+
+/// Renders an aggregation built with `read::aggregate` or `read::group_by_aggregate`,
+/// replacing the placeholder columns of its grouping expressions and aggregate functions.
+pub fn render_aggregation(
+    family: &SqlFamily,
+    select: Select<'static>,
+    selections: &[AggregationSelection],
+    group_by: &[GroupingKey],
+) -> crate::Result<(String, Vec<Value<'static>>)> {
+    let (sql, params) = build(family, select)?;
+    let sql = grouping::replace_placeholders(family, sql, group_by)?;
+    let sql = aggregate_functions::replace_placeholders(family, sql, selections, !group_by.is_empty())?;
+
+    Ok((sql, params))
+}
+
+fn build(family: &SqlFamily, select: Select<'static>) -> crate::Result<(String, Vec<Value<'static>>)> {
+    let rendered = match family {
+        SqlFamily::Postgres => visitor::Postgres::build(select),
+        SqlFamily::MySQL => visitor::Mysql::build(select),
+        SqlFamily::SQLite => visitor::Sqlite::build(select),
+        SqlFamily::MSSQL => visitor::Mssql::build(select),
+    };
+
+    Ok(rendered?)
+}
+
+/// The column as it is written in the dialect of the database.
+pub(super) fn render_column(family: &SqlFamily, column: Column<'static>) -> crate::Result<String> {
+    let (sql, _) = build(family, Select::default().column(column))?;
+
+    Ok(sql.trim_start_matches("SELECT ").to_owned())
+}
+
+/// Moves all `$n` placeholders of a PostgreSQL statement by `offset`.
+pub(super) fn shift_placeholders(sql: &str, offset: usize) -> String {
+    if offset == 0 {
+        return sql.to_owned();
+    }
+
+    let mut result = String::with_capacity(sql.len());
+    let mut chars = sql.chars().peekable();
+    let mut quote: Option<char> = None;
+
+    while let Some(c) = chars.next() {
+        result.push(c);
+
+        match (c, quote) {
+            ('"', None) | ('\'', None) => quote = Some(c),
+            (c, Some(q)) if c == q => quote = None,
+            ('$', None) => {
+                let mut digits = String::new();
+
+                while let Some(d) = chars.peek().filter(|d| d.is_ascii_digit()) {
+                    digits.push(*d);
+                    chars.next();
+                }
+
+                match digits.parse::<usize>() {
+                    Ok(n) => result.push_str(&(n + offset).to_string()),
+                    Err(_) => result.push_str(&digits),
+                }
+            }
+            _ => (),
+        }
+    }
+
+    result
+}
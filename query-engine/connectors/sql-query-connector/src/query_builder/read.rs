@@ -1,9 +1,21 @@
-use crate::{cursor_condition, filter_conversion::AliasedCondition, ordering};
-use connector_interface::{filter::Filter, AggregationSelection, QueryArguments};
+use super::{aggregate_functions, grouping};
+use crate::{
+    cursor_condition,
+    filter_conversion::{self, AliasedCondition},
+    ordering,
+    query_arguments_ext::QueryArgumentsExt,
+};
+use connector_interface::{filter::Filter, AggregationSelection, GroupingKey, QueryArguments};
 use itertools::Itertools;
 use prisma_models::*;
 use quaint::ast::*;
 
+/// Alias of the derived table numbering the records within their distinct combination.
+const DISTINCT_ALIAS: &str = "__prisma_distinct";
+
+/// Column holding the number of a record within its distinct combination.
+const ROW_NUMBER_ALIAS: &str = "__prisma_row_number";
+
 pub trait SelectDefinition {
     fn into_select(self, _: &ModelRef) -> Select<'static>;
 }
@@ -31,6 +43,10 @@ impl SelectDefinition for QueryArguments {
     fn into_select(self, model: &ModelRef) -> Select<'static> {
         let (table_opt, cursor_condition) = cursor_condition::build(&self, &model);
         let (orderings, joins) = ordering::build(&self, &model);
+        let distinct_condition = self
+            .distinct
+            .as_ref()
+            .map(|distinct| distinct_condition(model, &self, distinct));
 
         let limit = if self.ignore_take { None } else { self.take_abs() };
         let skip = if self.ignore_skip { 0 } else { self.skip.unwrap_or(0) };
@@ -46,6 +62,12 @@ impl SelectDefinition for QueryArguments {
             (filter, cursor) => ConditionTree::and(filter, cursor),
         };
 
+        let conditions = match (conditions, distinct_condition) {
+            (conditions, None) => conditions,
+            (ConditionTree::NoCondition, Some(distinct)) => distinct,
+            (conditions, Some(distinct)) => ConditionTree::and(conditions, distinct),
+        };
+
         let select_ast = Select::from_table(model.as_table())
             .so_that(conditions)
             .offset(skip as usize);
@@ -66,6 +88,75 @@ impl SelectDefinition for QueryArguments {
     }
 }
 
+/// Restricts the records to the first record of every combination of the
+/// `distinct` fields, in the order of the query. Skip, take and cursor are
+/// applied to the remaining records:
+/// ```sql
+/// (`Table`.`id`) IN (
+///     SELECT `__prisma_distinct`.`id`
+///     FROM (
+///         SELECT
+///             `Table`.`id`,
+///             ROW_NUMBER() OVER (
+///                 PARTITION BY `Table`.`name` ORDER BY `Table`.`age`, `Table`.`id`
+///             ) AS `__prisma_row_number`
+///         FROM `Table`
+///         WHERE <filter>
+///     ) AS `__prisma_distinct`
+///     WHERE `__prisma_distinct`.`__prisma_row_number` = 1
+/// )
+/// ```
+fn distinct_condition(model: &ModelRef, args: &QueryArguments, distinct: &ModelProjection) -> ConditionTree<'static> {
+    let (orderings, joins) = distinct_ordering(model, args);
+
+    let numbering = distinct
+        .as_columns()
+        .fold(row_number(), |acc, column| acc.partition_by(column));
+    let numbering = orderings
+        .into_iter()
+        .fold(numbering, |acc, ordering| acc.order_by(ordering));
+
+    let id_columns: Vec<Column<'static>> = model.primary_identifier().as_columns().collect();
+    let filter = args
+        .filter
+        .clone()
+        .map(|f| f.aliased_cond(None))
+        .unwrap_or(ConditionTree::NoCondition);
+
+    let numbered = Select::from_table(model.as_table())
+        .columns(id_columns.clone())
+        .value(Function::from(numbering).alias(ROW_NUMBER_ALIAS))
+        .so_that(filter);
+    let numbered = joins.into_iter().fold(numbered, |acc, join| acc.left_join(join));
+
+    let firsts = model
+        .primary_identifier()
+        .db_names()
+        .fold(
+            Select::from_table(Table::from(numbered).alias(DISTINCT_ALIAS)),
+            |acc, name| acc.column(Column::from((DISTINCT_ALIAS, name))),
+        )
+        .so_that(Column::from((DISTINCT_ALIAS, ROW_NUMBER_ALIAS)).equals(1));
+
+    Row::from(id_columns).in_selection(firsts).into()
+}
+
+/// The ordering deciding which record of a distinct combination is kept: The
+/// ordering of the query in forward direction, with the primary identifier
+/// breaking ties.
+pub(super) fn distinct_ordering(
+    model: &ModelRef,
+    args: &QueryArguments,
+) -> (Vec<OrderDefinition<'static>>, Vec<JoinData<'static>>) {
+    let mut forward = QueryArguments::new(model.clone());
+    forward.order_by = args.order_by.clone();
+
+    let (mut orderings, joins) = ordering::build(&forward, model);
+    orderings.extend(model.primary_identifier().as_columns().map(|column| column.ascend()));
+
+    (orderings, joins)
+}
+
 pub fn get_records<T>(model: &ModelRef, columns: impl Iterator<Item = Column<'static>>, query: T) -> Select<'static>
 where
     T: SelectDefinition,
@@ -99,6 +190,7 @@ where
 /// ```
 /// Important note: Do not use the AsColumn trait here as we need to construct column references that are relative,
 /// not absolute - e.g. `SELECT "field" FROM (...)` NOT `SELECT "full"."path"."to"."field" FROM (...)`.
+/// Aggregate functions quaint has no AST for are selected as placeholder columns, see `render_aggregation`.
 pub fn aggregate(model: &ModelRef, selections: &[AggregationSelection], args: QueryArguments) -> Select<'static> {
     let columns = extract_columns(model, &selections);
     let sub_query = get_records(model, columns.into_iter(), args);
@@ -108,6 +200,7 @@ pub fn aggregate(model: &ModelRef, selections: &[AggregationSelection], args: Qu
         .iter()
         .fold(Select::from_table(sub_table), |select, next_op| match next_op {
             AggregationSelection::Field(field) => select.column(Column::from(field.db_name().to_owned())),
+            AggregationSelection::Expression(key) => select.column(Column::from(key.field().db_name().to_owned())),
 
             AggregationSelection::Count { all, fields } => {
                 let select = fields.iter().fold(select, |select, next_field| {
@@ -136,20 +229,43 @@ pub fn aggregate(model: &ModelRef, selections: &[AggregationSelection], args: Qu
             AggregationSelection::Max(fields) => fields.iter().fold(select, |select, next_field| {
                 select.value(max(Column::from(next_field.db_name().to_owned())))
             }),
+
+            AggregationSelection::CountDistinct(fields)
+            | AggregationSelection::StringAgg(fields)
+            | AggregationSelection::Median(fields)
+            | AggregationSelection::StdDev(fields)
+            | AggregationSelection::Variance(fields) => fields.iter().fold(select, |select, next_field| {
+                select.column(aggregate_functions::placeholder(next_op, next_field))
+            }),
         })
 }
 
+/// Generates a query of the form:
+/// ```sql
+/// SELECT `Table`.`category`, COUNT(*)
+/// FROM `Table`
+/// WHERE 1 = 1
+/// GROUP BY `Table`.`category`
+/// HAVING COUNT(*) > 1
+/// ORDER BY `Table`.`category`
+/// ```
+/// Keys grouping by an expression over a field are selected, grouped, ordered
+/// and filtered by a placeholder column, as are aggregate functions quaint has
+/// no AST for, see `render_aggregation`.
 pub fn group_by_aggregate(
     model: &ModelRef,
-    args: QueryArguments,
+    mut args: QueryArguments,
     selections: &[AggregationSelection],
-    group_by: Vec<ScalarFieldRef>,
+    group_by: &[GroupingKey],
     having: Option<Filter>,
 ) -> Select<'static> {
+    let reversed = args.needs_reversed_order();
+    let order_by = std::mem::take(&mut args.order_by);
     let base_query: Select = args.into_select(model);
 
     let select_query = selections.iter().fold(base_query, |select, next_op| match next_op {
         AggregationSelection::Field(field) => select.column(field.as_column()),
+        AggregationSelection::Expression(key) => select.column(grouping::key_column(key)),
 
         AggregationSelection::Count { all, fields } => {
             let select = fields
@@ -178,15 +294,45 @@ pub fn group_by_aggregate(
         AggregationSelection::Max(fields) => fields
             .iter()
             .fold(select, |select, next_field| select.value(max(next_field.as_column()))),
+
+        AggregationSelection::CountDistinct(fields)
+        | AggregationSelection::StringAgg(fields)
+        | AggregationSelection::Median(fields)
+        | AggregationSelection::StdDev(fields)
+        | AggregationSelection::Variance(fields) => fields.iter().fold(select, |select, next_field| {
+            select.column(aggregate_functions::placeholder(next_op, next_field))
+        }),
     });
 
     let grouped = group_by
-        .into_iter()
-        .fold(select_query, |query, field| query.group_by(field.as_column()));
+        .iter()
+        .fold(select_query, |query, key| query.group_by(grouping::key_column(key)));
+
+    // Orderings are verified to be on grouped fields, which are ordered by their key.
+    let ordered = order_by.into_iter().fold(grouped, |query, order_by| {
+        let column = group_by
+            .iter()
+            .find(|key| key.field() == &order_by.field)
+            .map(grouping::key_column)
+            .unwrap_or_else(|| order_by.field.as_column());
+
+        match (order_by.sort_order, reversed) {
+            (SortOrder::Ascending, true) => query.order_by(column.descend()),
+            (SortOrder::Descending, true) => query.order_by(column.ascend()),
+            (SortOrder::Ascending, false) => query.order_by(column.ascend()),
+            (SortOrder::Descending, false) => query.order_by(column.descend()),
+        }
+    });
+
+    let expressions: Vec<_> = group_by
+        .iter()
+        .filter(|key| key.is_expression())
+        .map(|key| (key.field().clone(), grouping::key_column(key)))
+        .collect();
 
     match having {
-        Some(filter) => grouped.having(filter.aliased_cond(None)),
-        None => grouped,
+        Some(filter) => ordered.having(filter_conversion::having_cond(filter, &expressions)),
+        None => ordered,
     }
 }
 
@@ -195,6 +341,7 @@ fn extract_columns(model: &ModelRef, selections: &[AggregationSelection]) -> Vec
         .iter()
         .flat_map(|selection| match selection {
             AggregationSelection::Field(field) => vec![field.clone()],
+            AggregationSelection::Expression(key) => vec![key.field().clone()],
             AggregationSelection::Count { all: _, fields } => {
                 if fields.is_empty() {
                     model.primary_identifier().scalar_fields().collect()
@@ -206,6 +353,11 @@ fn extract_columns(model: &ModelRef, selections: &[AggregationSelection]) -> Vec
             AggregationSelection::Sum(fields) => fields.clone(),
             AggregationSelection::Min(fields) => fields.clone(),
             AggregationSelection::Max(fields) => fields.clone(),
+            AggregationSelection::CountDistinct(fields) => fields.clone(),
+            AggregationSelection::StringAgg(fields) => fields.clone(),
+            AggregationSelection::Median(fields) => fields.clone(),
+            AggregationSelection::StdDev(fields) => fields.clone(),
+            AggregationSelection::Variance(fields) => fields.clone(),
         })
         .unique_by(|field| field.db_name().to_owned())
         .collect();
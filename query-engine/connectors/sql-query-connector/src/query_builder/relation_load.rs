@@ -0,0 +1,320 @@
+//! Builds a single statement loading records together with a tree of nested
+//! relations.
+//!
+//! Every relation level is rendered as a correlated sub-select of its parent,
+//! aggregated into a JSON array:
+//! - On PostgreSQL, the sub-selects are joined with `LEFT JOIN LATERAL` and
+//!   aggregated with `json_agg`.
+//! - On MySQL 8, the sub-selects are part of the column list and aggregated
+//!   with `JSON_ARRAYAGG(JSON_OBJECT(..))`.
+//!
+//! Quaint has no notion of JSON aggregation, so every level is rendered with
+//! a placeholder in place of its nested relations, which is then replaced with
+//! the rendered sub-select.
+use super::{read, shift_placeholders};
+use crate::sql_info::{SqlFamily, SqlInfo};
+use connector_interface::{QueryArguments, RelationLoad};
+use prisma_models::*;
+use quaint::{
+    ast::*,
+    visitor::{self, Visitor},
+};
+
+/// Alias of the derived table holding the records of a relation level.
+const RELATION_ALIAS: &str = "__prisma_rel";
+
+/// Column holding the aggregated records of a relation level on PostgreSQL.
+const DATA_COLUMN: &str = "__prisma_data";
+
+/// The shape of the records of one relation level in the JSON document.
+#[derive(Debug)]
+pub struct RelationLevel {
+    pub model: ModelRef,
+    pub selected_fields: ModelProjection,
+    pub order_by: Vec<OrderBy>,
+
+    /// Nested levels with the key holding their records in a record of this level.
+    pub nested: Vec<(String, RelationLevel)>,
+}
+
+/// A rendered statement loading a set of records with their relations.
+/// The result set contains the `selected_fields` of the records, followed by
+/// one JSON column for every entry in `nested`.
+#[derive(Debug)]
+pub struct RelationLoadQuery {
+    pub sql: String,
+    pub params: Vec<Value<'static>>,
+    pub nested: Vec<(String, RelationLevel)>,
+}
+
+/// Builds the statement for the given relation tree. Returns `None` if the
+/// database or the shape of the relation tree is not supported, in which case
+/// the relations must be loaded with separate queries.
+pub fn build(
+    sql_info: &SqlInfo,
+    model: &ModelRef,
+    args: QueryArguments,
+    selected_fields: &ModelProjection,
+    relations: &[RelationLoad],
+) -> Option<RelationLoadQuery> {
+    if !matches!(sql_info.family, SqlFamily::Postgres | SqlFamily::MySQL) || args.can_batch() {
+        return None;
+    }
+
+    let mut builder = Builder {
+        family: &sql_info.family,
+        next_alias: 0,
+        path: vec![model.name.clone()],
+    };
+
+    let (sql, params, nested) = builder.level(model, args, selected_fields, relations, None)?;
+
+    match sql_info.max_bind_values {
+        Some(max) if params.len() > max => None,
+        _ => Some(RelationLoadQuery { sql, params, nested }),
+    }
+}
+
+struct Builder<'a> {
+    family: &'a SqlFamily,
+    next_alias: usize,
+
+    /// Models from the root to the current level. Tables are referenced by
+    /// their name in the correlation conditions, so a model can only appear
+    /// once on a path.
+    path: Vec<String>,
+}
+
+impl<'a> Builder<'a> {
+    fn level(
+        &mut self,
+        model: &ModelRef,
+        args: QueryArguments,
+        selected_fields: &ModelProjection,
+        relations: &[RelationLoad],
+        correlation: Option<ConditionTree<'static>>,
+    ) -> Option<(String, Vec<Value<'static>>, Vec<(String, RelationLevel)>)> {
+        let mut select = read::get_records(model, selected_fields.as_columns(), args);
+
+        if let Some(correlation) = correlation {
+            select = select.and_where(correlation);
+        }
+
+        let mut nested = Vec::with_capacity(relations.len());
+        let mut sub_selects = Vec::with_capacity(relations.len());
+
+        for relation in relations {
+            let alias = format!("__prisma_nested_{}", self.next_alias);
+            self.next_alias += 1;
+
+            let (sql, params, level) = self.relation(relation)?;
+
+            select = match self.family {
+                SqlFamily::Postgres => select
+                    .column(Column::from((alias.clone(), DATA_COLUMN.to_owned())).alias(alias.clone()))
+                    .left_join(
+                        Table::from(lateral_placeholder(&alias))
+                            .alias(alias.clone())
+                            .on(ConditionTree::NoCondition),
+                    ),
+                _ => select.column(Column::from(alias.clone())),
+            };
+
+            sub_selects.push((alias.clone(), sql, params));
+            nested.push((alias, level));
+        }
+
+        let (sql, params) = match self.family {
+            SqlFamily::Postgres => visitor::Postgres::build(select),
+            _ => visitor::Mysql::build(select),
+        }
+        .ok()?;
+
+        let (sql, params) = self.embed(sql, params, sub_selects);
+
+        Some((sql, params, nested))
+    }
+
+    /// Renders the aggregating sub-select of one relation, correlated to its parent.
+    fn relation(&mut self, relation: &RelationLoad) -> Option<(String, Vec<Value<'static>>, RelationLevel)> {
+        let model = relation.related_model();
+        let args = relation.args.clone();
+
+        if self.path.contains(&model.name) || !supports_arguments(&args) {
+            return None;
+        }
+
+        // Related records are ordered in memory after loading, as the order
+        // of aggregated records is not guaranteed on every database.
+        let order_fields: Vec<Field> = args.order_by.iter().map(|o| o.field.clone().into()).collect();
+        let selected_fields = relation
+            .selected_fields
+            .clone()
+            .merge(model.primary_identifier())
+            .merge(ModelProjection::new(order_fields));
+
+        if !supports_fields(&selected_fields) {
+            return None;
+        }
+
+        let order_by = args.order_by.clone();
+        let correlation = correlation(&relation.parent_field);
+
+        self.path.push(model.name.clone());
+        let (sql, params, nested) = self.level(&model, args, &selected_fields, &relation.nested, Some(correlation))?;
+        self.path.pop();
+
+        let sql = self.aggregate(sql, &selected_fields, &nested);
+
+        let level = RelationLevel {
+            model,
+            selected_fields,
+            order_by,
+            nested,
+        };
+
+        Some((sql, params, level))
+    }
+
+    /// Wraps the select of a relation level into a JSON aggregation.
+    fn aggregate(&self, sql: String, selected_fields: &ModelProjection, nested: &[(String, RelationLevel)]) -> String {
+        match self.family {
+            SqlFamily::Postgres => format!(
+                "SELECT COALESCE(json_agg({rel}), '[]'::json) AS {data} FROM ({sql}) AS {rel}",
+                rel = self.quote(RELATION_ALIAS),
+                data = self.quote(DATA_COLUMN),
+                sql = sql,
+            ),
+            _ => {
+                let keys = selected_fields
+                    .db_names()
+                    .chain(nested.iter().map(|(alias, _)| alias.clone()));
+
+                let pairs: Vec<String> = keys
+                    .map(|key| {
+                        format!(
+                            "'{}', {}.{}",
+                            key.replace('\'', "''"),
+                            self.quote(RELATION_ALIAS),
+                            self.quote(&key)
+                        )
+                    })
+                    .collect();
+
+                format!(
+                    "SELECT COALESCE(JSON_ARRAYAGG(JSON_OBJECT({pairs})), JSON_ARRAY()) FROM ({sql}) AS {rel}",
+                    pairs = pairs.join(", "),
+                    sql = sql,
+                    rel = self.quote(RELATION_ALIAS),
+                )
+            }
+        }
+    }
+
+    /// Replaces the placeholders of the nested relations with their sub-selects.
+    fn embed(
+        &self,
+        mut sql: String,
+        mut params: Vec<Value<'static>>,
+        sub_selects: Vec<(String, String, Vec<Value<'static>>)>,
+    ) -> (String, Vec<Value<'static>>) {
+        match self.family {
+            // Parameters are numbered, the ones of the sub-selects are appended.
+            SqlFamily::Postgres => {
+                for (alias, sub_sql, sub_params) in sub_selects {
+                    let sub_sql = shift_placeholders(&sub_sql, params.len());
+                    let placeholder = self.quote(&lateral_placeholder(&alias));
+
+                    sql = sql.replacen(&placeholder, &format!("LATERAL ({})", sub_sql), 1);
+                    params.extend(sub_params);
+                }
+
+                (sql, params)
+            }
+            // Parameters are positional. The sub-selects are in the column
+            // list, which comes before any other parameter of the statement.
+            _ => {
+                let mut all_params = Vec::new();
+
+                for (alias, sub_sql, sub_params) in sub_selects {
+                    let placeholder = self.quote(&alias);
+
+                    sql = sql.replacen(&placeholder, &format!("({}) AS {}", sub_sql, placeholder), 1);
+                    all_params.extend(sub_params);
+                }
+
+                all_params.extend(params);
+                (sql, all_params)
+            }
+        }
+    }
+
+    fn quote(&self, ident: &str) -> String {
+        match self.family {
+            SqlFamily::Postgres => format!("\"{}\"", ident.replace('"', "\"\"")),
+            _ => format!("`{}`", ident.replace('`', "``")),
+        }
+    }
+}
+
+fn lateral_placeholder(alias: &str) -> String {
+    format!("{}_lateral", alias)
+}
+
+/// The condition tying the records of a relation level to the current record of its parent.
+fn correlation(parent_field: &RelationFieldRef) -> ConditionTree<'static> {
+    let child_fields: Vec<_> = parent_field.related_field().linking_fields().scalar_fields().collect();
+
+    if parent_field.relation().is_many_to_many() {
+        let parent_ids = parent_field.model().primary_identifier();
+        let parent_columns = parent_field.related_field().m2m_columns();
+
+        let conditions: Vec<Expression<'static>> = parent_columns
+            .into_iter()
+            .zip(parent_ids.as_columns())
+            .map(|(a, b)| a.equals(b).into())
+            .collect();
+
+        let sub_select = Select::from_table(parent_field.relation().as_table())
+            .columns(parent_field.m2m_columns())
+            .so_that(ConditionTree::And(conditions));
+
+        let child_columns: Vec<Column<'static>> = child_fields.iter().map(|sf| sf.as_column()).collect();
+
+        Row::from(child_columns).in_selection(sub_select).into()
+    } else {
+        let parent_link = parent_field.linking_fields();
+
+        let conditions: Vec<Expression<'static>> = child_fields
+            .iter()
+            .zip(parent_link.scalar_fields())
+            .map(|(child, parent)| child.as_column().equals(parent.as_column()).into())
+            .collect();
+
+        ConditionTree::And(conditions)
+    }
+}
+
+/// Cursors are processed in memory, distinct can't be applied to the records
+/// of every parent on its own, and orderings by relations can't be applied to
+/// the aggregated records after loading.
+fn supports_arguments(args: &QueryArguments) -> bool {
+    args.cursor.is_none()
+        && args.distinct.is_none()
+        && args.order_by.iter().all(|o| o.path.is_empty())
+        && !(args.needs_reversed_order() && args.order_by.is_empty())
+}
+
+/// Binary values can't be represented in JSON, and decimals would lose their
+/// precision.
+fn supports_fields(selected_fields: &ModelProjection) -> bool {
+    selected_fields
+        .type_identifiers_with_arities()
+        .iter()
+        .all(|(ident, _)| {
+            !matches!(
+                ident,
+                TypeIdentifier::Bytes | TypeIdentifier::Xml | TypeIdentifier::Decimal | TypeIdentifier::Unsupported
+            )
+        })
+}
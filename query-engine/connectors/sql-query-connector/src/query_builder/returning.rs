@@ -0,0 +1,83 @@
+//! Renders write statements returning the columns of the written rows.
+//!
+//! Quaint can't render a returning clause on updates and deletes, so the
+//! clause is added to the rendered statement:
+//! - On PostgreSQL, a `RETURNING` clause is appended.
+//! - On SQL Server, an `OUTPUT` clause is placed in front of the `VALUES` or
+//!   `DEFAULT VALUES` of an insert, or the `WHERE` of an update or delete.
+//!
+//! MySQL can't return written rows, and SQLite only can from 3.35 on, which is
+//! newer than the bundled library. On both, the rows have to be read in the
+//! same transaction instead.
+use crate::sql_info::SqlFamily;
+use quaint::{
+    ast::*,
+    visitor::{self, Visitor},
+};
+
+/// The state of the written rows to return.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Returned {
+    /// The rows after an insert or update.
+    Inserted,
+
+    /// The rows before a delete.
+    Deleted,
+}
+
+/// Whether the database can return the rows written by a statement.
+pub fn returns_written_rows(family: &SqlFamily) -> bool {
+    matches!(family, SqlFamily::Postgres | SqlFamily::MSSQL)
+}
+
+/// Renders the statement returning the given columns. Returns `None` if the
+/// database can't return written rows.
+pub fn render(
+    family: &SqlFamily,
+    query: Query<'static>,
+    columns: &[String],
+    returned: Returned,
+) -> Option<(String, Vec<Value<'static>>)> {
+    let is_insert = matches!(query, Query::Insert(_));
+
+    let (sql, params) = match family {
+        SqlFamily::Postgres => visitor::Postgres::build(query),
+        SqlFamily::MSSQL => visitor::Mssql::build(query),
+        SqlFamily::MySQL | SqlFamily::SQLite => return None,
+    }
+    .ok()?;
+
+    let sql = match family {
+        SqlFamily::MSSQL => {
+            let prefix = match returned {
+                Returned::Inserted => "INSERTED",
+                Returned::Deleted => "DELETED",
+            };
+
+            let output = columns
+                .iter()
+                .map(|column| format!("{}.[{}]", prefix, column.replace(']', "]]")))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            let position = if is_insert {
+                sql.find(" DEFAULT VALUES").or_else(|| sql.find(" VALUES "))?
+            } else {
+                sql.find(" WHERE ")?
+            };
+
+            format!("{} OUTPUT {}{}", &sql[..position], output, &sql[position..])
+        }
+        _ => {
+            let returning = columns
+                .iter()
+                .map(|column| format!("\"{}\"", column.replace('"', "\"\"")))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            format!("{} RETURNING {}", sql, returning)
+        }
+    };
+
+    Some((sql, params))
+}
@@ -39,7 +39,13 @@ pub fn create_records(model: &ModelRef, args: Vec<WriteArgs>, skip_duplicates: b
     // We need to bring all write args into a uniform shape.
     // The easiest way to do this is to take go over all fields of the batch and apply the following:
     // All fields that have a default but are not explicitly provided are inserted with `DEFAULT`.
-    let fields = model.fields().scalar();
+    // Generated fields can't be written, not even with `DEFAULT` on every database, so they are left out.
+    let fields: Vec<ScalarFieldRef> = model
+        .fields()
+        .scalar()
+        .into_iter()
+        .filter(|field| field.generation_expression.is_none())
+        .collect();
 
     let values: Vec<_> = args
         .into_iter()
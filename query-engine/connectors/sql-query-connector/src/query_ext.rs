@@ -1,21 +1,44 @@
 use crate::{column_metadata, error::*, AliasedCondition, ColumnMetadata, SqlRow, ToSqlRow};
 use async_trait::async_trait;
-use connector_interface::{filter::Filter, RecordFilter};
+use connector_interface::{filter::Filter, query_log, RecordFilter};
 use futures::future::FutureExt;
 use prisma_models::*;
 use quaint::{
     ast::*,
-    connector::{self, Queryable},
+    connector::{self, Queryable, ResultSet, SqlFamily},
     pooled::PooledConnection,
+    visitor::{self, Visitor},
 };
 use tracing_futures::Instrument;
 
 use serde_json::{Map, Value};
-use std::panic::AssertUnwindSafe;
+use std::{panic::AssertUnwindSafe, time::Instant};
+
+tokio::task_local! {
+    /// The SQL family of the connection the current operation runs on.
+    /// Only used to render statements for the query log.
+    pub(crate) static SQL_FAMILY: SqlFamily;
+}
 
 impl<'t> QueryExt for connector::Transaction<'t> {}
 impl QueryExt for PooledConnection {}
 
+/// Renders a query to SQL text and JSON parameters for the query log.
+fn render_for_log(q: Query<'_>) -> Option<(String, Vec<Value>)> {
+    let rendered = SQL_FAMILY
+        .try_with(|family| match family {
+            SqlFamily::Postgres => visitor::Postgres::build(q),
+            SqlFamily::Mysql => visitor::Mysql::build(q),
+            SqlFamily::Sqlite => visitor::Sqlite::build(q),
+            SqlFamily::Mssql => visitor::Mssql::build(q),
+        })
+        .ok()?;
+
+    let (sql, params) = rendered.ok()?;
+
+    Some((sql, params.into_iter().map(Value::from).collect()))
+}
+
 /// An extension trait for Quaint's `Queryable`, offering certain Prisma-centric
 /// database operations on top of `Queryable`.
 #[async_trait]
@@ -23,7 +46,7 @@ pub trait QueryExt: Queryable + Send + Sync {
     /// Filter and map the resulting types with the given identifiers.
     async fn filter(&self, q: Query<'_>, idents: &[ColumnMetadata<'_>]) -> crate::Result<Vec<SqlRow>> {
         let result_set = self
-            .query(q)
+            .logged_query(q)
             .instrument(tracing::info_span!("Filter read query"))
             .await?;
 
@@ -36,6 +59,61 @@ pub trait QueryExt: Queryable + Send + Sync {
         Ok(sql_rows)
     }
 
+    /// Execute a query, reporting it to the query log if logging is enabled
+    /// for the current request.
+    async fn logged_query(&self, q: Query<'_>) -> quaint::Result<ResultSet> {
+        if !query_log::is_enabled() {
+            return self.query(q).await;
+        }
+
+        let rendered = render_for_log(q.clone());
+        let start = Instant::now();
+        let result_set = self.query(q).await?;
+
+        if let Some((sql, params)) = rendered {
+            query_log::record(sql, params, start.elapsed(), Some(result_set.len() as u64));
+        }
+
+        Ok(result_set)
+    }
+
+    /// Execute a query, returning the number of affected rows and reporting
+    /// it to the query log if logging is enabled for the current request.
+    async fn logged_execute(&self, q: Query<'_>) -> quaint::Result<u64> {
+        if !query_log::is_enabled() {
+            return self.execute(q).await;
+        }
+
+        let rendered = render_for_log(q.clone());
+        let start = Instant::now();
+        let count = self.execute(q).await?;
+
+        if let Some((sql, params)) = rendered {
+            query_log::record(sql, params, start.elapsed(), Some(count));
+        }
+
+        Ok(count)
+    }
+
+    /// Execute a statement that was rendered outside of the query AST,
+    /// reporting it to the query log if logging is enabled for the current request.
+    async fn logged_query_raw(&self, sql: &str, params: &[quaint::Value<'_>]) -> quaint::Result<ResultSet> {
+        let start = Instant::now();
+        let result_set = self.query_raw(sql, params).await?;
+
+        if query_log::is_enabled() {
+            let logged_params = params.iter().cloned().map(Value::from).collect();
+            query_log::record(
+                sql.to_owned(),
+                logged_params,
+                start.elapsed(),
+                Some(result_set.len() as u64),
+            );
+        }
+
+        Ok(result_set)
+    }
+
     /// Execute a singular SQL query in the database, returning an arbitrary
     /// JSON `Value` as a result.
     async fn raw_json<'a>(
@@ -44,8 +122,14 @@ pub trait QueryExt: Queryable + Send + Sync {
         params: Vec<PrismaValue>,
     ) -> std::result::Result<Value, crate::error::RawError> {
         let params: Vec<_> = params.into_iter().map(convert_lossy).collect();
+        let start = Instant::now();
         let result_set = AssertUnwindSafe(self.query_raw(&q, &params)).catch_unwind().await??;
 
+        if query_log::is_enabled() {
+            let logged_params = params.iter().cloned().map(Value::from).collect();
+            query_log::record(q, logged_params, start.elapsed(), Some(result_set.len() as u64));
+        }
+
         let columns: Vec<String> = result_set.columns().iter().map(ToString::to_string).collect();
         let mut result = Vec::new();
 
@@ -71,8 +155,14 @@ pub trait QueryExt: Queryable + Send + Sync {
         params: Vec<PrismaValue>,
     ) -> std::result::Result<usize, crate::error::RawError> {
         let params: Vec<_> = params.into_iter().map(convert_lossy).collect();
+        let start = Instant::now();
         let changes = AssertUnwindSafe(self.execute_raw(&q, &params)).catch_unwind().await??;
 
+        if query_log::is_enabled() {
+            let logged_params = params.iter().cloned().map(Value::from).collect();
+            query_log::record(q, logged_params, start.elapsed(), Some(changes));
+        }
+
         Ok(changes as usize)
     }
 
@@ -1,6 +1,6 @@
 use crate::{column_metadata::ColumnMetadata, error::SqlError};
-use bigdecimal::{BigDecimal, FromPrimitive};
-use chrono::{DateTime, NaiveDate, Utc};
+use bigdecimal::{BigDecimal, FromPrimitive, ToPrimitive};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 use connector_interface::{AggregationResult, AggregationSelection};
 use datamodel::FieldArity;
 use prisma_models::{PrismaValue, Record, TypeIdentifier};
@@ -29,6 +29,10 @@ impl SqlRow {
                     vec![AggregationResult::Field(field.clone(), values.pop().unwrap())]
                 }
 
+                AggregationSelection::Expression(key) => {
+                    vec![AggregationResult::Field(key.field().clone(), values.pop().unwrap())]
+                }
+
                 AggregationSelection::Count { all, fields } => {
                     let mut results: Vec<_> = fields
                         .iter()
@@ -77,6 +81,43 @@ impl SqlRow {
                         AggregationResult::Max(field.clone(), coerce_null_to_zero_value(values.pop().unwrap()))
                     })
                     .collect(),
+
+                AggregationSelection::CountDistinct(fields) => fields
+                    .iter()
+                    .map(|field| {
+                        AggregationResult::CountDistinct(
+                            field.clone(),
+                            coerce_null_to_zero_value(values.pop().unwrap()),
+                        )
+                    })
+                    .collect(),
+
+                // No values to concatenate is not the same as an empty string.
+                AggregationSelection::StringAgg(fields) => fields
+                    .iter()
+                    .map(|field| AggregationResult::StringAgg(field.clone(), values.pop().unwrap()))
+                    .collect(),
+
+                AggregationSelection::Median(fields) => fields
+                    .iter()
+                    .map(|field| {
+                        AggregationResult::Median(field.clone(), coerce_null_to_zero_value(values.pop().unwrap()))
+                    })
+                    .collect(),
+
+                AggregationSelection::StdDev(fields) => fields
+                    .iter()
+                    .map(|field| {
+                        AggregationResult::StdDev(field.clone(), coerce_null_to_zero_value(values.pop().unwrap()))
+                    })
+                    .collect(),
+
+                AggregationSelection::Variance(fields) => fields
+                    .iter()
+                    .map(|field| {
+                        AggregationResult::Variance(field.clone(), coerce_null_to_zero_value(values.pop().unwrap()))
+                    })
+                    .collect(),
             })
             .collect()
     }
@@ -111,33 +152,90 @@ impl ToSqlRow for ResultRow {
         row.values.reserve(row_width);
 
         for (i, p_value) in self.into_iter().enumerate().take(row_width) {
-            let pv = match (meta[i].identifier(), meta[i].arity()) {
-                (type_identifier, FieldArity::List) => match p_value {
-                    value if value.is_null() => Ok(PrismaValue::List(Vec::new())),
-                    Value::Array(None) => Ok(PrismaValue::List(Vec::new())),
-                    Value::Array(Some(l)) => l
-                        .into_iter()
-                        .map(|p_value| row_value_to_prisma_value(p_value, meta[i]))
-                        .collect::<crate::Result<Vec<_>>>()
-                        .map(PrismaValue::List),
-                    _ => {
-                        let error = io::Error::new(
-                            io::ErrorKind::InvalidData,
-                            format!("List field did not return an Array from database. Type identifier was {:?}. Value was {:?}.", &type_identifier, &p_value),
-                        );
-                        return Err(SqlError::ConversionError(error.into()));
-                    }
-                },
-                _ => row_value_to_prisma_value(p_value, meta[i]),
-            }?;
-
-            row.values.push(pv);
+            row.values.push(column_value_to_prisma_value(p_value, meta[i])?);
         }
 
         Ok(row)
     }
 }
 
+/// Converts a single column value, taking the arity of the column into account.
+pub fn column_value_to_prisma_value(p_value: Value, meta: ColumnMetadata<'_>) -> crate::Result<PrismaValue> {
+    match (meta.identifier(), meta.arity()) {
+        (type_identifier, FieldArity::List) => {
+            match p_value {
+                value if value.is_null() => Ok(PrismaValue::List(Vec::new())),
+                Value::Array(None) => Ok(PrismaValue::List(Vec::new())),
+                Value::Array(Some(l)) => l
+                    .into_iter()
+                    .map(|p_value| row_value_to_prisma_value(p_value, meta))
+                    .collect::<crate::Result<Vec<_>>>()
+                    .map(PrismaValue::List),
+                _ => {
+                    let error = io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("List field did not return an Array from database. Type identifier was {:?}. Value was {:?}.", &type_identifier, &p_value),
+                );
+                    Err(SqlError::ConversionError(error.into()))
+                }
+            }
+        }
+        _ => row_value_to_prisma_value(p_value, meta),
+    }
+}
+
+/// Converts a value read from a JSON document built by the database, e.g. an
+/// aggregated relation, into the column value the database would have returned.
+pub fn json_to_column_value(json: serde_json::Value, meta: ColumnMetadata<'_>) -> Value<'static> {
+    match (json, meta.arity()) {
+        (serde_json::Value::Array(items), FieldArity::List) => Value::Array(Some(
+            items.into_iter().map(|item| json_to_scalar_value(item, meta)).collect(),
+        )),
+        (json, _) => json_to_scalar_value(json, meta),
+    }
+}
+
+fn json_to_scalar_value(json: serde_json::Value, meta: ColumnMetadata<'_>) -> Value<'static> {
+    match (json, meta.identifier()) {
+        (serde_json::Value::Null, _) => Value::Integer(None),
+        (json, TypeIdentifier::Json) => Value::Json(Some(json)),
+        (serde_json::Value::Bool(b), _) => Value::Boolean(Some(b)),
+        (serde_json::Value::Number(n), TypeIdentifier::Float)
+        | (serde_json::Value::Number(n), TypeIdentifier::Decimal) => Value::Text(Some(n.to_string().into())),
+        (serde_json::Value::Number(n), _) => match n.as_i64() {
+            Some(i) => Value::Integer(Some(i)),
+            None if n.is_u64() => Value::Text(Some(n.to_string().into())),
+            None => Value::Double(n.as_f64()),
+        },
+        (serde_json::Value::String(s), TypeIdentifier::DateTime) => match parse_json_datetime(&s) {
+            Some(dt) => Value::DateTime(Some(dt)),
+            None => Value::Text(Some(s.into())),
+        },
+        (serde_json::Value::String(s), _) => Value::Text(Some(s.into())),
+        (json, _) => Value::Json(Some(json)),
+    }
+}
+
+/// Datetimes in JSON documents are rendered without a time zone by most
+/// databases. Those are always stored in UTC.
+fn parse_json_datetime(s: &str) -> Option<DateTime<Utc>> {
+    const FORMATS: &[&str] = &["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f"];
+
+    if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
+        return Some(dt.with_timezone(&Utc));
+    }
+
+    FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(s, format).ok())
+        .or_else(|| {
+            NaiveDate::parse_from_str(s, "%Y-%m-%d")
+                .ok()
+                .map(|d| d.and_hms(0, 0, 0))
+        })
+        .map(|naive| DateTime::from_utc(naive, Utc))
+}
+
 pub fn row_value_to_prisma_value(p_value: Value, meta: ColumnMetadata<'_>) -> Result<PrismaValue, SqlError> {
     let create_error = |value: &Value| {
         let message = match meta.name() {
@@ -216,35 +314,67 @@ pub fn row_value_to_prisma_value(p_value: Value, meta: ColumnMetadata<'_>) -> Re
             }
             _ => return Err(create_error(&p_value)),
         },
-        TypeIdentifier::Float | TypeIdentifier::Decimal => match p_value {
+        TypeIdentifier::Date => match p_value {
             value if value.is_null() => PrismaValue::Null,
-            Value::Numeric(Some(f)) => PrismaValue::Float(f.normalized()),
-            Value::Double(Some(f)) => match f {
-                f if f.is_nan() => return Err(create_error(&p_value)),
-                f if f.is_infinite() => return Err(create_error(&p_value)),
-                _ => PrismaValue::Float(BigDecimal::from_f64(f).unwrap().normalized()),
-            },
-            Value::Float(Some(f)) => match f {
-                f if f.is_nan() => return Err(create_error(&p_value)),
-                f if f.is_infinite() => return Err(create_error(&p_value)),
-                _ => PrismaValue::Float(BigDecimal::from_f32(f).unwrap().normalized()),
-            },
-            Value::Integer(Some(i)) => match BigDecimal::from_i64(i) {
-                Some(dec) => PrismaValue::Float(dec),
-                None => return Err(create_error(&p_value)),
-            },
-            Value::Text(_) | Value::Bytes(_) => {
-                let dec: BigDecimal = p_value
-                    .as_str()
-                    .expect("text/bytes as str")
-                    .parse()
-                    .map_err(|_| create_error(&p_value))?;
+            Value::Date(Some(d)) => PrismaValue::Date(d),
+            Value::DateTime(Some(dt)) => PrismaValue::Date(dt.naive_utc().date()),
+            Value::Text(Some(ref d_string)) => {
+                let d = NaiveDate::parse_from_str(d_string, "%Y-%m-%d")
+                    .ok()
+                    .or_else(|| parse_json_datetime(d_string).map(|dt| dt.naive_utc().date()))
+                    .ok_or_else(|| create_error(&p_value))?;
+
+                PrismaValue::Date(d)
+            }
+            _ => return Err(create_error(&p_value)),
+        },
+        TypeIdentifier::Time => match p_value {
+            value if value.is_null() => PrismaValue::Null,
+            Value::Time(Some(t)) => PrismaValue::Time(t),
+            Value::DateTime(Some(dt)) => PrismaValue::Time(dt.naive_utc().time()),
+            Value::Text(Some(ref t_string)) => {
+                let t = NaiveTime::parse_from_str(t_string, "%H:%M:%S%.f").map_err(|_| create_error(&p_value))?;
 
-                PrismaValue::Float(dec.normalized())
+                PrismaValue::Time(t)
             }
             _ => return Err(create_error(&p_value)),
         },
-        TypeIdentifier::Int | TypeIdentifier::BigInt => match p_value {
+        TypeIdentifier::Float | TypeIdentifier::Decimal => {
+            let decimal = match p_value {
+                value if value.is_null() => return Ok(PrismaValue::Null),
+                Value::Numeric(Some(f)) => f.normalized(),
+                Value::Double(Some(f)) => match f {
+                    f if f.is_nan() => return Err(create_error(&p_value)),
+                    f if f.is_infinite() => return Err(create_error(&p_value)),
+                    _ => BigDecimal::from_f64(f).unwrap().normalized(),
+                },
+                Value::Float(Some(f)) => match f {
+                    f if f.is_nan() => return Err(create_error(&p_value)),
+                    f if f.is_infinite() => return Err(create_error(&p_value)),
+                    _ => BigDecimal::from_f32(f).unwrap().normalized(),
+                },
+                Value::Integer(Some(i)) => match BigDecimal::from_i64(i) {
+                    Some(dec) => dec,
+                    None => return Err(create_error(&p_value)),
+                },
+                Value::Text(_) | Value::Bytes(_) => {
+                    let dec: BigDecimal = p_value
+                        .as_str()
+                        .expect("text/bytes as str")
+                        .parse()
+                        .map_err(|_| create_error(&p_value))?;
+
+                    dec.normalized()
+                }
+                _ => return Err(create_error(&p_value)),
+            };
+
+            match meta.identifier() {
+                TypeIdentifier::Decimal => PrismaValue::Decimal(decimal),
+                _ => PrismaValue::Float(decimal),
+            }
+        }
+        TypeIdentifier::Int => match p_value {
             Value::Integer(Some(i)) => PrismaValue::Int(i),
             Value::Bytes(Some(bytes)) => PrismaValue::Int(interpret_bytes_as_i64(&bytes)),
             Value::Text(Some(ref txt)) => {
@@ -252,6 +382,25 @@ pub fn row_value_to_prisma_value(p_value: Value, meta: ColumnMetadata<'_>) -> Re
             }
             other => PrismaValue::try_from(other)?,
         },
+        // Unsigned 64-bit columns hold values above `i64::MAX`, which the
+        // database returns as decimals or strings.
+        TypeIdentifier::BigInt => match p_value {
+            Value::Integer(Some(i)) => PrismaValue::Int(i),
+            Value::Bytes(Some(bytes)) => PrismaValue::Int(interpret_bytes_as_i64(&bytes)),
+            Value::Numeric(Some(ref decimal)) => match decimal.to_i64() {
+                Some(i) => PrismaValue::Int(i),
+                None => PrismaValue::UnsignedBigInt(decimal.to_u64().ok_or_else(|| create_error(&p_value))?),
+            },
+            Value::Text(Some(ref txt)) => {
+                let txt = txt.trim_start_matches('\0');
+
+                match i64::from_str(txt) {
+                    Ok(i) => PrismaValue::Int(i),
+                    Err(_) => PrismaValue::UnsignedBigInt(u64::from_str(txt).map_err(|_| create_error(&p_value))?),
+                }
+            }
+            other => PrismaValue::try_from(other)?,
+        },
         TypeIdentifier::String => match p_value {
             value if value.is_null() => PrismaValue::Null,
             Value::Uuid(Some(uuid)) => PrismaValue::String(uuid.to_string()),
@@ -22,7 +22,7 @@ pub struct SqlInfo {
 }
 
 impl SqlInfo {
-    fn sqlite() -> Self {
+    pub(crate) fn sqlite() -> Self {
         Self {
             family: SqlFamily::SQLite,
             max_rows: Some(999),
@@ -30,7 +30,7 @@ impl SqlInfo {
         }
     }
 
-    fn mysql() -> Self {
+    pub(crate) fn mysql() -> Self {
         Self {
             family: SqlFamily::MySQL,
             max_rows: None,
@@ -38,7 +38,7 @@ impl SqlInfo {
         }
     }
 
-    fn postgres() -> Self {
+    pub(crate) fn postgres() -> Self {
         Self {
             family: SqlFamily::Postgres,
             max_rows: None,
@@ -46,7 +46,7 @@ impl SqlInfo {
         }
     }
 
-    fn mssql() -> Self {
+    pub(crate) fn mssql() -> Self {
         Self {
             family: SqlFamily::MSSQL,
             max_rows: Some(1000),
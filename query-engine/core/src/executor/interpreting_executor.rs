@@ -1,7 +1,7 @@
 use super::{pipeline::QueryPipeline, QueryExecutor};
 use crate::{Operation, QueryGraphBuilder, QueryInterpreter, QuerySchemaRef, ResponseData};
 use async_trait::async_trait;
-use connector::{Connection, ConnectionLike, Connector};
+use connector::{query_log, Connection, ConnectionLike, Connector};
 use futures::future;
 
 /// Central query executor and main entry point into the query core.
@@ -25,6 +25,20 @@ where
         }
     }
 
+    /// Checks out a connection suitable for the given operation. Read operations that don't
+    /// run inside a transaction may be served by a read connection (e.g. a replica),
+    /// unless the caller explicitly asks for the primary (e.g. to read its own writes).
+    async fn connection_for(&self, operation: &Operation, force_primary: bool) -> crate::Result<Box<dyn Connection>> {
+        let conn = match operation {
+            Operation::Read(_) if !force_primary && !self.force_transactions => {
+                self.connector.get_read_connection().await?
+            }
+            _ => self.connector.get_connection().await?,
+        };
+
+        Ok(conn)
+    }
+
     /// Async wrapper for executing an individual operation to allow code sharing with `execute_batch`.
     #[tracing::instrument(skip(operation, conn, force_transactions, query_schema))]
     async fn execute_single_operation(
@@ -33,26 +47,31 @@ where
         force_transactions: bool,
         query_schema: QuerySchemaRef,
     ) -> crate::Result<ResponseData> {
-        // Parse, validate, and extract query graph from query document.
-        let (query_graph, serializer) = QueryGraphBuilder::new(query_schema).build(operation)?;
-        let is_transactional = force_transactions || query_graph.needs_transaction();
+        let operation_name = operation.name().to_owned();
 
-        if is_transactional {
-            let tx = conn.start_transaction().await?;
-            let interpreter = QueryInterpreter::new(ConnectionLike::Transaction(tx.as_ref()));
-            let result = QueryPipeline::new(query_graph, interpreter, serializer).execute().await;
+        query_log::with_operation(&operation_name, async move {
+            // Parse, validate, and extract query graph from query document.
+            let (query_graph, serializer) = QueryGraphBuilder::new(query_schema).build(operation)?;
+            let is_transactional = force_transactions || query_graph.needs_transaction();
 
-            if result.is_ok() {
-                tx.commit().await?;
+            if is_transactional {
+                let tx = conn.start_transaction().await?;
+                let interpreter = QueryInterpreter::new(ConnectionLike::Transaction(tx.as_ref()));
+                let result = QueryPipeline::new(query_graph, interpreter, serializer).execute().await;
+
+                if result.is_ok() {
+                    tx.commit().await?;
+                } else {
+                    tx.rollback().await?;
+                }
+
+                result
             } else {
-                tx.rollback().await?;
+                let interpreter = QueryInterpreter::new(ConnectionLike::Connection(conn.as_ref()));
+                QueryPipeline::new(query_graph, interpreter, serializer).execute().await
             }
-
-            result
-        } else {
-            let interpreter = QueryInterpreter::new(ConnectionLike::Connection(conn.as_ref()));
-            QueryPipeline::new(query_graph, interpreter, serializer).execute().await
-        }
+        })
+        .await
     }
 }
 
@@ -76,21 +95,28 @@ where
         &self,
         operations: Vec<Operation>,
         transactional: bool,
+        force_primary: bool,
         query_schema: QuerySchemaRef,
     ) -> crate::Result<Vec<crate::Result<ResponseData>>> {
         if transactional {
             let queries = operations
                 .into_iter()
-                .map(|op| QueryGraphBuilder::new(query_schema.clone()).build(op))
+                .map(|op| {
+                    let name = op.name().to_owned();
+                    QueryGraphBuilder::new(query_schema.clone())
+                        .build(op)
+                        .map(|(query, info)| (name, query, info))
+                })
                 .collect::<std::result::Result<Vec<_>, _>>()?;
 
             let conn = self.connector.get_connection().await?;
             let tx = conn.start_transaction().await?;
             let mut results = Vec::with_capacity(queries.len());
 
-            for (query, info) in queries {
+            for (name, query, info) in queries {
                 let interpreter = QueryInterpreter::new(ConnectionLike::Transaction(tx.as_ref()));
-                let result = QueryPipeline::new(query, interpreter, info).execute().await;
+                let pipeline = QueryPipeline::new(query, interpreter, info);
+                let result = query_log::with_operation(&name, pipeline.execute()).await;
 
                 if result.is_err() {
                     tx.rollback().await?;
@@ -105,13 +131,13 @@ where
             let mut futures = Vec::with_capacity(operations.len());
 
             for operation in operations {
-                let conn = self.connector.get_connection().await?;
-                futures.push(tokio::spawn(Self::execute_single_operation(
+                let conn = self.connection_for(&operation, force_primary).await?;
+                futures.push(tokio::spawn(query_log::propagate(Self::execute_single_operation(
                     operation,
                     conn,
                     self.force_transactions,
                     query_schema.clone(),
-                )));
+                ))));
             }
 
             let responses: Vec<_> = future::join_all(futures)
@@ -125,8 +151,14 @@ where
     }
 
     /// Executes a single operation. Execution will be inside of a transaction or not depending on the needs of the query.
-    async fn execute(&self, operation: Operation, query_schema: QuerySchemaRef) -> crate::Result<ResponseData> {
-        let conn = self.connector.get_connection().await?;
+    /// Read operations outside of a transaction may run on a read replica, unless `force_primary` is set.
+    async fn execute(
+        &self,
+        operation: Operation,
+        force_primary: bool,
+        query_schema: QuerySchemaRef,
+    ) -> crate::Result<ResponseData> {
+        let conn = self.connection_for(&operation, force_primary).await?;
         Self::execute_single_operation(operation, conn, self.force_transactions, query_schema.clone()).await
     }
 
@@ -17,13 +17,20 @@ use connector::Connector;
 #[async_trait]
 pub trait QueryExecutor {
     /// Executes a single operation and returns its result.
-    async fn execute(&self, operation: Operation, query_schema: QuerySchemaRef) -> crate::Result<ResponseData>;
+    /// `force_primary` prevents reads from being routed away from the primary database.
+    async fn execute(
+        &self,
+        operation: Operation,
+        force_primary: bool,
+        query_schema: QuerySchemaRef,
+    ) -> crate::Result<ResponseData>;
 
     // Executes a batch of operations as either a fanout of individual operations (non-transactional), or in series (transactional).
     async fn execute_batch(
         &self,
         operations: Vec<Operation>,
         transactional: bool,
+        force_primary: bool,
         query_schema: QuerySchemaRef,
     ) -> crate::Result<Vec<crate::Result<ResponseData>>>;
 
@@ -1,9 +1,14 @@
 use super::*;
-use crate::{interpreter::InterpretationResult, query_ast::*, result_ast::*};
-use connector::{self, ConnectionLike, QueryArguments, ReadOperations};
+use crate::{
+    interpreter::{InterpretationResult, InterpreterError},
+    query_ast::*,
+    result_ast::*,
+    QueryGraphBuilderError,
+};
+use connector::{self, ConnectionLike, QueryArguments, ReadOperations, RecordsWithRelations, RelationLoadStrategy};
 use futures::future::{BoxFuture, FutureExt};
 use inmemory_record_processor::InMemoryRecordProcessor;
-use prisma_models::ManyRecords;
+use prisma_models::{ManyRecords, ModelProjection, ModelRef};
 
 pub fn execute<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
@@ -31,6 +36,32 @@ fn read_one<'conn, 'tx>(
         let model = query.model;
         let model_id = model.primary_identifier();
         let filter = query.filter.expect("Expected filter to be set for ReadOne query.");
+
+        let mut args = QueryArguments::from((model.clone(), filter.clone()));
+        args.take = Some(1);
+
+        let joined = read_with_relations(
+            tx,
+            &model,
+            args,
+            &query.selected_fields,
+            &query.nested,
+            query.relation_load_strategy,
+        )
+        .await?;
+
+        if let Some(result) = joined {
+            return Ok(RecordSelection {
+                name: query.name,
+                fields: query.selection_order,
+                scalars: result.records,
+                nested: related_selections(query.nested, result.nested),
+                model_id,
+                query_arguments: QueryArguments::new(model),
+            }
+            .into());
+        }
+
         let scalars = tx.get_single_record(&model, &filter, &query.selected_fields).await?;
 
         match scalars {
@@ -64,17 +95,44 @@ fn read_one<'conn, 'tx>(
 }
 
 /// Queries a set of records.
-/// If the query specifies distinct, we need to lift up pagination (and distinct) processing to the core with in-memory record processing.
-/// -> Distinct can't be processed in the DB with our current query API model.
-///    We need to select IDs / uniques alongside the distincts, which doesn't work in SQL, as all records
-///    are distinct by definition if a unique is in the selection set.
+/// Some queries require pagination (and distinct) processing to be lifted up to the core, in memory:
+/// -> Distinct is applied in the DB if the connector supports it. Otherwise, all records are fetched and
+///    distinct is applied in memory.
 /// -> Unstable cursors can't reliably be fetched by the underlying datasource, so we need to process part of it in-memory.
 fn read_many<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
     mut query: ManyRecordsQuery,
 ) -> BoxFuture<'a, InterpretationResult<QueryResult>> {
     let fut = async move {
-        let scalars = if query.args.requires_inmemory_processing() {
+        let model_id = query.model.primary_identifier();
+        let inmemory =
+            query.args.requires_inmemory_processing() || (query.args.distinct.is_some() && !tx.supports_distinct());
+
+        if !inmemory {
+            let joined = read_with_relations(
+                tx,
+                &query.model,
+                query.args.clone(),
+                &query.selected_fields,
+                &query.nested,
+                query.relation_load_strategy,
+            )
+            .await?;
+
+            if let Some(result) = joined {
+                return Ok(RecordSelection {
+                    name: query.name,
+                    fields: query.selection_order,
+                    query_arguments: query.args,
+                    model_id,
+                    scalars: result.records,
+                    nested: related_selections(query.nested, result.nested),
+                }
+                .into());
+            }
+        }
+
+        let scalars = if inmemory {
             let processor = InMemoryRecordProcessor::new_from_query_args(&mut query.args);
             let scalars = tx
                 .get_many_records(&query.model, query.args.clone(), &query.selected_fields)
@@ -86,7 +144,6 @@ fn read_many<'a, 'b>(
                 .await?
         };
 
-        let model_id = query.model.primary_identifier();
         let nested: Vec<QueryResult> = process_nested(tx, query.nested, Some(&scalars)).await?;
 
         Ok(RecordSelection {
@@ -147,10 +204,66 @@ fn read_related<'a, 'b>(
     fut.boxed()
 }
 
+/// Tries to load records together with all of their nested relations in a single
+/// roundtrip. Returns `None` if the nested reads have to be resolved with separate
+/// queries instead, either because the connector doesn't support loading them or
+/// because they require in-memory processing.
+async fn read_with_relations<'a, 'b>(
+    tx: &'a ConnectionLike<'a, 'b>,
+    model: &ModelRef,
+    args: QueryArguments,
+    selected_fields: &ModelProjection,
+    nested: &[ReadQuery],
+    strategy: Option<RelationLoadStrategy>,
+) -> InterpretationResult<Option<RecordsWithRelations>> {
+    if nested.is_empty() || strategy == Some(RelationLoadStrategy::Query) {
+        return Ok(None);
+    }
+
+    let relations = match ReadQuery::relation_loads(nested) {
+        Some(relations) => relations,
+        None => return Ok(None),
+    };
+
+    Ok(tx
+        .get_many_records_with_relations(model, args, selected_fields, &relations, strategy)
+        .await?)
+}
+
+/// Builds the results of the nested reads from the records loaded by the connector.
+fn related_selections(nested: Vec<ReadQuery>, results: Vec<RecordsWithRelations>) -> Vec<QueryResult> {
+    nested
+        .into_iter()
+        .zip(results)
+        .map(|(query, result)| match query {
+            ReadQuery::RelatedRecordsQuery(q) => RecordSelection {
+                name: q.name,
+                fields: q.selection_order,
+                model_id: q.parent_field.related_model().primary_identifier(),
+                scalars: result.records,
+                nested: related_selections(q.nested, result.nested),
+                query_arguments: q.args,
+            }
+            .into(),
+            _ => unreachable!(),
+        })
+        .collect()
+}
+
 async fn aggregate<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
     query: AggregateRecordsQuery,
 ) -> InterpretationResult<QueryResult> {
+    // There is no in-memory aggregator to fall back to when the connector can't apply distinct.
+    if query.args.distinct.is_some() && !tx.supports_distinct() {
+        return Err(InterpreterError::QueryGraphBuilderError(
+            QueryGraphBuilderError::InputError(
+                "Unable to process distinct for aggregation query, the database does not support window functions."
+                    .to_owned(),
+            ),
+        ));
+    }
+
     let selection_order = query.selection_order;
 
     let results = tx
@@ -163,6 +276,27 @@ async fn aggregate<'a, 'b>(
     }))
 }
 
+/// Builds the selection of the records a many-records write returned,
+/// resolving its nested reads.
+pub async fn returned_records<'a, 'b>(
+    tx: &'a ConnectionLike<'a, 'b>,
+    model: &ModelRef,
+    scalars: ManyRecords,
+    returned: ReturnedRecords,
+) -> InterpretationResult<QueryResult> {
+    let nested: Vec<QueryResult> = process_nested(tx, returned.nested, Some(&scalars)).await?;
+
+    Ok(RecordSelection {
+        name: returned.name,
+        fields: returned.selection_order,
+        query_arguments: QueryArguments::new(model.clone()),
+        model_id: model.primary_identifier(),
+        scalars,
+        nested,
+    }
+    .into())
+}
+
 fn process_nested<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
     nested: Vec<ReadQuery>,
@@ -1,9 +1,11 @@
+use super::read;
 use crate::{
     interpreter::{InterpretationResult, InterpreterError},
     query_ast::*,
     QueryResult,
 };
-use connector::{ConnectionLike, WriteOperations};
+use connector::{ConnectionLike, IdFilter, RecordFilter, WriteArgs, WriteOperations};
+use prisma_models::ModelRef;
 use prisma_value::PrismaValue;
 
 pub async fn execute<'a, 'b>(
@@ -54,6 +56,14 @@ async fn create_many<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
     q: CreateManyRecords,
 ) -> InterpretationResult<QueryResult> {
+    if let Some(returned) = q.returning {
+        let records = tx
+            .create_records_returning(&q.model, q.args, q.skip_duplicates, &returned.selected_fields)
+            .await?;
+
+        return read::returned_records(tx, &q.model, records, returned).await;
+    }
+
     let affected_records = tx.create_records(&q.model, q.args, q.skip_duplicates).await?;
 
     Ok(QueryResult::Count(affected_records))
@@ -75,6 +85,13 @@ async fn delete_one<'a, 'b>(tx: &'a ConnectionLike<'a, 'b>, q: DeleteRecord) ->
         )),
     }?;
 
+    if let Some(args) = WriteArgs::soft_delete(&q.model) {
+        let res = tx
+            .update_records(&q.model, not_soft_deleted(&q.model, filter), args)
+            .await?;
+        return Ok(QueryResult::Count(res.len()));
+    }
+
     let res = tx.delete_records(&q.model, filter).await?;
 
     Ok(QueryResult::Count(res))
@@ -84,6 +101,14 @@ async fn update_many<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
     q: UpdateManyRecords,
 ) -> InterpretationResult<QueryResult> {
+    if let Some(returned) = q.returning {
+        let records = tx
+            .update_records_returning(&q.model, q.record_filter, q.args, &returned.selected_fields)
+            .await?;
+
+        return read::returned_records(tx, &q.model, records, returned).await;
+    }
+
     let res = tx.update_records(&q.model, q.record_filter, q.args).await?;
 
     Ok(QueryResult::Count(res.len()))
@@ -93,11 +118,47 @@ async fn delete_many<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
     q: DeleteManyRecords,
 ) -> InterpretationResult<QueryResult> {
+    // Soft deleting records sets their `@@softDelete` field instead of removing them.
+    if let Some(args) = WriteArgs::soft_delete(&q.model) {
+        let record_filter = not_soft_deleted(&q.model, q.record_filter);
+
+        if let Some(returned) = q.returning {
+            let records = tx
+                .update_records_returning(&q.model, record_filter, args, &returned.selected_fields)
+                .await?;
+
+            return read::returned_records(tx, &q.model, records, returned).await;
+        }
+
+        let res = tx.update_records(&q.model, record_filter, args).await?;
+
+        return Ok(QueryResult::Count(res.len()));
+    }
+
+    if let Some(returned) = q.returning {
+        let records = tx
+            .delete_records_returning(&q.model, q.record_filter, &returned.selected_fields)
+            .await?;
+
+        return read::returned_records(tx, &q.model, records, returned).await;
+    }
+
     let res = tx.delete_records(&q.model, q.record_filter).await?;
 
     Ok(QueryResult::Count(res))
 }
 
+/// Excludes records that are already soft deleted, so their deletion time is kept.
+/// Selectors are applied without the filter, they are turned into one.
+fn not_soft_deleted(model: &ModelRef, record_filter: RecordFilter) -> RecordFilter {
+    let filter = match record_filter.selectors {
+        Some(selectors) => selectors.filter(),
+        None => record_filter.filter,
+    };
+
+    filter.without_soft_deleted(model).into()
+}
+
 async fn connect<'a, 'b>(tx: &'a ConnectionLike<'a, 'b>, q: ConnectRecords) -> InterpretationResult<QueryResult> {
     tx.connect(
         &q.relation_field,
@@ -1,6 +1,8 @@
 //! Prisma read query AST
 use super::FilteredQuery;
-use connector::{filter::Filter, AggregationSelection, QueryArguments};
+use connector::{
+    filter::Filter, AggregationSelection, GroupingKey, QueryArguments, RelationLoad, RelationLoadStrategy,
+};
 use prisma_models::prelude::*;
 use std::fmt::Display;
 
@@ -41,6 +43,28 @@ impl ReadQuery {
             ReadQuery::AggregateRecordsQuery(x) => x.model.clone(),
         }
     }
+
+    /// Converts nested reads into relation loads for the connector.
+    /// Cursors and distinct selections are processed in memory, which is only possible
+    /// when every relation level is loaded separately.
+    pub fn relation_loads(nested: &[ReadQuery]) -> Option<Vec<RelationLoad>> {
+        nested
+            .iter()
+            .map(|query| match query {
+                ReadQuery::RelatedRecordsQuery(q)
+                    if q.parent_projections.is_none() && q.args.cursor.is_none() && q.args.distinct.is_none() =>
+                {
+                    Some(RelationLoad {
+                        parent_field: q.parent_field.clone(),
+                        args: q.args.clone(),
+                        selected_fields: q.selected_fields.clone(),
+                        nested: Self::relation_loads(&q.nested)?,
+                    })
+                }
+                _ => None,
+            })
+            .collect()
+    }
 }
 
 impl FilteredQuery for ReadQuery {
@@ -101,6 +125,9 @@ pub struct RecordQuery {
     pub selected_fields: ModelProjection,
     pub nested: Vec<ReadQuery>,
     pub selection_order: Vec<String>,
+
+    /// Overrides the default strategy of the connector for loading `nested`.
+    pub relation_load_strategy: Option<RelationLoadStrategy>,
 }
 
 #[derive(Debug, Clone)]
@@ -112,6 +139,9 @@ pub struct ManyRecordsQuery {
     pub selected_fields: ModelProjection,
     pub nested: Vec<ReadQuery>,
     pub selection_order: Vec<String>,
+
+    /// Overrides the default strategy of the connector for loading `nested`.
+    pub relation_load_strategy: Option<RelationLoadStrategy>,
 }
 
 #[derive(Debug, Clone)]
@@ -137,7 +167,7 @@ pub struct AggregateRecordsQuery {
     pub selection_order: Vec<(String, Option<Vec<String>>)>,
     pub args: QueryArguments,
     pub selectors: Vec<AggregationSelection>,
-    pub group_by: Vec<ScalarFieldRef>,
+    pub group_by: Vec<GroupingKey>,
     pub having: Option<Filter>,
 }
 
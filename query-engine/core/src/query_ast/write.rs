@@ -1,5 +1,5 @@
 //! Write query AST
-use super::FilteredQuery;
+use super::{FilteredQuery, ReadQuery};
 use connector::{filter::Filter, DatasourceFieldName, RecordFilter, WriteArgs};
 use prisma_models::prelude::*;
 use std::sync::Arc;
@@ -129,6 +129,7 @@ pub struct CreateManyRecords {
     pub model: ModelRef,
     pub args: Vec<WriteArgs>,
     pub skip_duplicates: bool,
+    pub returning: Option<ReturnedRecords>,
 }
 
 impl CreateManyRecords {
@@ -158,6 +159,7 @@ pub struct UpdateManyRecords {
     pub model: ModelRef,
     pub record_filter: RecordFilter,
     pub args: WriteArgs,
+    pub returning: Option<ReturnedRecords>,
 }
 
 #[derive(Debug, Clone)]
@@ -170,6 +172,17 @@ pub struct DeleteRecord {
 pub struct DeleteManyRecords {
     pub model: ModelRef,
     pub record_filter: RecordFilter,
+    pub returning: Option<ReturnedRecords>,
+}
+
+/// The selection of the records written by a many-records write, if the
+/// operation returns them instead of a count (e.g. `updateManyUserAndReturn`).
+#[derive(Debug, Clone)]
+pub struct ReturnedRecords {
+    pub name: String,
+    pub selected_fields: ModelProjection,
+    pub nested: Vec<ReadQuery>,
+    pub selection_order: Vec<String>,
 }
 
 #[derive(Debug, Clone)]
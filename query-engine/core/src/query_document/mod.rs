@@ -30,7 +30,13 @@ pub use query_value::*;
 pub use selection::*;
 pub use transformers::*;
 
-use crate::constants::inputs::args;
+use crate::{
+    constants::inputs::args,
+    resolve_compound_field,
+    schema::{QuerySchemaRef, QueryTag},
+};
+use indexmap::IndexMap;
+use prisma_models::ModelRef;
 
 pub type QueryParserResult<T> = std::result::Result<T, QueryParserError>;
 
@@ -60,54 +66,193 @@ impl BatchDocument {
         Self::Multi(operations, transactional)
     }
 
-    fn can_compact(&self) -> bool {
+    /// Returns the name of the `findMany` query of the model and the unique
+    /// criterion shared by all operations, if they can be compacted into one
+    /// `findMany`.
+    fn compaction_target(operations: &[Operation], schema: &QuerySchemaRef) -> Option<(String, UniqueCriterion)> {
+        let (first, rest) = operations.split_first()?;
+        let model = find_unique_model(first, schema)?;
+        let criterion = UniqueCriterion::of(first, &model)?;
+        let plural_name = find_many_name(&model, schema)?;
+
+        let compatible = rest
+            .iter()
+            .all(|op| first.selects_same(op) && UniqueCriterion::of(op, &model).as_ref() == Some(&criterion));
+
+        if compatible {
+            Some((plural_name, criterion))
+        } else {
+            None
+        }
+    }
+
+    /// Compacts a batch of `findOne` operations sent by the client.
+    pub fn compact(self, schema: &QuerySchemaRef) -> Self {
         match self {
-            Self::Multi(operations, _) => match operations.split_first() {
-                Some((first, rest)) if first.is_find_one() => rest.iter().all(|op| {
-                    op.is_find_one()
-                        && first.name() == op.name()
-                        && first.nested_selections().len() == op.nested_selections().len()
-                        && first
-                            .nested_selections()
-                            .iter()
-                            .all(|fop| op.nested_selections().contains(fop))
-                }),
-                _ => false,
-            },
-            Self::Compact(_) => false,
+            Self::Multi(ref operations, _) if operations.iter().all(Operation::is_find_one) => {
+                self.compact_unique_lookups(schema)
+            }
+            _ => self,
         }
     }
 
-    pub fn compact(self) -> Self {
+    /// Compacts a batch of `findOne` and `findUnique` operations.
+    pub fn compact_unique_lookups(self, schema: &QuerySchemaRef) -> Self {
         match self {
-            Self::Multi(operations, _) if self.can_compact() => Self::Compact(CompactedDocument::from(operations)),
+            Self::Multi(operations, transactional) => match Self::compaction_target(&operations, schema) {
+                Some((plural_name, criterion)) => {
+                    Self::Compact(CompactedDocument::new(operations, plural_name, criterion))
+                }
+                None => Self::Multi(operations, transactional),
+            },
             _ => self,
         }
     }
 }
 
+/// Returns the model of a `findOne` / `findUnique` operation.
+fn find_unique_model(operation: &Operation, schema: &QuerySchemaRef) -> Option<ModelRef> {
+    let read = match operation {
+        Operation::Read(selection) => selection,
+        Operation::Write(_) => return None,
+    };
+
+    let field = schema.find_query_field(read.name())?;
+    let info = field.query_info.as_ref()?;
+
+    match info.tag {
+        QueryTag::FindOne | QueryTag::FindUnique => info.model.clone(),
+        _ => None,
+    }
+}
+
+/// Returns the name of the `findMany` query of the model, as exposed by the
+/// query schema.
+fn find_many_name(model: &ModelRef, schema: &QuerySchemaRef) -> Option<String> {
+    schema.query().get_fields().iter().find_map(|field| {
+        let info = field.query_info.as_ref()?;
+        let model_matches = info.model.as_ref().map(|m| m.name == model.name).unwrap_or(false);
+
+        if info.tag == QueryTag::FindMany && model_matches {
+            Some(field.name.clone())
+        } else {
+            None
+        }
+    })
+}
+
+/// The unique criterion a `findUnique` selects its record by: either a single
+/// unique field, or a compound id or unique index of the model.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UniqueCriterion {
+    /// The name of the criterion in the `where` argument.
+    pub name: String,
+
+    /// The scalar fields of the criterion, in the order of their definition.
+    pub fields: Vec<String>,
+}
+
+impl UniqueCriterion {
+    /// Resolves the criterion of a `findOne` / `findUnique` operation.
+    pub fn for_operation(operation: &Operation, schema: &QuerySchemaRef) -> Option<Self> {
+        find_unique_model(operation, schema).and_then(|model| Self::of(operation, &model))
+    }
+
+    /// Resolves the criterion of the `where` argument of a `findUnique`
+    /// operation against the unique fields and indexes of the model. Returns
+    /// `None` if the operation has any other argument.
+    pub fn of(operation: &Operation, model: &ModelRef) -> Option<Self> {
+        match operation.arguments() {
+            [(name, QueryValue::Object(filter))] if name == args::WHERE && filter.len() == 1 => {
+                let (name, value) = filter.iter().next()?;
+                let criterion = Self::resolve(name, model)?;
+
+                // Compound criteria are given as an object holding all of their fields.
+                let complete = match value {
+                    QueryValue::Object(obj) if criterion.is_compound() => {
+                        criterion.fields.iter().all(|field| obj.contains_key(field))
+                    }
+                    QueryValue::Object(_) => false,
+                    _ => !criterion.is_compound(),
+                };
+
+                if complete {
+                    Some(criterion)
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn resolve(name: &str, model: &ModelRef) -> Option<Self> {
+        let fields = match model.fields().find_from_scalar(name) {
+            Ok(field) if field.unique() => vec![field.name.clone()],
+            Ok(_) => return None,
+            Err(_) => resolve_compound_field(name, model)?
+                .into_iter()
+                .map(|field| field.name.clone())
+                .collect(),
+        };
+
+        Some(Self {
+            name: name.to_owned(),
+            fields,
+        })
+    }
+
+    pub fn is_compound(&self) -> bool {
+        self.fields.len() > 1 || self.fields.first() != Some(&self.name)
+    }
+
+    /// Extracts the values of the criterion fields from the `where` argument
+    /// of an operation matching this criterion.
+    fn values(&self, filter: IndexMap<String, QueryValue>) -> Vec<(String, QueryValue)> {
+        let value = filter
+            .into_iter()
+            .next()
+            .map(|(_, value)| value)
+            .expect("Trying to compact a selection without a unique criterion.");
+
+        match value {
+            QueryValue::Object(mut obj) if self.is_compound() => self
+                .fields
+                .iter()
+                .map(|field| {
+                    let value = obj
+                        .remove(field)
+                        .expect("Missing field of a compound unique criterion.");
+                    (field.clone(), value)
+                })
+                .collect(),
+            value => vec![(self.name.clone(), value)],
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct CompactedDocument {
     pub arguments: Vec<Vec<(String, QueryValue)>>,
     pub nested_selection: Vec<String>,
     pub operation: Operation,
     pub keys: Vec<String>,
-    name: String,
+    single_name: String,
+    plural_name: String,
 }
 
 impl CompactedDocument {
     pub fn single_name(&self) -> String {
-        format!("findOne{}", self.name)
+        self.single_name.clone()
     }
 
     pub fn plural_name(&self) -> String {
-        format!("findMany{}", self.name)
+        self.plural_name.clone()
     }
-}
 
-/// Here be the dragons. Ay caramba!
-impl From<Vec<Operation>> for CompactedDocument {
-    fn from(ops: Vec<Operation>) -> Self {
+    /// Merges `findOne` / `findUnique` operations selecting by the same unique
+    /// `criterion` into one `plural_name` query with an `IN` filter.
+    pub fn new(ops: Vec<Operation>, plural_name: String, criterion: UniqueCriterion) -> Self {
         // Unpack all read queries (an enum) into a collection of selections.
         // We already took care earlier that all operations here must be reads.
         let selections: Vec<Selection> = ops
@@ -115,44 +260,43 @@ impl From<Vec<Operation>> for CompactedDocument {
             .map(|op| op.into_read().expect("Trying to compact a write operation."))
             .collect();
 
+        let single_name = selections[0].name().to_owned();
+
+        // Convert the selections into a vector of criterion values. This
+        // defines the response order and how we fetch the right data from the
+        // response set.
+        let arguments: Vec<Vec<(String, QueryValue)>> = selections
+            .iter()
+            .map(|selection| {
+                let filter = selection.arguments()[0]
+                    .1
+                    .clone()
+                    .into_object()
+                    .expect("Trying to compact a selection with non-object argument");
+
+                criterion.values(filter)
+            })
+            .collect();
+
         // This block creates the findMany query from the separate findOne queries.
         let selection = {
-            // The name of the query should be findManyX if the first query
-            // here is findOneX. We took care earlier the queries are all the
-            // same. Otherwise we fail hard here.
-            let mut builder = Selection::builder(selections[0].name().replacen("findOne", "findMany", 1));
+            let mut builder = Selection::builder(plural_name.clone());
 
             // Take the nested selection set from the first query. We took care
             // earlier that all the nested selections are the same in every
-            // query. Otherwise we fail hard here.
+            // query.
             builder.nested_selections(selections[0].nested_selections().to_vec());
 
-            // The query arguments are extracted here. Combine all query
-            // arguments from the different queries into a one large argument.
-            let selection_set = selections.iter().fold(SelectionSet::new(), |acc, selection| {
-                // findOne always has only one argument. We know it must be an
-                // object, otherwise this will panic.
-                let obj = selection.arguments()[0]
-                    .1
-                    .clone()
-                    .into_object()
-                    .expect("Trying to compact a selection with non-object argument");
-
-                // A "funny" trick to detect a compound key.
-                match obj.values().next() {
-                    // This means our query has a nested object, meaning we have
-                    // a compound filter in a form of {"col1_col2": {"col1": .., "col2": ..}}
-                    Some(QueryValue::Object(obj)) => obj
-                        .iter()
-                        .fold(acc, |acc, (key, val)| acc.push(key.clone(), val.clone())),
-                    // ...or a singular argument in a form of {"col1": ..}
-                    _ => obj.into_iter().fold(acc, |acc, (key, val)| acc.push(key, val)),
-                }
+            // Combine the criterion values of all queries into one argument.
+            let selection_set = arguments.iter().fold(SelectionSet::new(), |acc, values| {
+                values
+                    .iter()
+                    .fold(acc, |acc, (key, val)| acc.push(key.clone(), val.clone()))
             });
 
             // We must select all unique fields in the query so we can
             // match the right response back to the right request later on.
-            for key in selection_set.keys() {
+            for key in criterion.fields.iter() {
                 if !builder.contains_nested_selection(key) {
                     builder.push_nested_selection(Selection::builder(key).build());
                 }
@@ -178,47 +322,12 @@ impl From<Vec<Operation>> for CompactedDocument {
             .map(|s| s.name().to_string())
             .collect();
 
-        // Saving the stub of the query name for later use.
-        let name = selections[0].name().replacen("findOne", "", 1);
-
-        // Convert the selections into a vector of arguments. This defines the
-        // response order and how we fetch the right data from the response set.
-        let arguments: Vec<Vec<(String, QueryValue)>> = selections
-            .into_iter()
-            .map(|mut sel| {
-                let mut obj: Vec<(String, QueryValue)> = sel
-                    .pop_argument()
-                    .unwrap()
-                    .1
-                    .into_object()
-                    .unwrap()
-                    .into_iter()
-                    .collect();
-
-                // The trick again to detect if we have a compound key or not. (sigh)
-                match obj.pop() {
-                    Some((_, QueryValue::Object(obj))) => obj.into_iter().collect(),
-                    Some(pair) => {
-                        obj.push(pair);
-                        obj
-                    }
-                    None => unreachable!("No arguments!"),
-                }
-            })
-            .collect();
-
-        // The trick again to detect if we have a compound key or not. (sigh)
-        // Gets the argument keys for later mapping.
-        let keys = match arguments[0].get(0) {
-            Some((_, QueryValue::Object(obj))) => obj.iter().map(|(k, _)| k.to_string()).collect(),
-            _ => arguments[0].iter().map(|(k, _)| k.to_string()).collect(),
-        };
-
         Self {
-            name,
+            single_name,
+            plural_name,
             arguments,
             nested_selection,
-            keys,
+            keys: criterion.fields,
             operation: Operation::Read(selection),
         }
     }
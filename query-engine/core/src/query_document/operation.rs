@@ -1,4 +1,4 @@
-use super::Selection;
+use super::{QueryValue, Selection};
 
 #[derive(Debug, Clone)]
 pub enum Operation {
@@ -50,4 +50,22 @@ impl Operation {
             Self::Write(s) => s.nested_selections(),
         }
     }
+
+    /// Whether both operations run the same query with the same nested
+    /// selections, regardless of their arguments.
+    pub fn selects_same(&self, other: &Self) -> bool {
+        self.name() == other.name()
+            && self.nested_selections().len() == other.nested_selections().len()
+            && self
+                .nested_selections()
+                .iter()
+                .all(|selection| other.nested_selections().contains(selection))
+    }
+
+    pub fn arguments(&self) -> &[(String, QueryValue)] {
+        match self {
+            Self::Read(s) => s.arguments(),
+            Self::Write(s) => s.arguments(),
+        }
+    }
 }
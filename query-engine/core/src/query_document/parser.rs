@@ -236,15 +236,21 @@ impl QueryDocumentParser {
             (QueryValue::String(s), ScalarType::DateTime) => {
                 Self::parse_datetime(parent_path, s.as_str()).map(PrismaValue::DateTime)
             }
+            (QueryValue::String(s), ScalarType::Date) => {
+                Self::parse_date(parent_path, s.as_str()).map(PrismaValue::Date)
+            }
+            (QueryValue::String(s), ScalarType::Time) => {
+                Self::parse_time(parent_path, s.as_str()).map(PrismaValue::Time)
+            }
 
             (QueryValue::Int(i), ScalarType::Int) => Ok(PrismaValue::Int(i)),
             (QueryValue::Int(i), ScalarType::Float) => Ok(PrismaValue::Float(BigDecimal::from(i))),
-            (QueryValue::Int(i), ScalarType::Decimal) => Ok(PrismaValue::Float(BigDecimal::from(i))),
+            (QueryValue::Int(i), ScalarType::Decimal) => Ok(PrismaValue::Decimal(BigDecimal::from(i))),
             (QueryValue::Int(i), ScalarType::BigInt) => Ok(PrismaValue::BigInt(i)),
 
             (QueryValue::Float(f), ScalarType::Float) => Ok(PrismaValue::Float(f)),
             (QueryValue::Float(f), ScalarType::Int) => Ok(PrismaValue::Int(f.to_i64().unwrap())),
-            (QueryValue::Float(d), ScalarType::Decimal) => Ok(PrismaValue::Float(d)),
+            (QueryValue::Float(d), ScalarType::Decimal) => Ok(PrismaValue::Decimal(d)),
 
             (QueryValue::Boolean(b), ScalarType::Boolean) => Ok(PrismaValue::Boolean(b)),
 
@@ -269,6 +275,26 @@ impl QueryDocumentParser {
         })
     }
 
+    pub fn parse_date(path: &QueryPath, s: &str) -> QueryParserResult<NaiveDate> {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").map_err(|err| QueryParserError {
+            path: path.clone(),
+            error_kind: QueryParserErrorKind::ValueParseError(format!(
+                "Invalid Date: '{}' (must be of the form YYYY-MM-DD). Underlying error: {}",
+                s, err
+            )),
+        })
+    }
+
+    pub fn parse_time(path: &QueryPath, s: &str) -> QueryParserResult<NaiveTime> {
+        NaiveTime::parse_from_str(s, "%H:%M:%S%.f").map_err(|err| QueryParserError {
+            path: path.clone(),
+            error_kind: QueryParserErrorKind::ValueParseError(format!(
+                "Invalid Time: '{}' (must be of the form HH:MM:SS[.fff]). Underlying error: {}",
+                s, err
+            )),
+        })
+    }
+
     pub fn parse_bytes(path: &QueryPath, s: String) -> QueryParserResult<PrismaValue> {
         prisma_value::decode_bytes(&s)
             .map(PrismaValue::Bytes)
@@ -283,18 +309,23 @@ impl QueryDocumentParser {
 
     pub fn parse_decimal(path: &QueryPath, s: String) -> QueryParserResult<PrismaValue> {
         BigDecimal::from_str(&s)
-            .map(PrismaValue::Float)
+            .map(PrismaValue::Decimal)
             .map_err(|_| QueryParserError {
                 path: path.clone(),
                 error_kind: QueryParserErrorKind::ValueParseError(format!("'{}' is not a valid decimal string", s)),
             })
     }
 
+    /// Values above `i64::MAX` can only be stored in unsigned columns, and are
+    /// kept unsigned.
     pub fn parse_bigint(path: &QueryPath, s: String) -> QueryParserResult<PrismaValue> {
-        s.parse::<i64>().map(PrismaValue::BigInt).map_err(|_| QueryParserError {
-            path: path.clone(),
-            error_kind: QueryParserErrorKind::ValueParseError(format!("'{}' is not a valid big integer string", s)),
-        })
+        s.parse::<i64>()
+            .map(PrismaValue::BigInt)
+            .or_else(|_| s.parse::<u64>().map(PrismaValue::UnsignedBigInt))
+            .map_err(|_| QueryParserError {
+                path: path.clone(),
+                error_kind: QueryParserErrorKind::ValueParseError(format!("'{}' is not a valid big integer string", s)),
+            })
     }
 
     // [DTODO] This is likely incorrect or at least using the wrong abstractions.
@@ -1,6 +1,6 @@
 use bigdecimal::BigDecimal;
 use indexmap::IndexMap;
-use prisma_value::{stringify_date, PrismaValue};
+use prisma_value::{stringify_date, stringify_date_only, stringify_time_only, PrismaValue};
 use std::hash::Hash;
 
 #[derive(Debug, PartialEq, Clone, Eq)]
@@ -47,8 +47,11 @@ impl From<PrismaValue> for QueryValue {
         match pv {
             PrismaValue::String(s) => Self::String(s),
             PrismaValue::Float(f) => Self::Float(f),
+            PrismaValue::Decimal(d) => Self::String(d.to_string()),
             PrismaValue::Boolean(b) => Self::Boolean(b),
             PrismaValue::DateTime(dt) => Self::String(stringify_date(&dt)),
+            PrismaValue::Date(d) => Self::String(stringify_date_only(&d)),
+            PrismaValue::Time(t) => Self::String(stringify_time_only(&t)),
             PrismaValue::Enum(s) => Self::Enum(s),
             PrismaValue::List(l) => Self::List(l.into_iter().map(QueryValue::from).collect()),
             PrismaValue::Int(i) => Self::Int(i),
@@ -58,6 +61,7 @@ impl From<PrismaValue> for QueryValue {
             PrismaValue::Xml(s) => Self::String(s),
             PrismaValue::Bytes(b) => Self::String(prisma_value::encode_bytes(&b)),
             PrismaValue::BigInt(i) => Self::Int(i),
+            PrismaValue::UnsignedBigInt(u) => Self::String(u.to_string()),
         }
     }
 }
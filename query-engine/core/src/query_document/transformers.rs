@@ -167,7 +167,7 @@ impl TryInto<Option<f64>> for ParsedInputValue {
         let prisma_value: PrismaValue = self.try_into()?;
 
         match prisma_value {
-            PrismaValue::Float(d) => Ok(d.to_f64()),
+            PrismaValue::Float(d) | PrismaValue::Decimal(d) => Ok(d.to_f64()),
             PrismaValue::Null => Ok(None),
             v => Err(QueryParserError {
                 path: QueryPath::default(),
@@ -276,6 +276,11 @@ impl QueryGraph {
             .collect()
     }
 
+    /// Returns all nodes of the graph, in the order of their creation.
+    pub fn nodes(&self) -> Vec<NodeRef> {
+        self.graph.node_indices().map(|node_ix| NodeRef { node_ix }).collect()
+    }
+
     /// Creates a node with content `t` and adds it to the graph.
     /// Returns a `NodeRef` to the newly added node.
     pub fn create_node<T>(&mut self, t: T) -> NodeRef
@@ -735,6 +740,7 @@ impl QueryGraph {
                 selected_fields: ModelProjection::union(identifiers),
                 nested: vec![],
                 selection_order: vec![],
+                relation_load_strategy: None,
             });
 
             let query = Query::Read(read_query);
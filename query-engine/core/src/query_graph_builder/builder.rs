@@ -86,12 +86,27 @@ impl QueryGraphBuilder {
             (QueryTag::Aggregate, Some(m)) => read::aggregate(parsed_field, m).map(Into::into),
             (QueryTag::GroupBy, Some(m)) => read::group_by(parsed_field, m).map(Into::into),
             (QueryTag::CreateOne, Some(m)) => QueryGraph::root(|g| write::create_record(g, m, parsed_field)),
-            (QueryTag::CreateMany, Some(m)) => QueryGraph::root(|g| write::create_many_records(g, m, parsed_field)),
+            (QueryTag::CreateMany, Some(m)) => {
+                QueryGraph::root(|g| write::create_many_records(g, m, parsed_field, false))
+            }
+            (QueryTag::CreateManyAndReturn, Some(m)) => {
+                QueryGraph::root(|g| write::create_many_records(g, m, parsed_field, true))
+            }
             (QueryTag::UpdateOne, Some(m)) => QueryGraph::root(|g| write::update_record(g, m, parsed_field)),
-            (QueryTag::UpdateMany, Some(m)) => QueryGraph::root(|g| write::update_many_records(g, m, parsed_field)),
+            (QueryTag::UpdateMany, Some(m)) => {
+                QueryGraph::root(|g| write::update_many_records(g, m, parsed_field, false))
+            }
+            (QueryTag::UpdateManyAndReturn, Some(m)) => {
+                QueryGraph::root(|g| write::update_many_records(g, m, parsed_field, true))
+            }
             (QueryTag::UpsertOne, Some(m)) => QueryGraph::root(|g| write::upsert_record(g, m, parsed_field)),
             (QueryTag::DeleteOne, Some(m)) => QueryGraph::root(|g| write::delete_record(g, m, parsed_field)),
-            (QueryTag::DeleteMany, Some(m)) => QueryGraph::root(|g| write::delete_many_records(g, m, parsed_field)),
+            (QueryTag::DeleteMany, Some(m)) => {
+                QueryGraph::root(|g| write::delete_many_records(g, m, parsed_field, false))
+            }
+            (QueryTag::DeleteManyAndReturn, Some(m)) => {
+                QueryGraph::root(|g| write::delete_many_records(g, m, parsed_field, true))
+            }
             (QueryTag::ExecuteRaw, _) => QueryGraph::root(|g| write::execute_raw(g, parsed_field)),
             (QueryTag::QueryRaw, _) => QueryGraph::root(|g| write::query_raw(g, parsed_field)),
             _ => unreachable!("Query builder dispatching failed."),
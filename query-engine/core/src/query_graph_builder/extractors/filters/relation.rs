@@ -2,23 +2,54 @@ use super::extract_filter;
 use crate::{
     constants::inputs::filters, ParsedInputMap, ParsedInputValue, QueryGraphBuilderError, QueryGraphBuilderResult,
 };
-use connector::{Filter, RelationCompare};
-use prisma_models::RelationFieldRef;
+use connector::{Filter, RelationCompare, ScalarCondition};
+use prisma_models::{PrismaValue, RelationFieldRef};
 use std::convert::TryInto;
 
 pub fn parse(filter_key: &str, field: &RelationFieldRef, input: ParsedInputValue) -> QueryGraphBuilderResult<Filter> {
+    // Relation count filter, `{ _count: 5 }` or `{ _count: { gt: 5 } }`
+    if filter_key == filters::RELATION_COUNT {
+        let conditions = match input {
+            ParsedInputValue::Single(value) => vec![ScalarCondition::Equals(value)],
+            _ => count_conditions(input.try_into()?, false)?,
+        };
+
+        return Ok(Filter::and(
+            conditions
+                .into_iter()
+                .map(|condition| field.related_count(condition))
+                .collect(),
+        ));
+    }
+
     let value: Option<ParsedInputMap> = input.try_into()?;
+    let related_model = field.related_model();
+
+    // Soft deleted related records are ignored by relation filters.
+    let nested_filter = |value: ParsedInputMap| -> QueryGraphBuilderResult<Filter> {
+        Ok(extract_filter(value, &related_model)?.without_soft_deleted(&related_model))
+    };
 
     match (filter_key, value) {
         // Relation list filters
-        (filters::SOME, Some(value)) => Ok(field.at_least_one_related(extract_filter(value, &field.related_model())?)),
-        (filters::NONE, Some(value)) => Ok(field.no_related(extract_filter(value, &field.related_model())?)),
-        (filters::EVERY, Some(value)) => Ok(field.every_related(extract_filter(value, &field.related_model())?)),
+        (filters::SOME, Some(value)) => Ok(field.at_least_one_related(nested_filter(value)?)),
+        (filters::NONE, Some(value)) => Ok(field.no_related(nested_filter(value)?)),
+        (filters::EVERY, Some(value)) => {
+            let filter = extract_filter(value, &related_model)?;
+
+            // Every related record that is not soft deleted must match.
+            let filter = match Filter::not_soft_deleted(&related_model) {
+                Some(not_deleted) => Filter::or(vec![filter, Filter::not(vec![not_deleted])]),
+                None => filter,
+            };
+
+            Ok(field.every_related(filter))
+        }
 
         // One-relation filters
-        (filters::IS, Some(value)) => Ok(field.to_one_related(extract_filter(value, &field.related_model())?)),
+        (filters::IS, Some(value)) => Ok(field.to_one_related(nested_filter(value)?)),
         (filters::IS, None) => Ok(field.one_relation_is_null()),
-        (filters::IS_NOT, Some(value)) => Ok(field.no_related(extract_filter(value, &field.related_model())?)),
+        (filters::IS_NOT, Some(value)) => Ok(field.no_related(nested_filter(value)?)),
         (filters::IS_NOT, None) => Ok(Filter::not(vec![field.one_relation_is_null()])),
 
         _ => Err(QueryGraphBuilderError::InputError(format!(
@@ -27,3 +58,52 @@ pub fn parse(filter_key: &str, field: &RelationFieldRef, input: ParsedInputValue
         ))),
     }
 }
+
+fn count_conditions(input: ParsedInputMap, reverse: bool) -> QueryGraphBuilderResult<Vec<ScalarCondition>> {
+    let mut conditions = vec![];
+
+    for (key, value) in input {
+        match key.as_str() {
+            filters::NOT_LOWERCASE => match value {
+                ParsedInputValue::Single(value) if reverse => conditions.push(ScalarCondition::Equals(value)),
+                ParsedInputValue::Single(value) => conditions.push(ScalarCondition::NotEquals(value)),
+                _ => conditions.extend(count_conditions(value.try_into()?, !reverse)?),
+            },
+            _ => {
+                let value: PrismaValue = value.try_into()?;
+                conditions.push(count_condition(&key, value, reverse)?);
+            }
+        }
+    }
+
+    Ok(conditions)
+}
+
+fn count_condition(key: &str, value: PrismaValue, reverse: bool) -> QueryGraphBuilderResult<ScalarCondition> {
+    let condition = match (key, value) {
+        (filters::EQUALS, value) if reverse => ScalarCondition::NotEquals(value),
+        (filters::IN, PrismaValue::List(values)) if reverse => ScalarCondition::NotIn(values),
+        (filters::NOT_IN, PrismaValue::List(values)) if reverse => ScalarCondition::In(values),
+        (filters::LOWER_THAN, value) if reverse => ScalarCondition::GreaterThanOrEquals(value),
+        (filters::LOWER_THAN_OR_EQUAL, value) if reverse => ScalarCondition::GreaterThan(value),
+        (filters::GREATER_THAN, value) if reverse => ScalarCondition::LessThanOrEquals(value),
+        (filters::GREATER_THAN_OR_EQUAL, value) if reverse => ScalarCondition::LessThan(value),
+
+        (filters::EQUALS, value) => ScalarCondition::Equals(value),
+        (filters::IN, PrismaValue::List(values)) => ScalarCondition::In(values),
+        (filters::NOT_IN, PrismaValue::List(values)) => ScalarCondition::NotIn(values),
+        (filters::LOWER_THAN, value) => ScalarCondition::LessThan(value),
+        (filters::LOWER_THAN_OR_EQUAL, value) => ScalarCondition::LessThanOrEquals(value),
+        (filters::GREATER_THAN, value) => ScalarCondition::GreaterThan(value),
+        (filters::GREATER_THAN_OR_EQUAL, value) => ScalarCondition::GreaterThanOrEquals(value),
+
+        _ => {
+            return Err(QueryGraphBuilderError::InputError(format!(
+                "{} is not a valid relation count filter operation",
+                key
+            )))
+        }
+    };
+
+    Ok(condition)
+}
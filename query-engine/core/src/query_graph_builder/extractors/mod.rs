@@ -4,5 +4,6 @@ mod utils;
 
 pub use filters::*;
 pub use query_arguments::*;
+pub(crate) use utils::resolve_compound_field;
 
 use crate::query_document::*;
@@ -2,13 +2,19 @@ use super::*;
 use crate::{query_document::ParsedField, AggregateRecordsQuery, ReadQuery};
 use prisma_models::ModelRef;
 
-pub fn aggregate(field: ParsedField, model: ModelRef) -> QueryGraphBuilderResult<ReadQuery> {
+pub fn aggregate(mut field: ParsedField, model: ModelRef) -> QueryGraphBuilderResult<ReadQuery> {
     let name = field.name;
     let alias = field.alias;
     let model = model;
     let nested_fields = field.nested_fields.unwrap().fields;
     let selection_order = collect_selection_tree(&nested_fields);
+    let with_deleted = utils::extract_with_deleted(&mut field.arguments)?;
     let args = extractors::extract_query_args(field.arguments, &model)?;
+    let args = if with_deleted {
+        args
+    } else {
+        args.exclude_soft_deleted()
+    };
 
     // Reject any inmemory-requiring operation for aggregations, we don't have an in-memory aggregator yet.
     if args.requires_inmemory_processing() {
@@ -2,11 +2,13 @@ use std::convert::TryInto;
 
 use super::*;
 use crate::{
-    constants::inputs::args, query_document::ParsedField, AggregateRecordsQuery, ArgumentListLookup, ParsedInputValue,
-    ReadQuery,
+    constants::inputs::{args, buckets},
+    query_document::ParsedField,
+    AggregateRecordsQuery, ArgumentListLookup, ParsedInputMap, ParsedInputValue, ReadQuery,
 };
-use connector::Filter;
-use prisma_models::{ModelRef, OrderBy, ScalarFieldRef};
+use bigdecimal::BigDecimal;
+use connector::{DateTruncUnit, Filter, GroupingKey, TimeZone};
+use prisma_models::{ModelRef, OrderBy, PrismaValue, ScalarFieldRef, TypeIdentifier};
 
 pub fn group_by(mut field: ParsedField, model: ModelRef) -> QueryGraphBuilderResult<ReadQuery> {
     let name = field.name;
@@ -14,19 +16,32 @@ pub fn group_by(mut field: ParsedField, model: ModelRef) -> QueryGraphBuilderRes
     let model = model;
 
     let by_arg = field.arguments.lookup(args::BY).unwrap().value;
-    let group_by = extract_grouping(by_arg)?;
+    let by = extract_grouping(by_arg)?;
+    let expressions = match field.arguments.lookup(args::BUCKET_BY) {
+        Some(bucket_by_arg) => extract_buckets(bucket_by_arg.value.try_into()?, &model)?,
+        None => vec![],
+    };
+
+    let group_by = merge_grouping(by, expressions)?;
     let having: Option<Filter> = match field.arguments.lookup(args::HAVING) {
         Some(having_arg) => Some(extract_filter(having_arg.value.try_into()?, &model)?),
         None => None,
     };
 
+    let with_deleted = utils::extract_with_deleted(&mut field.arguments)?;
     let args = extractors::extract_query_args(field.arguments, &model)?;
+    let args = if with_deleted {
+        args
+    } else {
+        args.exclude_soft_deleted()
+    };
     let nested_fields = field.nested_fields.unwrap().fields;
     let selection_order = collect_selection_tree(&nested_fields);
 
     let selectors: Vec<_> = nested_fields
         .into_iter()
         .map(|field| resolve_query(field, &model))
+        .map(|result| result.map(|selector| select_expression(selector, &group_by)))
         .collect::<QueryGraphBuilderResult<_>>()?;
 
     verify_selections(&selectors, &group_by)
@@ -45,14 +60,25 @@ pub fn group_by(mut field: ParsedField, model: ModelRef) -> QueryGraphBuilderRes
     }))
 }
 
+/// Selected fields that are grouped by an expression select the value of the expression.
+fn select_expression(selector: AggregationSelection, group_by: &[GroupingKey]) -> AggregationSelection {
+    match selector {
+        AggregationSelection::Field(field) => match group_by.iter().find(|key| key.field() == &field) {
+            Some(key) if key.is_expression() => AggregationSelection::Expression(key.clone()),
+            _ => AggregationSelection::Field(field),
+        },
+        selector => selector,
+    }
+}
+
 /// Cross checks that the selections of the request are valid with regard to the requested group bys:
 /// Every plain scalar field in the selectors must be present in the group by as well.
-fn verify_selections(selectors: &[AggregationSelection], group_by: &[ScalarFieldRef]) -> QueryGraphBuilderResult<()> {
+fn verify_selections(selectors: &[AggregationSelection], group_by: &[GroupingKey]) -> QueryGraphBuilderResult<()> {
     let mut missing_fields = vec![];
 
     for selector in selectors {
         if let AggregationSelection::Field(field) = selector {
-            if !group_by.contains(&field) {
+            if !group_by.iter().any(|key| key.field() == field) {
                 missing_fields.push(field.name.clone());
             }
         }
@@ -71,11 +97,11 @@ fn verify_selections(selectors: &[AggregationSelection], group_by: &[ScalarField
 
 /// Cross checks that the requested order-bys of the request are valid with regard to the requested group bys.
 /// Every ordered field must be present in the group by as well. (Note: We do not yet allow order by aggregate)
-fn verify_orderings(orderings: &[OrderBy], group_by: &[ScalarFieldRef]) -> QueryGraphBuilderResult<()> {
+fn verify_orderings(orderings: &[OrderBy], group_by: &[GroupingKey]) -> QueryGraphBuilderResult<()> {
     let mut missing_fields = vec![];
 
     for ordering in orderings {
-        if !group_by.contains(&ordering.field) {
+        if !group_by.iter().any(|key| key.field() == &ordering.field) {
             missing_fields.push(ordering.field.name.clone());
         }
     }
@@ -98,6 +124,7 @@ fn verify_having(having: Option<&Filter>, selectors: &[AggregationSelection]) ->
             .iter()
             .filter_map(|selector| match selector {
                 AggregationSelection::Field(field) => Some(field),
+                AggregationSelection::Expression(key) => Some(key.field()),
                 _ => None,
             })
             .collect();
@@ -157,3 +184,128 @@ fn extract_grouping(value: ParsedInputValue) -> QueryGraphBuilderResult<Vec<Scal
         )),
     }
 }
+
+/// Replaces the fields of `by` that have a bucket in `bucketBy` with the grouping expression.
+/// Every bucketed field must be grouped by.
+fn merge_grouping(by: Vec<ScalarFieldRef>, expressions: Vec<GroupingKey>) -> QueryGraphBuilderResult<Vec<GroupingKey>> {
+    let missing_fields: Vec<String> = expressions
+        .iter()
+        .filter(|key| !by.contains(key.field()))
+        .map(|key| key.field().name.clone())
+        .collect();
+
+    if !missing_fields.is_empty() {
+        return Err(QueryGraphBuilderError::InputError(format!(
+            "Every field used in `bucketBy` must be included in the by-arguments of the query. Missing fields: {}",
+            missing_fields.join(", ")
+        )));
+    }
+
+    Ok(by
+        .into_iter()
+        .map(|field| {
+            expressions
+                .iter()
+                .find(|key| key.field() == &field)
+                .cloned()
+                .unwrap_or(GroupingKey::Field(field))
+        })
+        .collect())
+}
+
+fn extract_buckets(value: ParsedInputMap, model: &ModelRef) -> QueryGraphBuilderResult<Vec<GroupingKey>> {
+    value
+        .into_iter()
+        .map(|(name, bucket)| {
+            let field = model.fields().find_from_scalar(&name)?;
+            let mut bucket: ParsedInputMap = bucket.try_into()?;
+
+            match field.type_identifier {
+                TypeIdentifier::DateTime => {
+                    let unit: Option<String> = bucket.remove(buckets::UNIT).unwrap().try_into()?;
+                    let time_zone: Option<String> = match bucket.remove(buckets::TIME_ZONE) {
+                        Some(value) => value.try_into()?,
+                        None => None,
+                    };
+
+                    Ok(GroupingKey::DateTrunc {
+                        field,
+                        unit: extract_date_trunc_unit(unit.unwrap())?,
+                        time_zone: time_zone.map(|tz| extract_time_zone(&tz)).transpose()?,
+                    })
+                }
+                _ => {
+                    let width: PrismaValue = bucket.remove(buckets::WIDTH).unwrap().try_into()?;
+                    let positive = match &width {
+                        PrismaValue::Int(i) | PrismaValue::BigInt(i) => *i > 0,
+                        PrismaValue::Float(f) | PrismaValue::Decimal(f) => *f > BigDecimal::from(0),
+                        _ => false,
+                    };
+
+                    if !positive {
+                        return Err(QueryGraphBuilderError::InputError(format!(
+                            "The bucket width of `{}` must be a positive number.",
+                            field.name
+                        )));
+                    }
+
+                    Ok(GroupingKey::Bucket { field, width })
+                }
+            }
+        })
+        .collect()
+}
+
+fn extract_date_trunc_unit(unit: String) -> QueryGraphBuilderResult<DateTruncUnit> {
+    Ok(match unit.as_str() {
+        buckets::YEAR => DateTruncUnit::Year,
+        buckets::MONTH => DateTruncUnit::Month,
+        buckets::WEEK => DateTruncUnit::Week,
+        buckets::DAY => DateTruncUnit::Day,
+        buckets::HOUR => DateTruncUnit::Hour,
+        buckets::MINUTE => DateTruncUnit::Minute,
+        _ => unreachable!(), // Validation guarantees this.
+    })
+}
+
+/// Time zones are either an offset from UTC, like `+02:00`, or the name of an IANA time zone,
+/// like `Europe/Berlin`. Names are written into the statement and therefore checked strictly.
+fn extract_time_zone(time_zone: &str) -> QueryGraphBuilderResult<TimeZone> {
+    if time_zone == "Z" || time_zone == "UTC" {
+        return Ok(TimeZone::Offset(0));
+    }
+
+    let offset = time_zone
+        .strip_prefix('+')
+        .map(|rest| (1, rest))
+        .or_else(|| time_zone.strip_prefix('-').map(|rest| (-1, rest)))
+        .and_then(|(sign, rest)| {
+            let (hours, minutes) = rest.split_at(rest.find(':')?);
+            let hours: i32 = hours.parse().ok()?;
+            let minutes: i32 = minutes[1..].parse().ok()?;
+
+            if hours <= 14 && minutes < 60 {
+                Some(sign * (hours * 60 + minutes))
+            } else {
+                None
+            }
+        });
+
+    if let Some(offset) = offset {
+        return Ok(TimeZone::Offset(offset));
+    }
+
+    let is_name = time_zone.starts_with(|c: char| c.is_ascii_alphabetic())
+        && time_zone
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '/' | '_' | '-' | '+'));
+
+    if is_name {
+        Ok(TimeZone::Named(time_zone.to_owned()))
+    } else {
+        Err(QueryGraphBuilderError::InputError(format!(
+            "`{}` is not a valid time zone. Expected an offset like `+02:00` or a name like `Europe/Berlin`.",
+            time_zone
+        )))
+    }
+}
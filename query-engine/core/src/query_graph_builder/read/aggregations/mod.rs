@@ -45,6 +45,11 @@ fn resolve_query(mut field: FieldPair, model: &ModelRef) -> QueryGraphBuilderRes
         fields::SUM => AggregationSelection::Sum(resolve_fields(model, field)),
         fields::MIN => AggregationSelection::Min(resolve_fields(model, field)),
         fields::MAX => AggregationSelection::Max(resolve_fields(model, field)),
+        fields::COUNT_DISTINCT => AggregationSelection::CountDistinct(resolve_fields(model, field)),
+        fields::STRING_AGG => AggregationSelection::StringAgg(resolve_fields(model, field)),
+        fields::MEDIAN => AggregationSelection::Median(resolve_fields(model, field)),
+        fields::STDDEV => AggregationSelection::StdDev(resolve_fields(model, field)),
+        fields::VARIANCE => AggregationSelection::Variance(resolve_fields(model, field)),
         name => AggregationSelection::Field(model.fields().find_from_scalar(name).unwrap()),
     };
 
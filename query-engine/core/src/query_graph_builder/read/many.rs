@@ -1,9 +1,16 @@
 use super::*;
-use crate::{query_document::ParsedField, ManyRecordsQuery, ReadQuery};
+use crate::{query_document::ParsedField, ManyRecordsQuery, ReadQuery, ReturnedRecords};
 use prisma_models::ModelRef;
 
-pub fn find_many(field: ParsedField, model: ModelRef) -> QueryGraphBuilderResult<ReadQuery> {
+pub fn find_many(mut field: ParsedField, model: ModelRef) -> QueryGraphBuilderResult<ReadQuery> {
+    let relation_load_strategy = utils::extract_relation_load_strategy(&mut field.arguments)?;
+    let with_deleted = utils::extract_with_deleted(&mut field.arguments)?;
     let args = extractors::extract_query_args(field.arguments, &model)?;
+    let args = if with_deleted {
+        args
+    } else {
+        args.exclude_soft_deleted()
+    };
     let name = field.name;
     let alias = field.alias;
     let nested_fields = field.nested_fields.unwrap().fields;
@@ -23,5 +30,23 @@ pub fn find_many(field: ParsedField, model: ModelRef) -> QueryGraphBuilderResult
         selected_fields,
         nested,
         selection_order,
+        relation_load_strategy,
     }))
 }
+
+/// Collects the selection of the records a many-records write returns, e.g.
+/// for `updateManyUserAndReturn`.
+pub fn returned_records(field: ParsedField, model: &ModelRef) -> QueryGraphBuilderResult<ReturnedRecords> {
+    let nested_fields = field.nested_fields.unwrap().fields;
+    let selection_order: Vec<String> = utils::collect_selection_order(&nested_fields);
+    let selected_fields = utils::collect_selected_fields(&nested_fields, model);
+    let nested = utils::collect_nested_queries(nested_fields, model)?;
+    let selected_fields = utils::merge_relation_selections(selected_fields, None, &nested);
+
+    Ok(ReturnedRecords {
+        name: field.name,
+        selected_fields,
+        nested,
+        selection_order,
+    })
+}
@@ -5,6 +5,8 @@ use std::convert::TryInto;
 
 /// Builds a read query from a parsed incoming read query field.
 pub fn find_one(mut field: ParsedField, model: ModelRef) -> QueryGraphBuilderResult<ReadQuery> {
+    let relation_load_strategy = utils::extract_relation_load_strategy(&mut field.arguments)?;
+    let with_deleted = utils::extract_with_deleted(&mut field.arguments)?;
     let filter = match field.arguments.lookup(args::WHERE) {
         Some(where_arg) => {
             let arg: ParsedInputMap = where_arg.value.try_into()?;
@@ -13,6 +15,11 @@ pub fn find_one(mut field: ParsedField, model: ModelRef) -> QueryGraphBuilderRes
         None => None,
     };
 
+    let filter = match filter {
+        Some(filter) if !with_deleted => Some(filter.without_soft_deleted(&model)),
+        filter => filter,
+    };
+
     let name = field.name;
     let alias = field.alias;
     let model = model;
@@ -30,5 +37,6 @@ pub fn find_one(mut field: ParsedField, model: ModelRef) -> QueryGraphBuilderRes
         selected_fields,
         nested,
         selection_order,
+        relation_load_strategy,
     }))
 }
@@ -3,11 +3,21 @@ use crate::{query_document::ParsedField, ReadQuery, RelatedRecordsQuery};
 use prisma_models::{ModelRef, RelationFieldRef};
 
 pub fn find_related(
-    field: ParsedField,
+    mut field: ParsedField,
     parent: RelationFieldRef,
     model: ModelRef,
 ) -> QueryGraphBuilderResult<ReadQuery> {
+    let with_deleted = utils::extract_with_deleted(&mut field.arguments)?;
     let args = extractors::extract_query_args(field.arguments, &model)?;
+
+    // To-one relations are loaded as they are, excluding a soft deleted record
+    // would make a required relation appear to be missing.
+    let args = if parent.is_list && !with_deleted {
+        args.exclude_soft_deleted()
+    } else {
+        args
+    };
+
     let name = field.name;
     let alias = field.alias;
     let sub_selections = field.nested_fields.unwrap().fields;
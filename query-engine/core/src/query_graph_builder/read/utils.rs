@@ -1,7 +1,12 @@
 use super::*;
-use crate::{FieldPair, ReadQuery};
-use prisma_models::{Field, ModelProjection, ModelRef, RecordProjection, RelationFieldRef};
-use std::sync::Arc;
+use crate::{
+    constants::inputs::{args, relation_load_strategies},
+    query_document::{ArgumentListLookup, ParsedArgument},
+    FieldPair, ReadQuery,
+};
+use connector::RelationLoadStrategy;
+use prisma_models::{Field, ModelProjection, ModelRef, PrismaValue, RecordProjection, RelationFieldRef};
+use std::{convert::TryInto, sync::Arc};
 
 pub fn collect_selection_order(from: &[FieldPair]) -> Vec<String> {
     from.iter()
@@ -91,3 +96,38 @@ pub fn merge_cursor_fields(selected_fields: ModelProjection, cursor: &Option<Rec
         None => selected_fields,
     }
 }
+
+/// Removes the "relationLoadStrategy" argument from the arguments, if present.
+pub fn extract_relation_load_strategy(
+    arguments: &mut Vec<ParsedArgument>,
+) -> QueryGraphBuilderResult<Option<RelationLoadStrategy>> {
+    let arg = match arguments.lookup(args::RELATION_LOAD_STRATEGY) {
+        Some(arg) => arg,
+        None => return Ok(None),
+    };
+
+    let value: PrismaValue = arg.value.try_into()?;
+    let s = match value {
+        PrismaValue::Enum(s) => s,
+        PrismaValue::String(s) => s,
+        _ => unreachable!(),
+    };
+
+    Ok(match s.as_str() {
+        relation_load_strategies::QUERY => Some(RelationLoadStrategy::Query),
+        relation_load_strategies::JOIN => Some(RelationLoadStrategy::Join),
+        _ => unreachable!(),
+    })
+}
+
+/// Removes the "withDeleted" argument from the arguments, if present.
+/// Soft deleted records are excluded unless the argument is `true`.
+pub fn extract_with_deleted(arguments: &mut Vec<ParsedArgument>) -> QueryGraphBuilderResult<bool> {
+    match arguments.lookup(args::WITH_DELETED) {
+        Some(arg) => {
+            let with_deleted: Option<bool> = arg.value.try_into()?;
+            Ok(with_deleted.unwrap_or(false))
+        }
+        None => Ok(false),
+    }
+}
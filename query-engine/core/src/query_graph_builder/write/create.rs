@@ -51,11 +51,13 @@ pub fn create_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedF
     Ok(())
 }
 
-/// Creates a create record query and adds it to the query graph, together with it's nested queries and companion read query.
+/// Creates a create many records query and adds it to the query graph.
+/// With `returning` set, the query returns the created records instead of their count.
 pub fn create_many_records(
     graph: &mut QueryGraph,
     model: ModelRef,
     mut field: ParsedField,
+    returning: bool,
 ) -> QueryGraphBuilderResult<()> {
     graph.flag_transactional();
 
@@ -80,10 +82,17 @@ pub fn create_many_records(
         })
         .collect::<QueryGraphBuilderResult<Vec<_>>>()?;
 
+    let returning = if returning {
+        Some(read::returned_records(field, &model)?)
+    } else {
+        None
+    };
+
     let query = CreateManyRecords {
         model,
         args,
         skip_duplicates,
+        returning,
     };
 
     graph.create_node(Query::Write(WriteQuery::CreateManyRecords(query)));
@@ -51,10 +51,12 @@ pub fn delete_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedF
 }
 
 /// Creates a top level delete many records query and adds it to the query graph.
+/// With `returning` set, the query returns the deleted records instead of their count.
 pub fn delete_many_records(
     graph: &mut QueryGraph,
     model: ModelRef,
     mut field: ParsedField,
+    returning: bool,
 ) -> QueryGraphBuilderResult<()> {
     graph.flag_transactional();
 
@@ -66,14 +68,26 @@ pub fn delete_many_records(
     let model_id = model.primary_identifier();
     let read_query = utils::read_ids_infallible(model.clone(), model_id, filter.clone());
     let record_filter = filter.into();
+    let returning = if returning {
+        Some(read::returned_records(field, &model)?)
+    } else {
+        None
+    };
+
+    let returns_records = returning.is_some();
     let delete_many = WriteQuery::DeleteManyRecords(DeleteManyRecords {
         model: model.clone(),
         record_filter,
+        returning,
     });
 
     let read_query_node = graph.create_node(read_query);
     let delete_many_node = graph.create_node(Query::Write(delete_many));
 
+    if returns_records {
+        graph.add_result_node(&delete_many_node);
+    }
+
     utils::insert_deletion_checks(graph, &model, &read_query_node, &delete_many_node)?;
     graph.create_edge(
         &read_query_node,
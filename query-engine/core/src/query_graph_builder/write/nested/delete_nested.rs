@@ -43,6 +43,7 @@ pub fn nested_delete(
         let delete_many = WriteQuery::DeleteManyRecords(DeleteManyRecords {
             model: Arc::clone(&child_model),
             record_filter: or_filter.clone().into(),
+            returning: None,
         });
 
         let delete_many_node = graph.create_node(Query::Write(delete_many));
@@ -133,6 +134,7 @@ pub fn nested_delete_many(
         let delete_many = WriteQuery::DeleteManyRecords(DeleteManyRecords {
             model: Arc::clone(&child_model),
             record_filter: RecordFilter::empty(),
+            returning: None,
         });
 
         let delete_many_node = graph.create_node(Query::Write(delete_many));
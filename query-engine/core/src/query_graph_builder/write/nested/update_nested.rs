@@ -109,6 +109,7 @@ pub fn nested_update_many(
             model: Arc::clone(&child_model),
             record_filter: RecordFilter::empty(),
             args: update_args.args,
+            returning: None,
         });
 
         let update_many_node = graph.create_node(Query::Write(update_many));
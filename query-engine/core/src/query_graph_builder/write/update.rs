@@ -13,7 +13,8 @@ use std::{convert::TryInto, sync::Arc};
 pub fn update_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedField) -> QueryGraphBuilderResult<()> {
     // "where"
     let where_arg: ParsedInputMap = field.arguments.lookup(args::WHERE).unwrap().value.try_into()?;
-    let filter = extract_unique_filter(where_arg, &model)?;
+    // Soft deleted records can't be updated, like they can't be read.
+    let filter = extract_unique_filter(where_arg, &model)?.without_soft_deleted(&model);
 
     // "data"
     let data_argument = field.arguments.lookup(args::DATA).unwrap();
@@ -51,10 +52,12 @@ pub fn update_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedF
 }
 
 /// Creates an update many record query and adds it to the query graph.
+/// With `returning` set, the query returns the updated records instead of their count.
 pub fn update_many_records(
     graph: &mut QueryGraph,
     model: ModelRef,
     mut field: ParsedField,
+    returning: bool,
 ) -> QueryGraphBuilderResult<()> {
     graph.flag_transactional();
 
@@ -70,11 +73,18 @@ pub fn update_many_records(
     let mut args = update_args.args;
     args.update_datetimes(Arc::clone(&model));
 
+    let returning = if returning {
+        Some(read::returned_records(field, &model)?)
+    } else {
+        None
+    };
+
     let record_filter = filter.into();
     let update_many = WriteQuery::UpdateManyRecords(UpdateManyRecords {
         model,
         record_filter,
         args,
+        returning,
     });
     graph.create_node(Query::Write(update_many));
 
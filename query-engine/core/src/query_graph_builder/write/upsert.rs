@@ -14,7 +14,8 @@ pub fn upsert_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedF
 
     let where_arg: ParsedInputMap = field.arguments.lookup(args::WHERE).unwrap().value.try_into()?;
 
-    let filter = extract_unique_filter(where_arg, &model)?;
+    // Soft deleted records can't be updated, like they can't be read.
+    let filter = extract_unique_filter(where_arg, &model)?.without_soft_deleted(&model);
     let model_id = model.primary_identifier();
 
     let create_argument = field.arguments.lookup(args::CREATE).unwrap();
@@ -41,6 +41,7 @@ where
         selected_fields,
         nested: vec![],
         selection_order: vec![],
+        relation_load_strategy: None,
     });
 
     Query::Read(read_query)
@@ -148,6 +149,7 @@ where
         model,
         record_filter,
         args,
+        returning: None,
     };
 
     graph.create_node(Query::Write(WriteQuery::UpdateManyRecords(ur)))
@@ -302,6 +304,11 @@ pub fn insert_deletion_checks(
     parent_node: &NodeRef,
     child_node: &NodeRef,
 ) -> QueryGraphBuilderResult<()> {
+    // Soft deleted records stay in place, no relation can be violated.
+    if model.soft_delete_field().is_some() {
+        return Ok(());
+    }
+
     let internal_model = model.internal_data_model();
     let relation_fields = internal_model.fields_requiring_model(model);
     let mut check_nodes = vec![];
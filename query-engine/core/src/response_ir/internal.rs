@@ -4,7 +4,7 @@ use crate::{
     schema::{IntoArc, ObjectTypeStrongRef, OutputType, OutputTypeRef, ScalarType},
     CoreError, DatabaseEnumType, EnumType, OutputFieldRef, QueryResult, RecordAggregations, RecordSelection,
 };
-use bigdecimal::ToPrimitive;
+use bigdecimal::{BigDecimal, ToPrimitive};
 use connector::AggregationResult;
 use indexmap::IndexMap;
 use prisma_models::{PrismaValue, RecordProjection};
@@ -116,6 +116,46 @@ fn serialize_aggregations(
                         serialize_scalar(&output_field, coerce_non_numeric(value, &output_field.field_type))?,
                     );
                 }
+
+                AggregationResult::CountDistinct(field, count) => {
+                    flattened.insert(format!("countDistinct_{}", &field.name), Item::Value(count));
+                }
+
+                AggregationResult::StringAgg(field, value) => {
+                    let output_field =
+                        find_nested_aggregate_output_field(&aggregate_object_type, fields::STRING_AGG, &field.name);
+                    flattened.insert(
+                        format!("stringAgg_{}", &field.name),
+                        serialize_scalar(&output_field, value)?,
+                    );
+                }
+
+                AggregationResult::Median(field, value) => {
+                    let output_field =
+                        find_nested_aggregate_output_field(&aggregate_object_type, fields::MEDIAN, &field.name);
+                    flattened.insert(
+                        format!("median_{}", &field.name),
+                        serialize_scalar(&output_field, value)?,
+                    );
+                }
+
+                AggregationResult::StdDev(field, value) => {
+                    let output_field =
+                        find_nested_aggregate_output_field(&aggregate_object_type, fields::STDDEV, &field.name);
+                    flattened.insert(
+                        format!("stddev_{}", &field.name),
+                        serialize_scalar(&output_field, value)?,
+                    );
+                }
+
+                AggregationResult::Variance(field, value) => {
+                    let output_field =
+                        find_nested_aggregate_output_field(&aggregate_object_type, fields::VARIANCE, &field.name);
+                    flattened.insert(
+                        format!("variance_{}", &field.name),
+                        serialize_scalar(&output_field, value)?,
+                    );
+                }
             }
         }
 
@@ -434,23 +474,29 @@ fn convert_prisma_value(value: PrismaValue, st: &ScalarType) -> Result<PrismaVal
         (ScalarType::Json, PrismaValue::Json(s)) => PrismaValue::Json(s),
 
         (ScalarType::Int, PrismaValue::Float(f)) => PrismaValue::Int(f.to_i64().unwrap()),
+        (ScalarType::Int, PrismaValue::Decimal(d)) => PrismaValue::Int(d.to_i64().unwrap()),
         (ScalarType::Int, PrismaValue::Int(i)) => PrismaValue::Int(i),
 
         (ScalarType::Float, PrismaValue::Float(f)) => PrismaValue::Float(f),
+        (ScalarType::Float, PrismaValue::Decimal(d)) => PrismaValue::Float(d),
         (ScalarType::Float, PrismaValue::Int(i)) => {
             PrismaValue::Int(i.to_i64().expect("Unable to convert BigDecimal to i64."))
         }
 
-        (ScalarType::Decimal, PrismaValue::Int(i)) => PrismaValue::String(i.to_string()),
-        (ScalarType::Decimal, PrismaValue::Float(f)) => PrismaValue::String(f.to_string()),
+        (ScalarType::Decimal, PrismaValue::Decimal(d)) => PrismaValue::Decimal(d),
+        (ScalarType::Decimal, PrismaValue::Int(i)) => PrismaValue::Decimal(BigDecimal::from(i)),
+        (ScalarType::Decimal, PrismaValue::Float(f)) => PrismaValue::Decimal(f),
 
         (ScalarType::BigInt, PrismaValue::BigInt(i)) => PrismaValue::BigInt(i),
+        (ScalarType::BigInt, PrismaValue::UnsignedBigInt(u)) => PrismaValue::UnsignedBigInt(u),
         (ScalarType::BigInt, PrismaValue::Int(i)) => PrismaValue::BigInt(i),
         (ScalarType::BigInt, PrismaValue::Float(f)) => PrismaValue::BigInt(f.to_i64().unwrap()),
 
         (ScalarType::Boolean, PrismaValue::Boolean(b)) => PrismaValue::Boolean(b),
         (ScalarType::Int, PrismaValue::Boolean(b)) => PrismaValue::Int(b as i64),
         (ScalarType::DateTime, PrismaValue::DateTime(dt)) => PrismaValue::DateTime(dt),
+        (ScalarType::Date, PrismaValue::Date(d)) => PrismaValue::Date(d),
+        (ScalarType::Time, PrismaValue::Time(t)) => PrismaValue::Time(t),
         (ScalarType::UUID, PrismaValue::Uuid(u)) => PrismaValue::Uuid(u),
         (ScalarType::Bytes, PrismaValue::Bytes(b)) => PrismaValue::Bytes(b),
 
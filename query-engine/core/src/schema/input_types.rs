@@ -213,6 +213,14 @@ impl InputType {
         InputType::Scalar(ScalarType::DateTime)
     }
 
+    pub fn date() -> InputType {
+        InputType::Scalar(ScalarType::Date)
+    }
+
+    pub fn time() -> InputType {
+        InputType::Scalar(ScalarType::Time)
+    }
+
     pub fn json() -> InputType {
         InputType::Scalar(ScalarType::Json)
     }
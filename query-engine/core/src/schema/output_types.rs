@@ -49,6 +49,14 @@ impl OutputType {
         OutputType::Scalar(ScalarType::DateTime)
     }
 
+    pub fn date() -> OutputType {
+        OutputType::Scalar(ScalarType::Date)
+    }
+
+    pub fn time() -> OutputType {
+        OutputType::Scalar(ScalarType::Time)
+    }
+
     pub fn json() -> OutputType {
         OutputType::Scalar(ScalarType::Json)
     }
@@ -94,10 +94,13 @@ pub enum QueryTag {
     FindMany,
     CreateOne,
     CreateMany,
+    CreateManyAndReturn,
     UpdateOne,
     UpdateMany,
+    UpdateManyAndReturn,
     DeleteOne,
     DeleteMany,
+    DeleteManyAndReturn,
     UpsertOne,
     Aggregate,
     GroupBy,
@@ -114,10 +117,13 @@ impl fmt::Display for QueryTag {
             Self::FindMany => "findMany",
             Self::CreateOne => "createOne",
             Self::CreateMany => "createMany",
+            Self::CreateManyAndReturn => "createManyAndReturn",
             Self::UpdateOne => "updateOne",
             Self::UpdateMany => "updateMany",
+            Self::UpdateManyAndReturn => "updateManyAndReturn",
             Self::DeleteOne => "deleteOne",
             Self::DeleteMany => "deleteMany",
+            Self::DeleteManyAndReturn => "deleteManyAndReturn",
             Self::UpsertOne => "upsertOne",
             Self::Aggregate => "aggregate",
             Self::GroupBy => "groupBy",
@@ -172,6 +178,8 @@ pub enum ScalarType {
     Boolean,
     Enum(EnumTypeRef),
     DateTime,
+    Date,
+    Time,
     Json,
     JsonList,
     UUID,
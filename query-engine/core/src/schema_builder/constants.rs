@@ -17,6 +17,7 @@ pub mod inputs {
 
         // aggregation args
         pub const BY: &str = "by";
+        pub const BUCKET_BY: &str = "bucketBy";
         pub const HAVING: &str = "having";
 
         // raw specific args
@@ -27,6 +28,12 @@ pub mod inputs {
 
         // createMany-specific args
         pub const SKIP_DUPLICATES: &str = "skipDuplicates";
+
+        // relation loading args
+        pub const RELATION_LOAD_STRATEGY: &str = "relationLoadStrategy";
+
+        // soft delete args
+        pub const WITH_DELETED: &str = "withDeleted";
     }
 
     pub mod operations {
@@ -91,6 +98,7 @@ pub mod inputs {
         pub const EVERY: &str = "every";
         pub const SOME: &str = "some";
         pub const NONE: &str = "none";
+        pub const RELATION_COUNT: &str = "_count";
 
         // o2m filters
         pub const IS: &str = "is";
@@ -109,6 +117,25 @@ pub mod inputs {
         pub const ASC: &str = "asc";
         pub const DESC: &str = "desc";
     }
+
+    pub mod buckets {
+        pub const UNIT: &str = "unit";
+        pub const TIME_ZONE: &str = "timeZone";
+        pub const WIDTH: &str = "width";
+
+        pub const DATE_TRUNC_UNIT: &str = "DateTruncUnit";
+        pub const YEAR: &str = "year";
+        pub const MONTH: &str = "month";
+        pub const WEEK: &str = "week";
+        pub const DAY: &str = "day";
+        pub const HOUR: &str = "hour";
+        pub const MINUTE: &str = "minute";
+    }
+
+    pub mod relation_load_strategies {
+        pub const QUERY: &str = "query";
+        pub const JOIN: &str = "join";
+    }
 }
 
 pub mod outputs {
@@ -119,5 +146,10 @@ pub mod outputs {
         pub const MIN: &str = "min";
         pub const MAX: &str = "max";
         pub const SUM: &str = "sum";
+        pub const COUNT_DISTINCT: &str = "countDistinct";
+        pub const STRING_AGG: &str = "stringAgg";
+        pub const MEDIAN: &str = "median";
+        pub const STDDEV: &str = "stddev";
+        pub const VARIANCE: &str = "variance";
     }
 }
@@ -1,5 +1,5 @@
 use super::*;
-use constants::inputs::args;
+use constants::inputs::{args, relation_load_strategies};
 use datamodel_connector::ConnectorCapability;
 
 /// Builds "where" argument.
@@ -135,9 +135,37 @@ pub(crate) fn many_records_arguments(
         );
     }
 
+    append_opt(&mut args, with_deleted_argument(model));
+
     args
 }
 
+/// Builds "relationLoadStrategy" argument intended for the top-level read fields.
+/// Only present for connectors that can load nested relations with joins.
+pub(crate) fn relation_load_strategy_argument(ctx: &BuilderContext) -> Option<InputField> {
+    if ctx.capabilities.contains(ConnectorCapability::JoinRelationLoading) {
+        let enum_type = Arc::new(string_enum_type(
+            "RelationLoadStrategy",
+            vec![
+                relation_load_strategies::QUERY.to_owned(),
+                relation_load_strategies::JOIN.to_owned(),
+            ],
+        ));
+
+        Some(input_field(args::RELATION_LOAD_STRATEGY, InputType::enum_type(enum_type), None).optional())
+    } else {
+        None
+    }
+}
+
+/// Builds "withDeleted" argument intended for the read fields of soft deleted models.
+/// Only present for models with a `@@softDelete` attribute.
+pub(crate) fn with_deleted_argument(model: &ModelRef) -> Option<InputField> {
+    model
+        .soft_delete_field()
+        .map(|_| input_field(args::WITH_DELETED, InputType::boolean(), None).optional())
+}
+
 // Builds "orderBy" argument.
 pub(crate) fn order_by_argument(ctx: &mut BuilderContext, model: &ModelRef, include_relations: bool) -> InputField {
     let order_object_type = InputType::object(order_by_objects::order_by_object_type(
@@ -158,7 +186,7 @@ pub(crate) fn order_by_argument(ctx: &mut BuilderContext, model: &ModelRef, incl
 pub(crate) fn group_by_arguments(ctx: &mut BuilderContext, model: &ModelRef) -> Vec<InputField> {
     let field_enum_type = InputType::Enum(model_field_enum(model));
 
-    vec![
+    let mut arguments = vec![
         where_argument(ctx, &model),
         order_by_argument(ctx, &model, false),
         input_field(
@@ -174,5 +202,13 @@ pub(crate) fn group_by_arguments(ctx: &mut BuilderContext, model: &ModelRef) ->
         .optional(),
         input_field(args::TAKE, InputType::int(), None).optional(),
         input_field(args::SKIP, InputType::int(), None).optional(),
-    ]
+    ];
+
+    if let Some(bucket_by_object) = group_by_objects::bucket_by_object_type(ctx, model) {
+        arguments.push(input_field(args::BUCKET_BY, InputType::object(bucket_by_object), None).optional());
+    }
+
+    append_opt(&mut arguments, with_deleted_argument(model));
+
+    arguments
 }
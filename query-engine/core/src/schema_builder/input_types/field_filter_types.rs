@@ -75,6 +75,22 @@ fn full_relation_filter(ctx: &mut BuilderContext, rf: &RelationFieldRef) -> Inpu
             input_field(filters::EVERY, InputType::object(related_input_type.clone()), None).optional(),
             input_field(filters::SOME, InputType::object(related_input_type.clone()), None).optional(),
             input_field(filters::NONE, InputType::object(related_input_type), None).optional(),
+            input_field(
+                filters::RELATION_COUNT,
+                vec![
+                    InputType::object(full_scalar_filter_type(
+                        ctx,
+                        &TypeIdentifier::Int,
+                        false,
+                        false,
+                        false,
+                        false,
+                    )),
+                    InputType::int(),
+                ],
+                None,
+            )
+            .optional(),
         ]
     } else {
         vec![
@@ -153,6 +169,8 @@ fn full_scalar_filter_type(
         | TypeIdentifier::BigInt
         | TypeIdentifier::Float
         | TypeIdentifier::DateTime
+        | TypeIdentifier::Date
+        | TypeIdentifier::Time
         | TypeIdentifier::Decimal => equality_filters(mapped_scalar_type.clone(), nullable)
             .chain(inclusion_filters(mapped_scalar_type.clone(), nullable))
             .chain(alphanumeric_filters(mapped_scalar_type.clone()))
@@ -296,6 +314,8 @@ fn scalar_filter_name(
         TypeIdentifier::Decimal => format!("{}Decimal{}{}{}Filter", nested, nullable, list, aggregates),
         TypeIdentifier::Boolean => format!("{}Bool{}{}{}Filter", nested, nullable, list, aggregates),
         TypeIdentifier::DateTime => format!("{}DateTime{}{}{}Filter", nested, nullable, list, aggregates),
+        TypeIdentifier::Date => format!("{}Date{}{}{}Filter", nested, nullable, list, aggregates),
+        TypeIdentifier::Time => format!("{}Time{}{}{}Filter", nested, nullable, list, aggregates),
         TypeIdentifier::Json => format!("{}Json{}{}{}Filter", nested, nullable, list, aggregates),
         TypeIdentifier::Enum(ref e) => format!("{}Enum{}{}{}{}Filter", nested, e, nullable, list, aggregates),
         TypeIdentifier::Xml => format!("{}Xml{}{}{}Filter", nested, nullable, list, aggregates),
@@ -206,7 +206,7 @@ pub(crate) fn nested_update_input_field(ctx: &mut BuilderContext, parent_field:
 }
 
 /// Builds scalar input fields using the mapper and the given, prefiltered, scalar fields.
-/// The mapper is responsible for mapping the fields to input types.
+/// The mapper is responsible for mapping the fields to input types. Generated fields are left out.
 pub(crate) fn scalar_input_fields<T, F>(
     ctx: &mut BuilderContext,
     model_name: String,
@@ -220,6 +220,12 @@ where
     F: Fn(&mut BuilderContext, ScalarFieldRef, Option<DefaultValue>) -> InputField,
 {
     let input_object_name = input_object_name.into();
+
+    let prefiltered_fields: Vec<ScalarFieldRef> = prefiltered_fields
+        .into_iter()
+        .filter(|f| f.generation_expression.is_none())
+        .collect();
+
     let mut non_list_fields: Vec<InputField> = prefiltered_fields
         .iter()
         .filter(|f| !f.is_list)
@@ -22,6 +22,8 @@ fn map_scalar_input_type(ctx: &mut BuilderContext, typ: &TypeIdentifier, list: b
         TypeIdentifier::Boolean => InputType::boolean(),
         TypeIdentifier::UUID => InputType::uuid(),
         TypeIdentifier::DateTime => InputType::date_time(),
+        TypeIdentifier::Date => InputType::date(),
+        TypeIdentifier::Time => InputType::time(),
         TypeIdentifier::Json => InputType::json(),
         TypeIdentifier::Enum(e) => map_enum_input_type(ctx, e),
         TypeIdentifier::Xml => InputType::xml(),
@@ -0,0 +1,90 @@
+use super::*;
+use constants::inputs::buckets;
+
+/// Builds the "<Model>BucketByInput" object type of the `bucketBy` argument
+/// of group bys, with a field for every date time and numeric scalar field.
+/// Returns `None` if the model has no such fields.
+pub(crate) fn bucket_by_object_type(ctx: &mut BuilderContext, model: &ModelRef) -> Option<InputObjectTypeWeakRef> {
+    let fields: Vec<ScalarFieldRef> = model
+        .fields()
+        .scalar()
+        .into_iter()
+        .filter(|field| !field.is_list && bucket_name(&field.type_identifier).is_some())
+        .collect();
+
+    if fields.is_empty() {
+        None
+    } else {
+        Some(bucket_by_object(ctx, model, fields))
+    }
+}
+
+fn bucket_by_object(ctx: &mut BuilderContext, model: &ModelRef, fields: Vec<ScalarFieldRef>) -> InputObjectTypeWeakRef {
+    let ident = Identifier::new(format!("{}BucketByInput", model.name), PRISMA_NAMESPACE);
+    return_cached_input!(ctx, &ident);
+
+    let input_object = Arc::new(init_input_object_type(ident.clone()));
+    ctx.cache_input_type(ident, input_object.clone());
+
+    let fields = fields
+        .into_iter()
+        .map(|field| {
+            let bucket_object = bucket_object_type(ctx, &field.type_identifier);
+            input_field(field.name.clone(), InputType::object(bucket_object), None).optional()
+        })
+        .collect();
+
+    input_object.set_fields(fields);
+    Arc::downgrade(&input_object)
+}
+
+/// Builds the "DateTimeBucketInput" type, truncating dates to a unit, or the
+/// "<Type>BucketInput" type of a numeric type, rounding numbers down to a
+/// multiple of a width.
+fn bucket_object_type(ctx: &mut BuilderContext, typ: &TypeIdentifier) -> InputObjectTypeWeakRef {
+    let ident = Identifier::new(bucket_name(typ).unwrap().to_owned(), PRISMA_NAMESPACE);
+    return_cached_input!(ctx, &ident);
+
+    let input_object = Arc::new(init_input_object_type(ident.clone()));
+    ctx.cache_input_type(ident, input_object.clone());
+
+    let fields = match typ {
+        TypeIdentifier::DateTime => {
+            let unit_enum = Arc::new(string_enum_type(
+                buckets::DATE_TRUNC_UNIT,
+                vec![
+                    buckets::YEAR.to_owned(),
+                    buckets::MONTH.to_owned(),
+                    buckets::WEEK.to_owned(),
+                    buckets::DAY.to_owned(),
+                    buckets::HOUR.to_owned(),
+                    buckets::MINUTE.to_owned(),
+                ],
+            ));
+
+            vec![
+                input_field(buckets::UNIT, InputType::Enum(unit_enum), None),
+                input_field(buckets::TIME_ZONE, InputType::string(), None).optional(),
+            ]
+        }
+        typ => vec![input_field(
+            buckets::WIDTH,
+            map_scalar_input_type(ctx, typ, false),
+            None,
+        )],
+    };
+
+    input_object.set_fields(fields);
+    Arc::downgrade(&input_object)
+}
+
+fn bucket_name(typ: &TypeIdentifier) -> Option<&'static str> {
+    match typ {
+        TypeIdentifier::DateTime => Some("DateTimeBucketInput"),
+        TypeIdentifier::Int => Some("IntBucketInput"),
+        TypeIdentifier::BigInt => Some("BigIntBucketInput"),
+        TypeIdentifier::Float => Some("FloatBucketInput"),
+        TypeIdentifier::Decimal => Some("DecimalBucketInput"),
+        _ => None,
+    }
+}
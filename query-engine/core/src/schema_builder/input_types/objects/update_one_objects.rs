@@ -141,6 +141,8 @@ fn non_list_scalar_update_field_mapper(
         TypeIdentifier::Enum(e) => InputType::object(operations_object_type(ctx, &format!("Enum{}", e), field, false)),
         TypeIdentifier::Json => map_scalar_input_type_for_field(ctx, field),
         TypeIdentifier::DateTime => InputType::object(operations_object_type(ctx, "DateTime", field, false)),
+        TypeIdentifier::Date => InputType::object(operations_object_type(ctx, "Date", field, false)),
+        TypeIdentifier::Time => InputType::object(operations_object_type(ctx, "Time", field, false)),
         TypeIdentifier::UUID => InputType::object(operations_object_type(ctx, "Uuid", field, false)),
         TypeIdentifier::Xml => InputType::object(operations_object_type(ctx, "Xml", field, false)),
         TypeIdentifier::Bytes => InputType::object(operations_object_type(ctx, "Bytes", field, false)),
@@ -84,6 +84,8 @@ pub(crate) fn group_by_output_object_type(ctx: &mut BuilderContext, model: &Mode
         ),
     );
 
+    object_fields.extend(capability_aggregation_fields(ctx, model));
+
     object.set_fields(object_fields);
     ctx.cache_output_type(ident, ObjectTypeStrongRef::clone(&object));
 
@@ -1,6 +1,9 @@
 use super::output_objects::map_scalar_output_type_for_field;
 use super::*;
+use crate::constants::outputs::fields;
+use datamodel_connector::ConnectorCapability;
 use prisma_models::ScalarFieldRef;
+use std::convert::identity;
 
 pub(crate) mod group_by;
 pub(crate) mod plain;
@@ -31,6 +34,92 @@ fn collect_numeric_fields(model: &ModelRef) -> Vec<ScalarFieldRef> {
         .collect()
 }
 
+fn collect_string_fields(model: &ModelRef) -> Vec<ScalarFieldRef> {
+    model
+        .fields()
+        .scalar()
+        .into_iter()
+        .filter(|field| !field.is_list && field.type_identifier == TypeIdentifier::String)
+        .collect()
+}
+
+/// Aggregation fields of the functions only some connectors support, offered
+/// by the capabilities of the connector.
+fn capability_aggregation_fields(ctx: &mut BuilderContext, model: &ModelRef) -> Vec<OutputField> {
+    let mut object_fields = vec![];
+    let numeric_fields = collect_numeric_fields(model);
+
+    if ctx.capabilities.contains(ConnectorCapability::CountDistinctAggregation) {
+        append_opt(
+            &mut object_fields,
+            aggregation_field(
+                ctx,
+                fields::COUNT_DISTINCT,
+                &model,
+                collect_non_list_nor_json_fields(model),
+                |_, _| OutputType::int(),
+                identity,
+            ),
+        );
+    }
+
+    if ctx.capabilities.contains(ConnectorCapability::StringAggregation) {
+        append_opt(
+            &mut object_fields,
+            aggregation_field(
+                ctx,
+                fields::STRING_AGG,
+                &model,
+                collect_string_fields(model),
+                map_scalar_output_type_for_field,
+                identity,
+            ),
+        );
+    }
+
+    if ctx.capabilities.contains(ConnectorCapability::MedianAggregation) {
+        append_opt(
+            &mut object_fields,
+            aggregation_field(
+                ctx,
+                fields::MEDIAN,
+                &model,
+                numeric_fields.clone(),
+                field_avg_output_type,
+                identity,
+            ),
+        );
+    }
+
+    if ctx.capabilities.contains(ConnectorCapability::StdDevAggregation) {
+        append_opt(
+            &mut object_fields,
+            aggregation_field(
+                ctx,
+                fields::STDDEV,
+                &model,
+                numeric_fields.clone(),
+                field_avg_output_type,
+                identity,
+            ),
+        );
+
+        append_opt(
+            &mut object_fields,
+            aggregation_field(
+                ctx,
+                fields::VARIANCE,
+                &model,
+                numeric_fields,
+                field_avg_output_type,
+                identity,
+            ),
+        );
+    }
+
+    object_fields
+}
+
 /// Returns an aggregation field with given name if the passed fields contains any fields.
 /// Field types inside the object type of the field are determined by the passed mapper fn.
 fn aggregation_field<F, G>(
@@ -78,6 +78,8 @@ pub(crate) fn aggregation_object_type(ctx: &mut BuilderContext, model: &ModelRef
         ),
     );
 
+    object_fields.extend(capability_aggregation_fields(ctx, model));
+
     object.set_fields(object_fields);
     ctx.cache_output_type(ident, ObjectTypeStrongRef::clone(&object));
 
@@ -81,6 +81,8 @@ pub(crate) fn map_scalar_output_type(ctx: &mut BuilderContext, typ: &TypeIdentif
         TypeIdentifier::Enum(e) => map_enum_type(ctx, &e).into(),
         TypeIdentifier::Json => OutputType::json(),
         TypeIdentifier::DateTime => OutputType::date_time(),
+        TypeIdentifier::Date => OutputType::date(),
+        TypeIdentifier::Time => OutputType::time(),
         TypeIdentifier::UUID => OutputType::uuid(),
         TypeIdentifier::Int => OutputType::int(),
         TypeIdentifier::Xml => OutputType::xml(),
@@ -33,10 +33,14 @@ pub(crate) fn build(ctx: &mut BuilderContext) -> (OutputType, ObjectTypeStrongRe
 fn find_one_field(ctx: &mut BuilderContext, model: &ModelRef) -> Option<OutputField> {
     arguments::where_unique_argument(ctx, model).map(|arg| {
         let field_name = ctx.pluralize_internal(camel_case(&model.name), format!("findOne{}", model.name));
+        let mut args = vec![arg];
+
+        append_opt(&mut args, arguments::relation_load_strategy_argument(ctx));
+        append_opt(&mut args, arguments::with_deleted_argument(model));
 
         field(
             field_name,
-            vec![arg],
+            args,
             OutputType::object(output_objects::map_model_object_type(ctx, &model)),
             Some(QueryInfo {
                 model: Some(Arc::clone(&model)),
@@ -57,10 +61,14 @@ fn find_one_field(ctx: &mut BuilderContext, model: &ModelRef) -> Option<OutputFi
 fn find_unique_field(ctx: &mut BuilderContext, model: &ModelRef) -> Option<OutputField> {
     arguments::where_unique_argument(ctx, model).map(|arg| {
         let field_name = ctx.pluralize_internal(camel_case(&model.name), format!("findUnique{}", model.name));
+        let mut args = vec![arg];
+
+        append_opt(&mut args, arguments::relation_load_strategy_argument(ctx));
+        append_opt(&mut args, arguments::with_deleted_argument(model));
 
         field(
             field_name,
-            vec![arg],
+            args,
             OutputType::object(output_objects::map_model_object_type(ctx, &model)),
             Some(QueryInfo {
                 model: Some(Arc::clone(&model)),
@@ -73,7 +81,9 @@ fn find_unique_field(ctx: &mut BuilderContext, model: &ModelRef) -> Option<Outpu
 
 /// Builds a find first item field for given model.
 fn find_first_field(ctx: &mut BuilderContext, model: &ModelRef) -> OutputField {
-    let args = arguments::many_records_arguments(ctx, &model, true);
+    let mut args = arguments::many_records_arguments(ctx, &model, true);
+    append_opt(&mut args, arguments::relation_load_strategy_argument(ctx));
+
     let field_name = format!("findFirst{}", model.name);
 
     field(
@@ -90,7 +100,9 @@ fn find_first_field(ctx: &mut BuilderContext, model: &ModelRef) -> OutputField {
 
 /// Builds a "multiple" query arity items field (e.g. "users", "posts", ...) for given model.
 fn all_items_field(ctx: &mut BuilderContext, model: &ModelRef) -> OutputField {
-    let args = arguments::many_records_arguments(ctx, &model, true);
+    let mut args = arguments::many_records_arguments(ctx, &model, true);
+    append_opt(&mut args, arguments::relation_load_strategy_argument(ctx));
+
     let field_name = ctx.pluralize_internal(camel_case(pluralize(&model.name)), format!("findMany{}", model.name));
 
     field(
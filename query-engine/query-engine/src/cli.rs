@@ -1,4 +1,6 @@
 use crate::request_handlers::graphql::{self, GraphQlBody};
+#[cfg(feature = "sql")]
+use crate::request_handlers::preview;
 
 use crate::{
     context::PrismaContext,
@@ -7,6 +9,7 @@ use crate::{
     PrismaResult,
 };
 
+use connector::query_log::QueryLogConfig;
 use datamodel::diagnostics::ValidatedConfiguration;
 use datamodel::{Configuration, Datamodel};
 use datamodel_connector::ConnectorCapabilities;
@@ -20,6 +23,15 @@ pub struct ExecuteRequest {
     datamodel: Datamodel,
     config: Configuration,
     enable_raw_queries: bool,
+    query_log: Option<QueryLogConfig>,
+}
+
+pub struct PreviewRequest {
+    legacy: bool,
+    query: String,
+    explain: bool,
+    datamodel: Datamodel,
+    config: Configuration,
 }
 
 pub struct DmmfRequest {
@@ -37,6 +49,7 @@ pub enum CliCommand {
     Dmmf(DmmfRequest),
     GetConfig(GetConfigRequest),
     ExecuteRequest(ExecuteRequest),
+    PreviewRequest(PreviewRequest),
 }
 
 impl CliCommand {
@@ -70,6 +83,14 @@ impl CliCommand {
                 CliOpt::ExecuteRequest(input) => Ok(Some(CliCommand::ExecuteRequest(ExecuteRequest {
                     query: input.query.clone(),
                     enable_raw_queries: opts.enable_raw_queries,
+                    query_log: opts.query_log_config(),
+                    legacy: input.legacy,
+                    datamodel: opts.datamodel(true)?,
+                    config: opts.configuration(false)?.subject,
+                }))),
+                CliOpt::PreviewRequest(input) => Ok(Some(CliCommand::PreviewRequest(PreviewRequest {
+                    query: input.query.clone(),
+                    explain: input.explain,
                     legacy: input.legacy,
                     datamodel: opts.datamodel(true)?,
                     config: opts.configuration(false)?.subject,
@@ -83,6 +104,7 @@ impl CliCommand {
             CliCommand::Dmmf(request) => Self::dmmf(request).await,
             CliCommand::GetConfig(input) => Self::get_config(input.config),
             CliCommand::ExecuteRequest(request) => Self::execute_request(request).await,
+            CliCommand::PreviewRequest(request) => Self::preview_request(request).await,
         }
     }
 
@@ -130,12 +152,13 @@ impl CliCommand {
         )
         .legacy(request.legacy)
         .enable_raw_queries(request.enable_raw_queries)
+        .query_log(request.query_log)
         .build()
         .await?;
         let cx = Arc::new(cx);
 
         let body: GraphQlBody = serde_json::from_str(&decoded_request)?;
-        let res = graphql::handle(body, cx).await;
+        let res = graphql::handle(body, cx, false).await;
         let res = serde_json::to_string(&res).unwrap();
 
         let encoded_response = base64::encode(&res);
@@ -143,4 +166,33 @@ impl CliCommand {
 
         Ok(())
     }
+
+    #[cfg(feature = "sql")]
+    async fn preview_request(request: PreviewRequest) -> PrismaResult<()> {
+        let decoded = base64::decode(&request.query)?;
+        let decoded_request = String::from_utf8(decoded)?;
+
+        let cx = PrismaContext::builder(
+            request.config.validate_that_one_datasource_is_provided()?,
+            request.datamodel,
+        )
+        .legacy(request.legacy)
+        .build()
+        .await?;
+
+        let body: GraphQlBody = serde_json::from_str(&decoded_request)?;
+        let preview = preview::preview(body, &cx, request.explain).await?;
+
+        println!("{}", serde_json::to_string_pretty(&preview)?);
+
+        Ok(())
+    }
+
+    #[cfg(not(feature = "sql"))]
+    async fn preview_request(_: PreviewRequest) -> PrismaResult<()> {
+        Err(crate::PrismaError::UnsupportedFeatureError(
+            "SQL preview",
+            "The query engine was built without SQL connectors.".into(),
+        ))
+    }
 }
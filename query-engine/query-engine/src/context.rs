@@ -1,8 +1,9 @@
-use crate::{exec_loader, PrismaError, PrismaResult};
+use crate::{exec_loader, request_handlers::FindUniqueBatcher, PrismaError, PrismaResult};
+use connector::query_log::QueryLogConfig;
 use datamodel::{Configuration, Datamodel};
 use prisma_models::DatamodelConverter;
 use query_core::{schema::QuerySchemaRef, schema_builder, BuildMode, QueryExecutor};
-use std::{fmt, sync::Arc};
+use std::{fmt, sync::Arc, time::Duration};
 
 /// Prisma request context containing all immutable state of the process.
 /// There is usually only one context initialized per process.
@@ -13,6 +14,10 @@ pub struct PrismaContext {
     dm: Datamodel,
     /// Central query executor.
     pub executor: Box<dyn QueryExecutor + Send + Sync + 'static>,
+    /// Structured query log configuration, if enabled.
+    query_log: Option<Arc<QueryLogConfig>>,
+    /// Merges concurrent `findUnique` requests, if enabled.
+    find_unique_batcher: Option<FindUniqueBatcher>,
 }
 
 impl fmt::Debug for PrismaContext {
@@ -24,6 +29,8 @@ impl fmt::Debug for PrismaContext {
 pub struct ContextBuilder {
     legacy: bool,
     enable_raw_queries: bool,
+    query_log: Option<QueryLogConfig>,
+    find_unique_batch_window: Option<Duration>,
     datamodel: Datamodel,
     config: Configuration,
 }
@@ -39,8 +46,22 @@ impl ContextBuilder {
         self
     }
 
+    pub fn query_log(mut self, val: Option<QueryLogConfig>) -> Self {
+        self.query_log = val;
+        self
+    }
+
+    pub fn find_unique_batch_window(mut self, val: Option<Duration>) -> Self {
+        self.find_unique_batch_window = val;
+        self
+    }
+
     pub async fn build(self) -> PrismaResult<PrismaContext> {
-        PrismaContext::new(self.config, self.datamodel, self.legacy, self.enable_raw_queries).await
+        let mut context = PrismaContext::new(self.config, self.datamodel, self.legacy, self.enable_raw_queries).await?;
+        context.query_log = self.query_log.map(Arc::new);
+        context.find_unique_batcher = self.find_unique_batch_window.map(FindUniqueBatcher::new);
+
+        Ok(context)
     }
 }
 
@@ -74,6 +95,8 @@ impl PrismaContext {
             query_schema,
             dm,
             executor,
+            query_log: None,
+            find_unique_batcher: None,
         };
 
         context.verify_connection().await?;
@@ -90,6 +113,8 @@ impl PrismaContext {
         ContextBuilder {
             legacy: false,
             enable_raw_queries: false,
+            query_log: None,
+            find_unique_batch_window: None,
             datamodel,
             config,
         }
@@ -103,6 +128,14 @@ impl PrismaContext {
         &self.dm
     }
 
+    pub fn query_log(&self) -> Option<&Arc<QueryLogConfig>> {
+        self.query_log.as_ref()
+    }
+
+    pub fn find_unique_batcher(&self) -> Option<&FindUniqueBatcher> {
+        self.find_unique_batcher.as_ref()
+    }
+
     pub fn primary_connector(&self) -> String {
         self.executor.primary_connector().name()
     }
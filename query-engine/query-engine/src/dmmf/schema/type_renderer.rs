@@ -59,6 +59,8 @@ pub(super) fn render_output_type(output_type: &OutputType, ctx: &mut RenderConte
                 ScalarType::Float => "Float",
                 ScalarType::Decimal => "Decimal",
                 ScalarType::DateTime => "DateTime",
+                ScalarType::Date => "Date",
+                ScalarType::Time => "Time",
                 ScalarType::Json => "Json",
                 ScalarType::UUID => "UUID",
                 ScalarType::JsonList => "Json",
@@ -141,6 +143,8 @@ pub(super) fn render_input_type(input_type: &InputType, ctx: &mut RenderContext)
                 ScalarType::Float => "Float",
                 ScalarType::Decimal => "Decimal",
                 ScalarType::DateTime => "DateTime",
+                ScalarType::Date => "Date",
+                ScalarType::Time => "Time",
                 ScalarType::Json => "Json",
                 ScalarType::UUID => "UUID",
                 ScalarType::JsonList => "Json",
@@ -1,6 +1,6 @@
 use crate::{PrismaError, PrismaResult};
 use connection_string::JdbcString;
-use connector::Connector;
+use connector::{Connector, RoutingConnector};
 use std::str::FromStr;
 
 use datamodel::{
@@ -45,9 +45,22 @@ pub async fn load(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExe
     }
 }
 
+#[cfg(feature = "sql")]
+fn ensure_no_replicas(source: &Datasource) -> PrismaResult<()> {
+    if source.replica_urls().is_empty() {
+        Ok(())
+    } else {
+        Err(PrismaError::ConfigurationError(format!(
+            "Read replicas are not supported for the {} connector.",
+            source.active_provider
+        )))
+    }
+}
+
 #[cfg(feature = "sql")]
 async fn sqlite(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
     trace!("Loading SQLite connector...");
+    ensure_no_replicas(source)?;
 
     let sqlite = Sqlite::from_source(source).await?;
     let path = PathBuf::from(sqlite.file_path());
@@ -77,8 +90,17 @@ async fn postgres(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExe
         .and_then(|flag| flag.parse().ok())
         .unwrap_or(false);
 
+    let mut replicas = Vec::with_capacity(source.replica_urls().len());
+
+    for replica_url in source.replica_urls() {
+        let replica = PostgreSql::from_url(&replica_url.value).await?;
+        replicas.push(replica.with_relation_load_strategy(psql.relation_load_strategy()));
+    }
+
+    let connector = RoutingConnector::new(psql, replicas);
+
     trace!("Loaded Postgres connector.");
-    Ok((db_name, sql_executor(psql, force_transactions)))
+    Ok((db_name, sql_executor(connector, force_transactions)))
 }
 
 #[cfg(feature = "sql")]
@@ -97,13 +119,23 @@ async fn mysql(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecut
 
     let db_name = db_name.next().expect(err_str).to_owned();
 
+    let mut replicas = Vec::with_capacity(source.replica_urls().len());
+
+    for replica_url in source.replica_urls() {
+        let replica = Mysql::from_url(&replica_url.value).await?;
+        replicas.push(replica.with_relation_load_strategy(mysql.relation_load_strategy()));
+    }
+
+    let connector = RoutingConnector::new(mysql, replicas);
+
     trace!("Loaded MySQL connector.");
-    Ok((db_name, sql_executor(mysql, false)))
+    Ok((db_name, sql_executor(connector, false)))
 }
 
 #[cfg(feature = "sql")]
 async fn mssql(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
     trace!("Loading SQL Server connector...");
+    ensure_no_replicas(source)?;
 
     let mssql = Mssql::from_source(source).await?;
 
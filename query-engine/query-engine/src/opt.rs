@@ -1,8 +1,9 @@
 use crate::{error::PrismaError, PrismaResult};
+use connector::query_log::{QueryLogConfig, QueryLogTarget};
 use datamodel::diagnostics::ValidatedConfiguration;
 use datamodel::Datamodel;
 use serde::Deserialize;
-use std::{ffi::OsStr, fs::File, io::Read};
+use std::{ffi::OsStr, fs::File, io::Read, time::Duration};
 use structopt::StructOpt;
 
 #[derive(Debug, StructOpt, Clone)]
@@ -20,6 +21,18 @@ pub struct ExecuteRequestInput {
     pub legacy: bool,
 }
 
+#[derive(Debug, Clone, StructOpt)]
+pub struct PreviewRequestInput {
+    /// GraphQL query to preview
+    pub query: String,
+    /// Load the query plans of the statements with `EXPLAIN`
+    #[structopt(long)]
+    pub explain: bool,
+    /// Run in the legacy GraphQL mode
+    #[structopt(long)]
+    pub legacy: bool,
+}
+
 #[derive(Debug, Clone, StructOpt)]
 #[structopt(rename_all = "camelCase")]
 pub struct GetConfigInput {
@@ -35,6 +48,8 @@ pub enum CliOpt {
     GetConfig(GetConfigInput),
     /// Executes one request and then terminates.
     ExecuteRequest(ExecuteRequestInput),
+    /// Prints the SQL statements of one request without running it.
+    PreviewRequest(PreviewRequestInput),
 }
 
 #[derive(Debug, StructOpt, Clone)]
@@ -86,6 +101,24 @@ pub struct PrismaOpt {
     #[structopt(long = "log-format", env = "RUST_LOG_FORMAT")]
     pub log_format: Option<String>,
 
+    /// Enables the structured query log, either written to the log (`log`)
+    /// or returned in the response extensions (`extensions`).
+    #[structopt(long = "query-log", env = "PRISMA_QUERY_LOG", parse(try_from_str = parse_query_log_target))]
+    pub query_log: Option<QueryLogTarget>,
+
+    /// Replaces bound parameters with a placeholder in the query log.
+    #[structopt(long = "query-log-redact-params")]
+    pub query_log_redact_params: bool,
+
+    /// Only log statements taking at least the given amount of milliseconds.
+    #[structopt(long = "slow-query-threshold", env = "PRISMA_SLOW_QUERY_THRESHOLD")]
+    pub slow_query_threshold: Option<u64>,
+
+    /// Merges concurrent `findUnique` requests arriving within the given
+    /// amount of milliseconds into one query.
+    #[structopt(long = "find-unique-batch-window", env = "PRISMA_FIND_UNIQUE_BATCH_WINDOW")]
+    pub find_unique_batch_window: Option<u64>,
+
     #[structopt(subcommand)]
     pub subcommand: Option<Subcommand>,
 
@@ -155,6 +188,20 @@ impl PrismaOpt {
         }
     }
 
+    /// The query log configuration, if the query log is enabled.
+    pub(crate) fn query_log_config(&self) -> Option<QueryLogConfig> {
+        self.query_log.map(|target| QueryLogConfig {
+            target,
+            redact_parameters: self.query_log_redact_params,
+            slow_query_threshold: self.slow_query_threshold.map(Duration::from_millis),
+        })
+    }
+
+    /// The window for merging concurrent `findUnique` requests, if enabled.
+    pub(crate) fn find_unique_batch_window(&self) -> Option<Duration> {
+        self.find_unique_batch_window.map(Duration::from_millis)
+    }
+
     /// The unix path to listen on.
     pub(crate) fn unix_path(&self) -> Option<&String> {
         self.unix_path.as_ref()
@@ -175,6 +222,17 @@ fn parse_base64_string(s: &str) -> PrismaResult<String> {
     }
 }
 
+fn parse_query_log_target(s: &str) -> PrismaResult<QueryLogTarget> {
+    match s {
+        "log" => Ok(QueryLogTarget::Log),
+        "extensions" => Ok(QueryLogTarget::Extensions),
+        _ => Err(PrismaError::ConfigurationError(format!(
+            "Invalid query log target `{}`, expected `log` or `extensions`.",
+            s
+        ))),
+    }
+}
+
 fn load_datamodel_file(path: &OsStr) -> String {
     let mut f = File::open(path).unwrap_or_else(|_| panic!("Could not open datamodel file {:?}", path));
     let mut datamodel = String::new();
@@ -0,0 +1,135 @@
+use super::{handler, GQLResponse};
+use crate::context::PrismaContext;
+use futures::channel::oneshot;
+use query_core::{schema::QuerySchemaRef, Operation, UniqueCriterion};
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// Merges concurrent `findUnique` operations of separate requests into one
+/// `findMany` with an `IN` filter.
+///
+/// The first operation opens a batch, which is executed once `window` has
+/// passed. Every compatible operation arriving in the meantime joins the batch
+/// and receives its response from it. Operations are compatible if they query
+/// the same model with the same selection, by the same unique criterion.
+pub struct FindUniqueBatcher {
+    window: Duration,
+    state: Arc<Mutex<BatcherState>>,
+}
+
+#[derive(Default)]
+struct BatcherState {
+    next_id: usize,
+    batches: Vec<PendingBatch>,
+}
+
+struct PendingBatch {
+    id: usize,
+    criterion: UniqueCriterion,
+    force_primary: bool,
+    operations: Vec<Operation>,
+    senders: Vec<oneshot::Sender<GQLResponse>>,
+}
+
+impl PendingBatch {
+    fn accepts(&self, operation: &Operation, criterion: &UniqueCriterion, force_primary: bool) -> bool {
+        self.force_primary == force_primary
+            && &self.criterion == criterion
+            && self.operations[0].selects_same(operation)
+    }
+}
+
+impl FindUniqueBatcher {
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            state: Arc::new(Mutex::new(BatcherState::default())),
+        }
+    }
+
+    /// Whether the operation is a `findUnique` that can be batched.
+    pub fn accepts(&self, operation: &Operation, schema: &QuerySchemaRef) -> bool {
+        UniqueCriterion::for_operation(operation, schema).is_some()
+    }
+
+    /// Adds the operation to a pending batch, or opens a new one, and waits
+    /// for its response.
+    pub async fn enqueue(&self, operation: Operation, force_primary: bool, cx: Arc<PrismaContext>) -> GQLResponse {
+        let criterion = match UniqueCriterion::for_operation(&operation, cx.query_schema()) {
+            Some(criterion) => criterion,
+            None => return handler::handle_single_query(operation, cx, force_primary).await,
+        };
+
+        let (sender, receiver) = oneshot::channel();
+        let fallback = operation.clone();
+
+        let opened = {
+            let mut state = self.state.lock().unwrap();
+
+            match state
+                .batches
+                .iter_mut()
+                .find(|batch| batch.accepts(&operation, &criterion, force_primary))
+            {
+                Some(batch) => {
+                    batch.operations.push(operation);
+                    batch.senders.push(sender);
+
+                    None
+                }
+                None => {
+                    let id = state.next_id;
+                    state.next_id += 1;
+
+                    state.batches.push(PendingBatch {
+                        id,
+                        criterion,
+                        force_primary,
+                        operations: vec![operation],
+                        senders: vec![sender],
+                    });
+
+                    Some(id)
+                }
+            }
+        };
+
+        // The batch is executed in its own task, so that it completes even if
+        // the request that opened it goes away.
+        if let Some(id) = opened {
+            let state = self.state.clone();
+            let window = self.window;
+            let cx = cx.clone();
+
+            async_std::task::spawn(async move {
+                async_std::task::sleep(window).await;
+
+                let batch = {
+                    let mut state = state.lock().unwrap();
+                    let position = state.batches.iter().position(|batch| batch.id == id);
+
+                    position.map(|position| state.batches.remove(position))
+                };
+
+                if let Some(batch) = batch {
+                    trace!("Executing a batch of {} findUnique operations.", batch.operations.len());
+
+                    let responses = handler::handle_find_unique_batch(batch.operations, force_primary, cx).await;
+
+                    for (sender, response) in batch.senders.into_iter().zip(responses) {
+                        // The receiving request may be gone already.
+                        let _ = sender.send(response);
+                    }
+                }
+            });
+        }
+
+        match receiver.await {
+            Ok(response) => response,
+            // The batch failed without responding, run the operation on its own.
+            Err(_) => handler::handle_single_query(fallback, cx, force_primary).await,
+        }
+    }
+}
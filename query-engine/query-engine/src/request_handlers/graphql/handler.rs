@@ -1,11 +1,12 @@
 use super::{protocol_adapter::GraphQLProtocolAdapter, GQLBatchResponse, GQLResponse};
 use crate::{context::PrismaContext, PrismaResponse, PrismaResult};
+use connector::query_log;
 use futures::FutureExt;
 use graphql_parser as gql;
 use indexmap::IndexMap;
 use query_core::{BatchDocument, CompactedDocument, Item, Operation, QueryDocument, QueryValue, ResponseData};
 use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, panic::AssertUnwindSafe, sync::Arc};
+use std::{collections::HashMap, future::Future, panic::AssertUnwindSafe, sync::Arc};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase", untagged)]
@@ -75,23 +76,104 @@ impl GraphQlBody {
 }
 
 /// Handle a Graphql request.
-pub(crate) async fn handle(body: GraphQlBody, cx: Arc<PrismaContext>) -> PrismaResponse {
+/// With `force_primary` set, reads are never routed to a read replica.
+pub(crate) async fn handle(body: GraphQlBody, cx: Arc<PrismaContext>, force_primary: bool) -> PrismaResponse {
     debug!("Incoming GraphQL query: {:?}", body);
 
+    let (mut response, query_log) = with_query_log(&cx, handle_body(body, cx.clone(), force_primary)).await;
+
+    if let Some(events) = query_log {
+        response.insert_extension("queryLog", events);
+    }
+
+    response
+}
+
+/// Runs `fut` in a query log scope if the query log is enabled, returning the
+/// recorded events, if any.
+async fn with_query_log<T>(cx: &PrismaContext, fut: impl Future<Output = T>) -> (T, Option<serde_json::Value>) {
+    match cx.query_log() {
+        Some(config) => {
+            let (result, events) = query_log::scope(config.clone(), fut).await;
+
+            if events.is_empty() {
+                (result, None)
+            } else {
+                let events = serde_json::to_value(events).expect("Query log events are serializable.");
+                (result, Some(events))
+            }
+        }
+        None => (fut.await, None),
+    }
+}
+
+async fn handle_body(body: GraphQlBody, cx: Arc<PrismaContext>, force_primary: bool) -> PrismaResponse {
     match body.into_doc() {
-        Ok(QueryDocument::Single(query)) => handle_single_query(query, cx.clone()).await,
-        Ok(QueryDocument::Multi(batch)) => match batch.compact() {
-            BatchDocument::Multi(batch, transactional) => handle_batch(batch, transactional, &cx).await,
-            BatchDocument::Compact(compacted) => handle_compacted(compacted, &cx).await,
+        Ok(QueryDocument::Single(query)) => match cx.find_unique_batcher() {
+            Some(batcher) if batcher.accepts(&query, cx.query_schema()) => {
+                PrismaResponse::Single(batcher.enqueue(query, force_primary, cx.clone()).await)
+            }
+            _ => PrismaResponse::Single(handle_single_query(query, cx.clone(), force_primary).await),
+        },
+        Ok(QueryDocument::Multi(batch)) => match batch.compact(cx.query_schema()) {
+            BatchDocument::Multi(batch, transactional) => {
+                PrismaResponse::Multi(handle_batch(batch, transactional, force_primary, &cx).await)
+            }
+            BatchDocument::Compact(compacted) => {
+                PrismaResponse::Multi(handle_compacted(compacted, force_primary, &cx).await)
+            }
         },
         Err(err) => PrismaResponse::Single(err.into()),
     }
 }
 
-async fn handle_single_query(query: Operation, ctx: Arc<PrismaContext>) -> PrismaResponse {
+/// Executes `findUnique` operations of separate requests merged by the
+/// `FindUniqueBatcher`, returning one response per operation.
+///
+/// The statements of a merged batch serve all of its operations, so every
+/// response carries the query log events of the whole batch.
+pub(super) async fn handle_find_unique_batch(
+    operations: Vec<Operation>,
+    force_primary: bool,
+    cx: Arc<PrismaContext>,
+) -> Vec<GQLResponse> {
+    let count = operations.len();
+
+    if count == 1 {
+        let operation = operations.into_iter().next().unwrap();
+        let (mut response, query_log) =
+            with_query_log(&cx, handle_single_query(operation, cx.clone(), force_primary)).await;
+
+        if let Some(events) = query_log {
+            response.insert_extension("queryLog", events);
+        }
+
+        return vec![response];
+    }
+
+    let execute = async {
+        match BatchDocument::new(operations, false).compact_unique_lookups(cx.query_schema()) {
+            BatchDocument::Compact(compacted) => handle_compacted(compacted, force_primary, &cx).await,
+            BatchDocument::Multi(batch, transactional) => handle_batch(batch, transactional, force_primary, &cx).await,
+        }
+    };
+
+    let (response, query_log) = with_query_log(&cx, execute).await;
+    let mut responses = response.into_responses(count);
+
+    if let Some(events) = query_log {
+        for response in responses.iter_mut() {
+            response.insert_extension("queryLog", events.clone());
+        }
+    }
+
+    responses
+}
+
+pub(super) async fn handle_single_query(query: Operation, ctx: Arc<PrismaContext>, force_primary: bool) -> GQLResponse {
     use user_facing_errors::Error;
 
-    let gql_response = match AssertUnwindSafe(handle_graphql_query(query, &*ctx))
+    match AssertUnwindSafe(handle_graphql_query(query, force_primary, &*ctx))
         .catch_unwind()
         .await
     {
@@ -102,18 +184,23 @@ async fn handle_single_query(query: Operation, ctx: Arc<PrismaContext>) -> Prism
             let error = Error::from_panic_payload(&err);
             error.into()
         }
-    };
-
-    PrismaResponse::Single(gql_response)
+    }
 }
 
-async fn handle_batch(queries: Vec<Operation>, transactional: bool, ctx: &Arc<PrismaContext>) -> PrismaResponse {
+async fn handle_batch(
+    queries: Vec<Operation>,
+    transactional: bool,
+    force_primary: bool,
+    ctx: &Arc<PrismaContext>,
+) -> GQLBatchResponse {
     use user_facing_errors::Error;
 
-    match AssertUnwindSafe(
-        ctx.executor
-            .execute_batch(queries, transactional, ctx.query_schema().clone()),
-    )
+    match AssertUnwindSafe(ctx.executor.execute_batch(
+        queries,
+        transactional,
+        force_primary,
+        ctx.query_schema().clone(),
+    ))
     .catch_unwind()
     .await
     {
@@ -126,20 +213,22 @@ async fn handle_batch(queries: Vec<Operation>, transactional: bool, ctx: &Arc<Pr
                 })
                 .collect();
 
-            PrismaResponse::Multi(gql_responses.into())
+            gql_responses.into()
         }
-        Ok(Err(err)) => PrismaResponse::Multi(err.into()),
+        Ok(Err(err)) => err.into(),
         Err(err) => {
             // panicked
             let error = Error::from_panic_payload(&err);
-            let resp: GQLBatchResponse = error.into();
-
-            PrismaResponse::Multi(resp)
+            error.into()
         }
     }
 }
 
-async fn handle_compacted(document: CompactedDocument, ctx: &Arc<PrismaContext>) -> PrismaResponse {
+async fn handle_compacted(
+    document: CompactedDocument,
+    force_primary: bool,
+    ctx: &Arc<PrismaContext>,
+) -> GQLBatchResponse {
     use user_facing_errors::Error;
 
     let plural_name = document.plural_name();
@@ -148,7 +237,7 @@ async fn handle_compacted(document: CompactedDocument, ctx: &Arc<PrismaContext>)
     let arguments = document.arguments;
     let nested_selection = document.nested_selection;
 
-    match AssertUnwindSafe(handle_graphql_query(document.operation, ctx))
+    match AssertUnwindSafe(handle_graphql_query(document.operation, force_primary, ctx))
         .catch_unwind()
         .await
     {
@@ -193,19 +282,26 @@ async fn handle_compacted(document: CompactedDocument, ctx: &Arc<PrismaContext>)
                 })
                 .collect();
 
-            PrismaResponse::Multi(results.into())
+            results.into()
         }
 
-        Ok(Err(err)) => PrismaResponse::Multi(err.into()),
+        Ok(Err(err)) => err.into(),
 
         // panicked
         Err(err) => {
             let error = Error::from_panic_payload(&err);
-            PrismaResponse::Multi(error.into())
+            error.into()
         }
     }
 }
 
-async fn handle_graphql_query(query_doc: Operation, ctx: &PrismaContext) -> PrismaResult<ResponseData> {
-    Ok(ctx.executor.execute(query_doc, Arc::clone(ctx.query_schema())).await?)
+async fn handle_graphql_query(
+    query_doc: Operation,
+    force_primary: bool,
+    ctx: &PrismaContext,
+) -> PrismaResult<ResponseData> {
+    Ok(ctx
+        .executor
+        .execute(query_doc, force_primary, Arc::clone(ctx.query_schema()))
+        .await?)
 }
@@ -1,8 +1,10 @@
+mod batcher;
 mod handler;
 mod protocol_adapter;
 mod response;
 mod schema_renderer;
 
+pub use batcher::*;
 pub use handler::*;
 pub use protocol_adapter::*;
 pub use response::*;
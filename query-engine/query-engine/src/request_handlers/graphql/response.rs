@@ -14,6 +14,9 @@ pub struct GQLResponse {
 
     #[serde(skip_serializing_if = "Vec::is_empty")]
     errors: Vec<GQLError>,
+
+    #[serde(skip_serializing_if = "IndexMap::is_empty")]
+    extensions: IndexMap<String, serde_json::Value>,
 }
 #[derive(Debug, serde::Serialize, Default, PartialEq)]
 #[serde(rename_all = "camelCase")]
@@ -23,9 +26,12 @@ pub struct GQLBatchResponse {
 
     #[serde(skip_serializing_if = "Vec::is_empty")]
     errors: Vec<GQLError>,
+
+    #[serde(skip_serializing_if = "IndexMap::is_empty")]
+    extensions: IndexMap<String, serde_json::Value>,
 }
 
-#[derive(Debug, serde::Serialize, PartialEq)]
+#[derive(Debug, serde::Serialize, PartialEq, Clone)]
 pub struct GQLError {
     error: String,
     user_facing_error: user_facing_errors::Error,
@@ -52,6 +58,10 @@ impl GQLResponse {
     pub fn take_data(&mut self, key: impl AsRef<str>) -> Option<Item> {
         self.data.remove(key.as_ref())
     }
+
+    pub fn insert_extension(&mut self, key: impl Into<String>, value: serde_json::Value) {
+        self.extensions.insert(key.into(), value);
+    }
 }
 
 impl From<PrismaError> for GQLResponse {
@@ -135,6 +145,25 @@ impl GQLBatchResponse {
     pub fn insert_error(&mut self, error: impl Into<GQLError>) {
         self.errors.push(error.into());
     }
+
+    pub fn insert_extension(&mut self, key: impl Into<String>, value: serde_json::Value) {
+        self.extensions.insert(key.into(), value);
+    }
+
+    /// Splits the batch into the responses of its `count` operations. Errors
+    /// of the whole batch are returned for every operation.
+    pub fn into_responses(self, count: usize) -> Vec<GQLResponse> {
+        if self.errors.is_empty() {
+            return self.batch_result;
+        }
+
+        (0..count)
+            .map(|_| GQLResponse {
+                errors: self.errors.clone(),
+                ..Default::default()
+            })
+            .collect()
+    }
 }
 
 impl From<user_facing_errors::Error> for GQLBatchResponse {
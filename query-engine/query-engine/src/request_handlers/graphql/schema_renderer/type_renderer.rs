@@ -48,6 +48,8 @@ impl<'a> GqlTypeRenderer<'a> {
                     ScalarType::Float => "Float",
                     ScalarType::Decimal => "Decimal",
                     ScalarType::DateTime => "DateTime",
+                    ScalarType::Date => "Date",
+                    ScalarType::Time => "Time",
                     ScalarType::Json => "DateTime",
                     ScalarType::UUID => "UUID",
                     ScalarType::JsonList => "Json",
@@ -94,6 +96,8 @@ impl<'a> GqlTypeRenderer<'a> {
                     ScalarType::Float => "Float",
                     ScalarType::Decimal => "Decimal",
                     ScalarType::DateTime => "DateTime",
+                    ScalarType::Date => "Date",
+                    ScalarType::Time => "Time",
                     ScalarType::Json => "Json",
                     ScalarType::UUID => "UUID",
                     ScalarType::JsonList => "Json",
@@ -1,4 +1,6 @@
 pub mod graphql;
+#[cfg(feature = "sql")]
+pub mod preview;
 
 pub use graphql::*;
 pub use query_core::{response_ir, schema::QuerySchemaRenderer};
@@ -11,3 +13,12 @@ pub enum PrismaResponse {
     Single(GQLResponse),
     Multi(GQLBatchResponse),
 }
+
+impl PrismaResponse {
+    pub fn insert_extension(&mut self, key: impl Into<String>, value: serde_json::Value) {
+        match self {
+            Self::Single(response) => response.insert_extension(key, value),
+            Self::Multi(response) => response.insert_extension(key, value),
+        }
+    }
+}
@@ -0,0 +1,222 @@
+//! Previews the SQL statements of a GraphQL operation without running it.
+use super::GraphQlBody;
+use crate::{context::PrismaContext, PrismaError, PrismaResult};
+use connector::{filter::Filter, Connection, QueryArguments, ReadOperations, RelationLoadStrategy, WriteOperations};
+use prisma_models::{ModelProjection, ModelRef, PrismaValue};
+use query_core::{CoreError, Node, Query, QueryDocument, QueryGraphBuilder, ReadQuery};
+use serde::Serialize;
+use sql_connector::{RenderedStatement, SqlRenderer};
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryPreview {
+    /// The query graph of the operation, as rendered by its formatter.
+    pub query_graph: String,
+
+    /// The statements of the reads in the graph, in the order of their nodes.
+    pub statements: Vec<PreviewStatement>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PreviewStatement {
+    /// The id of the graph node the read belongs to.
+    pub node: String,
+
+    /// The name of the read, e.g. `findManyUser` or the name of a relation field.
+    pub query: String,
+
+    /// Nested reads are additionally restricted to the records of their
+    /// parent when running the operation.
+    pub nested: bool,
+
+    pub sql: String,
+    pub params: Vec<PrismaValue>,
+
+    /// The query plan, if requested and supported by the database.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plan: Option<serde_json::Value>,
+}
+
+/// Builds the query graph of the operation and renders the statements of its
+/// reads as the connector runs them, e.g. with a single statement when it
+/// loads relations with joins. With `explain` set, the query plans of the
+/// statements are loaded in a transaction that is rolled back afterwards.
+pub(crate) async fn preview(body: GraphQlBody, cx: &PrismaContext, explain: bool) -> PrismaResult<QueryPreview> {
+    let operation = match body.into_doc()? {
+        QueryDocument::Single(operation) => operation,
+        QueryDocument::Multi(_) => {
+            return Err(PrismaError::InvocationError(
+                "Batched operations can't be previewed.".into(),
+            ));
+        }
+    };
+
+    let connector_name = cx.primary_connector();
+    let renderer = SqlRenderer::for_connector(&connector_name).ok_or_else(|| {
+        PrismaError::UnsupportedFeatureError(
+            "SQL preview",
+            format!("The {} connector doesn't render SQL statements.", connector_name),
+        )
+    })?;
+
+    let (query_graph, _) = QueryGraphBuilder::new(cx.query_schema().clone())
+        .build(operation)
+        .map_err(CoreError::from)?;
+
+    let conn = cx.executor.primary_connector().get_connection().await?;
+    let mut statements = Vec::new();
+
+    for node in query_graph.nodes() {
+        if let Some(Node::Query(Query::Read(read))) = query_graph.node_content(&node) {
+            render_read(&renderer, conn.as_ref(), &node.id(), read, false, &mut statements)?;
+        }
+    }
+
+    if explain {
+        explain_statements(&renderer, conn.as_ref(), &mut statements).await?;
+    }
+
+    Ok(QueryPreview {
+        query_graph: query_graph.to_string(),
+        statements,
+    })
+}
+
+fn render_read(
+    renderer: &SqlRenderer,
+    conn: &dyn Connection,
+    node: &str,
+    read: &ReadQuery,
+    nested: bool,
+    statements: &mut Vec<PreviewStatement>,
+) -> PrismaResult<()> {
+    let (rendered, nested_reads) = match read {
+        ReadQuery::RecordQuery(q) => {
+            let filter = q.filter.clone().unwrap_or_else(Filter::empty);
+            let mut args = QueryArguments::from((q.model.clone(), filter.clone()));
+            args.take = Some(1);
+
+            match render_joined(
+                renderer,
+                conn,
+                &q.model,
+                args,
+                &q.selected_fields,
+                &q.nested,
+                q.relation_load_strategy,
+            )? {
+                Some(statement) => (vec![statement], &[][..]),
+                None => {
+                    let statement = renderer.single_record(&q.model, &filter, &q.selected_fields)?;
+                    (vec![statement], q.nested.as_slice())
+                }
+            }
+        }
+        ReadQuery::ManyRecordsQuery(q) => {
+            let inmemory =
+                q.args.requires_inmemory_processing() || (q.args.distinct.is_some() && !conn.supports_distinct());
+
+            let joined = if inmemory {
+                None
+            } else {
+                render_joined(
+                    renderer,
+                    conn,
+                    &q.model,
+                    q.args.clone(),
+                    &q.selected_fields,
+                    &q.nested,
+                    q.relation_load_strategy,
+                )?
+            };
+
+            match joined {
+                Some(statement) => (vec![statement], &[][..]),
+                None => (
+                    renderer.many_records(&q.model, q.args.clone(), &q.selected_fields)?,
+                    q.nested.as_slice(),
+                ),
+            }
+        }
+        ReadQuery::RelatedRecordsQuery(q) => (
+            renderer.many_records(&q.parent_field.related_model(), q.args.clone(), &q.selected_fields)?,
+            q.nested.as_slice(),
+        ),
+        ReadQuery::AggregateRecordsQuery(q) => {
+            let group_by = q.group_by.clone();
+            let statement = renderer.aggregate(&q.model, q.args.clone(), &q.selectors, group_by, q.having.clone())?;
+
+            (vec![statement], &[][..])
+        }
+    };
+
+    statements.extend(
+        rendered
+            .into_iter()
+            .map(|RenderedStatement { sql, params }| PreviewStatement {
+                node: node.to_owned(),
+                query: read.name().to_owned(),
+                nested,
+                sql,
+                params,
+                plan: None,
+            }),
+    );
+
+    for nested_read in nested_reads {
+        render_read(renderer, conn, node, nested_read, true, statements)?;
+    }
+
+    Ok(())
+}
+
+/// The single statement reading records together with their nested reads,
+/// if the connector loads them with joins.
+fn render_joined(
+    renderer: &SqlRenderer,
+    conn: &dyn Connection,
+    model: &ModelRef,
+    args: QueryArguments,
+    selected_fields: &ModelProjection,
+    nested: &[ReadQuery],
+    strategy: Option<RelationLoadStrategy>,
+) -> PrismaResult<Option<RenderedStatement>> {
+    if nested.is_empty() || !conn.joins_relations(strategy) {
+        return Ok(None);
+    }
+
+    match ReadQuery::relation_loads(nested) {
+        Some(relations) => Ok(renderer.many_records_with_relations(model, args, selected_fields, &relations)?),
+        None => Ok(None),
+    }
+}
+
+async fn explain_statements(
+    renderer: &SqlRenderer,
+    conn: &dyn Connection,
+    statements: &mut [PreviewStatement],
+) -> PrismaResult<()> {
+    let tx = conn.start_transaction().await?;
+
+    for statement in statements.iter_mut() {
+        let rendered = RenderedStatement {
+            sql: statement.sql.clone(),
+            params: statement.params.clone(),
+        };
+
+        if let Some(explain) = renderer.explain(&rendered) {
+            match tx.query_raw(explain.sql, explain.params).await {
+                Ok(plan) => statement.plan = Some(plan),
+                Err(err) => {
+                    tx.rollback().await?;
+                    return Err(err.into());
+                }
+            }
+        }
+    }
+
+    tx.rollback().await?;
+
+    Ok(())
+}
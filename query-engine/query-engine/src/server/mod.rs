@@ -4,6 +4,8 @@ use crate::context::PrismaContext;
 use crate::dmmf;
 use crate::opt::PrismaOpt;
 use crate::request_handlers::graphql::{self, GraphQLSchemaRenderer, GraphQlBody};
+#[cfg(feature = "sql")]
+use crate::request_handlers::preview;
 use crate::PrismaResult;
 use elapsed_middleware::ElapsedMiddleware;
 
@@ -56,6 +58,8 @@ pub async fn listen(opts: PrismaOpt) -> PrismaResult<()> {
     let cx = PrismaContext::builder(config, datamodel)
         .legacy(opts.legacy)
         .enable_raw_queries(opts.enable_raw_queries)
+        .query_log(opts.query_log_config())
+        .find_unique_batch_window(opts.find_unique_batch_window())
         .build()
         .await?;
 
@@ -70,6 +74,8 @@ pub async fn listen(opts: PrismaOpt) -> PrismaResult<()> {
     app.at("/").get(playground_handler);
     app.at("/sdl").get(sdl_handler);
     app.at("/dmmf").get(dmmf_handler);
+    #[cfg(feature = "sql")]
+    app.at("/preview").post(preview_handler);
     app.at("/server_info").get(server_info_handler);
     app.at("/status").get(|_| async move { Ok(json!({"status": "ok"})) });
 
@@ -94,9 +100,13 @@ async fn graphql_handler(mut req: Request<State>) -> tide::Result {
         }
     }
 
+    /// Header that makes all reads of the request go to the primary database.
+    static FORCE_PRIMARY_HEADER: &str = "x-prisma-force-primary";
+
+    let force_primary = req.header(FORCE_PRIMARY_HEADER).is_some();
     let body: GraphQlBody = req.body_json().await?;
     let cx = req.state().cx.clone();
-    let result = graphql::handle(body, cx).await;
+    let result = graphql::handle(body, cx, force_primary).await;
     let mut res = Response::new(StatusCode::Ok);
     res.set_body(Body::from_json(&result)?);
     Ok(res)
@@ -135,6 +145,35 @@ async fn dmmf_handler(req: Request<State>) -> tide::Result {
     Ok(res)
 }
 
+/// Renders the SQL statements of a GraphQL operation without running it.
+/// With `?explain=true`, the query plans of the statements are loaded in a
+/// transaction that is rolled back afterwards. Only available in debug mode.
+#[cfg(feature = "sql")]
+async fn preview_handler(mut req: Request<State>) -> tide::Result {
+    #[derive(Deserialize)]
+    struct PreviewParams {
+        #[serde(default)]
+        explain: bool,
+    }
+
+    if !req.state().enable_debug_mode {
+        return Ok(Response::new(StatusCode::NotFound));
+    }
+
+    let params: PreviewParams = req.query()?;
+    let body: GraphQlBody = req.body_json().await?;
+    let cx = req.state().cx.clone();
+
+    let mut res = Response::new(StatusCode::Ok);
+
+    match preview::preview(body, &cx, params.explain).await {
+        Ok(preview) => res.set_body(Body::from_json(&preview)?),
+        Err(err) => res.set_body(Body::from_json(&graphql::GQLResponse::from(err))?),
+    }
+
+    Ok(res)
+}
+
 /// Simple status endpoint
 async fn server_info_handler(req: Request<State>) -> tide::Result<impl Into<Response>> {
     Ok(json!({
@@ -0,0 +1,172 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static ORDERS: &str = indoc! {r#"
+    model Order {
+        id       Int    @id
+        customer String
+        category String
+        amount   Int
+    }
+"#};
+
+async fn seed(query_engine: &QueryEngine) {
+    let orders = &[
+        (1, "alice", "books", 2),
+        (2, "bob", "toys", 4),
+        (3, "bob", "toys", 4),
+        (4, "carol", "books", 4),
+        (5, "carol", "books", 5),
+        (6, "dave", "games", 5),
+        (7, "erin", "games", 7),
+        (8, "erin", "games", 9),
+    ];
+
+    for (id, customer, category, amount) in orders {
+        let mutation = format!(
+            r#"mutation {{ createOneOrder(data: {{ id: {}, customer: "{}", category: "{}", amount: {} }}) {{ id }} }}"#,
+            id, customer, category, amount
+        );
+
+        query_engine.request(mutation).await;
+    }
+}
+
+#[test_each_connector]
+async fn count_distinct_values(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&ORDERS).await?;
+    seed(&query_engine).await;
+
+    let response = query_engine
+        .request("query { aggregateOrder { countDistinct { customer category amount } } }")
+        .await;
+
+    assert_eq!(
+        json!({ "countDistinct": { "customer": 5, "category": 3, "amount": 5 } }),
+        response["data"]["aggregateOrder"]
+    );
+
+    let response = query_engine
+        .request(indoc! {r#"
+            query {
+                groupByOrder(by: [category], orderBy: { category: asc }) {
+                    category
+                    countDistinct { customer }
+                }
+            }
+        "#})
+        .await;
+
+    assert_eq!(
+        json!([
+            { "category": "books", "countDistinct": { "customer": 2 } },
+            { "category": "games", "countDistinct": { "customer": 2 } },
+            { "category": "toys", "countDistinct": { "customer": 1 } },
+        ]),
+        response["data"]["groupByOrder"]
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn string_agg_concatenates_values(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&ORDERS).await?;
+    seed(&query_engine).await;
+
+    // Each customer orders from a single category, the order of the values is up to the database.
+    let response = query_engine
+        .request(indoc! {r#"
+            query {
+                groupByOrder(by: [customer], orderBy: { customer: asc }) {
+                    customer
+                    stringAgg { category }
+                }
+            }
+        "#})
+        .await;
+
+    assert_eq!(
+        json!([
+            { "customer": "alice", "stringAgg": { "category": "books" } },
+            { "customer": "bob", "stringAgg": { "category": "toys,toys" } },
+            { "customer": "carol", "stringAgg": { "category": "books,books" } },
+            { "customer": "dave", "stringAgg": { "category": "games" } },
+            { "customer": "erin", "stringAgg": { "category": "games,games" } },
+        ]),
+        response["data"]["groupByOrder"]
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(ignore("sqlite"))]
+async fn stddev_and_variance(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&ORDERS).await?;
+    seed(&query_engine).await;
+
+    let response = query_engine
+        .request("query { aggregateOrder { stddev { amount } variance { amount } } }")
+        .await;
+
+    assert_eq!(
+        json!({ "stddev": { "amount": 2.0 }, "variance": { "amount": 4.0 } }),
+        response["data"]["aggregateOrder"]
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn median_interpolates_even_counts(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&ORDERS).await?;
+    seed(&query_engine).await;
+
+    let response = query_engine
+        .request("query { aggregateOrder { median { amount } } }")
+        .await;
+
+    assert_eq!(
+        json!({ "median": { "amount": 4.5 } }),
+        response["data"]["aggregateOrder"]
+    );
+
+    let response = query_engine
+        .request(indoc! {r#"
+            query {
+                groupByOrder(by: [category], orderBy: { category: asc }) {
+                    category
+                    median { amount }
+                }
+            }
+        "#})
+        .await;
+
+    assert_eq!(
+        json!([
+            { "category": "books", "median": { "amount": 4.0 } },
+            { "category": "games", "median": { "amount": 7.0 } },
+            { "category": "toys", "median": { "amount": 4.0 } },
+        ]),
+        response["data"]["groupByOrder"]
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn unsupported_functions_are_not_in_the_schema(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&ORDERS).await?;
+
+    for function in &["median", "stddev", "variance"] {
+        let query = format!("query {{ aggregateOrder {{ {} {{ amount }} }} }}", function);
+        let response = query_engine.request(query).await;
+
+        let message = response["errors"][0]["error"].as_str().unwrap();
+        assert!(message.contains(function), "{}", message);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static REMINDERS: &str = indoc! {"
+    model Reminder {
+        id    Int  @id
+        day   Date
+        alarm Time
+    }
+"};
+
+#[test_each_connector(ignore("sqlite"))]
+async fn dates_and_times_are_returned_without_the_other_part(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&REMINDERS).await?;
+
+    let response = query_engine
+        .request(indoc! {r#"
+            mutation {
+                createOneReminder(data: { id: 1, day: "2020-01-31", alarm: "13:30:00" }) { day alarm }
+            }
+        "#})
+        .await;
+
+    assert_eq!(
+        json!({ "day": "2020-01-31", "alarm": "13:30:00" }),
+        response["data"]["createOneReminder"]
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(ignore("sqlite"))]
+async fn dates_and_times_can_be_filtered_and_ordered(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&REMINDERS).await?;
+
+    let reminders = &[
+        (1, "2020-03-01", "07:00:00"),
+        (2, "2020-01-31", "13:30:00"),
+        (3, "2020-02-29", "06:15:00"),
+    ];
+
+    for (id, day, alarm) in reminders {
+        let mutation = format!(
+            r#"mutation {{ createOneReminder(data: {{ id: {}, day: "{}", alarm: "{}" }}) {{ id }} }}"#,
+            id, day, alarm
+        );
+
+        query_engine.request(mutation).await;
+    }
+
+    let response = query_engine
+        .request(r#"query { findManyReminder(where: { day: { gt: "2020-01-31" } }, orderBy: { alarm: asc }) { id } }"#)
+        .await;
+
+    assert_eq!(json!([{ "id": 3 }, { "id": 1 }]), response["data"]["findManyReminder"]);
+
+    Ok(())
+}
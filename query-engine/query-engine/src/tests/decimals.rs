@@ -0,0 +1,61 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static ACCOUNTS: &str = indoc! {"
+    model Account {
+        id      Int     @id
+        balance Decimal
+    }
+"};
+
+#[test_each_connector(ignore("sqlite"))]
+async fn decimals_are_returned_as_strings_without_losing_precision(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&ACCOUNTS).await?;
+
+    let response = query_engine
+        .request(r#"mutation { createOneAccount(data: { id: 1, balance: "12345678901234.56" }) { balance } }"#)
+        .await;
+
+    assert_eq!(
+        json!("12345678901234.56"),
+        response["data"]["createOneAccount"]["balance"]
+    );
+
+    let response = query_engine
+        .request(r#"query { findManyAccount(where: { balance: { equals: "12345678901234.56" } }) { id balance } }"#)
+        .await;
+
+    assert_eq!(
+        json!([{ "id": 1, "balance": "12345678901234.56" }]),
+        response["data"]["findManyAccount"]
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(ignore("sqlite"))]
+async fn decimal_aggregates_keep_their_precision(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&ACCOUNTS).await?;
+
+    for (id, balance) in &[(1, "12345678901234.56"), (2, "12345678901234.58")] {
+        let mutation = format!(
+            r#"mutation {{ createOneAccount(data: {{ id: {}, balance: "{}" }}) {{ id }} }}"#,
+            id, balance
+        );
+
+        query_engine.request(mutation).await;
+    }
+
+    let response = query_engine
+        .request("query { aggregateAccount { sum { balance } avg { balance } } }")
+        .await;
+
+    assert_eq!(
+        json!({ "sum": { "balance": "24691357802469.14" }, "avg": { "balance": "12345678901234.57" } }),
+        response["data"]["aggregateAccount"]
+    );
+
+    Ok(())
+}
@@ -0,0 +1,154 @@
+use super::test_api::*;
+use connector::query_log::{QueryLogConfig, QueryLogTarget};
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static SCORES: &str = indoc! {r#"
+    model Score {
+        id     Int    @id
+        player String
+        points Int
+    }
+"#};
+
+async fn seed(query_engine: &QueryEngine) {
+    let scores = &[
+        (1, "anna", 30),
+        (2, "bert", 50),
+        (3, "anna", 70),
+        (4, "carl", 10),
+        (5, "bert", 20),
+        (6, "dora", 40),
+    ];
+
+    for (id, player, points) in scores {
+        let mutation = format!(
+            r#"mutation {{ createOneScore(data: {{ id: {}, player: "{}", points: {} }}) {{ id }} }}"#,
+            id, player, points
+        );
+
+        query_engine.request(mutation).await;
+    }
+}
+
+#[test_each_connector]
+async fn distinct_keeps_the_first_record_in_the_order_of_the_query(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&SCORES).await?;
+    seed(&query_engine).await;
+
+    let response = query_engine
+        .request(r#"query { findManyScore(distinct: [player], orderBy: { points: desc }) { id player } }"#)
+        .await;
+
+    assert_eq!(
+        json!([
+            { "id": 3, "player": "anna" },
+            { "id": 2, "player": "bert" },
+            { "id": 6, "player": "dora" },
+            { "id": 4, "player": "carl" },
+        ]),
+        response["data"]["findManyScore"]
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn distinct_applies_skip_and_take_to_the_distinct_records(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&SCORES).await?;
+    seed(&query_engine).await;
+
+    let response = query_engine
+        .request(indoc! {r#"
+            query {
+                findManyScore(distinct: [player], orderBy: { points: asc }, skip: 1, take: 2) {
+                    id
+                }
+            }
+        "#})
+        .await;
+
+    // Distinct by ascending points: carl (4), bert (5), anna (1), dora (6).
+    assert_eq!(json!([{ "id": 5 }, { "id": 1 }]), response["data"]["findManyScore"]);
+
+    let response = query_engine
+        .request(r#"query { findManyScore(distinct: [player], orderBy: { id: asc }, take: -2) { id } }"#)
+        .await;
+
+    // Distinct by ascending ids: 1, 2, 4, 6.
+    assert_eq!(json!([{ "id": 4 }, { "id": 6 }]), response["data"]["findManyScore"]);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn distinct_applies_to_filtered_records(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&SCORES).await?;
+    seed(&query_engine).await;
+
+    let response = query_engine
+        .request(indoc! {r#"
+            query {
+                findManyScore(where: { points: { lt: 60 } }, distinct: [player], orderBy: { points: desc }) {
+                    id
+                }
+            }
+        "#})
+        .await;
+
+    // Anna's 70 points are filtered out before picking her first score.
+    assert_eq!(
+        json!([{ "id": 2 }, { "id": 6 }, { "id": 1 }, { "id": 4 }]),
+        response["data"]["findManyScore"]
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "sqlite", "mssql", "mysql_8"))]
+async fn distinct_is_applied_in_the_database(api: &TestApi) -> anyhow::Result<()> {
+    let config = QueryLogConfig::new(QueryLogTarget::Extensions);
+    let query_engine = api.create_engine_with_query_log(&SCORES, Some(config)).await?;
+    seed(&query_engine).await;
+
+    let response = query_engine
+        .request(r#"query { findManyScore(distinct: [player], orderBy: { id: asc }, take: 2) { id } }"#)
+        .await;
+
+    assert_eq!(json!([{ "id": 1 }, { "id": 2 }]), response["data"]["findManyScore"]);
+
+    let events = response["extensions"]["queryLog"].as_array().unwrap();
+    let query = events[0]["query"].as_str().unwrap();
+
+    assert_eq!(1, events.len());
+    assert!(
+        query.contains("ROW_NUMBER") || query.contains("DISTINCT ON"),
+        "{}",
+        query
+    );
+
+    Ok(())
+}
+
+/// MySQL 5.x has no window functions, distinct is applied in memory.
+#[test_each_connector(tags("mysql"), ignore("mysql_8", "mariadb"))]
+async fn distinct_is_applied_in_memory_without_window_functions(api: &TestApi) -> anyhow::Result<()> {
+    let config = QueryLogConfig::new(QueryLogTarget::Extensions);
+    let query_engine = api.create_engine_with_query_log(&SCORES, Some(config)).await?;
+    seed(&query_engine).await;
+
+    let response = query_engine
+        .request(r#"query { findManyScore(distinct: [player], orderBy: { id: asc }, take: 2) { id } }"#)
+        .await;
+
+    assert_eq!(json!([{ "id": 1 }, { "id": 2 }]), response["data"]["findManyScore"]);
+
+    let events = response["extensions"]["queryLog"].as_array().unwrap();
+
+    for event in events {
+        assert!(!event["query"].as_str().unwrap().contains("ROW_NUMBER"));
+    }
+
+    Ok(())
+}
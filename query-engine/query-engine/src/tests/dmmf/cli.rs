@@ -85,6 +85,10 @@ fn test_dmmf_cli_command(schema: &str) -> PrismaResult<()> {
         log_format: None,
         overwrite_datasources: None,
         port: 123,
+        query_log: None,
+        query_log_redact_params: false,
+        slow_query_threshold: None,
+        find_unique_batch_window: None,
         raw_feature_flags: vec![],
         unix_path: None,
         subcommand: Some(Subcommand::Cli(CliOpt::Dmmf)),
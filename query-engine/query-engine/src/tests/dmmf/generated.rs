@@ -0,0 +1,45 @@
+use super::helpers::*;
+use query_core::PRISMA_NAMESPACE;
+use serial_test::serial;
+use std::sync::Arc;
+
+// Tests in this file run serially because the function `get_query_schema` depends on setting an env var.
+
+#[test]
+#[serial]
+fn generated_fields_should_be_filtered_from_input_types() {
+    let dm = r#"
+        datasource pg {
+            provider = "postgresql"
+            url = "postgresql://"
+        }
+
+        model Order {
+            id       Int @id
+            price    Int
+            quantity Int
+            total    Int @generated("price * quantity")
+        }
+    "#;
+    let (query_schema, datamodel) = get_query_schema(dm);
+    let dmmf = crate::dmmf::render_dmmf(&datamodel, Arc::new(query_schema));
+    let write_inputs = &[
+        "OrderCreateInput",
+        "OrderUncheckedCreateInput",
+        "OrderCreateManyInput",
+        "OrderUpdateInput",
+        "OrderUncheckedUpdateInput",
+        "OrderUpdateManyMutationInput",
+        "OrderUncheckedUpdateManyInput",
+    ];
+
+    for name in write_inputs {
+        let input_type = find_input_type(&dmmf, PRISMA_NAMESPACE, name);
+
+        assert!(input_type.fields.iter().all(|field| field.name != "total"), "{}", name);
+    }
+
+    let order = find_output_type(&dmmf, PRISMA_NAMESPACE, "Order");
+
+    assert!(order.fields.iter().any(|field| field.name == "total"));
+}
@@ -1,4 +1,5 @@
 mod aggregation;
 mod cli;
-mod helpers;
+mod generated;
+pub(super) mod helpers;
 mod unsupported;
@@ -0,0 +1,207 @@
+use super::test_api::*;
+use connector::query_log::{QueryLogConfig, QueryLogTarget};
+use futures::future::join_all;
+use indoc::indoc;
+use serde_json::json;
+use std::time::Duration;
+use test_macros::test_each_connector;
+
+static ARTISTS: &str = indoc! {"
+    model Artist {
+        id        Int    @id
+        firstName String
+        lastName  String
+
+        @@unique([firstName, lastName])
+    }
+"};
+
+async fn seed(query_engine: &QueryEngine) {
+    for (id, first_name, last_name) in &[(1, "Ella", "Fitzgerald"), (2, "Nina", "Simone"), (3, "Etta", "James")] {
+        let mutation = format!(
+            r#"mutation {{ createOneArtist(data: {{ id: {}, firstName: "{}", lastName: "{}" }}) {{ id }} }}"#,
+            id, first_name, last_name
+        );
+
+        query_engine.request(mutation).await;
+    }
+}
+
+#[test_each_connector]
+async fn concurrent_find_unique_requests_are_batched(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = create_engine(api).await?;
+
+    seed(&query_engine).await;
+
+    let queries = vec![
+        "query { findUniqueArtist(where: { id: 2 }) { firstName } }",
+        "query { findUniqueArtist(where: { id: 4 }) { firstName } }",
+        "query { findUniqueArtist(where: { id: 1 }) { firstName } }",
+        "query { findUniqueArtist(where: { id: 2 }) { firstName } }",
+    ];
+
+    let responses = join_all(queries.into_iter().map(|query| query_engine.request(query))).await;
+
+    assert_eq!(
+        vec![
+            json!({ "firstName": "Nina" }),
+            json!(null),
+            json!({ "firstName": "Ella" }),
+            json!({ "firstName": "Nina" }),
+        ],
+        responses
+            .iter()
+            .map(|response| response["data"]["findUniqueArtist"].clone())
+            .collect::<Vec<_>>()
+    );
+
+    assert_single_batch(&responses);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn find_unique_requests_by_compound_uniques_are_batched(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = create_engine(api).await?;
+
+    seed(&query_engine).await;
+
+    let query = |first_name: &str, last_name: &str| {
+        let criterion = format!(r#"{{ lastName: "{}", firstName: "{}" }}"#, last_name, first_name);
+        format!(
+            "query {{ findUniqueArtist(where: {{ firstName_lastName: {} }}) {{ id }} }}",
+            criterion
+        )
+    };
+
+    let queries = vec![
+        query("Etta", "James"),
+        query("Ella", "Simone"),
+        query("Ella", "Fitzgerald"),
+    ];
+
+    let responses = join_all(queries.into_iter().map(|query| query_engine.request(query))).await;
+
+    assert_eq!(
+        vec![json!({ "id": 3 }), json!(null), json!({ "id": 1 })],
+        responses
+            .iter()
+            .map(|response| response["data"]["findUniqueArtist"].clone())
+            .collect::<Vec<_>>()
+    );
+
+    assert_single_batch(&responses);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn requests_with_different_selections_are_not_merged(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = create_engine(api).await?;
+
+    seed(&query_engine).await;
+
+    let (by_id, by_name) = futures::join!(
+        query_engine.request("query { findUniqueArtist(where: { id: 3 }) { lastName } }"),
+        query_engine.request(indoc! {r#"
+            query {
+                findUniqueArtist(where: { firstName_lastName: { firstName: "Nina", lastName: "Simone" } }) { id }
+            }
+        "#}),
+    );
+
+    assert_eq!(json!({ "lastName": "James" }), by_id["data"]["findUniqueArtist"]);
+    assert_eq!(json!({ "id": 2 }), by_name["data"]["findUniqueArtist"]);
+    assert_ne!(logged_queries(&by_id), logged_queries(&by_name));
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn batched_responses_carry_the_query_log_of_the_batch(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = create_engine(api).await?;
+
+    seed(&query_engine).await;
+
+    let (first, second) = futures::join!(
+        query_engine.request("query { findUniqueArtist(where: { id: 1 }) { firstName } }"),
+        query_engine.request("query { findUniqueArtist(where: { id: 2 }) { firstName } }"),
+    );
+
+    assert_single_batch(&[first, second]);
+
+    // An operation executed on its own gets its own query log.
+    let alone = query_engine
+        .request("query { findUniqueArtist(where: { id: 3 }) { firstName } }")
+        .await;
+
+    assert_eq!(json!({ "firstName": "Etta" }), alone["data"]["findUniqueArtist"]);
+    assert_eq!(1, logged_queries(&alone).len());
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn find_unique_requests_are_batched_in_legacy_mode(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api
+        .create_engine_with(&ARTISTS, |builder| {
+            builder
+                .legacy(true)
+                .find_unique_batch_window(Some(Duration::from_millis(20)))
+                .query_log(Some(QueryLogConfig::new(QueryLogTarget::Extensions)))
+        })
+        .await?;
+
+    for id in 1..=2 {
+        let mutation = format!(
+            r#"mutation {{ createArtist(data: {{ id: {}, firstName: "First {}", lastName: "Last" }}) {{ id }} }}"#,
+            id, id
+        );
+
+        query_engine.request(mutation).await;
+    }
+
+    let (first, second) = futures::join!(
+        query_engine.request("query { artist(where: { id: 2 }) { firstName } }"),
+        query_engine.request("query { artist(where: { id: 1 }) { firstName } }"),
+    );
+
+    assert_eq!(json!({ "firstName": "First 2" }), first["data"]["artist"]);
+    assert_eq!(json!({ "firstName": "First 1" }), second["data"]["artist"]);
+    assert_single_batch(&[first, second]);
+
+    Ok(())
+}
+
+async fn create_engine(api: &TestApi) -> anyhow::Result<QueryEngine> {
+    api.create_engine_with(&ARTISTS, |builder| {
+        builder
+            .find_unique_batch_window(Some(Duration::from_millis(20)))
+            .query_log(Some(QueryLogConfig::new(QueryLogTarget::Extensions)))
+    })
+    .await
+}
+
+/// The statements logged for the request.
+fn logged_queries(response: &serde_json::Value) -> Vec<String> {
+    response["extensions"]["queryLog"]
+        .as_array()
+        .map(|events| {
+            events
+                .iter()
+                .map(|event| event["query"].as_str().unwrap().to_owned())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Asserts that the requests were served by the same single statement.
+fn assert_single_batch(responses: &[serde_json::Value]) {
+    let first = logged_queries(&responses[0]);
+
+    assert_eq!(1, first.len());
+
+    for response in responses {
+        assert_eq!(first, logged_queries(response));
+    }
+}
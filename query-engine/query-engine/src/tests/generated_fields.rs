@@ -0,0 +1,60 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static ORDERS: &str = indoc! {r#"
+    model Order {
+        id       Int @id
+        price    Int
+        quantity Int
+        total    Int @generated("price * quantity")
+    }
+"#};
+
+#[test_each_connector(tags("postgres_12", "mysql_8", "mssql", "sqlite"))]
+async fn generated_fields_are_computed_on_create_and_update(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&ORDERS).await?;
+
+    let response = query_engine
+        .request(r#"mutation { createOneOrder(data: { id: 1, price: 3, quantity: 2 }) { id total } }"#)
+        .await;
+
+    assert_eq!(json!({ "id": 1, "total": 6 }), response["data"]["createOneOrder"]);
+
+    let response = query_engine
+        .request(r#"mutation { updateOneOrder(where: { id: 1 }, data: { quantity: 5 }) { id total } }"#)
+        .await;
+
+    assert_eq!(json!({ "id": 1, "total": 15 }), response["data"]["updateOneOrder"]);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres_12", "mysql_8", "mssql", "sqlite"))]
+async fn generated_fields_are_skipped_by_create_many(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&ORDERS).await?;
+
+    let response = query_engine
+        .request(indoc! {r#"
+            mutation {
+                createManyOrder(data: [{ id: 1, price: 3, quantity: 2 }, { id: 2, price: 4, quantity: 1 }]) {
+                    count
+                }
+            }
+        "#})
+        .await;
+
+    assert_eq!(json!({ "count": 2 }), response["data"]["createManyOrder"]);
+
+    let response = query_engine
+        .request(r#"query { findManyOrder(orderBy: { id: asc }) { id total } }"#)
+        .await;
+
+    assert_eq!(
+        json!([{ "id": 1, "total": 6 }, { "id": 2, "total": 4 }]),
+        response["data"]["findManyOrder"]
+    );
+
+    Ok(())
+}
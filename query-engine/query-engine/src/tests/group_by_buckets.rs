@@ -0,0 +1,230 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static SALES: &str = indoc! {r#"
+    model Sale {
+        id     Int      @id
+        soldAt DateTime
+        amount Int
+    }
+"#};
+
+async fn seed(query_engine: &QueryEngine) {
+    let sales = &[
+        (1, "2021-03-01T08:00:00Z", 120),
+        (2, "2021-03-01T23:30:00Z", 40),
+        (3, "2021-03-02T10:15:00Z", 310),
+        (4, "2021-03-08T09:00:00Z", 75),
+    ];
+
+    for (id, sold_at, amount) in sales {
+        let mutation = format!(
+            r#"mutation {{ createOneSale(data: {{ id: {}, soldAt: "{}", amount: {} }}) {{ id }} }}"#,
+            id, sold_at, amount
+        );
+
+        query_engine.request(mutation).await;
+    }
+}
+
+#[test_each_connector]
+async fn group_by_truncated_dates(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&SALES).await?;
+    seed(&query_engine).await;
+
+    let response = query_engine
+        .request(indoc! {r#"
+            query {
+                groupBySale(by: [soldAt], bucketBy: { soldAt: { unit: day } }, orderBy: { soldAt: asc }) {
+                    soldAt
+                    sum { amount }
+                }
+            }
+        "#})
+        .await;
+
+    assert_eq!(
+        json!([
+            { "soldAt": "2021-03-01T00:00:00+00:00", "sum": { "amount": 160 } },
+            { "soldAt": "2021-03-02T00:00:00+00:00", "sum": { "amount": 310 } },
+            { "soldAt": "2021-03-08T00:00:00+00:00", "sum": { "amount": 75 } },
+        ]),
+        response["data"]["groupBySale"]
+    );
+
+    let response = query_engine
+        .request(indoc! {r#"
+            query {
+                groupBySale(by: [soldAt], bucketBy: { soldAt: { unit: week } }, orderBy: { soldAt: desc }) {
+                    soldAt
+                    count { id }
+                }
+            }
+        "#})
+        .await;
+
+    // 2021-03-01 and 2021-03-08 are Mondays.
+    assert_eq!(
+        json!([
+            { "soldAt": "2021-03-08T00:00:00+00:00", "count": { "id": 1 } },
+            { "soldAt": "2021-03-01T00:00:00+00:00", "count": { "id": 3 } },
+        ]),
+        response["data"]["groupBySale"]
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn group_by_truncated_dates_in_a_time_zone(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&SALES).await?;
+    seed(&query_engine).await;
+
+    let response = query_engine
+        .request(indoc! {r#"
+            query {
+                groupBySale(
+                    by: [soldAt],
+                    bucketBy: { soldAt: { unit: day, timeZone: "+02:00" } },
+                    orderBy: { soldAt: asc },
+                    take: 2
+                ) {
+                    soldAt
+                    sum { amount }
+                }
+            }
+        "#})
+        .await;
+
+    // The sale at 23:30 UTC is made on the next day at 01:30 in the time zone.
+    assert_eq!(
+        json!([
+            { "soldAt": "2021-02-28T22:00:00+00:00", "sum": { "amount": 120 } },
+            { "soldAt": "2021-03-01T22:00:00+00:00", "sum": { "amount": 350 } },
+        ]),
+        response["data"]["groupBySale"]
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn group_by_truncated_dates_in_a_named_time_zone(api: &TestApi) -> anyhow::Result<()> {
+    let with_time_zone = indoc! {r#"
+        generator client {
+            provider        = "prisma-client-js"
+            previewFeatures = ["nativeTypes"]
+        }
+
+        model Sale {
+            id     Int      @id
+            soldAt DateTime @my_db.Timestamptz(3)
+            amount Int
+        }
+    "#};
+
+    for datamodel in &[SALES, with_time_zone] {
+        let query_engine = api.create_engine(datamodel).await?;
+        seed(&query_engine).await;
+
+        let response = query_engine
+            .request(indoc! {r#"
+                query {
+                    groupBySale(
+                        by: [soldAt],
+                        bucketBy: { soldAt: { unit: day, timeZone: "Europe/Berlin" } },
+                        orderBy: { soldAt: asc },
+                        take: 2
+                    ) {
+                        soldAt
+                        sum { amount }
+                    }
+                }
+            "#})
+            .await;
+
+        // Berlin is an hour ahead of UTC in March 2021.
+        assert_eq!(
+            json!([
+                { "soldAt": "2021-02-28T23:00:00+00:00", "sum": { "amount": 120 } },
+                { "soldAt": "2021-03-01T23:00:00+00:00", "sum": { "amount": 350 } },
+            ]),
+            response["data"]["groupBySale"]
+        );
+    }
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn unknown_time_zones_are_rejected_on_mysql(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&SALES).await?;
+    seed(&query_engine).await;
+
+    let response = query_engine
+        .request(indoc! {r#"
+            query {
+                groupBySale(by: [soldAt], bucketBy: { soldAt: { unit: day, timeZone: "Mars/Olympus_Mons" } }) {
+                    soldAt
+                }
+            }
+        "#})
+        .await;
+
+    let message = response["errors"][0]["error"].as_str().unwrap();
+    assert!(
+        message.contains("The time zone `Mars/Olympus_Mons` is unknown"),
+        "{}",
+        message
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn group_by_numeric_buckets_with_having(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&SALES).await?;
+    seed(&query_engine).await;
+
+    let response = query_engine
+        .request(indoc! {r#"
+            query {
+                groupBySale(
+                    by: [amount],
+                    bucketBy: { amount: { width: 100 } },
+                    having: { amount: { lt: 300 } },
+                    orderBy: { amount: asc }
+                ) {
+                    amount
+                    count { id }
+                }
+            }
+        "#})
+        .await;
+
+    assert_eq!(
+        json!([
+            { "amount": 0, "count": { "id": 2 } },
+            { "amount": 100, "count": { "id": 1 } },
+        ]),
+        response["data"]["groupBySale"]
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn bucketed_fields_must_be_grouped_by(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&SALES).await?;
+
+    let response = query_engine
+        .request(r#"query { groupBySale(by: [id], bucketBy: { amount: { width: 10 } }) { id } }"#)
+        .await;
+
+    let message = response["errors"][0]["error"].as_str().unwrap();
+    assert!(message.contains("Missing fields: amount"), "{}", message);
+
+    Ok(())
+}
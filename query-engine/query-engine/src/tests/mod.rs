@@ -1,4 +1,19 @@
+mod aggregate_functions;
+mod dates;
+mod decimals;
+mod distinct;
 mod dmmf;
 mod errors;
 mod execute_raw;
+mod find_unique_batching;
+mod generated_fields;
+mod group_by_buckets;
+mod preview;
+mod query_log;
+mod read_replicas;
+mod relation_count_filter;
+mod relation_load;
+mod returning_writes;
+mod soft_delete;
 mod test_api;
+mod unsigned_bigints;
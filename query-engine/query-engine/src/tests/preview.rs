@@ -0,0 +1,98 @@
+use super::test_api::*;
+use indoc::indoc;
+use test_macros::test_each_connector;
+
+static TODOS: &str = indoc! {"
+    model Todo {
+        id    Int    @id
+        title String
+        done  Boolean
+    }
+"};
+
+#[test_each_connector]
+async fn previewing_a_read_renders_its_statement(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODOS).await?;
+
+    let preview = query_engine
+        .preview("query { findManyTodo(where: { done: false }) { id title } }", false)
+        .await?;
+
+    assert_eq!(1, preview.statements.len());
+
+    let statement = &preview.statements[0];
+
+    assert_eq!("findManyTodo", statement.query);
+    assert!(statement.sql.starts_with("SELECT"));
+    assert!(statement.sql.contains("Todo"));
+    assert!(statement.plan.is_none());
+    assert!(!preview.query_graph.is_empty());
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn previewing_a_unique_read_limits_it_to_one_row(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODOS).await?;
+
+    let preview = query_engine
+        .preview("query { findUniqueTodo(where: { id: 1 }) { title } }", false)
+        .await?;
+
+    assert_eq!(1, preview.statements.len());
+
+    let sql = &preview.statements[0].sql;
+
+    assert!(sql.contains("LIMIT") || sql.contains("FETCH NEXT"), "{}", sql);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn previewing_a_distinct_read_renders_distinct_on_on_postgres(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODOS).await?;
+
+    let preview = query_engine
+        .preview("query { findManyTodo(distinct: [done]) { id } }", false)
+        .await?;
+
+    assert_eq!(1, preview.statements.len());
+
+    let sql = &preview.statements[0].sql;
+
+    assert!(sql.contains("DISTINCT ON"), "{}", sql);
+    assert!(!sql.contains("ROW_NUMBER"), "{}", sql);
+
+    Ok(())
+}
+
+#[test_each_connector(ignore("mssql"))]
+async fn previewing_with_explain_loads_the_query_plan(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODOS).await?;
+
+    let preview = query_engine
+        .preview("query { findUniqueTodo(where: { id: 1 }) { title } }", true)
+        .await?;
+
+    assert_eq!(1, preview.statements.len());
+    assert!(preview.statements[0].plan.is_some());
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn previewing_does_not_run_writes(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODOS).await?;
+
+    query_engine
+        .preview(
+            r#"mutation { createOneTodo(data: { id: 1, title: "Preview", done: false }) { id } }"#,
+            true,
+        )
+        .await?;
+
+    let response = query_engine.request("query { findManyTodo { id } }").await;
+    assert_eq!(serde_json::json!([]), response["data"]["findManyTodo"]);
+
+    Ok(())
+}
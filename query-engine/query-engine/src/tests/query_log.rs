@@ -0,0 +1,73 @@
+use super::test_api::*;
+use connector::query_log::{QueryLogConfig, QueryLogTarget};
+use indoc::indoc;
+use serde_json::json;
+use std::time::Duration;
+use test_macros::test_each_connector;
+
+static TODO: &str = indoc! {"
+    model Todo {
+        id String @id
+        title String
+    }
+"};
+
+#[test_each_connector]
+async fn query_log_is_returned_in_extensions(api: &TestApi) -> anyhow::Result<()> {
+    let config = QueryLogConfig::new(QueryLogTarget::Extensions);
+    let query_engine = api.create_engine_with_query_log(&TODO, Some(config)).await?;
+
+    query_engine
+        .request(r#"mutation { createOneTodo(data: { id: "id1", title: "secret" }) { id } }"#)
+        .await;
+
+    let response = query_engine.request("query { findManyTodo { id } }").await;
+
+    assert_eq!(json!([{ "id": "id1" }]), response["data"]["findManyTodo"]);
+
+    let events = response["extensions"]["queryLog"].as_array().unwrap();
+
+    assert_eq!(1, events.len());
+    assert_eq!(json!("findManyTodo"), events[0]["operation"]);
+    assert_eq!(json!(1), events[0]["affectedRows"]);
+    assert!(events[0]["query"].as_str().unwrap().contains("Todo"));
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn query_log_parameters_can_be_redacted(api: &TestApi) -> anyhow::Result<()> {
+    let mut config = QueryLogConfig::new(QueryLogTarget::Extensions);
+    config.redact_parameters = true;
+
+    let query_engine = api.create_engine_with_query_log(&TODO, Some(config)).await?;
+
+    let response = query_engine
+        .request(r#"mutation { createOneTodo(data: { id: "id1", title: "secret" }) { id } }"#)
+        .await;
+
+    let events = response["extensions"]["queryLog"].as_array().unwrap();
+    let insert = events
+        .iter()
+        .find(|event| event["query"].as_str().unwrap().starts_with("INSERT"));
+    let params = insert.unwrap()["params"].as_array().unwrap();
+
+    assert!(!params.is_empty());
+    assert!(params.iter().all(|param| param == &json!("[REDACTED]")));
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn query_log_skips_statements_below_the_slow_query_threshold(api: &TestApi) -> anyhow::Result<()> {
+    let mut config = QueryLogConfig::new(QueryLogTarget::Extensions);
+    config.slow_query_threshold = Some(Duration::from_secs(3600));
+
+    let query_engine = api.create_engine_with_query_log(&TODO, Some(config)).await?;
+    let response = query_engine.request("query { findManyTodo { id } }").await;
+
+    assert_eq!(json!([]), response["data"]["findManyTodo"]);
+    assert!(response.get("extensions").is_none());
+
+    Ok(())
+}
@@ -0,0 +1,146 @@
+use super::dmmf::helpers::get_query_schema;
+use connector::{
+    error::{ConnectorError, ErrorKind},
+    Connection, Connector, RoutingConnector,
+};
+use query_core::{CoreError, InterpretingExecutor, Operation, QueryExecutor, QuerySchemaRef, Selection};
+use std::sync::Arc;
+
+/// A connector that can't connect, and tells which database the connection
+/// was requested from in its error.
+struct FakeDatabase(&'static str);
+
+#[async_trait::async_trait]
+impl Connector for FakeDatabase {
+    async fn get_connection(&self) -> connector::Result<Box<dyn Connection>> {
+        Err(ConnectorError::from_kind(ErrorKind::ConnectionError(anyhow::anyhow!(
+            self.0
+        ))))
+    }
+
+    fn name(&self) -> String {
+        self.0.to_owned()
+    }
+}
+
+fn routed_to(result: connector::Result<Box<dyn Connection>>) -> String {
+    match result {
+        Err(ConnectorError {
+            kind: ErrorKind::ConnectionError(database),
+            ..
+        }) => database.to_string(),
+        Err(err) => panic!("Unexpected error: {}", err),
+        Ok(_) => panic!("The fake database returned a connection."),
+    }
+}
+
+fn executed_on(result: query_core::Result<impl std::fmt::Debug>) -> String {
+    match result {
+        Err(CoreError::ConnectorError(err)) => routed_to(Err(err)),
+        other => panic!("Expected a connection error, got {:?}", other),
+    }
+}
+
+fn executor(force_transactions: bool) -> InterpretingExecutor<RoutingConnector<FakeDatabase>> {
+    let connector = RoutingConnector::new(FakeDatabase("primary"), vec![FakeDatabase("replica")]);
+
+    InterpretingExecutor::new(connector, force_transactions)
+}
+
+fn query_schema() -> QuerySchemaRef {
+    let (query_schema, _) = get_query_schema(
+        r#"
+        model Cat {
+            id Int @id
+        }
+    "#,
+    );
+
+    Arc::new(query_schema)
+}
+
+fn find_many_cats() -> Operation {
+    let mut selection = Selection::builder("findManyCat");
+    selection.push_nested_selection(Selection::builder("id").build());
+
+    Operation::Read(selection.build())
+}
+
+fn create_cat() -> Operation {
+    let mut selection = Selection::builder("createOneCat");
+    selection.push_nested_selection(Selection::builder("id").build());
+
+    Operation::Write(selection.build())
+}
+
+#[tokio::test]
+async fn routing_connector_sends_reads_to_replicas_in_turn() {
+    let connector = RoutingConnector::new(
+        FakeDatabase("primary"),
+        vec![FakeDatabase("replica-1"), FakeDatabase("replica-2")],
+    );
+
+    assert_eq!(routed_to(connector.get_read_connection().await), "replica-1");
+    assert_eq!(routed_to(connector.get_read_connection().await), "replica-2");
+    assert_eq!(routed_to(connector.get_read_connection().await), "replica-1");
+    assert_eq!(routed_to(connector.get_connection().await), "primary");
+}
+
+#[tokio::test]
+async fn routing_connector_without_replicas_reads_from_the_primary() {
+    let connector = RoutingConnector::new(FakeDatabase("primary"), Vec::new());
+
+    assert_eq!(routed_to(connector.get_read_connection().await), "primary");
+}
+
+#[tokio::test]
+async fn reads_are_executed_on_a_replica() {
+    let result = executor(false).execute(find_many_cats(), false, query_schema()).await;
+
+    assert_eq!(executed_on(result), "replica");
+}
+
+#[tokio::test]
+async fn writes_are_executed_on_the_primary() {
+    let result = executor(false).execute(create_cat(), false, query_schema()).await;
+
+    assert_eq!(executed_on(result), "primary");
+}
+
+#[tokio::test]
+async fn reads_forced_to_the_primary_are_executed_on_the_primary() {
+    let result = executor(false).execute(find_many_cats(), true, query_schema()).await;
+
+    assert_eq!(executed_on(result), "primary");
+}
+
+#[tokio::test]
+async fn reads_in_forced_transactions_are_executed_on_the_primary() {
+    let result = executor(true).execute(find_many_cats(), false, query_schema()).await;
+
+    assert_eq!(executed_on(result), "primary");
+}
+
+#[tokio::test]
+async fn transactional_batches_are_executed_on_the_primary() {
+    let result = executor(false)
+        .execute_batch(vec![find_many_cats(), find_many_cats()], true, false, query_schema())
+        .await;
+
+    assert_eq!(executed_on(result), "primary");
+}
+
+#[tokio::test]
+async fn non_transactional_batches_of_reads_are_executed_on_a_replica() {
+    let executor = executor(false);
+
+    let result = executor
+        .execute_batch(vec![find_many_cats()], false, false, query_schema())
+        .await;
+    assert_eq!(executed_on(result), "replica");
+
+    let result = executor
+        .execute_batch(vec![find_many_cats()], false, true, query_schema())
+        .await;
+    assert_eq!(executed_on(result), "primary");
+}
@@ -0,0 +1,82 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static BLOG: &str = indoc! {r#"
+    model User {
+        id    Int    @id
+        posts Post[]
+        tags  Tag[]
+    }
+
+    model Post {
+        id       Int  @id
+        authorId Int
+        author   User @relation(fields: [authorId], references: [id])
+    }
+
+    model Tag {
+        id    Int    @id
+        users User[]
+    }
+"#};
+
+async fn seed(query_engine: &QueryEngine) {
+    let users = &[
+        r#"{ id: 1, posts: { create: [{ id: 1 }, { id: 2 }, { id: 3 }] }, tags: { create: [{ id: 1 }] } }"#,
+        r#"{ id: 2, posts: { create: [{ id: 4 }] }, tags: { connect: [{ id: 1 }] } }"#,
+        r#"{ id: 3 }"#,
+    ];
+
+    for user in users {
+        let mutation = format!("mutation {{ createOneUser(data: {}) {{ id }} }}", user);
+        query_engine.request(mutation).await;
+    }
+}
+
+#[test_each_connector]
+async fn relation_count_filters_compare_the_number_of_related_records(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&BLOG).await?;
+    seed(&query_engine).await;
+
+    let response = query_engine
+        .request(r#"query { findManyUser(where: { posts: { _count: { gt: 1 } } }) { id } }"#)
+        .await;
+
+    assert_eq!(json!([{ "id": 1 }]), response["data"]["findManyUser"]);
+
+    let response = query_engine
+        .request(r#"query { findManyUser(where: { posts: { _count: 1 } }) { id } }"#)
+        .await;
+
+    assert_eq!(json!([{ "id": 2 }]), response["data"]["findManyUser"]);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn relation_count_filters_include_records_without_related_records(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&BLOG).await?;
+    seed(&query_engine).await;
+
+    let response = query_engine
+        .request(indoc! {r#"
+            query {
+                findManyUser(where: { posts: { _count: { lte: 1 } } }, orderBy: { id: asc }) {
+                    id
+                }
+            }
+        "#})
+        .await;
+
+    assert_eq!(json!([{ "id": 2 }, { "id": 3 }]), response["data"]["findManyUser"]);
+
+    let response = query_engine
+        .request(r#"query { findManyUser(where: { tags: { _count: { not: { gte: 1 } } } }) { id } }"#)
+        .await;
+
+    assert_eq!(json!([{ "id": 3 }]), response["data"]["findManyUser"]);
+
+    Ok(())
+}
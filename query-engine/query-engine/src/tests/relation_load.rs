@@ -0,0 +1,145 @@
+use super::test_api::*;
+use connector::query_log::{QueryLogConfig, QueryLogTarget};
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static BLOG: &str = indoc! {"
+    model User {
+        id    Int    @id
+        name  String
+        posts Post[]
+    }
+
+    model Post {
+        id       Int    @id
+        title    String
+        authorId Int
+        author   User   @relation(fields: [authorId], references: [id])
+    }
+"};
+
+async fn seed(query_engine: &QueryEngine) {
+    query_engine
+        .request(indoc! {r#"
+            mutation {
+                createOneUser(data: {
+                    id: 1,
+                    name: "Alice",
+                    posts: { create: [{ id: 1, title: "first" }, { id: 2, title: "second" }] }
+                }) { id }
+            }
+        "#})
+        .await;
+
+    query_engine
+        .request(r#"mutation { createOneUser(data: { id: 2, name: "Bob" }) { id } }"#)
+        .await;
+}
+
+#[test_each_connector(tags("postgres", "mysql_8"))]
+async fn join_strategy_loads_nested_relations(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&BLOG).await?;
+    seed(&query_engine).await;
+
+    let query = indoc! {r#"
+        query {
+            findManyUser(orderBy: { id: asc }, relationLoadStrategy: join) {
+                name
+                posts(orderBy: { id: desc }) { title }
+            }
+        }
+    "#};
+
+    let response = query_engine.request(query).await;
+
+    assert_eq!(
+        json!([
+            { "name": "Alice", "posts": [
+                { "title": "second" },
+                { "title": "first" },
+            ]},
+            { "name": "Bob", "posts": [] },
+        ]),
+        response["data"]["findManyUser"]
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mysql_8"))]
+async fn join_strategy_uses_a_single_statement(api: &TestApi) -> anyhow::Result<()> {
+    let config = QueryLogConfig::new(QueryLogTarget::Extensions);
+    let query_engine = api.create_engine_with_query_log(&BLOG, Some(config)).await?;
+    seed(&query_engine).await;
+
+    let query = indoc! {r#"
+        query {
+            findUniqueUser(where: { id: 1 }, relationLoadStrategy: join) {
+                posts(orderBy: { id: asc }) { title }
+            }
+        }
+    "#};
+    let response = query_engine.request(query).await;
+
+    assert_eq!(
+        json!({ "posts": [{ "title": "first" }, { "title": "second" }] }),
+        response["data"]["findUniqueUser"]
+    );
+
+    let events = response["extensions"]["queryLog"].as_array().unwrap();
+    assert_eq!(1, events.len());
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"), ignore("mysql_8"))]
+async fn join_strategy_falls_back_to_query_loading_on_older_mysql_versions(api: &TestApi) -> anyhow::Result<()> {
+    let config = QueryLogConfig::new(QueryLogTarget::Extensions);
+    let query_engine = api.create_engine_with_query_log(&BLOG, Some(config)).await?;
+    seed(&query_engine).await;
+
+    let query = indoc! {r#"
+        query {
+            findUniqueUser(where: { id: 1 }, relationLoadStrategy: join) {
+                posts(orderBy: { id: asc }) { title }
+            }
+        }
+    "#};
+    let response = query_engine.request(query).await;
+
+    assert_eq!(
+        json!({ "posts": [{ "title": "first" }, { "title": "second" }] }),
+        response["data"]["findUniqueUser"]
+    );
+
+    let events = response["extensions"]["queryLog"].as_array().unwrap();
+    assert!(events.len() > 1);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mysql_8"))]
+async fn previewing_the_join_strategy_renders_a_single_statement(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&BLOG).await?;
+
+    let query = indoc! {r#"
+        query {
+            findManyUser(relationLoadStrategy: join) {
+                name
+                posts { title }
+            }
+        }
+    "#};
+
+    let preview = query_engine.preview(query, false).await?;
+
+    assert_eq!(1, preview.statements.len());
+
+    let statement = &preview.statements[0];
+
+    assert_eq!("findManyUser", statement.query);
+    assert!(statement.sql.contains("Post"), "{}", statement.sql);
+
+    Ok(())
+}
@@ -0,0 +1,185 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static TODOS: &str = indoc! {r#"
+    model Todo {
+        id     Int     @id
+        title  String
+        done   Boolean @default(false)
+        listId Int
+        list   List    @relation(fields: [listId], references: [id])
+    }
+
+    model List {
+        id    Int    @id
+        name  String
+        todos Todo[]
+    }
+"#};
+
+async fn seed(query_engine: &QueryEngine) {
+    query_engine
+        .request(indoc! {r#"
+            mutation {
+                createOneList(data: {
+                    id: 1,
+                    name: "chores",
+                    todos: {
+                        create: [
+                            { id: 1, title: "dishes" },
+                            { id: 2, title: "laundry" },
+                            { id: 3, title: "groceries", done: true }
+                        ]
+                    }
+                }) { id }
+            }
+        "#})
+        .await;
+}
+
+#[test_each_connector]
+async fn update_many_and_return_returns_the_updated_records(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODOS).await?;
+    seed(&query_engine).await;
+
+    let response = query_engine
+        .request(indoc! {r#"
+            mutation {
+                updateManyTodoAndReturn(where: { done: false }, data: { done: true }) {
+                    id
+                    done
+                    list { name }
+                }
+            }
+        "#})
+        .await;
+
+    let mut todos = response["data"]["updateManyTodoAndReturn"].as_array().unwrap().clone();
+    todos.sort_by_key(|todo| todo["id"].as_i64());
+
+    assert_eq!(
+        vec![
+            json!({ "id": 1, "done": true, "list": { "name": "chores" } }),
+            json!({ "id": 2, "done": true, "list": { "name": "chores" } }),
+        ],
+        todos
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn delete_many_and_return_returns_the_deleted_records(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODOS).await?;
+    seed(&query_engine).await;
+
+    let response = query_engine
+        .request(r#"mutation { deleteManyTodoAndReturn(where: { done: true }) { id title } }"#)
+        .await;
+
+    assert_eq!(
+        json!([{ "id": 3, "title": "groceries" }]),
+        response["data"]["deleteManyTodoAndReturn"]
+    );
+
+    let response = query_engine.request(r#"query { findManyTodo { id } }"#).await;
+
+    assert_eq!(json!([{ "id": 1 }, { "id": 2 }]), response["data"]["findManyTodo"]);
+
+    Ok(())
+}
+
+#[test_each_connector(ignore("sqlite"))]
+async fn create_many_and_return_returns_the_created_records(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODOS).await?;
+    seed(&query_engine).await;
+
+    let response = query_engine
+        .request(indoc! {r#"
+            mutation {
+                createManyTodoAndReturn(data: [
+                    { id: 4, title: "vacuum", listId: 1 },
+                    { id: 5, title: "windows", listId: 1 }
+                ]) {
+                    id
+                    title
+                    done
+                }
+            }
+        "#})
+        .await;
+
+    let mut todos = response["data"]["createManyTodoAndReturn"].as_array().unwrap().clone();
+    todos.sort_by_key(|todo| todo["id"].as_i64());
+
+    assert_eq!(
+        vec![
+            json!({ "id": 4, "title": "vacuum", "done": false }),
+            json!({ "id": 5, "title": "windows", "done": false }),
+        ],
+        todos
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mysql"))]
+async fn create_many_and_return_skips_duplicates(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODOS).await?;
+    seed(&query_engine).await;
+
+    let response = query_engine
+        .request(indoc! {r#"
+            mutation {
+                createManyTodoAndReturn(
+                    data: [
+                        { id: 1, title: "not dishes", listId: 1 },
+                        { id: 4, title: "vacuum", listId: 1 }
+                    ],
+                    skipDuplicates: true
+                ) {
+                    id
+                    title
+                }
+            }
+        "#})
+        .await;
+
+    assert_eq!(
+        json!([{ "id": 4, "title": "vacuum" }]),
+        response["data"]["createManyTodoAndReturn"]
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(ignore("sqlite"))]
+async fn create_many_and_return_inserts_records_with_only_default_values(api: &TestApi) -> anyhow::Result<()> {
+    let datamodel = indoc! {r#"
+        model Counter {
+            id    Int @id @default(autoincrement())
+            count Int @default(0)
+        }
+    "#};
+
+    let query_engine = api.create_engine(datamodel).await?;
+
+    let response = query_engine
+        .request("mutation { createManyCounterAndReturn(data: [{}, {}]) { id count } }")
+        .await;
+
+    let mut counters = response["data"]["createManyCounterAndReturn"]
+        .as_array()
+        .unwrap()
+        .clone();
+    counters.sort_by_key(|counter| counter["id"].as_i64());
+
+    assert_eq!(
+        vec![json!({ "id": 1, "count": 0 }), json!({ "id": 2, "count": 0 })],
+        counters
+    );
+
+    Ok(())
+}
@@ -0,0 +1,200 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static POSTS: &str = indoc! {r#"
+    model User {
+        id    Int    @id
+        name  String
+        posts Post[]
+    }
+
+    model Post {
+        id        Int       @id
+        title     String
+        authorId  Int
+        author    User      @relation(fields: [authorId], references: [id])
+        deletedAt DateTime?
+
+        @@softDelete(field: deletedAt)
+    }
+"#};
+
+async fn seed(query_engine: &QueryEngine) {
+    query_engine
+        .request(indoc! {r#"
+            mutation {
+                createOneUser(data: {
+                    id: 1,
+                    name: "ada",
+                    posts: {
+                        create: [
+                            { id: 1, title: "first" },
+                            { id: 2, title: "second" },
+                            { id: 3, title: "third" }
+                        ]
+                    }
+                }) { id }
+            }
+        "#})
+        .await;
+}
+
+#[test_each_connector]
+async fn deleted_records_are_kept_but_not_read(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&POSTS).await?;
+    seed(&query_engine).await;
+
+    query_engine
+        .request(r#"mutation { deleteOnePost(where: { id: 1 }) { id } }"#)
+        .await;
+
+    let response = query_engine
+        .request(r#"mutation { deleteManyPost(where: { title: { startsWith: "s" } }) { count } }"#)
+        .await;
+
+    assert_eq!(json!(1), response["data"]["deleteManyPost"]["count"]);
+
+    let response = query_engine
+        .request(r#"query { findManyPost(orderBy: { id: asc }) { id } }"#)
+        .await;
+
+    assert_eq!(json!([{ "id": 3 }]), response["data"]["findManyPost"]);
+
+    let response = query_engine
+        .request(r#"query { findUniquePost(where: { id: 1 }) { id } }"#)
+        .await;
+
+    assert_eq!(json!(null), response["data"]["findUniquePost"]);
+
+    let response = query_engine
+        .request(r#"query { aggregatePost { count { _all } } }"#)
+        .await;
+
+    assert_eq!(json!(1), response["data"]["aggregatePost"]["count"]["_all"]);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn with_deleted_includes_deleted_records(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&POSTS).await?;
+    seed(&query_engine).await;
+
+    query_engine
+        .request(r#"mutation { deleteOnePost(where: { id: 1 }) { id } }"#)
+        .await;
+
+    let response = query_engine
+        .request(r#"query { findManyPost(withDeleted: true, orderBy: { id: asc }) { id } }"#)
+        .await;
+
+    assert_eq!(
+        json!([{ "id": 1 }, { "id": 2 }, { "id": 3 }]),
+        response["data"]["findManyPost"]
+    );
+
+    let response = query_engine
+        .request(r#"query { findUniquePost(where: { id: 1 }, withDeleted: true) { id } }"#)
+        .await;
+
+    assert_eq!(json!({ "id": 1 }), response["data"]["findUniquePost"]);
+
+    let response = query_engine
+        .request(r#"query { findUniquePost(where: { id: 1 }, withDeleted: true) { deletedAt } }"#)
+        .await;
+
+    assert!(response["data"]["findUniquePost"]["deletedAt"].is_string());
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn deleting_a_deleted_record_fails(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&POSTS).await?;
+    seed(&query_engine).await;
+
+    query_engine
+        .request(r#"mutation { deleteOnePost(where: { id: 1 }) { id } }"#)
+        .await;
+
+    let response = query_engine
+        .request(r#"mutation { deleteOnePost(where: { id: 1 }) { id } }"#)
+        .await;
+
+    let message = response["errors"][0]["error"].as_str().unwrap();
+    assert!(message.contains("Record to delete does not exist."));
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn nested_reads_and_relation_filters_exclude_deleted_records(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&POSTS).await?;
+    seed(&query_engine).await;
+
+    query_engine
+        .request(r#"mutation { deleteManyPost(where: { id: { in: [1, 2] } }) { count } }"#)
+        .await;
+
+    let response = query_engine
+        .request(r#"query { findManyUser { posts(orderBy: { id: asc }) { id } } }"#)
+        .await;
+
+    assert_eq!(json!([{ "posts": [{ "id": 3 }] }]), response["data"]["findManyUser"]);
+
+    let response = query_engine
+        .request(r#"query { findManyUser(where: { posts: { some: { title: "first" } } }) { id } }"#)
+        .await;
+
+    assert_eq!(json!([]), response["data"]["findManyUser"]);
+
+    let response = query_engine
+        .request(r#"query { findManyUser(where: { posts: { every: { title: "third" } } }) { id } }"#)
+        .await;
+
+    assert_eq!(json!([{ "id": 1 }]), response["data"]["findManyUser"]);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn deleted_records_are_not_updated(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&POSTS).await?;
+    seed(&query_engine).await;
+
+    query_engine
+        .request(r#"mutation { deleteOnePost(where: { id: 1 }) { id } }"#)
+        .await;
+
+    let response = query_engine
+        .request(r#"mutation { updateOnePost(where: { id: 1 }, data: { title: "updated" }) { id } }"#)
+        .await;
+
+    let message = response["errors"][0]["error"].as_str().unwrap();
+    assert!(message.contains("Record to update not found."), "{}", message);
+
+    // The upsert doesn't find the deleted record, so it tries to create it again.
+    let response = query_engine
+        .request(indoc! {r#"
+            mutation {
+                upsertOnePost(
+                    where: { id: 1 },
+                    create: { id: 1, title: "created", author: { connect: { id: 1 } } },
+                    update: { title: "upserted" }
+                ) { id }
+            }
+        "#})
+        .await;
+
+    assert!(response["errors"][0]["error"].is_string(), "{}", response);
+
+    let response = query_engine
+        .request(r#"query { findUniquePost(where: { id: 1 }, withDeleted: true) { title } }"#)
+        .await;
+
+    assert_eq!(json!({ "title": "first" }), response["data"]["findUniquePost"]);
+
+    Ok(())
+}
@@ -1,8 +1,13 @@
 use crate::{
-    context::PrismaContext,
-    request_handlers::{graphql, GraphQlBody, SingleQuery},
-    PrismaResponse,
+    context::{ContextBuilder, PrismaContext},
+    request_handlers::{
+        graphql,
+        preview::{self, QueryPreview},
+        GraphQlBody, SingleQuery,
+    },
+    PrismaResponse, PrismaResult,
 };
+use connector::query_log::QueryLogConfig;
 use enumflags2::BitFlags;
 use migration_core::{
     api::{GenericApi, MigrationApi},
@@ -13,7 +18,7 @@ use quaint::{
     connector::ConnectionInfo,
     visitor::{self, Visitor},
 };
-use sql_migration_connector::SqlMigrationConnector;
+use sql_migration_connector::{MigrationsTable, SqlMigrationConnector};
 use std::sync::Arc;
 use test_setup::*;
 
@@ -30,11 +35,16 @@ impl QueryEngine {
     pub async fn request(&self, body: impl Into<SingleQuery>) -> serde_json::Value {
         let body = GraphQlBody::Single(body.into());
         let cx = self.context.clone();
-        match graphql::handle(body, cx).await {
+        match graphql::handle(body, cx, false).await {
             PrismaResponse::Single(response) => serde_json::to_value(response).unwrap(),
             _ => unreachable!(),
         }
     }
+
+    pub async fn preview(&self, body: impl Into<SingleQuery>, explain: bool) -> PrismaResult<QueryPreview> {
+        let body = GraphQlBody::Single(body.into());
+        preview::preview(body, &self.context, explain).await
+    }
 }
 
 pub struct TestApi {
@@ -45,6 +55,23 @@ pub struct TestApi {
 
 impl TestApi {
     pub async fn create_engine(&self, datamodel: &str) -> anyhow::Result<QueryEngine> {
+        self.create_engine_with_query_log(datamodel, None).await
+    }
+
+    pub async fn create_engine_with_query_log(
+        &self,
+        datamodel: &str,
+        query_log: Option<QueryLogConfig>,
+    ) -> anyhow::Result<QueryEngine> {
+        self.create_engine_with(datamodel, |builder| builder.query_log(query_log))
+            .await
+    }
+
+    pub async fn create_engine_with(
+        &self,
+        datamodel: &str,
+        configure: impl FnOnce(ContextBuilder) -> ContextBuilder,
+    ) -> anyhow::Result<QueryEngine> {
         feature_flags::initialize(&[String::from("all")]).unwrap();
 
         let datamodel_string = format!("{}\n\n{}", self.config, datamodel);
@@ -56,14 +83,12 @@ impl TestApi {
                 schema: datamodel_string,
                 force: true,
                 assume_empty: true,
+                enum_value_renames: Vec::new(),
             })
             .await?;
 
-        let context = PrismaContext::builder(config.subject, dml)
-            .enable_raw_queries(true)
-            .build()
-            .await
-            .unwrap();
+        let builder = PrismaContext::builder(config.subject, dml).enable_raw_queries(true);
+        let context = configure(builder).build().await.unwrap();
 
         Ok(QueryEngine {
             context: Arc::new(context),
@@ -278,21 +303,27 @@ pub async fn mssql_2019_test_api(args: TestAPIArgs) -> TestApi {
 
 pub(super) async fn mysql_migration_connector(url_str: &str) -> SqlMigrationConnector {
     create_mysql_database(&url_str.parse().unwrap()).await.unwrap();
-    SqlMigrationConnector::new(url_str, BitFlags::all()).await.unwrap()
+    SqlMigrationConnector::new(url_str, BitFlags::all(), MigrationsTable::default())
+        .await
+        .unwrap()
 }
 
 pub(super) async fn mssql_migration_connector(url_str: &str) -> SqlMigrationConnector {
     create_mssql_database(url_str).await.unwrap();
-    SqlMigrationConnector::new(url_str, BitFlags::all()).await.unwrap()
+    SqlMigrationConnector::new(url_str, BitFlags::all(), MigrationsTable::default())
+        .await
+        .unwrap()
 }
 
 pub(super) async fn postgres_migration_connector(url_str: &str) -> SqlMigrationConnector {
     create_postgres_database(&url_str.parse().unwrap()).await.unwrap();
-    SqlMigrationConnector::new(url_str, BitFlags::all()).await.unwrap()
+    SqlMigrationConnector::new(url_str, BitFlags::all(), MigrationsTable::default())
+        .await
+        .unwrap()
 }
 
 pub(super) async fn sqlite_migration_connector(db_name: &str) -> SqlMigrationConnector {
-    SqlMigrationConnector::new(&sqlite_test_url(db_name), BitFlags::all())
+    SqlMigrationConnector::new(&sqlite_test_url(db_name), BitFlags::all(), MigrationsTable::default())
         .await
         .unwrap()
 }
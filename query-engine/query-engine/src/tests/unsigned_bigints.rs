@@ -0,0 +1,76 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static EVENTS: &str = indoc! {r#"
+    generator client {
+        provider        = "prisma-client-js"
+        previewFeatures = ["nativeTypes"]
+    }
+
+    model Event {
+        id        BigInt @id @my_db.UnsignedBigInt
+        sessionId BigInt @my_db.UnsignedBigInt
+    }
+"#};
+
+#[test_each_connector(tags("mysql"))]
+async fn unsigned_bigints_above_i64_max_round_trip(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&EVENTS).await?;
+
+    let response = query_engine
+        .request(indoc! {r#"
+            mutation {
+                createOneEvent(data: { id: "18446744073709551615", sessionId: "9223372036854775808" }) {
+                    id
+                    sessionId
+                }
+            }
+        "#})
+        .await;
+
+    assert_eq!(
+        json!({ "id": "18446744073709551615", "sessionId": "9223372036854775808" }),
+        response["data"]["createOneEvent"]
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn unsigned_bigints_above_i64_max_can_be_filtered(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&EVENTS).await?;
+
+    let events = &[
+        ("1", "9223372036854775807"),
+        ("2", "9223372036854775808"),
+        ("3", "18446744073709551615"),
+    ];
+
+    for (id, session_id) in events {
+        let mutation = format!(
+            r#"mutation {{ createOneEvent(data: {{ id: "{}", sessionId: "{}" }}) {{ id }} }}"#,
+            id, session_id
+        );
+
+        query_engine.request(mutation).await;
+    }
+
+    let response = query_engine
+        .request(r#"query { findManyEvent(where: { sessionId: { gt: "9223372036854775807" } }) { id } }"#)
+        .await;
+
+    assert_eq!(json!([{ "id": "2" }, { "id": "3" }]), response["data"]["findManyEvent"]);
+
+    let response = query_engine
+        .request(r#"query { findUniqueEvent(where: { id: "18446744073709551615" }) { sessionId } }"#)
+        .await;
+
+    assert_eq!(
+        json!({ "sessionId": "18446744073709551615" }),
+        response["data"]["findUniqueEvent"]
+    );
+
+    Ok(())
+}